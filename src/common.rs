@@ -3,6 +3,10 @@ use crate::model::property::Duration;
 use std::cmp::Ordering;
 use std::ops::{Add, Sub};
 
+mod calendar_address;
+
+pub use calendar_address::CalendarAddress;
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub enum CalendarUserType {
     #[default]
@@ -96,6 +100,52 @@ impl LanguageTag {
     }
 }
 
+/// Display mode for an IMAGE property, RFC 7986 section 6.1
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub enum DisplayType {
+    #[default]
+    Badge,
+    Graphic,
+    FullSize,
+    Thumbnail,
+    XName(String),
+    IanaToken(String),
+}
+
+/// Feature of a CONFERENCE property, RFC 7986 section 6.3
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FeatureType {
+    Audio,
+    Chat,
+    Feed,
+    Moderator,
+    Phone,
+    Screen,
+    Video,
+    XName(String),
+    IanaToken(String),
+}
+
+/// Who is responsible for scheduling messages for a calendar user, RFC 6638 section 7.1
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub enum ScheduleAgent {
+    #[default]
+    Server,
+    Client,
+    None,
+    XName(String),
+    IanaToken(String),
+}
+
+/// A scheduling message that the server must send, RFC 6638 section 7.2
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ScheduleForceSend {
+    Request,
+    Reply,
+    XName(String),
+    IanaToken(String),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Range {
     ThisAndFuture,
@@ -114,6 +164,20 @@ pub enum RelationshipType {
     Parent,
     Child,
     Sibling,
+    /// RFC 9074, section 7.1
+    Snooze,
+    /// RFC 9253, section 5
+    Concept,
+    DependsOn,
+    First,
+    FinishToFinish,
+    FinishToStart,
+    Next,
+    RefId,
+    Replaces,
+    Requires,
+    StartToFinish,
+    StartToStart,
     XName(String),
     IanaToken(String),
 }
@@ -145,6 +209,10 @@ pub enum Value {
     Time,
     Uri,
     UtcOffset,
+    /// RFC 9253, section 9.1
+    Uid,
+    /// RFC 9253, section 9.2
+    XmlReference,
     XName(String),
     IanaToken(String),
 }
@@ -218,6 +286,45 @@ impl OffsetWeekday {
     }
 }
 
+/// A month in a `BYMONTH` recurrence rule part.
+///
+/// Calendar scales other than Gregorian may have a 13th month, or leap months which are written
+/// with an `L` suffix such as `5L`, see RFC 7529.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct RecurMonth {
+    pub number: u8,
+    pub leap: bool,
+}
+
+impl RecurMonth {
+    pub fn new(number: u8) -> Self {
+        RecurMonth {
+            number,
+            leap: false,
+        }
+    }
+
+    pub fn leap_month(number: u8) -> Self {
+        RecurMonth { number, leap: true }
+    }
+}
+
+impl From<time::Month> for RecurMonth {
+    fn from(month: time::Month) -> Self {
+        RecurMonth::new(month as u8)
+    }
+}
+
+/// How to handle recurrence instances that fall on a date which does not exist, such as the
+/// 30th of February or a leap month in a common year, see RFC 7529.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Skip {
+    #[default]
+    Omit,
+    Backward,
+    Forward,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CalendarDateTime {
     date: time::Date,
@@ -478,6 +585,23 @@ pub enum PropertyKind {
     Classification,
     Url,
     RelatedTo,
+    Name,
+    RefreshInterval,
+    Source,
+    Color,
+    Image,
+    Conference,
+    BusyType,
+    StyledDescription,
+    StructuredData,
+    LocationType,
+    ParticipantType,
+    ResourceType,
+    Acknowledged,
+    Proximity,
+    Concept,
+    Link,
+    RefId,
 }
 
 #[cfg(test)]
@@ -0,0 +1,260 @@
+use crate::parser::types::{Host, Uri};
+use crate::parser::{param_value_uri, Error};
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A calendar user address, as used by ATTENDEE and ORGANIZER and by params such as SENT-BY and
+/// DELEGATED-TO, RFC 5545 section 3.3.3.
+///
+/// Addresses are compared by a normalized form of their URI, so `mailto:Bob@Example.com`,
+/// `MAILTO:bob@example.com` and `bob@example.com` are the same address. The address is kept as
+/// it was given for display and serialization.
+///
+/// Normalization follows RFC 3986 section 6.2.2: the scheme and host are lowercased, percent
+/// encoding is made consistent and default ports are removed. A `mailto:` address is compared
+/// without case or header fields, since mail systems match addresses that way in practice. A
+/// bare email address is treated as a `mailto:` address. A value that is not a URI is only
+/// trimmed and lowercased.
+#[derive(Debug, Clone)]
+pub struct CalendarAddress {
+    raw: String,
+    normalized: String,
+}
+
+impl CalendarAddress {
+    pub fn new(address: &str) -> Self {
+        CalendarAddress {
+            raw: address.to_string(),
+            normalized: normalize(address),
+        }
+    }
+
+    /// The address as it was given.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// The normalized form of the address, which is what addresses are compared by.
+    pub fn normalized(&self) -> &str {
+        &self.normalized
+    }
+
+    /// The scheme of the normalized address, in lowercase.
+    pub fn scheme(&self) -> Option<&str> {
+        self.normalized
+            .split_once(':')
+            .map(|(scheme, _)| scheme)
+            .filter(|scheme| !scheme.is_empty() && scheme.bytes().all(is_scheme_char))
+    }
+
+    /// The email address of a `mailto:` address, as it was given but without the scheme or any
+    /// header fields.
+    pub fn email(&self) -> Option<&str> {
+        if self.scheme() != Some("mailto") {
+            return None;
+        }
+
+        let raw = self.raw.trim();
+        let address = match raw.split_once(':') {
+            Some((scheme, address)) if scheme.eq_ignore_ascii_case("mailto") => address,
+            _ => raw,
+        };
+        Some(address.split('?').next().unwrap_or(address))
+    }
+}
+
+impl PartialEq for CalendarAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized == other.normalized
+    }
+}
+
+impl Eq for CalendarAddress {}
+
+impl Hash for CalendarAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized.hash(state);
+    }
+}
+
+impl Display for CalendarAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl From<&str> for CalendarAddress {
+    fn from(address: &str) -> Self {
+        CalendarAddress::new(address)
+    }
+}
+
+impl From<&String> for CalendarAddress {
+    fn from(address: &String) -> Self {
+        CalendarAddress::new(address)
+    }
+}
+
+impl From<String> for CalendarAddress {
+    fn from(address: String) -> Self {
+        CalendarAddress {
+            normalized: normalize(&address),
+            raw: address,
+        }
+    }
+}
+
+/// The characters that never need to be percent encoded, RFC 3986 section 2.3.
+#[inline]
+const fn is_unreserved(b: u8) -> bool {
+    matches!(b, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
+}
+
+#[inline]
+const fn is_scheme_char(b: u8) -> bool {
+    matches!(b, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'-' | b'.')
+}
+
+fn normalize(address: &str) -> String {
+    let address = normalize_percent_encoding(address.trim());
+
+    // The URI parser is a streaming parser, so it needs to see where the value ends
+    let mut input = address.clone().into_bytes();
+    input.extend_from_slice(b"\r\n");
+    let uri = match param_value_uri::<Error>(&input) {
+        Ok((b"\r\n", uri)) => Some(uri),
+        _ => None,
+    };
+
+    match uri {
+        Some(uri) if uri.scheme.eq_ignore_ascii_case(b"mailto") => {
+            format!(
+                "mailto:{}",
+                String::from_utf8_lossy(&uri.path).to_lowercase()
+            )
+        }
+        Some(uri) => normalize_uri(uri),
+        None if !address.contains(':') && address.contains('@') => {
+            format!("mailto:{}", address.to_ascii_lowercase())
+        }
+        None => address.to_lowercase(),
+    }
+}
+
+fn normalize_uri(mut uri: Uri) -> String {
+    let scheme = String::from_utf8_lossy(uri.scheme).to_ascii_lowercase();
+
+    if let Some(authority) = &mut uri.authority {
+        if let Host::RegName(name) = &mut authority.host {
+            name.make_ascii_lowercase();
+        }
+
+        // Default ports, RFC 3986 section 6.2.3
+        let default_port = match scheme.as_str() {
+            "http" | "ws" => Some(80),
+            "https" | "wss" => Some(443),
+            "ldap" => Some(389),
+            _ => None,
+        };
+        if authority.port.is_some() && authority.port == default_port {
+            authority.port = None;
+        }
+
+        if uri.path.is_empty() && default_port.is_some() {
+            uri.path = b"/".to_vec();
+        }
+    }
+
+    let uri = Uri {
+        scheme: scheme.as_bytes(),
+        ..uri
+    };
+    uri.to_string()
+}
+
+/// Decode percent encoded characters that do not need to be encoded and use uppercase hex digits
+/// for the rest, RFC 3986 section 6.2.2.2.
+fn normalize_percent_encoding(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match &bytes[i..] {
+            [b'%', hi, lo, ..] if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                let decoded = hex::decode([*hi, *lo]).unwrap_or_default();
+                match decoded.as_slice() {
+                    [b] if is_unreserved(*b) => out.push(*b),
+                    _ => out.extend([b'%', hi.to_ascii_uppercase(), lo.to_ascii_uppercase()]),
+                }
+                i += 3;
+            }
+            [b, ..] => {
+                out.push(*b);
+                i += 1;
+            }
+            [] => break,
+        }
+    }
+
+    String::from_utf8(out).unwrap_or_else(|_| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mailto_addresses() {
+        let address = CalendarAddress::new("mailto:Bob@Example.com");
+
+        assert_eq!(address, CalendarAddress::new("MAILTO:bob@example.com"));
+        assert_eq!(address, CalendarAddress::new(" bob@EXAMPLE.com "));
+        assert_eq!(address, CalendarAddress::new("mailto:b%6Fb@example.com"));
+        assert_ne!(address, CalendarAddress::new("mailto:carol@example.com"));
+
+        assert_eq!("mailto:bob@example.com", address.normalized());
+        assert_eq!("mailto:Bob@Example.com", address.to_string());
+        assert_eq!(Some("mailto"), address.scheme());
+        assert_eq!(Some("Bob@Example.com"), address.email());
+        assert_eq!(
+            Some("bob@example.com"),
+            CalendarAddress::new("bob@example.com").email()
+        );
+    }
+
+    #[test]
+    fn other_uris() {
+        let address = CalendarAddress::new("HTTPS://Cal.Example.com:443/Users/Bob");
+
+        assert_eq!("https://cal.example.com/Users/Bob", address.normalized());
+        assert_eq!(
+            address,
+            CalendarAddress::new("https://cal.example.com/Users/%42ob")
+        );
+        // The path is case sensitive
+        assert_ne!(
+            address,
+            CalendarAddress::new("https://cal.example.com/users/bob")
+        );
+        assert_eq!(None, address.email());
+
+        assert_eq!(
+            "https://example.com/",
+            CalendarAddress::new("https://Example.com").normalized()
+        );
+        assert_eq!(
+            CalendarAddress::new("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+            CalendarAddress::new("URN:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        );
+    }
+
+    #[test]
+    fn not_a_uri() {
+        let address = CalendarAddress::new("Bob Smith");
+
+        assert_eq!("bob smith", address.normalized());
+        assert_eq!(None, address.scheme());
+        assert_eq!(address, CalendarAddress::new("bob smith "));
+    }
+}
@@ -0,0 +1,397 @@
+use crate::common::FreeBusyTimeType;
+use crate::expand::{Expander, Occurrence, TimeRange};
+use crate::free_busy::event_time_type;
+use crate::model::access::ComponentAccess;
+use crate::model::component::{CalendarComponent, EventComponent};
+use crate::model::object::ICalObject;
+use crate::model::property::UniqueIdentifierProperty;
+use time::{OffsetDateTime, UtcOffset};
+
+/// An occurrence of an event that takes up time.
+#[derive(Debug, Clone)]
+pub struct BusyInstance<'a> {
+    /// The position of the calendar object that the event is in, in the order that objects were
+    /// added to the [ConflictDetector].
+    pub object_index: usize,
+    pub event: &'a EventComponent,
+    pub occurrence: Occurrence,
+    /// How the occurrence takes up time, either BUSY or BUSY-TENTATIVE.
+    pub time_type: FreeBusyTimeType,
+}
+
+/// Two busy instances that take up some of the same time.
+#[derive(Debug, Clone)]
+pub struct Conflict<'a> {
+    /// The instance that starts first, or that was added first if they start together.
+    pub first: BusyInstance<'a>,
+    pub second: BusyInstance<'a>,
+    /// The start of the time that both instances take up.
+    pub start: OffsetDateTime,
+    /// The end of the time that both instances take up.
+    pub end: OffsetDateTime,
+}
+
+/// Finds events that take up the same time, such as double bookings of a room or a person.
+///
+/// Events are expanded into their occurrences within the range, resolving their time zones
+/// against the VTIMEZONE components of their calendar object. As for free/busy time, events
+/// that are transparent or cancelled do not take up time and events with no duration cannot
+/// conflict. All-day events take up their whole days in the floating offset, and their end is
+/// exclusive, so an all-day event does not conflict with events on the following day.
+///
+/// Occurrences of the same event are not reported as conflicting with each other. Events are the
+/// same if they have the same UID, so this includes the overridden instances of a recurring event
+/// and copies of an event in other calendar objects.
+pub struct ConflictDetector<'a> {
+    objects: Vec<&'a ICalObject>,
+    range: TimeRange,
+    floating_offset: UtcOffset,
+    include_tentative: bool,
+    include_all_day: bool,
+}
+
+impl<'a> ConflictDetector<'a> {
+    /// Find conflicts that overlap the range from `start`, inclusive, to `end`, exclusive.
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        ConflictDetector {
+            objects: Vec::new(),
+            range: TimeRange::new(start, end),
+            floating_offset: UtcOffset::UTC,
+            include_tentative: true,
+            include_all_day: true,
+        }
+    }
+
+    /// Add a calendar object whose events should be checked against each other and against the
+    /// events of the other objects.
+    pub fn add_object(mut self, object: &'a ICalObject) -> Self {
+        self.objects.push(object);
+        self
+    }
+
+    /// Set the offset that floating date-times, including all-day events, are interpreted in.
+    /// Defaults to UTC.
+    pub fn set_floating_offset(mut self, offset: UtcOffset) -> Self {
+        self.floating_offset = offset;
+        self
+    }
+
+    /// Set whether tentative events can conflict. Defaults to true.
+    pub fn set_include_tentative(mut self, include: bool) -> Self {
+        self.include_tentative = include;
+        self
+    }
+
+    /// Set whether all-day events can conflict. Defaults to true.
+    pub fn set_include_all_day(mut self, include: bool) -> Self {
+        self.include_all_day = include;
+        self
+    }
+
+    /// Find the occurrences of the events that take up time within the range, ordered by their
+    /// start.
+    pub fn busy_instances(&self) -> Vec<BusyInstance<'a>> {
+        let mut instances = Vec::new();
+
+        for (object_index, object) in self.objects.iter().enumerate() {
+            let mut expander = Expander::new(object);
+            expander.set_floating_offset(self.floating_offset);
+
+            for component in &object.components {
+                let CalendarComponent::Event(event) = component else {
+                    continue;
+                };
+                let Some(time_type) = event_time_type(component) else {
+                    continue;
+                };
+                if !self.include_tentative && time_type == FreeBusyTimeType::BusyTentative {
+                    continue;
+                }
+
+                for occurrence in expander.occurrences(component, &self.range) {
+                    if occurrence.end <= occurrence.start
+                        || (occurrence.all_day && !self.include_all_day)
+                    {
+                        continue;
+                    }
+
+                    instances.push(BusyInstance {
+                        object_index,
+                        event,
+                        occurrence,
+                        time_type: time_type.clone(),
+                    });
+                }
+            }
+        }
+
+        // A stable sort keeps instances that start together in the order they were added
+        instances.sort_by_key(|i| i.occurrence.start);
+        instances
+    }
+
+    /// Find the pairs of busy instances that overlap, ordered by the start of the overlap.
+    pub fn conflicts(&self) -> Vec<Conflict<'a>> {
+        let instances = self.busy_instances();
+
+        let mut conflicts = Vec::new();
+        let mut active: Vec<&BusyInstance<'a>> = Vec::new();
+        for instance in &instances {
+            active.retain(|a| a.occurrence.end > instance.occurrence.start);
+
+            for earlier in &active {
+                if is_same_event(earlier.event, instance.event) {
+                    continue;
+                }
+
+                conflicts.push(Conflict {
+                    first: (*earlier).clone(),
+                    second: instance.clone(),
+                    start: instance.occurrence.start,
+                    end: earlier.occurrence.end.min(instance.occurrence.end),
+                });
+            }
+
+            active.push(instance);
+        }
+
+        conflicts.sort_by_key(|c| c.start);
+        conflicts
+    }
+}
+
+fn is_same_event(a: &EventComponent, b: &EventComponent) -> bool {
+    match (
+        a.get_property::<UniqueIdentifierProperty>(),
+        b.get_property::<UniqueIdentifierProperty>(),
+    ) {
+        (Some(a), Some(b)) => a.value == b.value,
+        _ => std::ptr::eq(a, b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701101T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700308T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=America/New_York:20240108T090000\r\n\
+DURATION:PT30M\r\n\
+RRULE:FREQ=DAILY;COUNT=3\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:transparent\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240108T140000Z\r\n\
+DTEND:20240108T150000Z\r\n\
+TRANSP:TRANSPARENT\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:holiday\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;VALUE=DATE:20240110\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    const OTHER_CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:review\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240109T141500Z\r\n\
+DTEND:20240109T150000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:cancelled\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240108T140000Z\r\n\
+DTEND:20240108T150000Z\r\n\
+STATUS:CANCELLED\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:maybe\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240108T142000Z\r\n\
+DTEND:20240108T144000Z\r\n\
+STATUS:TENTATIVE\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:after-holiday\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240111T000000Z\r\n\
+DTEND:20240111T010000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::January, day).unwrap(),
+            Time::from_hms(hour, minute, 0).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    fn uid<'a>(instance: &BusyInstance<'a>) -> &'a str {
+        &instance
+            .event
+            .get_property::<UniqueIdentifierProperty>()
+            .unwrap()
+            .value
+    }
+
+    fn summary(conflicts: &[Conflict]) -> Vec<(String, String, OffsetDateTime, OffsetDateTime)> {
+        conflicts
+            .iter()
+            .map(|c| {
+                (
+                    uid(&c.first).to_string(),
+                    uid(&c.second).to_string(),
+                    c.start,
+                    c.end,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn conflicts_across_time_zones() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let other = load_ical(OTHER_CALENDAR).unwrap().pop().unwrap();
+
+        let detector = ConflictDetector::new(utc(1, 0, 0), utc(31, 0, 0))
+            .add_object(&object)
+            .add_object(&other);
+
+        // The standup is at 14:00 UTC, the transparent and cancelled events are ignored and the
+        // all-day event ends before the event on the following day
+        assert_eq!(
+            vec![
+                (
+                    "standup".to_string(),
+                    "maybe".to_string(),
+                    utc(8, 14, 20),
+                    utc(8, 14, 30)
+                ),
+                (
+                    "standup".to_string(),
+                    "review".to_string(),
+                    utc(9, 14, 15),
+                    utc(9, 14, 30)
+                ),
+                (
+                    "holiday".to_string(),
+                    "standup".to_string(),
+                    utc(10, 14, 0),
+                    utc(10, 14, 30)
+                ),
+            ],
+            summary(&detector.conflicts())
+        );
+
+        let conflicts = detector
+            .set_include_tentative(false)
+            .set_include_all_day(false)
+            .conflicts();
+        assert_eq!(1, conflicts.len());
+        assert_eq!("review", uid(&conflicts[0].second));
+        assert_eq!(1, conflicts[0].second.object_index);
+        assert_eq!(utc(9, 14, 0), conflicts[0].first.occurrence.start);
+    }
+
+    #[test]
+    fn time_range_filter() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let other = load_ical(OTHER_CALENDAR).unwrap().pop().unwrap();
+
+        let conflicts = ConflictDetector::new(utc(9, 0, 0), utc(10, 0, 0))
+            .add_object(&object)
+            .add_object(&other)
+            .conflicts();
+
+        assert_eq!(
+            vec![(
+                "standup".to_string(),
+                "review".to_string(),
+                utc(9, 14, 15),
+                utc(9, 14, 30)
+            )],
+            summary(&conflicts)
+        );
+    }
+
+    #[test]
+    fn all_day_events_use_the_floating_offset() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let other = load_ical(OTHER_CALENDAR).unwrap().pop().unwrap();
+
+        // The holiday runs to 01:00 UTC on the following day
+        let conflicts = ConflictDetector::new(utc(10, 0, 0), utc(12, 0, 0))
+            .add_object(&object)
+            .add_object(&other)
+            .set_floating_offset(UtcOffset::from_hms(-1, 0, 0).unwrap())
+            .conflicts();
+
+        assert_eq!(
+            vec![
+                (
+                    "holiday".to_string(),
+                    "standup".to_string(),
+                    utc(10, 14, 0),
+                    utc(10, 14, 30)
+                ),
+                (
+                    "holiday".to_string(),
+                    "after-holiday".to_string(),
+                    utc(11, 0, 0),
+                    utc(11, 1, 0)
+                ),
+            ],
+            summary(&conflicts)
+        );
+    }
+
+    #[test]
+    fn overridden_instances_do_not_conflict_with_their_series() {
+        let content = CALENDAR.replace(
+            "BEGIN:VEVENT\r\nUID:transparent\r\n",
+            "BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RECURRENCE-ID;TZID=America/New_York:20240109T090000\r\n\
+DTSTART;TZID=America/New_York:20240108T091500\r\n\
+DURATION:PT30M\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:transparent\r\n",
+        );
+        let object = load_ical(&content).unwrap().pop().unwrap();
+
+        // Tuesday's standup is moved to overlap Monday's, which is still the same event
+        let detector = ConflictDetector::new(utc(8, 0, 0), utc(9, 0, 0)).add_object(&object);
+        assert_eq!(2, detector.busy_instances().len());
+        assert!(detector.conflicts().is_empty());
+    }
+}
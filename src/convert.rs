@@ -4,6 +4,9 @@ mod component;
 mod object;
 mod param;
 mod property;
+mod value;
+
+pub(crate) use value::decode_typed_values;
 
 /// Conversion trait for converting parser model types to model types.
 pub trait ToModel {
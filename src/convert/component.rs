@@ -1,8 +1,9 @@
 use crate::convert::{convert_string, ToModel};
 use crate::error::AetoliaResult;
 use crate::model::component::{
-    AlarmComponent, DaylightComponent, EventComponent, FreeBusyComponent, JournalComponent,
-    StandardComponent, TimeZoneComponent, ToDoComponent,
+    AlarmComponent, AvailabilityComponent, AvailableComponent, DaylightComponent, EventComponent,
+    FreeBusyComponent, JournalComponent, LocationComponent, ParticipantComponent,
+    ResourceComponent, StandardComponent, TimeZoneComponent, ToDoComponent,
 };
 use crate::model::property::ComponentProperty;
 use crate::parser::types::ContentLine;
@@ -12,7 +13,10 @@ impl ToModel for crate::parser::types::CalendarComponent<'_> {
 
     fn to_model(&self) -> AetoliaResult<Self::Model> {
         match self {
-            crate::parser::types::CalendarComponent::Event { properties, alarms } => {
+            crate::parser::types::CalendarComponent::Event {
+                properties,
+                components,
+            } => {
                 let mut component = EventComponent::new();
                 component.properties.reserve(properties.len());
 
@@ -20,9 +24,9 @@ impl ToModel for crate::parser::types::CalendarComponent<'_> {
                     component.properties.push(property.to_model()?);
                 }
 
-                component.alarms.reserve(alarms.len());
-                for alarm in alarms {
-                    component.alarms.push(alarm.to_model()?);
+                component.components.reserve(components.len());
+                for nested in components {
+                    component.components.push(nested.to_model()?);
                 }
 
                 Ok(crate::model::component::CalendarComponent::Event(component))
@@ -118,6 +122,82 @@ impl ToModel for crate::parser::types::CalendarComponent<'_> {
 
                 Ok(crate::model::component::CalendarComponent::Alarm(alarm))
             }
+            crate::parser::types::CalendarComponent::Availability {
+                properties,
+                components,
+            } => {
+                let mut availability = AvailabilityComponent::new();
+                availability.properties.reserve(properties.len());
+
+                for property in properties {
+                    availability.properties.push(property.to_model()?);
+                }
+
+                availability.components.reserve(components.len());
+                for component in components {
+                    availability.components.push(component.to_model()?);
+                }
+
+                Ok(crate::model::component::CalendarComponent::Availability(
+                    availability,
+                ))
+            }
+            crate::parser::types::CalendarComponent::Available { properties } => {
+                let mut available = AvailableComponent::new();
+                available.properties.reserve(properties.len());
+
+                for property in properties {
+                    available.properties.push(property.to_model()?);
+                }
+
+                Ok(crate::model::component::CalendarComponent::Available(
+                    available,
+                ))
+            }
+            crate::parser::types::CalendarComponent::Participant {
+                properties,
+                components,
+            } => {
+                let mut participant = ParticipantComponent::new();
+                participant.properties.reserve(properties.len());
+
+                for property in properties {
+                    participant.properties.push(property.to_model()?);
+                }
+
+                participant.components.reserve(components.len());
+                for component in components {
+                    participant.components.push(component.to_model()?);
+                }
+
+                Ok(crate::model::component::CalendarComponent::Participant(
+                    participant,
+                ))
+            }
+            crate::parser::types::CalendarComponent::Location { properties } => {
+                let mut location = LocationComponent::new();
+                location.properties.reserve(properties.len());
+
+                for property in properties {
+                    location.properties.push(property.to_model()?);
+                }
+
+                Ok(crate::model::component::CalendarComponent::Location(
+                    location,
+                ))
+            }
+            crate::parser::types::CalendarComponent::Resource { properties } => {
+                let mut resource = ResourceComponent::new();
+                resource.properties.reserve(properties.len());
+
+                for property in properties {
+                    resource.properties.push(property.to_model()?);
+                }
+
+                Ok(crate::model::component::CalendarComponent::Resource(
+                    resource,
+                ))
+            }
             crate::parser::types::CalendarComponent::IanaComp { name, lines } => {
                 let mut component =
                     crate::model::component::IanaComponent::new(convert_string(name));
@@ -155,6 +235,7 @@ fn map_unknown_lines(
                     name: m.name,
                     value: m.value,
                     params: m.params,
+                    extension: None,
                 },
             ));
         } else {
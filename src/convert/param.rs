@@ -1,12 +1,15 @@
 use crate::convert::{convert_string, ToModel};
 use crate::error::AetoliaResult;
 use crate::model::param::{
-    AlternateRepresentationParam, CalendarUserTypeParam, CommonNameParam, DelegatedFromParam,
-    DelegatedToParam, DirectoryEntryReferenceParam, EncodingParam, FormatTypeParam,
-    FreeBusyTimeTypeParam, LanguageParam, MembersParam, Param as ModelParam,
-    ParticipationStatusParam, RangeParam, RelationshipTypeParam, RoleParam, RsvpParam, SentByParam,
-    TimeZoneIdParam, TriggerRelationshipParam, ValueTypeParam,
+    is_x_name, AlternateRepresentationParam, CalendarUserTypeParam, CommonNameParam,
+    DelegatedFromParam, DelegatedToParam, DirectoryEntryReferenceParam, DisplayParam, EmailParam,
+    EncodingParam, FeatureParam, FormatTypeParam, FreeBusyTimeTypeParam, GapParam, LabelParam,
+    LanguageParam, LinkRelationParam, MembersParam, Param as ModelParam, ParticipationStatusParam,
+    RangeParam, RelationshipTypeParam, RoleParam, RsvpParam, ScheduleAgentParam,
+    ScheduleForceSendParam, ScheduleStatusParam, SentByParam, TimeZoneIdParam,
+    TriggerRelationshipParam, ValueTypeParam,
 };
+use crate::parser::decode_param_text;
 use crate::parser::types::ParamValue as ParserParam;
 
 impl ToModel for ParserParam<'_> {
@@ -89,14 +92,57 @@ impl ToModel for ParserParam<'_> {
             ParserParam::ValueType { value } => ModelParam::ValueType(ValueTypeParam {
                 value: value.clone(),
             }),
+            ParserParam::Display { display } => ModelParam::Display(DisplayParam {
+                display: display.clone(),
+            }),
+            ParserParam::Email { email } => ModelParam::Email(EmailParam {
+                email: email.to_string(),
+            }),
+            ParserParam::Feature { features } => ModelParam::Feature(FeatureParam {
+                features: features.clone(),
+            }),
+            ParserParam::Label { label } => ModelParam::Label(LabelParam {
+                label: label.to_string(),
+            }),
+            ParserParam::ScheduleAgent { agent } => ModelParam::ScheduleAgent(ScheduleAgentParam {
+                agent: agent.clone(),
+            }),
+            ParserParam::ScheduleForceSend { force_send } => {
+                ModelParam::ScheduleForceSend(ScheduleForceSendParam {
+                    force_send: force_send.clone(),
+                })
+            }
+            ParserParam::ScheduleStatus { statuses } => {
+                ModelParam::ScheduleStatus(ScheduleStatusParam {
+                    statuses: statuses.clone(),
+                })
+            }
+            ParserParam::Gap { duration } => ModelParam::Gap(GapParam {
+                duration: duration.to_model()?,
+            }),
+            ParserParam::LinkRelation { relation } => ModelParam::LinkRelation(LinkRelationParam {
+                relation: relation.to_string(),
+            }),
+            ParserParam::Other { name, value } if is_x_name(name) => ModelParam::XName {
+                name: convert_string(name),
+                values: vec![convert_param_text(value)],
+            },
+            ParserParam::Others { name, values } if is_x_name(name) => ModelParam::XName {
+                name: convert_string(name),
+                values: values.iter().map(|v| convert_param_text(v)).collect(),
+            },
             ParserParam::Other { name, value } => ModelParam::Other {
                 name: convert_string(name),
-                value: convert_string(value),
+                value: convert_param_text(value),
             },
             ParserParam::Others { name, values } => ModelParam::Others {
                 name: convert_string(name),
-                values: values.iter().map(|v| convert_string(v)).collect(),
+                values: values.iter().map(|v| convert_param_text(v)).collect(),
             },
         })
     }
 }
+
+fn convert_param_text(input: &[u8]) -> String {
+    decode_param_text(convert_string(input))
+}
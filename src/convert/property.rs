@@ -59,6 +59,254 @@ impl ToModel for crate::parser::types::DateTimeStartProperty<'_> {
     }
 }
 
+impl ToModel for crate::parser::types::BusyTypeProperty<'_> {
+    type Model = crate::model::property::BusyTypeProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::BusyTypeProperty {
+            value: self.value.to_model()?,
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::BusyType<'_> {
+    type Model = crate::model::property::BusyType;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(match self {
+            crate::parser::types::BusyType::Busy => crate::model::property::BusyType::Busy,
+            crate::parser::types::BusyType::BusyUnavailable => {
+                crate::model::property::BusyType::BusyUnavailable
+            }
+            crate::parser::types::BusyType::BusyTentative => {
+                crate::model::property::BusyType::BusyTentative
+            }
+            crate::parser::types::BusyType::XName(name) => {
+                crate::model::property::BusyType::XName(convert_string(name))
+            }
+            crate::parser::types::BusyType::IanaToken(token) => {
+                crate::model::property::BusyType::IanaToken(convert_string(token))
+            }
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::StructuredDataValue<'_> {
+    type Model = String;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(match self {
+            crate::parser::types::StructuredDataValue::Text(text) => convert_string(text),
+            crate::parser::types::StructuredDataValue::Uri(uri) => convert_string(uri),
+            crate::parser::types::StructuredDataValue::Binary(binary) => convert_string(binary),
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::StyledDescriptionProperty<'_> {
+    type Model = crate::model::property::StyledDescriptionProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::StyledDescriptionProperty {
+            value: self.value.to_model()?,
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::StructuredDataProperty<'_> {
+    type Model = crate::model::property::StructuredDataProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::StructuredDataProperty {
+            value: self.value.to_model()?,
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::LocationTypeProperty<'_> {
+    type Model = crate::model::property::LocationTypeProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::LocationTypeProperty {
+            value: self.value.iter().map(|v| convert_string(v)).collect(),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ParticipantTypeProperty<'_> {
+    type Model = crate::model::property::ParticipantTypeProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ParticipantTypeProperty {
+            value: self.value.to_model()?,
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ParticipantType<'_> {
+    type Model = crate::model::property::ParticipantType;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(match self {
+            crate::parser::types::ParticipantType::Active => {
+                crate::model::property::ParticipantType::Active
+            }
+            crate::parser::types::ParticipantType::Inactive => {
+                crate::model::property::ParticipantType::Inactive
+            }
+            crate::parser::types::ParticipantType::Sponsor => {
+                crate::model::property::ParticipantType::Sponsor
+            }
+            crate::parser::types::ParticipantType::Contact => {
+                crate::model::property::ParticipantType::Contact
+            }
+            crate::parser::types::ParticipantType::BookingContact => {
+                crate::model::property::ParticipantType::BookingContact
+            }
+            crate::parser::types::ParticipantType::EmergencyContact => {
+                crate::model::property::ParticipantType::EmergencyContact
+            }
+            crate::parser::types::ParticipantType::PublicityContact => {
+                crate::model::property::ParticipantType::PublicityContact
+            }
+            crate::parser::types::ParticipantType::PlannerContact => {
+                crate::model::property::ParticipantType::PlannerContact
+            }
+            crate::parser::types::ParticipantType::Performer => {
+                crate::model::property::ParticipantType::Performer
+            }
+            crate::parser::types::ParticipantType::Speaker => {
+                crate::model::property::ParticipantType::Speaker
+            }
+            crate::parser::types::ParticipantType::XName(name) => {
+                crate::model::property::ParticipantType::XName(convert_string(name))
+            }
+            crate::parser::types::ParticipantType::IanaToken(token) => {
+                crate::model::property::ParticipantType::IanaToken(convert_string(token))
+            }
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ResourceTypeProperty<'_> {
+    type Model = crate::model::property::ResourceTypeProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ResourceTypeProperty {
+            value: self.value.to_model()?,
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ResourceType<'_> {
+    type Model = crate::model::property::ResourceType;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(match self {
+            crate::parser::types::ResourceType::Projector => {
+                crate::model::property::ResourceType::Projector
+            }
+            crate::parser::types::ResourceType::Room => crate::model::property::ResourceType::Room,
+            crate::parser::types::ResourceType::RemoteConferenceAudio => {
+                crate::model::property::ResourceType::RemoteConferenceAudio
+            }
+            crate::parser::types::ResourceType::RemoteConferenceVideo => {
+                crate::model::property::ResourceType::RemoteConferenceVideo
+            }
+            crate::parser::types::ResourceType::XName(name) => {
+                crate::model::property::ResourceType::XName(convert_string(name))
+            }
+            crate::parser::types::ResourceType::IanaToken(token) => {
+                crate::model::property::ResourceType::IanaToken(convert_string(token))
+            }
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::AcknowledgedProperty<'_> {
+    type Model = crate::model::property::AcknowledgedProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        let (date, time, is_utc) = (&self.value).try_into()?;
+
+        Ok(crate::model::property::AcknowledgedProperty {
+            value: (date, time, is_utc).into(),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ProximityProperty<'_> {
+    type Model = crate::model::property::ProximityProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ProximityProperty {
+            value: self.value.to_model()?,
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::Proximity<'_> {
+    type Model = crate::model::property::Proximity;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(match self {
+            crate::parser::types::Proximity::Arrive => crate::model::property::Proximity::Arrive,
+            crate::parser::types::Proximity::Depart => crate::model::property::Proximity::Depart,
+            crate::parser::types::Proximity::Connect => crate::model::property::Proximity::Connect,
+            crate::parser::types::Proximity::Disconnect => {
+                crate::model::property::Proximity::Disconnect
+            }
+            crate::parser::types::Proximity::XName(name) => {
+                crate::model::property::Proximity::XName(convert_string(name))
+            }
+            crate::parser::types::Proximity::IanaToken(token) => {
+                crate::model::property::Proximity::IanaToken(convert_string(token))
+            }
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ConceptProperty<'_> {
+    type Model = crate::model::property::ConceptProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ConceptProperty {
+            value: convert_string(self.value),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::LinkProperty<'_> {
+    type Model = crate::model::property::LinkProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::LinkProperty {
+            value: convert_string(&self.value),
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::RefIdProperty<'_> {
+    type Model = crate::model::property::RefIdProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::RefIdProperty {
+            value: convert_string(&self.value),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
 impl ToModel for crate::parser::types::ClassificationProperty<'_> {
     type Model = crate::model::property::ClassificationProperty;
 
@@ -297,6 +545,77 @@ impl ToModel for crate::parser::types::AttachProperty<'_> {
     }
 }
 
+impl ToModel for crate::parser::types::NameProperty<'_> {
+    type Model = crate::model::property::NameProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::NameProperty {
+            value: convert_string(&self.value),
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::RefreshIntervalProperty<'_> {
+    type Model = crate::model::property::RefreshIntervalProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::RefreshIntervalProperty {
+            value: self.value.to_model()?,
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::SourceProperty<'_> {
+    type Model = crate::model::property::SourceProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::SourceProperty {
+            value: self.value.to_string(),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ColorProperty<'_> {
+    type Model = crate::model::property::ColorProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ColorProperty {
+            value: convert_string(&self.value),
+            params: self.other_params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ImageProperty<'_> {
+    type Model = crate::model::property::ImageProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        let value = match self.value {
+            crate::parser::types::AttachValue::Uri(uri) => convert_string(uri),
+            crate::parser::types::AttachValue::Binary(binary) => convert_string(binary),
+        };
+
+        Ok(crate::model::property::ImageProperty {
+            value,
+            params: self.params.to_model()?,
+        })
+    }
+}
+
+impl ToModel for crate::parser::types::ConferenceProperty<'_> {
+    type Model = crate::model::property::ConferenceProperty;
+
+    fn to_model(&self) -> AetoliaResult<Self::Model> {
+        Ok(crate::model::property::ConferenceProperty {
+            value: convert_string(self.value),
+            params: self.params.to_model()?,
+        })
+    }
+}
+
 impl ToModel for crate::parser::types::AttendeeProperty<'_> {
     type Model = crate::model::property::AttendeeProperty;
 
@@ -401,6 +720,7 @@ impl ToModel for crate::parser::types::XProperty<'_> {
             name: convert_string(self.name),
             value: convert_string(&self.value),
             params: self.params.to_model()?,
+            extension: None,
         })
     }
 }
@@ -413,6 +733,7 @@ impl ToModel for crate::parser::types::IanaProperty<'_> {
             name: convert_string(self.name),
             value: convert_string(&self.value),
             params: self.params.to_model()?,
+            extension: None,
         })
     }
 }
@@ -547,6 +868,32 @@ impl ToModel for crate::parser::types::CalendarProperty<'_> {
             crate::parser::types::CalendarProperty::Method(method) => Ok(
                 crate::model::property::CalendarProperty::Method(method.to_model()?),
             ),
+            crate::parser::types::CalendarProperty::Name(name) => Ok(
+                crate::model::property::CalendarProperty::Name(name.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::Description(description) => Ok(
+                crate::model::property::CalendarProperty::Description(description.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::LastModified(last_modified) => Ok(
+                crate::model::property::CalendarProperty::LastModified(last_modified.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::Url(url) => Ok(
+                crate::model::property::CalendarProperty::Url(url.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::RefreshInterval(refresh_interval) => {
+                Ok(crate::model::property::CalendarProperty::RefreshInterval(
+                    refresh_interval.to_model()?,
+                ))
+            }
+            crate::parser::types::CalendarProperty::Source(source) => Ok(
+                crate::model::property::CalendarProperty::Source(source.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::Color(color) => Ok(
+                crate::model::property::CalendarProperty::Color(color.to_model()?),
+            ),
+            crate::parser::types::CalendarProperty::Image(image) => Ok(
+                crate::model::property::CalendarProperty::Image(image.to_model()?),
+            ),
             crate::parser::types::CalendarProperty::XProperty(x_prop) => Ok(
                 crate::model::property::CalendarProperty::XProperty(x_prop.to_model()?),
             ),
@@ -894,6 +1241,54 @@ impl ToModel for crate::parser::types::ComponentProperty<'_> {
             crate::parser::types::ComponentProperty::RepeatCount(repeat_count) => Ok(
                 crate::model::property::ComponentProperty::Repeat(repeat_count.to_model()?),
             ),
+            crate::parser::types::ComponentProperty::Color(color) => Ok(
+                crate::model::property::ComponentProperty::Color(color.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Image(image) => Ok(
+                crate::model::property::ComponentProperty::Image(image.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Conference(conference) => Ok(
+                crate::model::property::ComponentProperty::Conference(conference.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::BusyType(busy_type) => Ok(
+                crate::model::property::ComponentProperty::BusyType(busy_type.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::StyledDescription(styled_description) => Ok(
+                crate::model::property::ComponentProperty::StyledDescription(
+                    styled_description.to_model()?,
+                ),
+            ),
+            crate::parser::types::ComponentProperty::StructuredData(structured_data) => {
+                Ok(crate::model::property::ComponentProperty::StructuredData(
+                    structured_data.to_model()?,
+                ))
+            }
+            crate::parser::types::ComponentProperty::LocationType(location_type) => Ok(
+                crate::model::property::ComponentProperty::LocationType(location_type.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::ParticipantType(participant_type) => {
+                Ok(crate::model::property::ComponentProperty::ParticipantType(
+                    participant_type.to_model()?,
+                ))
+            }
+            crate::parser::types::ComponentProperty::ResourceType(resource_type) => Ok(
+                crate::model::property::ComponentProperty::ResourceType(resource_type.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Acknowledged(acknowledged) => Ok(
+                crate::model::property::ComponentProperty::Acknowledged(acknowledged.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Proximity(proximity) => Ok(
+                crate::model::property::ComponentProperty::Proximity(proximity.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Concept(concept) => Ok(
+                crate::model::property::ComponentProperty::Concept(concept.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::Link(link) => Ok(
+                crate::model::property::ComponentProperty::Link(link.to_model()?),
+            ),
+            crate::parser::types::ComponentProperty::RefId(ref_id) => Ok(
+                crate::model::property::ComponentProperty::RefId(ref_id.to_model()?),
+            ),
             crate::parser::types::ComponentProperty::XProperty(x_prop) => Ok(
                 crate::model::property::ComponentProperty::XProperty(x_prop.to_model()?),
             ),
@@ -912,6 +1307,7 @@ impl ToModel for ContentLine<'_> {
             name: convert_string(self.property_name),
             value: convert_string(&self.value),
             params: self.params.to_model()?,
+            extension: None,
         })
     }
 }
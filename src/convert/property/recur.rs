@@ -1,5 +1,5 @@
 use crate::convert::ToModel;
-use crate::error::{AetoliaError, AetoliaResult};
+use crate::error::AetoliaResult;
 use crate::model::property::RecurrenceRule;
 use crate::parser::types::RecurRulePart;
 
@@ -45,12 +45,7 @@ impl ToModel for Vec<RecurRulePart> {
                     rule = rule.set_by_week_number(week.clone());
                 }
                 RecurRulePart::ByMonth(month) => {
-                    rule = rule.set_by_month(
-                        month
-                            .iter()
-                            .map(|m| time::Month::try_from(*m).map_err(AetoliaError::time))
-                            .collect::<AetoliaResult<Vec<_>>>()?,
-                    );
+                    rule = rule.set_by_month(month.clone());
                 }
                 RecurRulePart::BySetPos(by_set_pos) => {
                     rule = rule.set_by_set_pos(by_set_pos.clone());
@@ -58,6 +53,12 @@ impl ToModel for Vec<RecurRulePart> {
                 RecurRulePart::WeekStart(week_start) => {
                     rule = rule.set_week_start(week_start.clone());
                 }
+                RecurRulePart::RScale(rscale) => {
+                    rule = rule.set_rscale(rscale);
+                }
+                RecurRulePart::Skip(skip) => {
+                    rule = rule.set_skip(*skip);
+                }
             }
         }
 
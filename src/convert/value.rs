@@ -0,0 +1,219 @@
+use crate::common::Value;
+use crate::convert::ToModel;
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::model::property::TypedValue;
+use crate::parser::{
+    param_value_uri, prop_value_binary, prop_value_date, prop_value_date_time, prop_value_duration,
+    prop_value_float, prop_value_integer, prop_value_period, prop_value_recur, prop_value_text,
+    prop_value_time, prop_value_utc_offset, Error,
+};
+use base64::Engine;
+use nom::IResult;
+
+/// Appended to a value before it is parsed. The value parsers are streaming parsers, so they
+/// need to see where the value ends rather than running out of input.
+const VALUE_TERMINATOR: &[u8] = b"\r\n;";
+
+/// Decode a raw property value, as it was written in the content line, to typed values.
+pub(crate) fn decode_typed_values(value_type: &Value, raw: &str) -> AetoliaResult<Vec<TypedValue>> {
+    match value_type {
+        // Neither of these can be a list, and a recurrence rule may contain commas.
+        Value::Binary | Value::Recurrence => Ok(vec![decode_typed_value(value_type, raw)?]),
+        Value::XmlReference | Value::XName(_) | Value::IanaToken(_) => {
+            Ok(vec![TypedValue::Other(raw.to_string())])
+        }
+        _ => split_values(raw)
+            .into_iter()
+            .map(|v| decode_typed_value(value_type, v))
+            .collect(),
+    }
+}
+
+fn decode_typed_value(value_type: &Value, raw: &str) -> AetoliaResult<TypedValue> {
+    let mut buffer = Vec::with_capacity(raw.len() + VALUE_TERMINATOR.len());
+    buffer.extend_from_slice(raw.as_bytes());
+    buffer.extend_from_slice(VALUE_TERMINATOR);
+    let input = buffer.as_slice();
+
+    Ok(match value_type {
+        Value::Binary => {
+            let content = complete(value_type, prop_value_binary::<Error>(input))?;
+            TypedValue::Binary(
+                base64::prelude::BASE64_STANDARD
+                    .decode(content)
+                    .map_err(|e| AetoliaError::other(format!("Invalid BINARY value: {e}")))?,
+            )
+        }
+        Value::Boolean => {
+            if raw.eq_ignore_ascii_case("TRUE") {
+                TypedValue::Boolean(true)
+            } else if raw.eq_ignore_ascii_case("FALSE") {
+                TypedValue::Boolean(false)
+            } else {
+                return Err(invalid_value(value_type));
+            }
+        }
+        Value::CalendarAddress => {
+            complete(value_type, param_value_uri::<Error>(input))?;
+            TypedValue::CalendarAddress(raw.to_string())
+        }
+        Value::Date => {
+            let date = complete(value_type, prop_value_date::<Error>(input))?;
+            TypedValue::Date((&date).try_into()?)
+        }
+        Value::DateTime => {
+            let date_time = complete(value_type, prop_value_date_time::<Error>(input))?;
+            let (date, time, is_utc) = (&date_time).try_into()?;
+            TypedValue::DateTime((date, Some(time), is_utc).into())
+        }
+        Value::Duration => {
+            let duration = complete(value_type, prop_value_duration::<Error>(input))?;
+            TypedValue::Duration(duration.to_model()?)
+        }
+        Value::Float => TypedValue::Float(complete(value_type, prop_value_float::<Error>(input))?),
+        Value::Integer => {
+            TypedValue::Integer(complete(value_type, prop_value_integer::<Error>(input))?)
+        }
+        Value::Period => {
+            let period = complete(value_type, prop_value_period::<Error>(input))?;
+            TypedValue::Period(period.to_model()?)
+        }
+        Value::Recurrence => {
+            let rule = complete(value_type, prop_value_recur::<Error>(input))?;
+            TypedValue::Recurrence(rule.to_model()?)
+        }
+        Value::Text => {
+            let text = complete(value_type, prop_value_text::<Error>(input))?;
+            TypedValue::Text(String::from_utf8(text).map_err(|_| invalid_value(value_type))?)
+        }
+        Value::Time => {
+            let time = complete(value_type, prop_value_time::<Error>(input))?;
+            TypedValue::Time(
+                time::Time::from_hms(time.hour, time.minute, time.second)
+                    .map_err(AetoliaError::time)?,
+                time.is_utc,
+            )
+        }
+        Value::Uri => {
+            complete(value_type, param_value_uri::<Error>(input))?;
+            TypedValue::Uri(raw.to_string())
+        }
+        Value::Uid => {
+            let text = complete(value_type, prop_value_text::<Error>(input))?;
+            TypedValue::Text(String::from_utf8(text).map_err(|_| invalid_value(value_type))?)
+        }
+        Value::UtcOffset => {
+            let offset = complete(value_type, prop_value_utc_offset::<Error>(input))?;
+            TypedValue::UtcOffset(offset.to_model()?)
+        }
+        Value::XmlReference | Value::XName(_) | Value::IanaToken(_) => {
+            TypedValue::Other(raw.to_string())
+        }
+    })
+}
+
+fn complete<'a, T>(
+    value_type: &Value,
+    result: IResult<&'a [u8], T, Error<'a>>,
+) -> AetoliaResult<T> {
+    match result {
+        Ok((rem, value)) if rem == VALUE_TERMINATOR => Ok(value),
+        _ => Err(invalid_value(value_type)),
+    }
+}
+
+fn invalid_value(value_type: &Value) -> AetoliaError {
+    AetoliaError::other(format!(
+        "Property value is not a valid {value_type:?} value"
+    ))
+}
+
+/// Split a list of values on the commas that are not escaped.
+fn split_values(raw: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in raw.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ',' => {
+                values.push(&raw[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    values.push(&raw[start..]);
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::property::{Duration, RecurRulePart, TimeZoneOffset};
+
+    #[test]
+    fn decode_simple_types() {
+        assert_eq!(
+            vec![TypedValue::Float(1.5), TypedValue::Float(-2.0)],
+            decode_typed_values(&Value::Float, "1.5,-2").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Boolean(false)],
+            decode_typed_values(&Value::Boolean, "false").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Binary(b"hello".to_vec())],
+            decode_typed_values(&Value::Binary, "aGVsbG8=").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Uri("https://example.com/cal".to_string())],
+            decode_typed_values(&Value::Uri, "https://example.com/cal").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Time(
+                time::Time::from_hms(10, 30, 0).unwrap(),
+                true
+            )],
+            decode_typed_values(&Value::Time, "103000Z").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::UtcOffset(TimeZoneOffset::new(-1, 5, 0, None))],
+            decode_typed_values(&Value::UtcOffset, "-0500").unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Other("anything, really".to_string())],
+            decode_typed_values(&Value::XName("X-THING".to_string()), "anything, really").unwrap()
+        );
+    }
+
+    #[test]
+    fn decode_structured_types() {
+        assert_eq!(
+            vec![TypedValue::Duration(Duration::hours(1, 2).build())],
+            decode_typed_values(&Value::Duration, "PT2H").unwrap()
+        );
+
+        let values = decode_typed_values(&Value::Recurrence, "FREQ=WEEKLY;BYDAY=MO,WE").unwrap();
+        match &values[..] {
+            [TypedValue::Recurrence(rule)] => {
+                assert_eq!(2, rule.parts.len());
+                assert!(matches!(rule.parts[1], RecurRulePart::ByDay(ref days) if days.len() == 2));
+            }
+            _ => panic!("Expected a single recurrence rule, got {:?}", values),
+        }
+
+        let values = decode_typed_values(&Value::Period, "20240101T100000Z/PT1H").unwrap();
+        assert_eq!(1, values.len());
+        assert!(matches!(values[0], TypedValue::Period(_)));
+    }
+
+    #[test]
+    fn reject_trailing_content() {
+        assert!(decode_typed_values(&Value::Integer, "12abc").is_err());
+        assert!(decode_typed_values(&Value::Date, "2024010").is_err());
+        assert!(decode_typed_values(&Value::Boolean, "yes").is_err());
+    }
+}
@@ -0,0 +1,943 @@
+mod rule;
+mod scale;
+mod time_zone;
+
+use crate::common::{CalendarDateTime, TriggerRelationship};
+use crate::model::access::{ComponentAccess, PropertyAccess};
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::TriggerRelationshipParam;
+use crate::model::property::{
+    ComponentProperty, Duration, DurationProperty, PeriodEnd, RecurRulePart,
+    RecurrenceDateTimesPropertyValue, RepeatProperty, TriggerProperty, TriggerValue,
+};
+use std::collections::HashMap;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+pub use rule::RecurrenceIter;
+pub use time_zone::TimeZoneResolver;
+
+pub(crate) use time_zone::{to_primitive, tz_id_of};
+
+/// A span of time, using UTC instants. Either bound may be left open.
+///
+/// The start is inclusive and the end is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: Option<OffsetDateTime>,
+    pub end: Option<OffsetDateTime>,
+}
+
+impl TimeRange {
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        TimeRange {
+            start: Some(start.to_offset(UtcOffset::UTC)),
+            end: Some(end.to_offset(UtcOffset::UTC)),
+        }
+    }
+
+    pub fn starting_at(start: OffsetDateTime) -> Self {
+        TimeRange {
+            start: Some(start.to_offset(UtcOffset::UTC)),
+            end: None,
+        }
+    }
+
+    pub fn ending_at(end: OffsetDateTime) -> Self {
+        TimeRange {
+            start: None,
+            end: Some(end.to_offset(UtcOffset::UTC)),
+        }
+    }
+
+    /// Check whether an instant falls within the range.
+    pub fn contains(&self, instant: OffsetDateTime) -> bool {
+        self.start.is_none_or(|start| start <= instant) && self.end.is_none_or(|end| instant < end)
+    }
+
+    /// Check whether a span of time overlaps the range, following RFC 4791, 9.9.
+    ///
+    /// A span with no duration overlaps if its start is contained in the range.
+    pub fn overlaps(&self, start: OffsetDateTime, end: OffsetDateTime) -> bool {
+        if end > start {
+            self.start.is_none_or(|s| s < end) && self.end.is_none_or(|e| e > start)
+        } else {
+            self.contains(start)
+        }
+    }
+}
+
+/// A single occurrence of a component, resolved to UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    pub start: OffsetDateTime,
+    /// The end of the occurrence. Equal to the start when the component has no duration.
+    pub end: OffsetDateTime,
+    /// Whether the occurrence is defined using DATE values rather than DATE-TIME values.
+    pub all_day: bool,
+}
+
+/// A time at which an alarm fires.
+#[derive(Debug, Clone)]
+pub struct ScheduledAlarm<'b> {
+    /// The VALARM component.
+    pub alarm: &'b CalendarComponent,
+    /// The position of the alarm among the alarms of its parent component.
+    pub alarm_index: usize,
+    /// The occurrence of the parent component that the trigger is relative to, or `None` if the
+    /// trigger is an absolute time.
+    pub occurrence: Option<Occurrence>,
+    /// Zero for the trigger itself, then counting up through the repetitions given by `REPEAT`.
+    pub repetition: u32,
+    pub at: OffsetDateTime,
+}
+
+/// Expands components of a calendar into their occurrences.
+///
+/// Recurrence is computed from `DTSTART`, `RRULE`, `RDATE` and `EXDATE`. Occurrences that have
+/// been overridden by another component with the same `UID` and a matching `RECURRENCE-ID` are
+/// left out of the expansion of the recurring component.
+pub struct Expander<'a> {
+    resolver: TimeZoneResolver<'a>,
+    overrides: HashMap<&'a str, Vec<OffsetDateTime>>,
+}
+
+impl<'a> Expander<'a> {
+    pub fn new(object: &'a ICalObject) -> Self {
+        let resolver = TimeZoneResolver::new(object);
+
+        let mut overrides = HashMap::<&'a str, Vec<OffsetDateTime>>::new();
+        for component in &object.components {
+            let mut uid = None;
+            let mut recurrence_id = None;
+            for property in component.properties() {
+                match property {
+                    ComponentProperty::UniqueIdentifier(p) => uid = Some(p.value.as_str()),
+                    ComponentProperty::RecurrenceId(p) => recurrence_id = Some(p),
+                    _ => {}
+                }
+            }
+
+            if let (Some(uid), Some(recurrence_id)) = (uid, recurrence_id) {
+                overrides
+                    .entry(uid)
+                    .or_default()
+                    .push(resolver.resolve_property(recurrence_id));
+            }
+        }
+
+        Expander {
+            resolver,
+            overrides,
+        }
+    }
+
+    /// Set the offset that floating date-times are interpreted in.
+    pub fn set_floating_offset(&mut self, offset: UtcOffset) {
+        self.resolver.set_floating_offset(offset);
+    }
+
+    pub fn time_zones(&self) -> &TimeZoneResolver<'a> {
+        &self.resolver
+    }
+
+    /// Find the occurrences of a component that overlap the given range, in order of their start.
+    ///
+    /// Components without a `DTSTART`, or a `DUE` for to-dos, have no occurrences. A component
+    /// with a `RECURRENCE-ID` is an override and only ever has the one occurrence.
+    pub fn occurrences<'b>(
+        &'b self,
+        component: &'b CalendarComponent,
+        range: &TimeRange,
+    ) -> Box<dyn Iterator<Item = Occurrence> + 'b> {
+        let timing = match Timing::new(component) {
+            Some(timing) => timing,
+            None => return Box::new(std::iter::empty()),
+        };
+
+        let range_for_filter = range.clone();
+        let range_end = range.end;
+        let bounded = move |occurrences: Box<dyn Iterator<Item = Occurrence> + 'b>| {
+            let range = range_for_filter.clone();
+            Box::new(
+                occurrences
+                    .take_while(move |o| range_end.is_none_or(|end| o.start < end))
+                    .filter(move |o| range.overlaps(o.start, o.end)),
+            ) as Box<dyn Iterator<Item = Occurrence> + 'b>
+        };
+
+        let first = self.occurrence_at(&timing, timing.start_local);
+        let is_override = component
+            .properties()
+            .iter()
+            .any(|p| matches!(p, ComponentProperty::RecurrenceId(_)));
+        if is_override {
+            return bounded(Box::new(first.into_iter()));
+        }
+
+        // Rule expansion must go a little past the end of the range because the local times that
+        // the rules produce may be ahead of UTC.
+        let horizon = range
+            .end
+            .and_then(|end| end.checked_add(time::Duration::days(2)))
+            .map(|end| PrimitiveDateTime::new(end.date(), end.time()))
+            .unwrap_or(PrimitiveDateTime::MAX);
+
+        let mut sources: Vec<std::iter::Peekable<Box<dyn Iterator<Item = Occurrence> + 'b>>> =
+            vec![(Box::new(first.into_iter()) as Box<dyn Iterator<Item = Occurrence>>).peekable()];
+        let mut extra = Vec::new();
+        let mut exceptions = Vec::new();
+
+        for property in component.properties() {
+            match property {
+                ComponentProperty::RecurrenceRule(p) => {
+                    let until = p.value.parts.iter().find_map(|part| match part {
+                        RecurRulePart::Until(until) => {
+                            Some(self.resolver.resolve(until, timing.start_tz))
+                        }
+                        _ => None,
+                    });
+                    let timing = timing.clone();
+                    let iter = RecurrenceIter::new(&p.value, timing.start_local, horizon)
+                        .filter_map(move |local| self.occurrence_at(&timing, local))
+                        .take_while(move |o| until.is_none_or(|until| o.start <= until));
+                    sources
+                        .push((Box::new(iter) as Box<dyn Iterator<Item = Occurrence>>).peekable());
+                }
+                ComponentProperty::RecurrenceDateTimes(p) => {
+                    let tz_id = tz_id_of(&p.params);
+                    match &p.value {
+                        RecurrenceDateTimesPropertyValue::DateTimes(date_times) => {
+                            for date_time in date_times {
+                                if let Some(occurrence) =
+                                    self.occurrence_at_value(&timing, date_time, tz_id)
+                                {
+                                    extra.push(occurrence);
+                                }
+                            }
+                        }
+                        RecurrenceDateTimesPropertyValue::Periods(periods) => {
+                            for period in periods {
+                                let start = self.resolver.resolve_local(
+                                    PrimitiveDateTime::new(period.start.0, period.start.1),
+                                    tz_id,
+                                    period.start.2,
+                                );
+                                let end = match &period.end {
+                                    PeriodEnd::DateTime((date, time, utc)) => {
+                                        Some(self.resolver.resolve_local(
+                                            PrimitiveDateTime::new(*date, *time),
+                                            tz_id,
+                                            *utc,
+                                        ))
+                                    }
+                                    PeriodEnd::Duration(duration) => {
+                                        start.checked_add(exact_duration(duration))
+                                    }
+                                };
+                                if let Some(end) = end {
+                                    extra.push(Occurrence {
+                                        start,
+                                        end,
+                                        all_day: false,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+                ComponentProperty::ExceptionDateTimes(p) => {
+                    let tz_id = tz_id_of(&p.params);
+                    for date_time in &p.value {
+                        exceptions.push(self.resolver.resolve(date_time, tz_id));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        extra.sort_by_key(|o| o.start);
+        sources
+            .push((Box::new(extra.into_iter()) as Box<dyn Iterator<Item = Occurrence>>).peekable());
+
+        if let Some(overridden) = timing.uid.and_then(|uid| self.overrides.get(uid)) {
+            exceptions.extend(overridden.iter().cloned());
+        }
+
+        let merged = MergeOccurrences {
+            sources,
+            last: None,
+        }
+        .filter(move |o| !exceptions.contains(&o.start));
+
+        bounded(Box::new(merged))
+    }
+
+    /// Find the times at which an alarm triggers within the given range, in order.
+    ///
+    /// Triggers that are relative to the start or end of the `parent` component are computed for
+    /// each of its occurrences. Repetitions of the alarm, as given by `REPEAT` and `DURATION`,
+    /// are included.
+    pub fn alarm_triggers(
+        &self,
+        parent: &CalendarComponent,
+        alarm: &CalendarComponent,
+        range: &TimeRange,
+    ) -> Vec<OffsetDateTime> {
+        let mut triggers = self
+            .scheduled_alarm(parent, 0, alarm, range)
+            .into_iter()
+            .map(|t| t.at)
+            .collect::<Vec<_>>();
+
+        triggers.sort();
+        triggers.dedup();
+        triggers
+    }
+
+    /// Find when each of the alarms of an event or to-do fires within the given range, ordered
+    /// by the time they fire and then by the position of the alarm.
+    ///
+    /// As for [Self::alarm_triggers], relative triggers are computed for each occurrence of the
+    /// `parent` and repetitions are included. A trigger related to the end of a to-do is
+    /// relative to its `DUE`.
+    pub fn scheduled_alarms<'b>(
+        &self,
+        parent: &'b CalendarComponent,
+        range: &TimeRange,
+    ) -> Vec<ScheduledAlarm<'b>> {
+        let mut scheduled = parent
+            .nested_components()
+            .iter()
+            .filter(|c| matches!(c, CalendarComponent::Alarm(_)))
+            .enumerate()
+            .flat_map(|(alarm_index, alarm)| {
+                self.scheduled_alarm(parent, alarm_index, alarm, range)
+            })
+            .collect::<Vec<_>>();
+
+        scheduled.sort_by_key(|t| (t.at, t.alarm_index, t.repetition));
+        scheduled
+    }
+
+    fn scheduled_alarm<'b>(
+        &self,
+        parent: &CalendarComponent,
+        alarm_index: usize,
+        alarm: &'b CalendarComponent,
+        range: &TimeRange,
+    ) -> Vec<ScheduledAlarm<'b>> {
+        let trigger = match alarm.get_property::<TriggerProperty>() {
+            Some(trigger) => trigger,
+            None => return Vec::new(),
+        };
+
+        let repeat = alarm
+            .get_property::<RepeatProperty>()
+            .map(|r| *r.value())
+            .unwrap_or(0);
+        let interval = alarm
+            .get_property::<DurationProperty>()
+            .map(|d| exact_duration(d.value()).abs())
+            .unwrap_or(time::Duration::ZERO);
+        // The time from the first trigger to the last repetition, or `None` if that is too long
+        // to represent, in which case the range is not limited by it.
+        let repeat_span = i32::try_from(repeat)
+            .ok()
+            .and_then(|repeat| interval.checked_mul(repeat));
+
+        let mut base_times = Vec::new();
+        match trigger.value() {
+            TriggerValue::Absolute(at) => {
+                base_times.push((None, self.resolver.resolve(at, tz_id_of(trigger.params()))));
+            }
+            TriggerValue::Relative(offset) => {
+                let offset = exact_duration(offset);
+                let related = trigger
+                    .get_param::<TriggerRelationshipParam>()
+                    .map(|p| p.trigger_relationship.clone())
+                    .unwrap_or_default();
+
+                // Widen the range so that occurrences whose alarms fall into it are found.
+                let span = repeat_span.and_then(|span| span.checked_add(offset.abs()));
+                let widened = TimeRange {
+                    start: range
+                        .start
+                        .zip(span)
+                        .and_then(|(start, span)| start.checked_sub(span)),
+                    end: range
+                        .end
+                        .zip(span)
+                        .and_then(|(end, span)| end.checked_add(span)),
+                };
+
+                for occurrence in self.occurrences(parent, &widened) {
+                    let base = match related {
+                        TriggerRelationship::Start => occurrence.start,
+                        TriggerRelationship::End => occurrence.end,
+                    };
+                    if let Some(at) = base.checked_add(offset) {
+                        base_times.push((Some(occurrence), at));
+                    }
+                }
+            }
+        }
+
+        let mut scheduled = Vec::new();
+        for (occurrence, base) in base_times {
+            let mut at = Some(base);
+            for repetition in 0..=repeat {
+                let Some(current) = at else {
+                    break;
+                };
+                // Repetitions only get later, so once one is past the range the rest are too
+                if range.end.is_some_and(|end| current >= end) {
+                    break;
+                }
+                if range.contains(current) {
+                    scheduled.push(ScheduledAlarm {
+                        alarm,
+                        alarm_index,
+                        occurrence: occurrence.clone(),
+                        repetition,
+                        at: current,
+                    });
+                }
+
+                // Without an interval every repetition would fire at the same time as the first
+                if interval.is_zero() {
+                    break;
+                }
+                at = current.checked_add(interval);
+            }
+        }
+
+        scheduled
+    }
+
+    fn occurrence_at(&self, timing: &Timing, local: PrimitiveDateTime) -> Option<Occurrence> {
+        let start = self
+            .resolver
+            .resolve_local(local, timing.start_tz, timing.start.is_utc());
+
+        let end = match &timing.length {
+            Length::End(end, end_tz) => {
+                let same_frame =
+                    end.is_utc() == timing.start.is_utc() && *end_tz == timing.start_tz;
+                if same_frame {
+                    let nominal = to_primitive(end) - timing.start_local;
+                    self.resolver
+                        .resolve_local(local.checked_add(nominal)?, *end_tz, end.is_utc())
+                } else {
+                    let exact = self.resolver.resolve(end, *end_tz)
+                        - self.resolver.resolve(timing.start, timing.start_tz);
+                    start.checked_add(exact)?
+                }
+            }
+            Length::Duration(duration) => {
+                let (days, seconds) = split_duration(duration);
+                let shifted = local.checked_add(time::Duration::days(days))?;
+                self.resolver
+                    .resolve_local(shifted, timing.start_tz, timing.start.is_utc())
+                    .checked_add(time::Duration::seconds(seconds))?
+            }
+            Length::Day => self.resolver.resolve_local(
+                local.checked_add(time::Duration::days(1))?,
+                timing.start_tz,
+                false,
+            ),
+            Length::None => start,
+        };
+
+        Some(Occurrence {
+            start,
+            end,
+            all_day: timing.start.is_date(),
+        })
+    }
+
+    fn occurrence_at_value(
+        &self,
+        timing: &Timing,
+        value: &CalendarDateTime,
+        tz_id: Option<&str>,
+    ) -> Option<Occurrence> {
+        if tz_id == timing.start_tz && value.is_utc() == timing.start.is_utc() {
+            return self.occurrence_at(timing, to_primitive(value));
+        }
+
+        // A different time zone from the start, so work out the length from the first occurrence.
+        let first = self.occurrence_at(timing, timing.start_local)?;
+        let start = self.resolver.resolve(value, tz_id);
+        Some(Occurrence {
+            start,
+            end: start.checked_add(first.end - first.start)?,
+            all_day: value.is_date(),
+        })
+    }
+}
+
+/// Convert a duration to an exact amount of time, taking days as 24 hours.
+pub(crate) fn exact_duration(duration: &Duration) -> time::Duration {
+    let (days, seconds) = split_duration(duration);
+    time::Duration::days(days) + time::Duration::seconds(seconds)
+}
+
+/// Split a duration into nominal days and exact seconds, with the sign applied.
+pub(crate) fn split_duration(duration: &Duration) -> (i64, i64) {
+    let sign = if duration.sign < 0 { -1 } else { 1 };
+    let days = duration.weeks.unwrap_or(0) * 7 + duration.days.unwrap_or(0);
+    let seconds = duration.hours.unwrap_or(0) * 60 * 60
+        + duration.minutes.unwrap_or(0) * 60
+        + duration.seconds.unwrap_or(0);
+
+    (sign * days as i64, sign * seconds as i64)
+}
+
+#[derive(Clone)]
+struct Timing<'b> {
+    uid: Option<&'b str>,
+    start: &'b CalendarDateTime,
+    start_tz: Option<&'b str>,
+    start_local: PrimitiveDateTime,
+    length: Length<'b>,
+}
+
+#[derive(Clone)]
+enum Length<'b> {
+    End(&'b CalendarDateTime, Option<&'b str>),
+    Duration(&'b Duration),
+    Day,
+    None,
+}
+
+impl<'b> Timing<'b> {
+    fn new(component: &'b CalendarComponent) -> Option<Self> {
+        let mut uid = None;
+        let mut start = None;
+        let mut end = None;
+        let mut due = None;
+        let mut duration = None;
+        for property in component.properties() {
+            match property {
+                ComponentProperty::UniqueIdentifier(p) => uid = Some(p.value.as_str()),
+                ComponentProperty::DateTimeStart(p) => {
+                    start = Some((p.value(), tz_id_of(p.params())))
+                }
+                ComponentProperty::DateTimeEnd(p) => end = Some((p.value(), tz_id_of(p.params()))),
+                ComponentProperty::DateTimeDue(p) => due = Some((p.value(), tz_id_of(p.params()))),
+                ComponentProperty::Duration(p) => duration = Some(p.value()),
+                _ => {}
+            }
+        }
+
+        let end = end.or(due);
+        let (start, start_tz, length) = match (start, end, duration) {
+            (Some((start, tz)), Some((end, end_tz)), _) => (start, tz, Length::End(end, end_tz)),
+            (Some((start, tz)), None, Some(duration)) => (start, tz, Length::Duration(duration)),
+            (Some((start, tz)), None, None) if start.is_date() => (start, tz, Length::Day),
+            (Some((start, tz)), None, None) => (start, tz, Length::None),
+            // A to-do may only have a due date, which is then the only point in time available
+            (None, Some((due, tz)), _) => (due, tz, Length::None),
+            (None, None, _) => return None,
+        };
+
+        Some(Timing {
+            uid,
+            start,
+            start_tz,
+            start_local: to_primitive(start),
+            length,
+        })
+    }
+}
+
+struct MergeOccurrences<'b> {
+    sources: Vec<std::iter::Peekable<Box<dyn Iterator<Item = Occurrence> + 'b>>>,
+    last: Option<OffsetDateTime>,
+}
+
+impl Iterator for MergeOccurrences<'_> {
+    type Item = Occurrence;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next_index = self
+                .sources
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(i, s)| s.peek().map(|o| (i, o.start)))
+                .min_by_key(|(_, start)| *start)
+                .map(|(i, _)| i)?;
+
+            let next = self.sources[next_index].next()?;
+            if self.last == Some(next.start) {
+                continue;
+            }
+
+            self.last = Some(next.start);
+            return Some(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+    use time::{Date, Month, Time};
+
+    fn utc(year: i32, month: Month, day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms(hour, minute, 0).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Europe/Berlin\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19810329T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0200\r\n\
+END:DAYLIGHT\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19961027T030000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\n\
+TZOFFSETFROM:+0200\r\n\
+TZOFFSETTO:+0100\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=Europe/Berlin:20240318T100000\r\n\
+DTEND;TZID=Europe/Berlin:20240318T110000\r\n\
+RRULE:FREQ=WEEKLY;COUNT=4\r\n\
+EXDATE;TZID=Europe/Berlin:20240401T100000\r\n\
+RDATE:20240410T120000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RECURRENCE-ID;TZID=Europe/Berlin:20240325T100000\r\n\
+DTSTART;TZID=Europe/Berlin:20240325T150000\r\n\
+DURATION:PT30M\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:all-day\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;VALUE=DATE:20240501\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn expand_recurring_event() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+
+        let occurrences: Vec<Occurrence> = expander
+            .occurrences(
+                &object.components[1],
+                &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+            )
+            .collect();
+
+        assert_eq!(
+            vec![
+                // Before the switch to summer time
+                Occurrence {
+                    start: utc(2024, Month::March, 18, 9, 0),
+                    end: utc(2024, Month::March, 18, 10, 0),
+                    all_day: false,
+                },
+                // 2024-03-25 is overridden and 2024-04-01 is excluded
+                Occurrence {
+                    start: utc(2024, Month::April, 8, 8, 0),
+                    end: utc(2024, Month::April, 8, 9, 0),
+                    all_day: false,
+                },
+                Occurrence {
+                    start: utc(2024, Month::April, 10, 12, 0),
+                    end: utc(2024, Month::April, 10, 13, 0),
+                    all_day: false,
+                },
+            ],
+            occurrences
+        );
+    }
+
+    #[test]
+    fn expand_override() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+
+        let occurrences: Vec<Occurrence> = expander
+            .occurrences(
+                &object.components[2],
+                &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+            )
+            .collect();
+
+        assert_eq!(
+            vec![Occurrence {
+                start: utc(2024, Month::March, 25, 14, 0),
+                end: utc(2024, Month::March, 25, 14, 30),
+                all_day: false,
+            }],
+            occurrences
+        );
+    }
+
+    #[test]
+    fn expand_within_range() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+
+        let range = TimeRange::new(
+            utc(2024, Month::April, 8, 8, 30),
+            utc(2024, Month::April, 9, 0, 0),
+        );
+        let occurrences: Vec<Occurrence> = expander
+            .occurrences(&object.components[1], &range)
+            .collect();
+
+        assert_eq!(1, occurrences.len());
+        assert_eq!(utc(2024, Month::April, 8, 8, 0), occurrences[0].start);
+    }
+
+    #[test]
+    fn expand_start_that_does_not_match_the_rule() {
+        let object = load_ical(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240316T090000Z\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=2\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n",
+        )
+        .unwrap()
+        .pop()
+        .unwrap();
+        let expander = Expander::new(&object);
+
+        let occurrences: Vec<OffsetDateTime> = expander
+            .occurrences(
+                &object.components[0],
+                &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+            )
+            .map(|o| o.start)
+            .collect();
+
+        // The start on a Saturday is the first of the two occurrences
+        assert_eq!(
+            vec![
+                utc(2024, Month::March, 16, 9, 0),
+                utc(2024, Month::March, 18, 9, 0),
+            ],
+            occurrences
+        );
+    }
+
+    #[test]
+    fn expand_all_day() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let mut expander = Expander::new(&object);
+        expander.set_floating_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+
+        let occurrences: Vec<Occurrence> = expander
+            .occurrences(
+                &object.components[3],
+                &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+            )
+            .collect();
+
+        assert_eq!(
+            vec![Occurrence {
+                start: utc(2024, Month::April, 30, 22, 0),
+                end: utc(2024, Month::May, 1, 22, 0),
+                all_day: true,
+            }],
+            occurrences
+        );
+    }
+
+    const ALARMS: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:daily\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T100000Z\r\n\
+DURATION:PT1H\r\n\
+RRULE:FREQ=DAILY;COUNT=3\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Starting soon\r\n\
+TRIGGER:-PT15M\r\n\
+REPEAT:2\r\n\
+DURATION:PT5M\r\n\
+END:VALARM\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Finished\r\n\
+TRIGGER;RELATED=END:PT0S\r\n\
+END:VALARM\r\n\
+BEGIN:VALARM\r\n\
+ACTION:AUDIO\r\n\
+TRIGGER;VALUE=DATE-TIME:20240102T080000Z\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VTODO\r\n\
+UID:report\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DUE:20240105T170000Z\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Due soon\r\n\
+TRIGGER;RELATED=END:-PT1H\r\n\
+END:VALARM\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn alarm_triggers_with_repeat() {
+        let object = load_ical(ALARMS).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+        let event = &object.components[0];
+
+        let triggers = expander.alarm_triggers(
+            event,
+            &event.nested_components()[0],
+            &TimeRange::new(
+                utc(2024, Month::January, 3, 9, 50),
+                utc(2024, Month::January, 4, 9, 50),
+            ),
+        );
+
+        assert_eq!(
+            vec![
+                utc(2024, Month::January, 3, 9, 50),
+                utc(2024, Month::January, 3, 9, 55),
+                utc(2024, Month::January, 4, 9, 45),
+            ],
+            triggers
+        );
+    }
+
+    #[test]
+    fn scheduled_alarms_per_occurrence() {
+        let object = load_ical(ALARMS).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+        let event = &object.components[0];
+
+        let scheduled = expander.scheduled_alarms(
+            event,
+            &TimeRange::new(
+                utc(2024, Month::January, 2, 0, 0),
+                utc(2024, Month::January, 3, 0, 0),
+            ),
+        );
+
+        assert_eq!(
+            vec![
+                (2, 0, None, utc(2024, Month::January, 2, 8, 0)),
+                (
+                    0,
+                    0,
+                    Some(utc(2024, Month::January, 2, 10, 0)),
+                    utc(2024, Month::January, 2, 9, 45)
+                ),
+                (
+                    0,
+                    1,
+                    Some(utc(2024, Month::January, 2, 10, 0)),
+                    utc(2024, Month::January, 2, 9, 50)
+                ),
+                (
+                    0,
+                    2,
+                    Some(utc(2024, Month::January, 2, 10, 0)),
+                    utc(2024, Month::January, 2, 9, 55)
+                ),
+                (
+                    1,
+                    0,
+                    Some(utc(2024, Month::January, 2, 10, 0)),
+                    utc(2024, Month::January, 2, 11, 0)
+                ),
+            ],
+            scheduled
+                .iter()
+                .map(|s| (
+                    s.alarm_index,
+                    s.repetition,
+                    s.occurrence.as_ref().map(|o| o.start),
+                    s.at
+                ))
+                .collect::<Vec<_>>()
+        );
+        assert!(std::ptr::eq(
+            &event.nested_components()[1],
+            scheduled[4].alarm
+        ));
+    }
+
+    #[test]
+    fn scheduled_alarms_for_to_do_due() {
+        let object = load_ical(ALARMS).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+
+        let scheduled = expander.scheduled_alarms(
+            &object.components[1],
+            &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+        );
+
+        assert_eq!(1, scheduled.len());
+        assert_eq!(utc(2024, Month::January, 5, 16, 0), scheduled[0].at);
+    }
+
+    #[test]
+    fn scheduled_alarms_with_a_large_repeat() {
+        let content = ALARMS
+            .replace("REPEAT:2\r\n", "REPEAT:2147483647\r\n")
+            .replace("DURATION:PT5M\r\n", "DURATION:P10000W\r\n");
+        let object = load_ical(&content).unwrap().pop().unwrap();
+        let expander = Expander::new(&object);
+        let event = &object.components[0];
+
+        let triggers = expander.alarm_triggers(
+            event,
+            &event.nested_components()[0],
+            &TimeRange::new(
+                utc(2024, Month::January, 1, 0, 0),
+                utc(2025, Month::January, 1, 0, 0),
+            ),
+        );
+        assert_eq!(
+            vec![
+                utc(2024, Month::January, 2, 9, 45),
+                utc(2024, Month::January, 3, 9, 45),
+                utc(2024, Month::January, 4, 9, 45),
+            ],
+            triggers
+        );
+
+        // Without an end to the range, repetitions stop where dates can no longer be represented
+        let triggers = expander.alarm_triggers(
+            event,
+            &event.nested_components()[0],
+            &TimeRange::starting_at(utc(2024, Month::January, 1, 0, 0)),
+        );
+        assert_eq!(3 * 42, triggers.len());
+    }
+}
@@ -0,0 +1,1131 @@
+use crate::common::{OffsetWeekday, RecurFreq, RecurMonth, Skip, Weekday};
+use crate::expand::scale::{days_of_month, DayInfo, Scale, ScaleCalendar, ScaleMonth};
+use crate::model::property::{RecurRulePart, RecurrenceRule};
+use std::collections::VecDeque;
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+/// Iterator over the local date-times generated by a recurrence rule, following the expansion
+/// rules in RFC 5545, 3.3.10.
+///
+/// The values are produced in the same local time frame as the `start` that the iterator was
+/// created with. The `UNTIL` part is not applied here because comparing it to local times
+/// requires time zone information, see [crate::expand::Expander] for that. The `COUNT` part is
+/// applied, with the `start` counted as the first value even if the rule does not produce it,
+/// as RFC 5545 requires. The caller is expected to include the `start` in that case.
+///
+/// Iteration stops once a recurrence period begins after the `horizon`.
+///
+/// Rules with an `RSCALE` part are expanded in that calendar scale, following RFC 7529. The
+/// Gregorian, Hebrew and Chinese scales are supported, and a rule with any other scale produces
+/// no values.
+pub struct RecurrenceIter<'a> {
+    rule: RuleParts<'a>,
+    start: PrimitiveDateTime,
+    start_info: DayInfo,
+    horizon: PrimitiveDateTime,
+    calendar: Option<ScaleCalendar>,
+    month_cursor: Option<MonthCursor>,
+    period: u64,
+    pending: VecDeque<PrimitiveDateTime>,
+    emitted: u64,
+    start_counted: bool,
+    done: bool,
+}
+
+/// A month of a calendar scale, as the number of months after the start month.
+#[derive(Clone, Copy)]
+struct MonthCursor {
+    step: i64,
+    year: i32,
+    index: usize,
+}
+
+/// The candidate days of a recurrence period.
+struct Period {
+    days: Vec<DayInfo>,
+    /// The time of day that is fixed by the period for sub-daily frequencies.
+    fixed: Option<PrimitiveDateTime>,
+    start: PrimitiveDateTime,
+    /// Whether the days have already been selected by the rule, so they must not be filtered.
+    resolved: bool,
+}
+
+struct RuleParts<'a> {
+    freq: RecurFreq,
+    interval: u64,
+    count: Option<u64>,
+    by_second: Option<&'a [u8]>,
+    by_minute: Option<&'a [u8]>,
+    by_hour: Option<&'a [u8]>,
+    by_day: Option<&'a [OffsetWeekday]>,
+    by_month_day: Option<&'a [i8]>,
+    by_year_day: Option<&'a [i16]>,
+    by_week_number: Option<&'a [i8]>,
+    by_month: Option<&'a [RecurMonth]>,
+    by_set_pos: Option<&'a [i16]>,
+    week_start: Weekday,
+    rscale: Option<&'a str>,
+    skip: Skip,
+}
+
+impl<'a> RuleParts<'a> {
+    fn new(rule: &'a RecurrenceRule) -> Self {
+        let mut parts = RuleParts {
+            freq: RecurFreq::Daily,
+            interval: 1,
+            count: None,
+            by_second: None,
+            by_minute: None,
+            by_hour: None,
+            by_day: None,
+            by_month_day: None,
+            by_year_day: None,
+            by_week_number: None,
+            by_month: None,
+            by_set_pos: None,
+            week_start: Weekday::Monday,
+            rscale: None,
+            skip: Skip::Omit,
+        };
+
+        for part in &rule.parts {
+            match part {
+                RecurRulePart::Freq(freq) => parts.freq = freq.clone(),
+                RecurRulePart::Until(_) => {}
+                RecurRulePart::Count(count) => parts.count = Some(*count),
+                RecurRulePart::Interval(interval) => parts.interval = (*interval).max(1),
+                RecurRulePart::BySecList(v) => parts.by_second = Some(v),
+                RecurRulePart::ByMinute(v) => parts.by_minute = Some(v),
+                RecurRulePart::ByHour(v) => parts.by_hour = Some(v),
+                RecurRulePart::ByDay(v) => parts.by_day = Some(v),
+                RecurRulePart::ByMonthDay(v) => parts.by_month_day = Some(v),
+                RecurRulePart::ByYearDay(v) => parts.by_year_day = Some(v),
+                RecurRulePart::ByWeekNumber(v) => parts.by_week_number = Some(v),
+                RecurRulePart::ByMonth(v) => parts.by_month = Some(v),
+                RecurRulePart::BySetPos(v) => parts.by_set_pos = Some(v),
+                RecurRulePart::WeekStart(w) => parts.week_start = w.clone(),
+                RecurRulePart::RScale(rscale) => parts.rscale = Some(rscale),
+                RecurRulePart::Skip(skip) => parts.skip = *skip,
+            }
+        }
+
+        parts
+    }
+}
+
+impl<'a> RecurrenceIter<'a> {
+    pub fn new(
+        rule: &'a RecurrenceRule,
+        start: PrimitiveDateTime,
+        horizon: PrimitiveDateTime,
+    ) -> Self {
+        let rule = RuleParts::new(rule);
+
+        let mut calendar = rule
+            .rscale
+            .and_then(Scale::from_name)
+            .map(ScaleCalendar::new);
+        let start_info = match &mut calendar {
+            Some(calendar) => calendar.locate(start.date()),
+            None => Some(DayInfo::gregorian(start.date())),
+        };
+        let done = start_info.is_none() || (rule.rscale.is_some() && calendar.is_none());
+
+        RecurrenceIter {
+            rule,
+            start,
+            start_info: start_info.unwrap_or(DayInfo::gregorian(start.date())),
+            horizon,
+            calendar,
+            month_cursor: None,
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            start_counted: false,
+            done,
+        }
+    }
+
+    /// Compute the candidate days for the next period.
+    ///
+    /// Returns `None` when the period cannot be represented or is beyond the horizon.
+    fn next_period(&mut self) -> Option<Period> {
+        let step = self.period.checked_mul(self.rule.interval)?;
+        let step = i64::try_from(step).ok()?;
+        self.period += 1;
+
+        let period = match self.calendar.take() {
+            Some(mut calendar) => {
+                let period = self.scale_period(&mut calendar, step);
+                self.calendar = Some(calendar);
+                period?
+            }
+            None => self.gregorian_period(step)?,
+        };
+
+        if period.start > self.horizon {
+            return None;
+        }
+
+        Some(period)
+    }
+
+    fn gregorian_period(&self, step: i64) -> Option<Period> {
+        let start_date = self.start.date();
+        let (days, fixed, period_start) = match self.rule.freq {
+            RecurFreq::Yearly => {
+                let year = i32::try_from(start_date.year() as i64 + step).ok()?;
+                let first = Date::from_calendar_date(year, Month::January, 1).ok()?;
+                (self.year_days(year)?, None, first)
+            }
+            RecurFreq::Monthly => {
+                let index = start_date.year() as i64 * 12 + start_date.month() as i64 - 1 + step;
+                let year = i32::try_from(index.div_euclid(12)).ok()?;
+                let month = Month::try_from(index.rem_euclid(12) as u8 + 1).ok()?;
+                let first = Date::from_calendar_date(year, month, 1).ok()?;
+                let days = (1..=month.length(year))
+                    .filter_map(|d| Date::from_calendar_date(year, month, d).ok())
+                    .collect();
+                (days, None, first)
+            }
+            _ => self.day_period(step)?,
+        };
+
+        Some(Period {
+            days: days.into_iter().map(DayInfo::gregorian).collect(),
+            fixed,
+            start: fixed.unwrap_or(PrimitiveDateTime::new(period_start, Time::MIDNIGHT)),
+            resolved: false,
+        })
+    }
+
+    /// The days of a period for frequencies that do not depend on the calendar scale, along with
+    /// the time of day that is fixed by the period for sub-daily frequencies.
+    fn day_period(&self, step: i64) -> Option<(Vec<Date>, Option<PrimitiveDateTime>, Date)> {
+        let start_date = self.start.date();
+        Some(match self.rule.freq {
+            RecurFreq::Yearly | RecurFreq::Monthly => return None,
+            RecurFreq::Weekly => {
+                let first = week_start_of(start_date, &self.rule.week_start)
+                    .checked_add(time::Duration::weeks(step))?;
+                let days = (0..7)
+                    .filter_map(|d| first.checked_add(time::Duration::days(d)))
+                    .collect();
+                (days, None, first)
+            }
+            RecurFreq::Daily => {
+                let day = start_date.checked_add(time::Duration::days(step))?;
+                (vec![day], None, day)
+            }
+            RecurFreq::Hourly => {
+                let base = self
+                    .start
+                    .replace_time(Time::from_hms(self.start.hour(), 0, 0).ok()?);
+                let at = base.checked_add(time::Duration::hours(step))?;
+                (vec![at.date()], Some(at), at.date())
+            }
+            RecurFreq::Minutely => {
+                let base = self
+                    .start
+                    .replace_time(Time::from_hms(self.start.hour(), self.start.minute(), 0).ok()?);
+                let at = base.checked_add(time::Duration::minutes(step))?;
+                (vec![at.date()], Some(at), at.date())
+            }
+            RecurFreq::Secondly => {
+                let at = self.start.checked_add(time::Duration::seconds(step))?;
+                (vec![at.date()], Some(at), at.date())
+            }
+        })
+    }
+
+    fn scale_period(&mut self, calendar: &mut ScaleCalendar, step: i64) -> Option<Period> {
+        let (year, months, indices) = match self.rule.freq {
+            RecurFreq::Yearly => {
+                let year = i32::try_from(self.start_info.year as i64 + step).ok()?;
+                let months = calendar.months(year)?;
+                let indices = if self.resolves_days() {
+                    self.year_month_indices(&months)
+                } else {
+                    (0..months.len())
+                        .filter(|i| match self.rule.by_month {
+                            Some(by_month) if self.rule.by_year_day.is_none() => {
+                                by_month.contains(&months[*i].month)
+                            }
+                            _ => true,
+                        })
+                        .collect()
+                };
+                (year, months, indices)
+            }
+            RecurFreq::Monthly => {
+                let cursor = self.month_at(calendar, step)?;
+                let months = calendar.months(cursor.year)?;
+                (cursor.year, months, vec![cursor.index])
+            }
+            _ => {
+                let (days, fixed, period_start) = self.day_period(step)?;
+                return Some(Period {
+                    days: days
+                        .into_iter()
+                        .filter_map(|d| calendar.locate(d))
+                        .collect(),
+                    fixed,
+                    start: fixed.unwrap_or(PrimitiveDateTime::new(period_start, Time::MIDNIGHT)),
+                    resolved: false,
+                });
+            }
+        };
+
+        let first = match self.rule.freq {
+            RecurFreq::Monthly => months.get(*indices.first()?)?.first,
+            _ => months.first()?.first,
+        };
+
+        let (days, resolved) = if self.resolves_days() {
+            (self.resolve_days(calendar, &months, &indices), true)
+        } else {
+            (
+                indices
+                    .iter()
+                    .flat_map(|i| days_of_month(year, &months, *i))
+                    .collect(),
+                false,
+            )
+        };
+
+        Some(Period {
+            days,
+            fixed: None,
+            start: PrimitiveDateTime::new(first, Time::MIDNIGHT),
+            resolved,
+        })
+    }
+
+    /// Find the month that is `step` months after the start month in the calendar scale.
+    ///
+    /// Months vary in number between years, so they are counted from the last month found.
+    fn month_at(&mut self, calendar: &mut ScaleCalendar, step: i64) -> Option<MonthCursor> {
+        let mut cursor = match self.month_cursor {
+            Some(cursor) if cursor.step <= step => cursor,
+            _ => {
+                let months = calendar.months(self.start_info.year)?;
+                MonthCursor {
+                    step: 0,
+                    year: self.start_info.year,
+                    index: months
+                        .iter()
+                        .position(|m| m.month == self.start_info.month)?,
+                }
+            }
+        };
+
+        let mut months = calendar.months(cursor.year)?;
+        while cursor.step < step {
+            cursor.step += 1;
+            cursor.index += 1;
+            if cursor.index >= months.len() {
+                cursor.year = cursor.year.checked_add(1)?;
+                cursor.index = 0;
+                months = calendar.months(cursor.year)?;
+            }
+        }
+
+        self.month_cursor = Some(cursor);
+        Some(cursor)
+    }
+
+    /// Whether the days of a period in a calendar scale are chosen directly from the month and
+    /// day of the month, so that dates which do not exist can be handled according to `SKIP`.
+    fn resolves_days(&self) -> bool {
+        matches!(self.rule.freq, RecurFreq::Yearly | RecurFreq::Monthly)
+            && self.rule.by_day.is_none()
+            && self.rule.by_year_day.is_none()
+            && self.rule.by_week_number.is_none()
+    }
+
+    /// The months of a year that are selected by `BYMONTH`, or the start month. A leap month
+    /// that is missing from the year is moved according to `SKIP`.
+    fn year_month_indices(&self, months: &[ScaleMonth]) -> Vec<usize> {
+        let wanted = match self.rule.by_month {
+            Some(by_month) => by_month.to_vec(),
+            None => vec![self.start_info.month],
+        };
+
+        let mut indices: Vec<usize> = wanted
+            .iter()
+            .filter_map(|month| {
+                if let Some(index) = months.iter().position(|m| m.month == *month) {
+                    return Some(index);
+                }
+
+                if !month.leap {
+                    return None;
+                }
+
+                let base = months
+                    .iter()
+                    .position(|m| m.month == RecurMonth::new(month.number))?;
+                match self.rule.skip {
+                    Skip::Omit => None,
+                    Skip::Backward => Some(base),
+                    Skip::Forward => Some(base + 1).filter(|i| *i < months.len()),
+                }
+            })
+            .collect();
+        indices.sort();
+        indices.dedup();
+        indices
+    }
+
+    /// Select the days of the given months from `BYMONTHDAY`, or the start day. A day past the
+    /// end of its month is moved according to `SKIP`.
+    fn resolve_days(
+        &self,
+        calendar: &mut ScaleCalendar,
+        months: &[ScaleMonth],
+        indices: &[usize],
+    ) -> Vec<DayInfo> {
+        let month_days = match self.rule.by_month_day {
+            Some(month_days) => month_days.to_vec(),
+            None => vec![self.start_info.day as i8],
+        };
+
+        let mut days = Vec::new();
+        for month in indices.iter().filter_map(|i| months.get(*i)) {
+            if self.rule.freq == RecurFreq::Monthly {
+                if let Some(by_month) = self.rule.by_month {
+                    if !by_month.contains(&month.month) {
+                        continue;
+                    }
+                }
+            }
+
+            let length = month.length as i32;
+            for month_day in &month_days {
+                let day = if *month_day > 0 {
+                    *month_day as i32
+                } else {
+                    length + *month_day as i32 + 1
+                };
+
+                let date = if (1..=length).contains(&day) {
+                    month.day(day as u8)
+                } else {
+                    match (self.rule.skip, day > length) {
+                        (Skip::Omit, _) => None,
+                        (Skip::Backward, true) => month.day(month.length),
+                        (Skip::Backward, false) => month.first.previous_day(),
+                        (Skip::Forward, true) => month.day(month.length).and_then(|d| d.next_day()),
+                        (Skip::Forward, false) => Some(month.first),
+                    }
+                };
+
+                if let Some(info) = date.and_then(|d| calendar.locate(d)) {
+                    days.push(info);
+                }
+            }
+        }
+
+        days.sort_by_key(|d| d.date);
+        days.dedup_by_key(|d| d.date);
+        days
+    }
+
+    fn year_days(&self, year: i32) -> Option<Vec<Date>> {
+        if self.rule.by_week_number.is_some() {
+            // Weeks at the edge of the year may begin or end in the neighbouring years.
+            let mut day = Date::from_calendar_date(year, Month::January, 1)
+                .ok()?
+                .checked_sub(time::Duration::days(7))?;
+            let last = Date::from_calendar_date(year, Month::December, 31)
+                .ok()?
+                .checked_add(time::Duration::days(7))?;
+            let mut days = Vec::with_capacity(380);
+            while day <= last {
+                if week_number(day, &self.rule.week_start).0 == year {
+                    days.push(day);
+                }
+                day = day.next_day()?;
+            }
+            Some(days)
+        } else {
+            let months: Vec<Month> = match self.rule.by_month {
+                Some(months) if self.rule.by_year_day.is_none() => {
+                    let mut months: Vec<Month> = months
+                        .iter()
+                        .filter(|m| !m.leap)
+                        .filter_map(|m| Month::try_from(m.number).ok())
+                        .collect();
+                    months.sort_by_key(|m| *m as u8);
+                    months.dedup();
+                    months
+                }
+                _ => (1..=12).filter_map(|m| Month::try_from(m).ok()).collect(),
+            };
+
+            Some(
+                months
+                    .into_iter()
+                    .flat_map(|month| {
+                        (1..=month.length(year))
+                            .filter_map(move |d| Date::from_calendar_date(year, month, d).ok())
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    fn day_matches(&self, day: &DayInfo) -> bool {
+        let rule = &self.rule;
+
+        if let Some(months) = rule.by_month {
+            if !months.contains(&day.month) {
+                return false;
+            }
+        }
+
+        if let Some(weeks) = rule.by_week_number {
+            if rule.freq == RecurFreq::Yearly {
+                let (_, week, weeks_in_year) = week_number(day.date, &rule.week_start);
+                if !weeks
+                    .iter()
+                    .any(|w| matches_ordinal(*w as i32, week, weeks_in_year))
+                {
+                    return false;
+                }
+            }
+        }
+
+        if let Some(year_days) = rule.by_year_day {
+            if !year_days
+                .iter()
+                .any(|d| matches_ordinal(*d as i32, day.ordinal as i32, day.year_length as i32))
+            {
+                return false;
+            }
+        }
+
+        if let Some(month_days) = rule.by_month_day {
+            if !month_days
+                .iter()
+                .any(|d| matches_ordinal(*d as i32, day.day as i32, day.month_length as i32))
+            {
+                return false;
+            }
+        }
+
+        if let Some(week_days) = rule.by_day {
+            if !week_days.iter().any(|wd| self.week_day_matches(wd, day)) {
+                return false;
+            }
+        }
+
+        // Without any BYxxx rule parts that select days, the day is taken from the start.
+        match rule.freq {
+            RecurFreq::Yearly
+                if rule.by_week_number.is_none()
+                    && rule.by_year_day.is_none()
+                    && rule.by_month_day.is_none()
+                    && rule.by_day.is_none() =>
+            {
+                day.day == self.start_info.day
+                    && (rule.by_month.is_some() || day.month == self.start_info.month)
+            }
+            RecurFreq::Monthly if rule.by_month_day.is_none() && rule.by_day.is_none() => {
+                day.day == self.start_info.day
+            }
+            RecurFreq::Weekly if rule.by_day.is_none() => {
+                day.date.weekday() == self.start.weekday()
+            }
+            _ => true,
+        }
+    }
+
+    fn week_day_matches(&self, week_day: &OffsetWeekday, day: &DayInfo) -> bool {
+        if to_time_weekday(&week_day.weekday) != day.date.weekday() {
+            return false;
+        }
+
+        let offset = match week_day.offset_weeks {
+            Some(offset) => offset as i32,
+            None => return true,
+        };
+
+        match self.rule.freq {
+            RecurFreq::Monthly => matches_nth(offset, day.day as i32, day.month_length as i32),
+            RecurFreq::Yearly if self.rule.by_month.is_some() => {
+                matches_nth(offset, day.day as i32, day.month_length as i32)
+            }
+            RecurFreq::Yearly if self.rule.by_week_number.is_none() => {
+                matches_nth(offset, day.ordinal as i32, day.year_length as i32)
+            }
+            // An offset is not meaningful for other frequencies, so it is ignored.
+            _ => true,
+        }
+    }
+
+    fn times(&self, fixed: Option<PrimitiveDateTime>) -> Vec<Time> {
+        let rule = &self.rule;
+
+        let select = |fixed: Option<u8>, by: Option<&[u8]>, default: u8| -> Vec<u8> {
+            match (fixed, by) {
+                (Some(v), Some(by)) if !by.contains(&v) => Vec::new(),
+                (Some(v), _) => vec![v],
+                (None, Some(by)) => {
+                    let mut by = by.to_vec();
+                    by.sort();
+                    by.dedup();
+                    by
+                }
+                (None, None) => vec![default],
+            }
+        };
+
+        let hours = select(fixed.map(|f| f.hour()), rule.by_hour, self.start.hour());
+        let minutes = select(
+            fixed
+                .filter(|_| matches!(rule.freq, RecurFreq::Minutely | RecurFreq::Secondly))
+                .map(|f| f.minute()),
+            rule.by_minute,
+            self.start.minute(),
+        );
+        let seconds = select(
+            fixed
+                .filter(|_| rule.freq == RecurFreq::Secondly)
+                .map(|f| f.second()),
+            rule.by_second,
+            self.start.second(),
+        );
+
+        let mut times = Vec::with_capacity(hours.len() * minutes.len() * seconds.len());
+        for h in &hours {
+            for m in &minutes {
+                for s in &seconds {
+                    // Leap seconds and other invalid values cannot be represented and are skipped.
+                    if let Ok(t) = Time::from_hms(*h, *m, *s) {
+                        times.push(t);
+                    }
+                }
+            }
+        }
+
+        times
+    }
+
+    fn fill_period(&mut self) {
+        let period = match self.next_period() {
+            Some(period) => period,
+            None => {
+                self.done = true;
+                return;
+            }
+        };
+
+        let times = self.times(period.fixed);
+        let mut set = Vec::new();
+        for day in period.days {
+            if period.resolved || self.day_matches(&day) {
+                for t in &times {
+                    set.push(PrimitiveDateTime::new(day.date, *t));
+                }
+            }
+        }
+
+        if let Some(positions) = self.rule.by_set_pos {
+            let len = set.len() as i64;
+            let mut selected: Vec<PrimitiveDateTime> = positions
+                .iter()
+                .filter_map(|p| {
+                    let index = if *p > 0 {
+                        *p as i64 - 1
+                    } else {
+                        len + *p as i64
+                    };
+                    (0..len).contains(&index).then(|| set[index as usize])
+                })
+                .collect();
+            selected.sort();
+            selected.dedup();
+            set = selected;
+        }
+
+        for candidate in set {
+            if candidate < self.start {
+                continue;
+            }
+
+            // The start is always the first occurrence, so it counts towards `COUNT` even when the
+            // rule does not produce it.
+            if !self.start_counted {
+                self.start_counted = true;
+                if candidate != self.start {
+                    self.emitted += 1;
+                }
+            }
+
+            if candidate > self.horizon {
+                self.done = true;
+                break;
+            }
+
+            if let Some(count) = self.rule.count {
+                if self.emitted >= count {
+                    self.done = true;
+                    break;
+                }
+            }
+
+            self.emitted += 1;
+            self.pending.push_back(candidate);
+        }
+
+        if let Some(count) = self.rule.count {
+            if self.emitted >= count {
+                self.done = true;
+            }
+        }
+    }
+}
+
+impl Iterator for RecurrenceIter<'_> {
+    type Item = PrimitiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(next) = self.pending.pop_front() {
+                return Some(next);
+            }
+
+            if self.done {
+                return None;
+            }
+
+            self.fill_period();
+        }
+    }
+}
+
+pub(crate) fn to_time_weekday(weekday: &Weekday) -> time::Weekday {
+    match weekday {
+        Weekday::Monday => time::Weekday::Monday,
+        Weekday::Tuesday => time::Weekday::Tuesday,
+        Weekday::Wednesday => time::Weekday::Wednesday,
+        Weekday::Thursday => time::Weekday::Thursday,
+        Weekday::Friday => time::Weekday::Friday,
+        Weekday::Saturday => time::Weekday::Saturday,
+        Weekday::Sunday => time::Weekday::Sunday,
+    }
+}
+
+/// Check a positive or negative rule ordinal against a 1-based value within a range of `len`.
+fn matches_ordinal(ordinal: i32, value: i32, len: i32) -> bool {
+    if ordinal > 0 {
+        ordinal == value
+    } else {
+        len + ordinal + 1 == value
+    }
+}
+
+/// Check whether the day at 1-based `position` is the `nth` occurrence of its weekday within a
+/// span of `len` days.
+fn matches_nth(nth: i32, position: i32, len: i32) -> bool {
+    if nth > 0 {
+        (position - 1) / 7 + 1 == nth
+    } else {
+        -((len - position) / 7 + 1) == nth
+    }
+}
+
+fn week_start_of(date: Date, week_start: &Weekday) -> Date {
+    let back = (date.weekday().number_days_from_monday() as i64
+        - to_time_weekday(week_start).number_days_from_monday() as i64)
+        .rem_euclid(7);
+    date.checked_sub(time::Duration::days(back)).unwrap_or(date)
+}
+
+/// The week numbering year, week number and number of weeks in that year for a date.
+///
+/// Week 1 is the first week that contains at least four days of the year, with weeks beginning
+/// on `week_start`.
+fn week_number(date: Date, week_start: &Weekday) -> (i32, i32, i32) {
+    let first_week_start = |year: i32| {
+        Date::from_calendar_date(year, Month::January, 4)
+            .map(|d| week_start_of(d, week_start))
+            .ok()
+    };
+
+    let start = week_start_of(date, week_start);
+    let year = start
+        .checked_add(time::Duration::days(3))
+        .map(|d| d.year())
+        .unwrap_or(date.year());
+
+    match (first_week_start(year), first_week_start(year + 1)) {
+        (Some(first), Some(next)) => (
+            year,
+            ((start - first).whole_days() / 7 + 1) as i32,
+            ((next - first).whole_days() / 7) as i32,
+        ),
+        _ => (year, 0, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{OffsetWeekday, RecurMonth, Skip};
+
+    fn date_time(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap(),
+            Time::from_hms(hour, minute, 0).unwrap(),
+        )
+    }
+
+    fn expand(rule: &RecurrenceRule, start: PrimitiveDateTime) -> Vec<PrimitiveDateTime> {
+        RecurrenceIter::new(rule, start, date_time(2100, 1, 1, 0, 0)).collect()
+    }
+
+    #[test]
+    fn daily_with_count() {
+        let rule = RecurrenceRule::new(RecurFreq::Daily).set_count(3);
+        let out = expand(&rule, date_time(1997, 9, 2, 9, 0));
+
+        assert_eq!(
+            vec![
+                date_time(1997, 9, 2, 9, 0),
+                date_time(1997, 9, 3, 9, 0),
+                date_time(1997, 9, 4, 9, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn weekly_by_day_with_interval() {
+        // RFC 5545: Every other week on Monday, Wednesday, and Friday until 8 occurrences
+        let rule = RecurrenceRule::new(RecurFreq::Weekly)
+            .set_interval(2)
+            .set_count(8)
+            .set_week_start(Weekday::Sunday)
+            .set_by_day(vec![
+                OffsetWeekday::new(Weekday::Monday, None),
+                OffsetWeekday::new(Weekday::Wednesday, None),
+                OffsetWeekday::new(Weekday::Friday, None),
+            ]);
+        let out = expand(&rule, date_time(1997, 9, 1, 9, 0));
+
+        let days: Vec<u8> = out.iter().map(|d| d.day()).collect();
+        assert_eq!(vec![1, 3, 5, 15, 17, 19, 29, 1], days);
+        assert_eq!(Month::October, out[7].month());
+    }
+
+    #[test]
+    fn start_counts_when_not_produced_by_the_rule() {
+        let rule = RecurrenceRule::new(RecurFreq::Weekly)
+            .set_count(2)
+            .set_by_day(vec![OffsetWeekday::new(Weekday::Monday, None)]);
+
+        // A Saturday, which is the first occurrence, so the rule only adds one more
+        let out = expand(&rule, date_time(2024, 1, 6, 9, 0));
+        assert_eq!(vec![date_time(2024, 1, 8, 9, 0)], out);
+
+        // A Monday, which the rule produces itself
+        let out = expand(&rule, date_time(2024, 1, 8, 9, 0));
+        assert_eq!(
+            vec![date_time(2024, 1, 8, 9, 0), date_time(2024, 1, 15, 9, 0)],
+            out
+        );
+    }
+
+    #[test]
+    fn monthly_last_weekday_by_set_pos() {
+        // RFC 5545: The last work day of the month
+        let rule = RecurrenceRule::new(RecurFreq::Monthly)
+            .set_count(3)
+            .set_by_day(vec![
+                OffsetWeekday::new(Weekday::Monday, None),
+                OffsetWeekday::new(Weekday::Tuesday, None),
+                OffsetWeekday::new(Weekday::Wednesday, None),
+                OffsetWeekday::new(Weekday::Thursday, None),
+                OffsetWeekday::new(Weekday::Friday, None),
+            ])
+            .set_by_set_pos(vec![-1]);
+        let out = expand(&rule, date_time(1997, 9, 30, 9, 0));
+
+        assert_eq!(
+            vec![
+                date_time(1997, 9, 30, 9, 0),
+                date_time(1997, 10, 31, 9, 0),
+                date_time(1997, 11, 28, 9, 0),
+            ],
+            out
+        );
+
+        // The start in the RFC example is not the last work day, but it is still the first of the
+        // three occurrences
+        let out = expand(&rule, date_time(1997, 9, 29, 9, 0));
+        assert_eq!(
+            vec![date_time(1997, 9, 30, 9, 0), date_time(1997, 10, 31, 9, 0)],
+            out
+        );
+    }
+
+    #[test]
+    fn monthly_nth_weekday() {
+        // RFC 5545: Monthly on the first Friday for 3 occurrences
+        let rule = RecurrenceRule::new(RecurFreq::Monthly)
+            .set_count(3)
+            .set_by_day(vec![OffsetWeekday::new(Weekday::Friday, Some(1))]);
+        let out = expand(&rule, date_time(1997, 9, 5, 9, 0));
+
+        assert_eq!(
+            vec![
+                date_time(1997, 9, 5, 9, 0),
+                date_time(1997, 10, 3, 9, 0),
+                date_time(1997, 11, 7, 9, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn monthly_skips_invalid_days() {
+        let rule = RecurrenceRule::new(RecurFreq::Monthly).set_count(3);
+        let out = expand(&rule, date_time(2024, 1, 31, 10, 0));
+
+        assert_eq!(
+            vec![
+                date_time(2024, 1, 31, 10, 0),
+                date_time(2024, 3, 31, 10, 0),
+                date_time(2024, 5, 31, 10, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn yearly_by_month_and_day() {
+        // A typical daylight saving time rule, the last Sunday in March
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_by_month(vec![Month::March])
+            .set_by_day(vec![OffsetWeekday::new(Weekday::Sunday, Some(-1))]);
+        let out: Vec<PrimitiveDateTime> = RecurrenceIter::new(
+            &rule,
+            date_time(1981, 3, 29, 1, 0),
+            date_time(1984, 1, 1, 0, 0),
+        )
+        .collect();
+
+        assert_eq!(
+            vec![
+                date_time(1981, 3, 29, 1, 0),
+                date_time(1982, 3, 28, 1, 0),
+                date_time(1983, 3, 27, 1, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn yearly_by_week_number() {
+        // RFC 5545: Monday of week number 20
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_count(3)
+            .set_by_week_number(vec![20])
+            .set_by_day(vec![OffsetWeekday::new(Weekday::Monday, None)]);
+        let out = expand(&rule, date_time(1997, 5, 12, 9, 0));
+
+        assert_eq!(
+            vec![
+                date_time(1997, 5, 12, 9, 0),
+                date_time(1998, 5, 11, 9, 0),
+                date_time(1999, 5, 17, 9, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn hourly_with_interval() {
+        let rule = RecurrenceRule::new(RecurFreq::Hourly)
+            .set_interval(3)
+            .set_count(3);
+        let out = expand(&rule, date_time(1997, 9, 2, 22, 0));
+
+        assert_eq!(
+            vec![
+                date_time(1997, 9, 2, 22, 0),
+                date_time(1997, 9, 3, 1, 0),
+                date_time(1997, 9, 3, 4, 0),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn daily_by_hour_and_minute() {
+        let rule = RecurrenceRule::new(RecurFreq::Daily)
+            .set_count(4)
+            .set_by_hour(vec![9, 17])
+            .set_by_minute(vec![30]);
+        let out = expand(&rule, date_time(2024, 1, 1, 9, 30));
+
+        assert_eq!(
+            vec![
+                date_time(2024, 1, 1, 9, 30),
+                date_time(2024, 1, 1, 17, 30),
+                date_time(2024, 1, 2, 9, 30),
+                date_time(2024, 1, 2, 17, 30),
+            ],
+            out
+        );
+    }
+
+    #[test]
+    fn stops_at_horizon() {
+        let rule = RecurrenceRule::new(RecurFreq::Daily);
+        let out: Vec<PrimitiveDateTime> = RecurrenceIter::new(
+            &rule,
+            date_time(2024, 1, 1, 9, 0),
+            date_time(2024, 1, 3, 9, 0),
+        )
+        .collect();
+
+        assert_eq!(3, out.len());
+    }
+
+    fn dates(out: &[PrimitiveDateTime]) -> Vec<Date> {
+        out.iter().map(|d| d.date()).collect()
+    }
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        date_time(year, month, day, 0, 0).date()
+    }
+
+    #[test]
+    fn rscale_chinese_new_year() {
+        // RFC 7529: Chinese New Year
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("CHINESE")
+            .set_count(4);
+        let out = expand(&rule, date_time(2013, 2, 10, 0, 0));
+
+        assert_eq!(
+            vec![
+                date(2013, 2, 10),
+                date(2014, 1, 31),
+                date(2015, 2, 19),
+                date(2016, 2, 8),
+            ],
+            dates(&out)
+        );
+    }
+
+    #[test]
+    fn rscale_chinese_monthly_with_leap_month() {
+        // 2023 has a leap second month
+        let rule = RecurrenceRule::new(RecurFreq::Monthly)
+            .set_rscale("CHINESE")
+            .set_count(4);
+        let out = expand(&rule, date_time(2023, 1, 22, 9, 0));
+
+        assert_eq!(
+            vec![
+                date(2023, 1, 22),
+                date(2023, 2, 20),
+                date(2023, 3, 22),
+                date(2023, 4, 20),
+            ],
+            dates(&out)
+        );
+
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("CHINESE")
+            .set_by_month(vec![RecurMonth::leap_month(2)])
+            .set_count(2);
+        let out: Vec<PrimitiveDateTime> = RecurrenceIter::new(
+            &rule,
+            date_time(2023, 3, 22, 9, 0),
+            date_time(2030, 1, 1, 0, 0),
+        )
+        .collect();
+        assert_eq!(vec![date(2023, 3, 22)], dates(&out));
+    }
+
+    #[test]
+    fn rscale_hebrew_leap_month_skip_forward() {
+        // RFC 7529: The 8th day of Adar I, or Adar in years without a leap month
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("HEBREW")
+            .set_by_month(vec![RecurMonth::leap_month(5)])
+            .set_by_month_day(vec![8])
+            .set_skip(Skip::Forward)
+            .set_count(5);
+        let out = expand(&rule, date_time(2014, 2, 8, 0, 0));
+
+        assert_eq!(
+            vec![
+                date(2014, 2, 8),
+                date(2015, 2, 27),
+                date(2016, 2, 17),
+                date(2017, 3, 6),
+                date(2018, 2, 23),
+            ],
+            dates(&out)
+        );
+    }
+
+    #[test]
+    fn rscale_hebrew_leap_month_omit() {
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("HEBREW")
+            .set_by_month(vec![RecurMonth::leap_month(5)])
+            .set_by_month_day(vec![8])
+            .set_count(2);
+        let out = expand(&rule, date_time(2014, 2, 8, 0, 0));
+
+        assert_eq!(vec![date(2014, 2, 8), date(2016, 2, 17)], dates(&out));
+    }
+
+    #[test]
+    fn rscale_gregorian_skip() {
+        // RFC 7529: Monthly on the last day of the month when the 31st does not exist
+        let rule = RecurrenceRule::new(RecurFreq::Monthly)
+            .set_rscale("GREGORIAN")
+            .set_skip(Skip::Backward)
+            .set_count(4);
+        let out = expand(&rule, date_time(2024, 1, 31, 10, 0));
+
+        assert_eq!(
+            vec![
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                date(2024, 4, 30),
+            ],
+            dates(&out)
+        );
+
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("GREGORIAN")
+            .set_skip(Skip::Forward)
+            .set_count(3);
+        let out = expand(&rule, date_time(2024, 2, 29, 10, 0));
+
+        assert_eq!(
+            vec![date(2024, 2, 29), date(2025, 3, 1), date(2026, 3, 1)],
+            dates(&out)
+        );
+    }
+
+    #[test]
+    fn rscale_unsupported() {
+        let rule = RecurrenceRule::new(RecurFreq::Yearly)
+            .set_rscale("ETHIOPIC")
+            .set_count(4);
+        let out = expand(&rule, date_time(2024, 2, 29, 10, 0));
+
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,724 @@
+//! Calendar scales for recurrence rules with an `RSCALE` part, see RFC 7529.
+//!
+//! Each scale describes its years as a list of months, with the Gregorian date that each month
+//! starts on. That is all the recurrence expansion needs to work in a non-Gregorian calendar.
+
+use crate::common::RecurMonth;
+use std::collections::HashMap;
+use time::{Date, Month};
+
+/// The calendar scales that recurrence rules can be expanded in.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Scale {
+    Gregorian,
+    Hebrew,
+    Chinese,
+}
+
+impl Scale {
+    /// Look up a scale by its CLDR calendar name, ignoring case.
+    pub(crate) fn from_name(name: &str) -> Option<Scale> {
+        if name.eq_ignore_ascii_case("GREGORIAN") {
+            Some(Scale::Gregorian)
+        } else if name.eq_ignore_ascii_case("HEBREW") {
+            Some(Scale::Hebrew)
+        } else if name.eq_ignore_ascii_case("CHINESE") {
+            Some(Scale::Chinese)
+        } else {
+            None
+        }
+    }
+
+    /// The number of the year in this scale that begins during the given Gregorian year.
+    fn year_beginning_in(&self, gregorian_year: i32) -> i32 {
+        match self {
+            Scale::Hebrew => gregorian_year + 3761,
+            Scale::Gregorian | Scale::Chinese => gregorian_year,
+        }
+    }
+
+    /// The months of a year, in order.
+    ///
+    /// Chinese years are numbered by the Gregorian year that they begin in.
+    fn months(&self, year: i32) -> Option<Vec<ScaleMonth>> {
+        match self {
+            Scale::Gregorian => (1..=12)
+                .map(|m| {
+                    let month = Month::try_from(m).ok()?;
+                    Some(ScaleMonth {
+                        month: RecurMonth::new(m),
+                        first: Date::from_calendar_date(year, month, 1).ok()?,
+                        length: month.length(year),
+                    })
+                })
+                .collect(),
+            Scale::Hebrew => hebrew::months(year),
+            Scale::Chinese => chinese::months(year),
+        }
+    }
+}
+
+/// A month within a year of a calendar scale.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct ScaleMonth {
+    pub(crate) month: RecurMonth,
+    pub(crate) first: Date,
+    pub(crate) length: u8,
+}
+
+impl ScaleMonth {
+    pub(crate) fn day(&self, day: u8) -> Option<Date> {
+        self.first.checked_add(time::Duration::days(day as i64 - 1))
+    }
+}
+
+/// The position of a day within a calendar scale.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct DayInfo {
+    pub(crate) date: Date,
+    pub(crate) year: i32,
+    pub(crate) month: RecurMonth,
+    pub(crate) day: u8,
+    pub(crate) month_length: u8,
+    pub(crate) ordinal: u16,
+    pub(crate) year_length: u16,
+}
+
+impl DayInfo {
+    /// The position of a day in the Gregorian calendar, without needing a [ScaleCalendar].
+    pub(crate) fn gregorian(date: Date) -> Self {
+        DayInfo {
+            date,
+            year: date.year(),
+            month: RecurMonth::new(date.month() as u8),
+            day: date.day(),
+            month_length: date.month().length(date.year()),
+            ordinal: date.ordinal(),
+            year_length: time::util::days_in_year(date.year()),
+        }
+    }
+}
+
+/// A calendar scale with a cache of the years that have been looked up, because working out
+/// the months of a lunar year is relatively expensive.
+pub(crate) struct ScaleCalendar {
+    scale: Scale,
+    years: HashMap<i32, Vec<ScaleMonth>>,
+}
+
+impl ScaleCalendar {
+    pub(crate) fn new(scale: Scale) -> Self {
+        ScaleCalendar {
+            scale,
+            years: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn months(&mut self, year: i32) -> Option<Vec<ScaleMonth>> {
+        if let Some(months) = self.years.get(&year) {
+            return Some(months.clone());
+        }
+
+        let months = self.scale.months(year)?;
+        if months.is_empty() {
+            return None;
+        }
+        self.years.insert(year, months.clone());
+        Some(months)
+    }
+
+    /// Find the year and month of a date within this scale.
+    pub(crate) fn locate(&mut self, date: Date) -> Option<DayInfo> {
+        let mut year = self.scale.year_beginning_in(date.year());
+        let mut months = self.months(year)?;
+        if date < months[0].first {
+            year -= 1;
+            months = self.months(year)?;
+        }
+
+        let year_length = months.iter().map(|m| m.length as u16).sum();
+        let ordinal = (date - months[0].first).whole_days() as u16 + 1;
+        let month = months.iter().rev().find(|m| m.first <= date)?;
+
+        Some(DayInfo {
+            date,
+            year,
+            month: month.month,
+            day: (date - month.first).whole_days() as u8 + 1,
+            month_length: month.length,
+            ordinal,
+            year_length,
+        })
+    }
+}
+
+/// All the days of the month at `index` within the months of a year.
+pub(crate) fn days_of_month(year: i32, months: &[ScaleMonth], index: usize) -> Vec<DayInfo> {
+    let Some(month) = months.get(index) else {
+        return Vec::new();
+    };
+    let offset: u16 = months[..index].iter().map(|m| m.length as u16).sum();
+    let year_length = months.iter().map(|m| m.length as u16).sum();
+
+    (1..=month.length)
+        .filter_map(|day| {
+            Some(DayInfo {
+                date: month.day(day)?,
+                year,
+                month: month.month,
+                day,
+                month_length: month.length,
+                ordinal: offset + day as u16,
+                year_length,
+            })
+        })
+        .collect()
+}
+
+/// Days are counted as Julian day numbers, which the `time` crate can convert to and from.
+fn to_date(day: i64) -> Option<Date> {
+    Date::from_julian_day(i32::try_from(day).ok()?).ok()
+}
+
+fn to_day(date: Date) -> i64 {
+    date.to_julian_day() as i64
+}
+
+/// The Hebrew calendar, following the arithmetic in "Calendrical Calculations" by Reingold and
+/// Dershowitz.
+///
+/// Months are numbered from Tishri, as in RFC 7529. Adar I is the leap month `5L`, and month 6
+/// is Adar, or Adar II in a leap year.
+mod hebrew {
+    use super::{to_date, ScaleMonth};
+    use crate::common::RecurMonth;
+
+    /// The Julian day number of 1 Tishri AM 1.
+    const EPOCH: i64 = 347_998;
+
+    fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn elapsed_days(year: i64) -> i64 {
+        let months_elapsed = (235 * year - 234).div_euclid(19);
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    fn year_length_correction(year: i64) -> i64 {
+        let ny0 = elapsed_days(year - 1);
+        let ny1 = elapsed_days(year);
+        let ny2 = elapsed_days(year + 1);
+
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    pub(super) fn new_year(year: i64) -> i64 {
+        EPOCH + elapsed_days(year) + year_length_correction(year)
+    }
+
+    pub(super) fn months(year: i32) -> Option<Vec<ScaleMonth>> {
+        let year = year as i64;
+        let first = new_year(year);
+        let days_in_year = new_year(year + 1) - first;
+        let leap = is_leap_year(year);
+
+        let heshvan = if days_in_year % 10 == 5 { 30 } else { 29 };
+        let kislev = if days_in_year % 10 == 3 { 29 } else { 30 };
+
+        let mut lengths = vec![
+            (RecurMonth::new(1), 30),
+            (RecurMonth::new(2), heshvan),
+            (RecurMonth::new(3), kislev),
+            (RecurMonth::new(4), 29),
+            (RecurMonth::new(5), 30),
+        ];
+        if leap {
+            lengths.push((RecurMonth::leap_month(5), 30));
+        }
+        lengths.extend([
+            (RecurMonth::new(6), 29),
+            (RecurMonth::new(7), 30),
+            (RecurMonth::new(8), 29),
+            (RecurMonth::new(9), 30),
+            (RecurMonth::new(10), 29),
+            (RecurMonth::new(11), 30),
+            (RecurMonth::new(12), 29),
+        ]);
+
+        let mut day = first;
+        lengths
+            .into_iter()
+            .map(|(month, length)| {
+                let first = to_date(day)?;
+                day += length as i64;
+                Some(ScaleMonth {
+                    month,
+                    first,
+                    length,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The Chinese calendar, following the astronomical rules in "Calendrical Calculations" by
+/// Reingold and Dershowitz, with new moons and solar longitudes from "Astronomical Algorithms"
+/// by Meeus. Dates are reckoned in China standard time, UTC+8.
+///
+/// Months are numbered from 1 at the new year, and a leap month takes the number of the month
+/// before it, so `6L` follows month 6.
+mod chinese {
+    use super::{to_date, to_day, ScaleMonth};
+    use crate::common::RecurMonth;
+    use time::{Date, Month};
+
+    const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+    const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+    const J2000: f64 = 2_451_545.0;
+    /// The new moon of 6 January 2000 in dynamical time, which new moons are counted from.
+    const FIRST_NEW_MOON: f64 = 2451550.09766;
+
+    fn sin_deg(degrees: f64) -> f64 {
+        degrees.to_radians().sin()
+    }
+
+    fn cos_deg(degrees: f64) -> f64 {
+        degrees.to_radians().cos()
+    }
+
+    /// The difference between dynamical and universal time in days, using the polynomial
+    /// approximations from Espenak and Meeus.
+    fn delta_t(moment: f64) -> f64 {
+        let year = 2000.0 + (moment - J2000) / 365.25;
+        let seconds = if year < 1900.0 {
+            let u = (year - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u
+        } else if year < 1920.0 {
+            let t = year - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        } else if year < 1941.0 {
+            let t = year - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        } else if year < 1961.0 {
+            let t = year - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        } else if year < 1986.0 {
+            let t = year - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        } else if year < 2005.0 {
+            let t = year - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        } else if year < 2050.0 {
+            let t = year - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        } else if year < 2150.0 {
+            let u = (year - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - year)
+        } else {
+            let u = (year - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u
+        };
+
+        seconds / 86400.0
+    }
+
+    /// Coefficients of the periodic terms for the solar longitude, from Bretagnon and Simon.
+    const SOLAR_TERMS: [(f64, f64, f64); 49] = [
+        (403406.0, 270.54861, 0.9287892),
+        (195207.0, 340.19128, 35999.1376958),
+        (119433.0, 63.91854, 35999.4089666),
+        (112392.0, 331.26220, 35998.7287385),
+        (3891.0, 317.843, 71998.20261),
+        (2819.0, 86.631, 71998.4403),
+        (1721.0, 240.052, 36000.35726),
+        (660.0, 310.26, 71997.4812),
+        (350.0, 247.23, 32964.4678),
+        (334.0, 260.87, -19.4410),
+        (314.0, 297.82, 445267.1117),
+        (268.0, 343.14, 45036.8840),
+        (242.0, 166.79, 3.1008),
+        (234.0, 81.53, 22518.4434),
+        (158.0, 3.50, -19.9739),
+        (132.0, 132.75, 65928.9345),
+        (129.0, 182.95, 9038.0293),
+        (114.0, 162.03, 3034.7684),
+        (99.0, 29.8, 33718.148),
+        (93.0, 266.4, 3034.448),
+        (86.0, 249.2, -2280.773),
+        (78.0, 157.6, 29929.992),
+        (72.0, 257.8, 31556.493),
+        (68.0, 185.1, 149.588),
+        (64.0, 69.9, 9037.750),
+        (46.0, 8.0, 107997.405),
+        (38.0, 197.1, -4444.176),
+        (37.0, 250.4, 151.771),
+        (32.0, 65.3, 67555.316),
+        (29.0, 162.7, 31556.080),
+        (28.0, 341.5, -4561.540),
+        (27.0, 291.6, 107996.706),
+        (27.0, 98.5, 1221.655),
+        (25.0, 146.7, 62894.167),
+        (24.0, 110.0, 31437.369),
+        (21.0, 5.2, 14578.298),
+        (21.0, 342.6, -31931.757),
+        (20.0, 230.9, 34777.243),
+        (18.0, 256.1, 1221.999),
+        (17.0, 45.3, 62894.511),
+        (14.0, 242.9, -4442.039),
+        (13.0, 115.2, 107997.909),
+        (13.0, 151.8, 119.066),
+        (13.0, 285.3, 16859.071),
+        (12.0, 53.3, -4.578),
+        (10.0, 126.6, 26895.292),
+        (10.0, 205.7, -39.127),
+        (10.0, 85.9, 12297.536),
+        (10.0, 146.1, 90073.778),
+    ];
+
+    /// The apparent longitude of the sun in degrees at a moment, given as a Julian date in
+    /// universal time.
+    pub(super) fn solar_longitude(moment: f64) -> f64 {
+        let c = (moment + delta_t(moment) - J2000) / 36525.0;
+        let sum: f64 = SOLAR_TERMS
+            .iter()
+            .map(|(x, y, z)| x * sin_deg(y + z * c))
+            .sum();
+        let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+
+        let aberration = 0.0000974 * cos_deg(177.63 + 35999.01848 * c) - 0.005575;
+        let a = 124.90 - 1934.134 * c + 0.002063 * c * c;
+        let b = 201.11 + 72001.5377 * c + 0.00057 * c * c;
+        let nutation = -0.004778 * sin_deg(a) - 0.0003667 * sin_deg(b);
+
+        (lambda + aberration + nutation).rem_euclid(360.0)
+    }
+
+    /// The moment of the `k`th new moon after the one in January 2000, as a Julian date in
+    /// universal time.
+    pub(super) fn nth_new_moon(k: i64) -> f64 {
+        let k = k as f64;
+        let t = k / 1236.85;
+        let jde = FIRST_NEW_MOON + MEAN_SYNODIC_MONTH * k + 0.00015437 * t.powi(2)
+            - 0.000000150 * t.powi(3)
+            + 0.00000000073 * t.powi(4);
+        let e = 1.0 - 0.002516 * t - 0.0000074 * t.powi(2);
+        let m = 2.5534 + 29.10535670 * k - 0.0000014 * t.powi(2) - 0.00000011 * t.powi(3);
+        let mp = 201.5643 + 385.81693528 * k + 0.0107582 * t.powi(2) + 0.00001238 * t.powi(3)
+            - 0.000000058 * t.powi(4);
+        let f = 160.7108 + 390.67050284 * k - 0.0016118 * t.powi(2) - 0.00000227 * t.powi(3)
+            + 0.000000011 * t.powi(4);
+        let omega = 124.7746 - 1.56375588 * k + 0.0020672 * t.powi(2) + 0.00000215 * t.powi(3);
+
+        let correction = -0.40720 * sin_deg(mp)
+            + 0.17241 * e * sin_deg(m)
+            + 0.01608 * sin_deg(2.0 * mp)
+            + 0.01039 * sin_deg(2.0 * f)
+            + 0.00739 * e * sin_deg(mp - m)
+            - 0.00514 * e * sin_deg(mp + m)
+            + 0.00208 * e * e * sin_deg(2.0 * m)
+            - 0.00111 * sin_deg(mp - 2.0 * f)
+            - 0.00057 * sin_deg(mp + 2.0 * f)
+            + 0.00056 * e * sin_deg(2.0 * mp + m)
+            - 0.00042 * sin_deg(3.0 * mp)
+            + 0.00042 * e * sin_deg(m + 2.0 * f)
+            + 0.00038 * e * sin_deg(m - 2.0 * f)
+            - 0.00024 * e * sin_deg(2.0 * mp - m)
+            - 0.00017 * sin_deg(omega)
+            - 0.00007 * sin_deg(mp + 2.0 * m)
+            + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+            + 0.00004 * sin_deg(3.0 * m)
+            + 0.00003 * sin_deg(mp + m - 2.0 * f)
+            + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+            - 0.00003 * sin_deg(mp + m + 2.0 * f)
+            + 0.00003 * sin_deg(mp - m + 2.0 * f)
+            - 0.00002 * sin_deg(mp - m - 2.0 * f)
+            - 0.00002 * sin_deg(3.0 * mp + m)
+            + 0.00002 * sin_deg(4.0 * mp);
+
+        let planetary = [
+            (0.000325, 299.77 + 0.107408 * k - 0.009173 * t.powi(2)),
+            (0.000165, 251.88 + 0.016321 * k),
+            (0.000164, 251.83 + 26.651886 * k),
+            (0.000126, 349.42 + 36.412478 * k),
+            (0.000110, 84.66 + 18.206239 * k),
+            (0.000062, 141.74 + 53.303771 * k),
+            (0.000060, 207.14 + 2.453732 * k),
+            (0.000056, 154.84 + 7.306860 * k),
+            (0.000047, 34.52 + 27.261239 * k),
+            (0.000042, 207.19 + 0.121824 * k),
+            (0.000040, 291.34 + 1.844379 * k),
+            (0.000037, 161.72 + 24.198154 * k),
+            (0.000035, 239.56 + 25.513099 * k),
+            (0.000023, 331.55 + 3.592518 * k),
+        ]
+        .iter()
+        .map(|(amplitude, argument)| amplitude * sin_deg(*argument))
+        .sum::<f64>();
+
+        let moment = jde + correction + planetary;
+        moment - delta_t(moment)
+    }
+
+    /// The index of the first new moon at or after a moment.
+    fn new_moon_index_at_or_after(moment: f64) -> i64 {
+        let mut k = ((moment - FIRST_NEW_MOON) / MEAN_SYNODIC_MONTH).floor() as i64;
+        while nth_new_moon(k) < moment {
+            k += 1;
+        }
+        while nth_new_moon(k - 1) >= moment {
+            k -= 1;
+        }
+        k
+    }
+
+    /// The moment that a day begins in China.
+    fn midnight_in_china(day: i64) -> f64 {
+        day as f64 - 0.5 - 8.0 / 24.0
+    }
+
+    /// The day in China that a moment falls on.
+    fn china_day(moment: f64) -> i64 {
+        (moment + 0.5 + 8.0 / 24.0).floor() as i64
+    }
+
+    fn new_moon_on_or_after(day: i64) -> i64 {
+        china_day(nth_new_moon(new_moon_index_at_or_after(midnight_in_china(
+            day,
+        ))))
+    }
+
+    fn new_moon_before(day: i64) -> i64 {
+        china_day(nth_new_moon(
+            new_moon_index_at_or_after(midnight_in_china(day)) - 1,
+        ))
+    }
+
+    /// The last major solar term, numbered 1 to 12, at the start of a day.
+    fn major_solar_term(day: i64) -> i64 {
+        let longitude = solar_longitude(midnight_in_china(day));
+        (2 + (longitude / 30.0).floor() as i64 - 1).rem_euclid(12) + 1
+    }
+
+    fn no_major_solar_term(month_start: i64) -> bool {
+        major_solar_term(month_start) == major_solar_term(new_moon_on_or_after(month_start + 1))
+    }
+
+    /// Whether there is a month without a major solar term between the month starting on
+    /// `earlier` and the one starting on `month_start`, inclusive.
+    fn prior_leap_month(earlier: i64, mut month_start: i64) -> bool {
+        while month_start >= earlier {
+            if no_major_solar_term(month_start) {
+                return true;
+            }
+            month_start = new_moon_before(month_start);
+        }
+        false
+    }
+
+    fn estimate_prior_solar_longitude(longitude: f64, moment: f64) -> f64 {
+        let rate = MEAN_TROPICAL_YEAR / 360.0;
+        let tau = moment - rate * (solar_longitude(moment) - longitude).rem_euclid(360.0);
+        let delta = (solar_longitude(tau) - longitude + 180.0).rem_euclid(360.0) - 180.0;
+        moment.min(tau - rate * delta)
+    }
+
+    fn winter_solstice_on_or_before(day: i64) -> i64 {
+        let approx = estimate_prior_solar_longitude(270.0, midnight_in_china(day + 1));
+        let mut day = (approx + 0.5).floor() as i64 - 1;
+        while solar_longitude(midnight_in_china(day + 1)) <= 270.0 {
+            day += 1;
+        }
+        day
+    }
+
+    fn new_year_in_sui(day: i64) -> i64 {
+        let s1 = winter_solstice_on_or_before(day);
+        let s2 = winter_solstice_on_or_before(s1 + 370);
+        let m12 = new_moon_on_or_after(s1 + 1);
+        let m13 = new_moon_on_or_after(m12 + 1);
+        let next_m11 = new_moon_before(s2 + 1);
+
+        if ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 == 12
+            && (no_major_solar_term(m12) || no_major_solar_term(m13))
+        {
+            new_moon_on_or_after(m13 + 1)
+        } else {
+            m13
+        }
+    }
+
+    pub(super) fn new_year_on_or_before(day: i64) -> i64 {
+        let new_year = new_year_in_sui(day);
+        if day >= new_year {
+            new_year
+        } else {
+            new_year_in_sui(day - 180)
+        }
+    }
+
+    /// The number of the month starting on a day, and whether it is a leap month.
+    pub(super) fn month_label(month_start: i64) -> RecurMonth {
+        let s1 = winter_solstice_on_or_before(month_start);
+        let s2 = winter_solstice_on_or_before(s1 + 370);
+        let m12 = new_moon_on_or_after(s1 + 1);
+        let next_m11 = new_moon_before(s2 + 1);
+        let leap_year = ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 == 12;
+
+        let elapsed = ((month_start - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64;
+        let adjust = if leap_year && prior_leap_month(m12, month_start) {
+            1
+        } else {
+            0
+        };
+        let number = (elapsed - adjust - 1).rem_euclid(12) + 1;
+        let leap = leap_year
+            && no_major_solar_term(month_start)
+            && !prior_leap_month(m12, new_moon_before(month_start));
+
+        RecurMonth {
+            number: number as u8,
+            leap,
+        }
+    }
+
+    pub(super) fn months(year: i32) -> Option<Vec<ScaleMonth>> {
+        let mid_year = to_day(Date::from_calendar_date(year, Month::July, 1).ok()?);
+        let first = new_year_on_or_before(mid_year);
+        let next = new_year_on_or_before(first + 400);
+
+        let mut months = Vec::with_capacity(13);
+        let mut start = first;
+        while start < next {
+            let end = new_moon_on_or_after(start + 1);
+            months.push(ScaleMonth {
+                month: month_label(start),
+                first: to_date(start)?,
+                length: (end - start) as u8,
+            });
+            start = end;
+        }
+
+        Some(months)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap()
+    }
+
+    #[test]
+    fn hebrew_new_years() {
+        let mut calendar = ScaleCalendar::new(Scale::Hebrew);
+
+        for (year, first) in [
+            (5775, date(2014, 9, 25)),
+            (5776, date(2015, 9, 14)),
+            (5777, date(2016, 10, 3)),
+            (5784, date(2023, 9, 16)),
+            (5785, date(2024, 10, 3)),
+        ] {
+            assert_eq!(first, calendar.months(year).unwrap()[0].first, "{year}");
+        }
+
+        // 5784 is a leap year, with Adar I and Adar II
+        let months = calendar.months(5784).unwrap();
+        assert_eq!(13, months.len());
+        assert_eq!(RecurMonth::leap_month(5), months[5].month);
+        assert_eq!(date(2024, 2, 10), months[5].first);
+        assert_eq!(date(2024, 4, 9), months[7].first);
+
+        let info = calendar.locate(date(2024, 4, 23)).unwrap();
+        assert_eq!(5784, info.year);
+        assert_eq!(RecurMonth::new(7), info.month);
+        assert_eq!(15, info.day);
+    }
+
+    #[test]
+    fn chinese_new_years() {
+        let mut calendar = ScaleCalendar::new(Scale::Chinese);
+
+        for first in [
+            date(2000, 2, 5),
+            date(2001, 1, 24),
+            date(2002, 2, 12),
+            date(2003, 2, 1),
+            date(2004, 1, 22),
+            date(2005, 2, 9),
+            date(2006, 1, 29),
+            date(2007, 2, 18),
+            date(2008, 2, 7),
+            date(2009, 1, 26),
+            date(2010, 2, 14),
+            date(2011, 2, 3),
+            date(2012, 1, 23),
+            date(2013, 2, 10),
+            date(2014, 1, 31),
+            date(2015, 2, 19),
+            date(2016, 2, 8),
+            date(2017, 1, 28),
+            date(2018, 2, 16),
+            date(2019, 2, 5),
+            date(2020, 1, 25),
+            date(2021, 2, 12),
+            date(2022, 2, 1),
+            date(2023, 1, 22),
+            date(2024, 2, 10),
+            date(2025, 1, 29),
+            date(2026, 2, 17),
+            date(2027, 2, 6),
+            date(2028, 1, 26),
+            date(2029, 2, 13),
+            date(2030, 2, 3),
+        ] {
+            assert_eq!(first, calendar.months(first.year()).unwrap()[0].first);
+        }
+    }
+
+    #[test]
+    fn chinese_leap_months() {
+        let mut calendar = ScaleCalendar::new(Scale::Chinese);
+
+        for (year, leap) in [
+            (2001, 4),
+            (2004, 2),
+            (2006, 7),
+            (2009, 5),
+            (2012, 4),
+            (2014, 9),
+            (2017, 6),
+            (2020, 4),
+            (2023, 2),
+            (2025, 6),
+            (2028, 5),
+        ] {
+            let months = calendar.months(year).unwrap();
+            let leap_months: Vec<RecurMonth> =
+                months.iter().map(|m| m.month).filter(|m| m.leap).collect();
+            assert_eq!(vec![RecurMonth::leap_month(leap)], leap_months, "{year}");
+            assert_eq!(13, months.len());
+        }
+
+        assert_eq!(12, calendar.months(2024).unwrap().len());
+    }
+}
@@ -0,0 +1,361 @@
+use crate::common::CalendarDateTime;
+use crate::expand::rule::RecurrenceIter;
+use crate::model::access::{ComponentAccess, PropertyAccess};
+use crate::model::component::{CalendarComponent, TimeZoneComponent};
+use crate::model::object::ICalObject;
+use crate::model::param::TimeZoneIdParam;
+use crate::model::property::{
+    ComponentProperty, RecurRulePart, RecurrenceDateTimesPropertyValue, TimeZoneIdProperty,
+    TimeZoneOffset,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// How far past a requested year the transitions of a time zone are computed in one go.
+const TRANSITION_LOOKAHEAD_YEARS: i32 = 10;
+
+/// Resolves date-times to absolute instants using the `VTIMEZONE` components of a calendar.
+///
+/// Values that are in UTC are used as-is. Values with a `TZID` that matches a `VTIMEZONE` in the
+/// calendar are resolved using that time zone's `STANDARD` and `DAYLIGHT` observances. Any other
+/// values, including those that reference an unknown time zone, are treated as floating and
+/// resolved using the floating offset, which defaults to UTC.
+pub struct TimeZoneResolver<'a> {
+    time_zones: HashMap<&'a str, &'a TimeZoneComponent>,
+    floating_offset: UtcOffset,
+    transitions: RefCell<HashMap<&'a str, Transitions>>,
+}
+
+#[derive(Default)]
+struct Transitions {
+    computed_to_year: i32,
+    /// Local onset time, offset before the onset, offset after the onset. Sorted by onset.
+    list: Vec<(PrimitiveDateTime, UtcOffset, UtcOffset)>,
+}
+
+impl<'a> TimeZoneResolver<'a> {
+    pub fn new(object: &'a ICalObject) -> Self {
+        let time_zones = object
+            .components
+            .iter()
+            .filter_map(|c| match c {
+                CalendarComponent::TimeZone(tz) => tz
+                    .get_property::<TimeZoneIdProperty>()
+                    .map(|id| (id.value().id.as_str(), tz)),
+                _ => None,
+            })
+            .collect();
+
+        TimeZoneResolver {
+            time_zones,
+            floating_offset: UtcOffset::UTC,
+            transitions: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Set the offset that floating date-times are interpreted in.
+    pub fn set_floating_offset(&mut self, offset: UtcOffset) {
+        self.floating_offset = offset;
+    }
+
+    pub fn floating_offset(&self) -> UtcOffset {
+        self.floating_offset
+    }
+
+    /// Whether the calendar defines a time zone with the given `TZID`.
+    pub fn has_time_zone(&self, tz_id: &str) -> bool {
+        self.time_zones.contains_key(tz_id)
+    }
+
+    /// Find the UTC offset that is in effect at a local time in the named time zone.
+    ///
+    /// Returns `None` if the time zone is not defined by the calendar or has no usable
+    /// observances.
+    pub fn offset_at(&self, tz_id: &str, local: PrimitiveDateTime) -> Option<UtcOffset> {
+        let (key, time_zone) = self.time_zones.get_key_value(tz_id)?;
+
+        let mut cache = self.transitions.borrow_mut();
+        let transitions = cache.entry(key).or_default();
+        if transitions.list.is_empty() || transitions.computed_to_year < local.year() {
+            let to_year = local.year().saturating_add(TRANSITION_LOOKAHEAD_YEARS);
+            transitions.list = compute_transitions(time_zone, to_year);
+            transitions.computed_to_year = to_year;
+        }
+
+        let index = transitions
+            .list
+            .partition_point(|(onset, _, _)| *onset <= local);
+        if index == 0 {
+            transitions.list.first().map(|(_, from, _)| *from)
+        } else {
+            Some(transitions.list[index - 1].2)
+        }
+    }
+
+    /// Resolve a local date-time, as written in a property with an optional `TZID`, to an
+    /// instant in UTC.
+    pub fn resolve_local(
+        &self,
+        local: PrimitiveDateTime,
+        tz_id: Option<&str>,
+        is_utc: bool,
+    ) -> OffsetDateTime {
+        let offset = if is_utc {
+            UtcOffset::UTC
+        } else {
+            tz_id
+                .and_then(|tz_id| self.offset_at(tz_id, local))
+                .unwrap_or(self.floating_offset)
+        };
+
+        // Near the ends of the supported range of dates the instant may not be representable in
+        // UTC, in which case it is clamped to the range.
+        match local
+            .assume_offset(offset)
+            .checked_to_offset(UtcOffset::UTC)
+        {
+            Some(resolved) => resolved,
+            None if offset.is_negative() => PrimitiveDateTime::MAX.assume_utc(),
+            None => PrimitiveDateTime::MIN.assume_utc(),
+        }
+    }
+
+    /// Resolve a date or date-time value to an instant in UTC. Dates are taken as the start of
+    /// the day.
+    pub fn resolve(&self, value: &CalendarDateTime, tz_id: Option<&str>) -> OffsetDateTime {
+        self.resolve_local(to_primitive(value), tz_id, value.is_utc())
+    }
+
+    /// Resolve the value of a date-time property to an instant in UTC, using its `TZID` param.
+    pub fn resolve_property<P>(&self, property: &P) -> OffsetDateTime
+    where
+        P: PropertyAccess<CalendarDateTime>,
+    {
+        self.resolve(property.value(), tz_id_of(property.params()))
+    }
+}
+
+pub(crate) fn tz_id_of(params: &[crate::model::param::Param]) -> Option<&str> {
+    params.iter().find_map(|p| match p {
+        crate::model::param::Param::TimeZoneId(TimeZoneIdParam { tz_id, .. }) => {
+            Some(tz_id.as_str())
+        }
+        _ => None,
+    })
+}
+
+pub(crate) fn to_primitive(value: &CalendarDateTime) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(
+        *value.date(),
+        value.time_opt().cloned().unwrap_or(Time::MIDNIGHT),
+    )
+}
+
+pub(crate) fn to_utc_offset(offset: &TimeZoneOffset) -> UtcOffset {
+    let sign = if offset.sign < 0 { -1 } else { 1 };
+    UtcOffset::from_hms(
+        sign * offset.hours as i8,
+        sign * offset.minutes as i8,
+        sign * offset.seconds.unwrap_or(0) as i8,
+    )
+    .unwrap_or(UtcOffset::UTC)
+}
+
+fn compute_transitions(
+    time_zone: &TimeZoneComponent,
+    to_year: i32,
+) -> Vec<(PrimitiveDateTime, UtcOffset, UtcOffset)> {
+    let horizon = Date::from_calendar_date(to_year.min(9999), Month::December, 31)
+        .map(|d| PrimitiveDateTime::new(d, Time::MIDNIGHT))
+        .unwrap_or(PrimitiveDateTime::MAX);
+
+    let mut list = Vec::new();
+    for observance in time_zone.nested_components() {
+        let properties = match observance {
+            CalendarComponent::Standard(s) => s.properties(),
+            CalendarComponent::Daylight(d) => d.properties(),
+            _ => continue,
+        };
+
+        let mut start = None;
+        let mut from = None;
+        let mut to = None;
+        for property in properties {
+            match property {
+                ComponentProperty::DateTimeStart(p) => start = Some(to_primitive(&p.value)),
+                ComponentProperty::TimeZoneOffsetFrom(p) => from = Some(to_utc_offset(&p.value)),
+                ComponentProperty::TimeZoneOffsetTo(p) => to = Some(to_utc_offset(&p.value)),
+                _ => {}
+            }
+        }
+
+        let (start, from, to) = match (start, from, to) {
+            (Some(start), Some(from), Some(to)) => (start, from, to),
+            _ => continue,
+        };
+
+        list.push((start, from, to));
+
+        for property in properties {
+            match property {
+                ComponentProperty::RecurrenceRule(p) => {
+                    // The UNTIL of an observance is in UTC, onsets are local to the `from` offset.
+                    let until = p.value.parts.iter().find_map(|part| match part {
+                        RecurRulePart::Until(until) if until.is_utc() => {
+                            match to_primitive(until).assume_utc().checked_to_offset(from) {
+                                Some(until) => {
+                                    Some(PrimitiveDateTime::new(until.date(), until.time()))
+                                }
+                                // Outside the supported range of dates, so clamp to the range
+                                None if from.is_negative() => Some(PrimitiveDateTime::MIN),
+                                None => Some(PrimitiveDateTime::MAX),
+                            }
+                        }
+                        RecurRulePart::Until(until) => Some(to_primitive(until)),
+                        _ => None,
+                    });
+
+                    for onset in RecurrenceIter::new(&p.value, start, horizon) {
+                        if let Some(until) = until {
+                            if onset > until {
+                                break;
+                            }
+                        }
+                        if onset != start {
+                            list.push((onset, from, to));
+                        }
+                    }
+                }
+                ComponentProperty::RecurrenceDateTimes(p) => {
+                    if let RecurrenceDateTimesPropertyValue::DateTimes(date_times) = &p.value {
+                        for date_time in date_times {
+                            let onset = to_primitive(date_time);
+                            if onset != start {
+                                list.push((onset, from, to));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    list.sort_by_key(|(onset, _, _)| *onset);
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+
+    const NEW_YORK: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:20070311T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+TZNAME:EDT\r\n\
+END:DAYLIGHT\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:20071104T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+TZNAME:EST\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+END:VCALENDAR\r\n";
+
+    fn local(year: i32, month: Month, day: u8, hour: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms(hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn offset_follows_observances() {
+        let object = load_ical(NEW_YORK).unwrap().pop().unwrap();
+        let resolver = TimeZoneResolver::new(&object);
+
+        let winter = resolver.offset_at("America/New_York", local(2024, Month::January, 15, 12));
+        assert_eq!(Some(UtcOffset::from_hms(-5, 0, 0).unwrap()), winter);
+
+        let summer = resolver.offset_at("America/New_York", local(2024, Month::July, 15, 12));
+        assert_eq!(Some(UtcOffset::from_hms(-4, 0, 0).unwrap()), summer);
+
+        // 2024-03-10 was the second Sunday in March
+        let before = resolver.offset_at("America/New_York", local(2024, Month::March, 10, 1));
+        assert_eq!(Some(UtcOffset::from_hms(-5, 0, 0).unwrap()), before);
+        let after = resolver.offset_at("America/New_York", local(2024, Month::March, 10, 3));
+        assert_eq!(Some(UtcOffset::from_hms(-4, 0, 0).unwrap()), after);
+    }
+
+    #[test]
+    fn unknown_time_zone_is_floating() {
+        let object = load_ical(NEW_YORK).unwrap().pop().unwrap();
+        let mut resolver = TimeZoneResolver::new(&object);
+        resolver.set_floating_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+
+        let resolved = resolver.resolve_local(
+            local(2024, Month::January, 15, 12),
+            Some("Europe/Nowhere"),
+            false,
+        );
+        assert_eq!(10, resolved.hour());
+        assert!(resolver
+            .offset_at("Europe/Nowhere", local(2024, Month::January, 15, 12))
+            .is_none());
+    }
+
+    #[test]
+    fn resolve_at_the_end_of_the_supported_range() {
+        let object = load_ical(NEW_YORK).unwrap().pop().unwrap();
+        let resolver = TimeZoneResolver::new(&object);
+
+        let resolved = resolver.resolve_local(
+            local(9999, Month::December, 31, 23),
+            Some("America/New_York"),
+            false,
+        );
+        assert_eq!(PrimitiveDateTime::MAX.assume_utc(), resolved);
+
+        let mut resolver = TimeZoneResolver::new(&object);
+        resolver.set_floating_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        let resolved = resolver.resolve_local(PrimitiveDateTime::MIN, None, false);
+        assert_eq!(PrimitiveDateTime::MIN.assume_utc(), resolved);
+    }
+
+    #[test]
+    fn observance_until_at_the_end_of_the_supported_range() {
+        let object = load_ical(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Fixed\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19700101T000000\r\n\
+RRULE:FREQ=YEARLY;UNTIL=99991231T230000Z\r\n\
+TZOFFSETFROM:+0200\r\n\
+TZOFFSETTO:+0200\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+END:VCALENDAR\r\n",
+        )
+        .unwrap()
+        .pop()
+        .unwrap();
+        let resolver = TimeZoneResolver::new(&object);
+
+        let offset = resolver.offset_at("Fixed", local(9999, Month::June, 1, 12));
+        assert_eq!(Some(UtcOffset::from_hms(2, 0, 0).unwrap()), offset);
+    }
+}
@@ -0,0 +1,585 @@
+//! Properties that are not defined by an RFC are kept as [XProperty] or [IanaProperty] values
+//! with a raw text value. An [ExtensionRegistry] lets an application declare how the values of
+//! its own properties are parsed, serialized and validated, and which properties its own
+//! components require.
+//!
+//! Register the extensions, then use [crate::ops::load_ical_with_extensions] to load data with
+//! the registered property values decoded, and [crate::validate::validate_model_with_extensions]
+//! to check the extension rules alongside the standard ones. Decoded values are available from
+//! [XProperty::extension_value] and are written back using the registered serializer.
+//!
+//! Decoding is a second pass over the finished model rather than a part of converting the parsed
+//! content to the model. The conversion has no context beyond the content it is given, and the
+//! registry would otherwise have to be threaded through every conversion to reach the X- and
+//! IANA properties. As a separate pass, the same decoding applies to objects that were built in
+//! code or loaded without a registry, so [load_ical](crate::ops::load_ical) does not decode
+//! anything and leaves the raw values in place.
+
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::model::object::ICalObject;
+use crate::model::param::Param;
+use crate::model::property::{CalendarProperty, ComponentProperty};
+use crate::model::property::{IanaProperty, XProperty};
+use crate::model::visit::{walk_calendar_property_mut, walk_component_property_mut, VisitorMut};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A decoded extension property value.
+///
+/// This is implemented for any type that can be compared and debug printed, so applications do
+/// not need to implement it themselves.
+pub trait ExtensionValue: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn eq_value(&self, other: &dyn ExtensionValue) -> bool;
+}
+
+impl<T> ExtensionValue for T
+where
+    T: Any + Debug + PartialEq + Send + Sync,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_value(&self, other: &dyn ExtensionValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+type ParseFn = dyn Fn(&str, &[Param]) -> AetoliaResult<Box<dyn ExtensionValue>> + Send + Sync;
+type SerializeFn = dyn Fn(&dyn ExtensionValue) -> AetoliaResult<String> + Send + Sync;
+type ValidateFn = dyn Fn(&dyn ExtensionValue, &[Param]) -> Vec<String> + Send + Sync;
+type ComponentValidateFn =
+    dyn Fn(&crate::model::component::CalendarComponent) -> Vec<String> + Send + Sync;
+
+/// The decoded value of an extension property, along with the serializer that writes it.
+#[derive(Clone)]
+pub struct ExtensionData {
+    value: Arc<dyn ExtensionValue>,
+    serialize: Arc<SerializeFn>,
+}
+
+impl ExtensionData {
+    pub fn value(&self) -> &dyn ExtensionValue {
+        self.value.as_ref()
+    }
+
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.as_any().downcast_ref::<T>()
+    }
+
+    /// Serialize the value using the serializer of the extension that produced it.
+    pub fn serialize(&self) -> AetoliaResult<String> {
+        (self.serialize)(self.value.as_ref())
+    }
+}
+
+impl Debug for ExtensionData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl PartialEq for ExtensionData {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq_value(other.value.as_ref())
+    }
+}
+
+/// The declaration of an application defined property.
+#[derive(Clone)]
+pub struct PropertyExtension {
+    name: String,
+    parse: Arc<ParseFn>,
+    serialize: Arc<SerializeFn>,
+    validate: Option<Arc<ValidateFn>>,
+    allowed_in: Option<Vec<String>>,
+    once: bool,
+}
+
+impl PropertyExtension {
+    /// Declare a property with a value of type `T`.
+    ///
+    /// The parser is given the raw property value, as it appears in the content line, and the
+    /// params of the property. The serializer must produce a value that the parser accepts.
+    pub fn new<N, T, P, S>(name: N, parse: P, serialize: S) -> Self
+    where
+        N: ToString,
+        T: ExtensionValue,
+        P: Fn(&str, &[Param]) -> AetoliaResult<T> + Send + Sync + 'static,
+        S: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        let name = name.to_string();
+        let type_name = name.clone();
+
+        PropertyExtension {
+            name,
+            parse: Arc::new(move |value, params| {
+                parse(value, params).map(|v| Box::new(v) as Box<dyn ExtensionValue>)
+            }),
+            serialize: Arc::new(move |value| {
+                value
+                    .as_any()
+                    .downcast_ref::<T>()
+                    .map(&serialize)
+                    .ok_or_else(|| {
+                        AetoliaError::other(format!("Wrong value type for property {type_name}"))
+                    })
+            }),
+            validate: None,
+            allowed_in: None,
+            once: false,
+        }
+    }
+
+    /// Add a validation rule for the decoded value. The rule returns a message for each problem
+    /// that it finds.
+    pub fn set_validate<T, V>(mut self, validate: V) -> Self
+    where
+        T: ExtensionValue,
+        V: Fn(&T, &[Param]) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.validate = Some(Arc::new(move |value, params| {
+            match value.as_any().downcast_ref::<T>() {
+                Some(value) => validate(value, params),
+                None => vec!["Value has the wrong type".to_string()],
+            }
+        }));
+        self
+    }
+
+    /// Restrict the property to the named components. Use `VCALENDAR` to allow the property at
+    /// the calendar level. By default, the property is allowed anywhere.
+    pub fn set_allowed_in<C: ToString>(mut self, components: Vec<C>) -> Self {
+        self.allowed_in = Some(
+            components
+                .into_iter()
+                .map(|c| c.to_string().to_ascii_uppercase())
+                .collect(),
+        );
+        self
+    }
+
+    /// Only allow the property to appear once in a component.
+    pub fn set_once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Parse a raw value into extension data for this property.
+    pub fn parse(&self, value: &str, params: &[Param]) -> AetoliaResult<ExtensionData> {
+        let value = (self.parse)(value, params).map_err(|e| {
+            AetoliaError::other(format!("Invalid value for property {}: {e}", self.name))
+        })?;
+
+        Ok(ExtensionData {
+            value: Arc::from(value),
+            serialize: self.serialize.clone(),
+        })
+    }
+
+    /// Wrap a value as extension data for this property.
+    pub fn wrap<T: ExtensionValue>(&self, value: T) -> ExtensionData {
+        ExtensionData {
+            value: Arc::new(value),
+            serialize: self.serialize.clone(),
+        }
+    }
+
+    pub(crate) fn validate_value(&self, data: &ExtensionData, params: &[Param]) -> Vec<String> {
+        match &self.validate {
+            Some(validate) => validate(data.value(), params),
+            None => Vec::new(),
+        }
+    }
+
+    pub(crate) fn is_allowed_in(&self, component_name: &str) -> bool {
+        match &self.allowed_in {
+            Some(allowed_in) => allowed_in
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(component_name)),
+            None => true,
+        }
+    }
+
+    pub(crate) fn is_once(&self) -> bool {
+        self.once
+    }
+}
+
+/// The declaration of an application defined component.
+#[derive(Clone)]
+pub struct ComponentExtension {
+    name: String,
+    required_properties: Vec<String>,
+    validate: Option<Arc<ComponentValidateFn>>,
+}
+
+impl ComponentExtension {
+    pub fn new<N: ToString>(name: N) -> Self {
+        ComponentExtension {
+            name: name.to_string(),
+            required_properties: Vec::new(),
+            validate: None,
+        }
+    }
+
+    /// Require the component to contain a property with this name.
+    pub fn add_required_property<N: ToString>(mut self, name: N) -> Self {
+        self.required_properties.push(name.to_string());
+        self
+    }
+
+    /// Add a validation rule for the whole component. The rule returns a message for each
+    /// problem that it finds.
+    pub fn set_validate<V>(mut self, validate: V) -> Self
+    where
+        V: Fn(&crate::model::component::CalendarComponent) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.validate = Some(Arc::new(validate));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn required_properties(&self) -> &[String] {
+        &self.required_properties
+    }
+
+    pub(crate) fn validate_component(
+        &self,
+        component: &crate::model::component::CalendarComponent,
+    ) -> Vec<String> {
+        match &self.validate {
+            Some(validate) => validate(component),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// The application defined properties and components that are known when loading, validating
+/// and writing calendar data.
+///
+/// Names are matched case-insensitively.
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    properties: HashMap<String, PropertyExtension>,
+    components: HashMap<String, ComponentExtension>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        ExtensionRegistry::default()
+    }
+
+    pub fn register_property(mut self, extension: PropertyExtension) -> Self {
+        self.properties
+            .insert(extension.name.to_ascii_uppercase(), extension);
+        self
+    }
+
+    pub fn register_component(mut self, extension: ComponentExtension) -> Self {
+        self.components
+            .insert(extension.name.to_ascii_uppercase(), extension);
+        self
+    }
+
+    pub fn property(&self, name: &str) -> Option<&PropertyExtension> {
+        self.properties.get(&name.to_ascii_uppercase())
+    }
+
+    pub fn component(&self, name: &str) -> Option<&ComponentExtension> {
+        self.components.get(&name.to_ascii_uppercase())
+    }
+
+    /// Decode the values of all registered properties in a calendar object.
+    ///
+    /// This can be used on any calendar object, whether it was loaded without the registry or
+    /// built in code.
+    ///
+    /// Fails on the first value that cannot be parsed.
+    pub fn decode(&self, object: &mut ICalObject) -> AetoliaResult<()> {
+        let mut decoder = Decoder {
+            registry: self,
+            error: None,
+        };
+        decoder.visit_object_mut(object);
+
+        match decoder.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn decode_property(
+        &self,
+        name: &str,
+        value: &str,
+        params: &[Param],
+    ) -> AetoliaResult<Option<ExtensionData>> {
+        match self.property(name) {
+            Some(extension) => extension.parse(value, params).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+struct Decoder<'a> {
+    registry: &'a ExtensionRegistry,
+    error: Option<AetoliaError>,
+}
+
+impl Decoder<'_> {
+    fn decode_x(&mut self, property: &mut XProperty) {
+        match self
+            .registry
+            .decode_property(&property.name, &property.value, &property.params)
+        {
+            Ok(Some(data)) => property.extension = Some(data),
+            Ok(None) => {}
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+    }
+
+    fn decode_iana(&mut self, property: &mut IanaProperty) {
+        match self
+            .registry
+            .decode_property(&property.name, &property.value, &property.params)
+        {
+            Ok(Some(data)) => property.extension = Some(data),
+            Ok(None) => {}
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+    }
+}
+
+impl VisitorMut for Decoder<'_> {
+    fn visit_calendar_property_mut(&mut self, property: &mut CalendarProperty) {
+        match property {
+            CalendarProperty::XProperty(p) => self.decode_x(p),
+            CalendarProperty::IanaProperty(p) => self.decode_iana(p),
+            _ => {}
+        }
+        walk_calendar_property_mut(self, property);
+    }
+
+    fn visit_component_property_mut(&mut self, property: &mut ComponentProperty) {
+        match property {
+            ComponentProperty::XProperty(p) => self.decode_x(p),
+            ComponentProperty::IanaProperty(p) => self.decode_iana(p),
+            _ => {}
+        }
+        walk_component_property_mut(self, property);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::access::ComponentAccess;
+    use crate::ops::load_ical_with_extensions;
+    use crate::serialize::WriteModel;
+    use crate::validate::validate_model_with_extensions;
+
+    #[derive(Debug, PartialEq)]
+    struct Project {
+        id: u32,
+        name: String,
+    }
+
+    fn project_extension() -> PropertyExtension {
+        PropertyExtension::new(
+            "X-OURAPP-PROJECT",
+            |value, _| {
+                let (id, name) = value
+                    .split_once('/')
+                    .ok_or_else(|| AetoliaError::other("Expected <id>/<name>"))?;
+                Ok(Project {
+                    id: id.parse().map_err(AetoliaError::other)?,
+                    name: name.to_string(),
+                })
+            },
+            |project: &Project| format!("{}/{}", project.id, project.name),
+        )
+        .set_validate(|project: &Project, _| {
+            if project.name.is_empty() {
+                vec!["Project name must not be empty".to_string()]
+            } else {
+                Vec::new()
+            }
+        })
+        .set_allowed_in(vec!["VEVENT", "X-OURAPP-BOARD"])
+        .set_once()
+    }
+
+    fn registry() -> ExtensionRegistry {
+        ExtensionRegistry::new()
+            .register_property(project_extension())
+            .register_component(
+                ComponentExtension::new("X-OURAPP-BOARD").add_required_property("X-OURAPP-PROJECT"),
+            )
+    }
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+X-WR-CALNAME:Work\r\n\
+BEGIN:VEVENT\r\n\
+UID:1\r\n\
+DTSTAMP:20240101T120000Z\r\n\
+DTSTART:20240102T100000Z\r\n\
+X-OURAPP-PROJECT:42/Apollo\r\n\
+END:VEVENT\r\n\
+BEGIN:X-OURAPP-BOARD\r\n\
+X-OURAPP-PROJECT:7/Gemini\r\n\
+END:X-OURAPP-BOARD\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn load_and_write() {
+        let registry = registry();
+        let object = load_ical_with_extensions(CALENDAR, &registry)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let project = object.components[0].find_x_properties("X-OURAPP-PROJECT")[0];
+        assert_eq!(
+            Some(&Project {
+                id: 42,
+                name: "Apollo".to_string()
+            }),
+            project.extension_value::<Project>()
+        );
+
+        let board_project = object.components[1].find_x_properties("x-ourapp-project")[0];
+        assert_eq!(7, board_project.extension_value::<Project>().unwrap().id);
+
+        let errors = validate_model_with_extensions(&object, &registry).unwrap();
+        assert!(
+            errors.is_empty(),
+            "{:?}",
+            errors.iter().map(|e| e.to_string()).collect::<Vec<_>>()
+        );
+
+        let mut out = Vec::new();
+        object.write_model(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("\r\nX-WR-CALNAME:Work\r\n"));
+        assert!(out.contains("\r\nX-OURAPP-PROJECT:42/Apollo\r\n"));
+        assert!(out.contains("\r\nX-OURAPP-PROJECT:7/Gemini\r\n"));
+    }
+
+    #[test]
+    fn decode_after_load() {
+        let mut object = crate::ops::load_ical(CALENDAR).unwrap().pop().unwrap();
+
+        let project = object.components[0].find_x_properties("X-OURAPP-PROJECT")[0];
+        assert_eq!(None, project.extension_value::<Project>());
+
+        registry().decode(&mut object).unwrap();
+
+        let project = object.components[0].find_x_properties("X-OURAPP-PROJECT")[0];
+        assert_eq!(42, project.extension_value::<Project>().unwrap().id);
+    }
+
+    #[test]
+    fn invalid_value_fails_decode() {
+        let input = CALENDAR.replace("42/Apollo", "Apollo");
+        assert!(load_ical_with_extensions(input, &registry()).is_err());
+    }
+
+    #[test]
+    fn build_and_modify() {
+        let extension = project_extension();
+        let mut object = ICalObject::builder()
+            .add_product_id("-//test//EN")
+            .finish_property()
+            .add_event_component()
+            .add_extension_property(
+                &extension,
+                Project {
+                    id: 1,
+                    name: "Mercury".to_string(),
+                },
+            )
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let property = &object.components[0].find_x_properties("X-OURAPP-PROJECT")[0];
+        assert_eq!("1/Mercury", property.value);
+
+        match &mut object.components[0] {
+            crate::model::component::CalendarComponent::Event(event) => {
+                match &mut event.properties[0] {
+                    ComponentProperty::XProperty(p) => p
+                        .set_extension_value(
+                            &extension,
+                            Project {
+                                id: 2,
+                                name: "Gemini".to_string(),
+                            },
+                        )
+                        .unwrap(),
+                    _ => panic!("Expected an X- property"),
+                }
+            }
+            _ => panic!("Expected an event"),
+        }
+
+        let property = &object.components[0].find_x_properties("X-OURAPP-PROJECT")[0];
+        assert_eq!("2/Gemini", property.value);
+        assert_eq!(
+            "Gemini",
+            property.extension_value::<Project>().unwrap().name
+        );
+    }
+
+    #[test]
+    fn validate_extension_rules() {
+        let input = CALENDAR
+            .replace(
+                "X-OURAPP-PROJECT:7/Gemini\r\n",
+                "X-OTHER:value\r\n",
+            )
+            .replace(
+                "X-OURAPP-PROJECT:42/Apollo\r\n",
+                "X-OURAPP-PROJECT:42/\r\nX-OURAPP-PROJECT:43/Apollo\r\n",
+            )
+            .replace(
+                "BEGIN:X-OURAPP-BOARD\r\n",
+                "BEGIN:VTODO\r\nUID:2\r\nDTSTAMP:20240101T120000Z\r\nX-OURAPP-PROJECT:1/Todo\r\nEND:VTODO\r\nBEGIN:X-OURAPP-BOARD\r\n",
+            );
+        let object = crate::ops::load_ical(input).unwrap().pop().unwrap();
+
+        let errors = validate_model_with_extensions(&object, &registry())
+            .unwrap()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "In component \"VEVENT\" at index 0, in component property \"X-OURAPP-PROJECT\" at index 3: Project name must not be empty",
+                "In component \"VEVENT\" at index 0, in component property \"X-OURAPP-PROJECT\" at index 4: Property is allowed at most once",
+                "In component \"VTODO\" at index 1, in component property \"X-OURAPP-PROJECT\" at index 2: Property is not allowed in VTODO",
+                "In component \"X-OURAPP-BOARD\" at index 2: Required property \"X-OURAPP-PROJECT\" is missing",
+            ],
+            errors
+        );
+    }
+}
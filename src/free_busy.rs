@@ -0,0 +1,434 @@
+mod interval;
+
+pub use interval::{IntervalSet, SlotSearch};
+
+use crate::common::{FreeBusyTimeType, Status, TimeTransparency};
+use crate::expand::{to_primitive, Expander, TimeRange};
+use crate::model::access::ComponentAccess;
+use crate::model::component::{CalendarComponent, FreeBusyComponent};
+use crate::model::object::ICalObject;
+use crate::model::param::{FreeBusyTimeTypeParam, Param};
+use crate::model::property::{
+    AttendeeProperty, ComponentProperty, DateTimeEndProperty, DateTimeStampProperty,
+    DateTimeStartProperty, FreeBusyTimeProperty, OrganizerProperty, StatusProperty,
+    TimeTransparencyProperty, UniqueIdentifierProperty,
+};
+use time::{OffsetDateTime, UtcOffset};
+
+/// Computes the free/busy time of a calendar user from their calendars, producing a VFREEBUSY
+/// component as described for the CalDAV free-busy-query report in RFC 4791 section 7.10.
+///
+/// Events are expanded into their occurrences within the range. Events that are transparent or
+/// cancelled do not take up time, and tentative events are reported as BUSY-TENTATIVE. The
+/// FREEBUSY properties of VFREEBUSY components in the calendars are included as they are.
+///
+/// Periods of the same type that overlap or touch are coalesced, and each type is reported in a
+/// single FREEBUSY property, in UTC.
+pub struct FreeBusyCalculator<'a> {
+    objects: Vec<&'a ICalObject>,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    floating_offset: UtcOffset,
+    uid: Option<String>,
+    date_time_stamp: OffsetDateTime,
+    organizer: Option<String>,
+    attendee: Option<String>,
+}
+
+impl<'a> FreeBusyCalculator<'a> {
+    /// Compute free/busy time from `start`, inclusive, to `end`, exclusive.
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        let now = OffsetDateTime::now_utc();
+        FreeBusyCalculator {
+            objects: Vec::new(),
+            start: start.to_offset(UtcOffset::UTC),
+            end: end.to_offset(UtcOffset::UTC),
+            floating_offset: UtcOffset::UTC,
+            uid: None,
+            date_time_stamp: now.replace_nanosecond(0).unwrap_or(now),
+            organizer: None,
+            attendee: None,
+        }
+    }
+
+    /// Add a calendar to compute free/busy time from.
+    pub fn add_object(mut self, object: &'a ICalObject) -> Self {
+        self.objects.push(object);
+        self
+    }
+
+    /// Set the offset that floating date-times, including all-day events, are interpreted in.
+    /// Defaults to UTC.
+    pub fn set_floating_offset(mut self, offset: UtcOffset) -> Self {
+        self.floating_offset = offset;
+        self
+    }
+
+    /// Set the UID of the VFREEBUSY, which defaults to a value derived from the range and the
+    /// DTSTAMP.
+    pub fn set_uid(mut self, uid: impl ToString) -> Self {
+        self.uid = Some(uid.to_string());
+        self
+    }
+
+    /// Set the DTSTAMP of the VFREEBUSY, which defaults to the current time.
+    pub fn set_date_time_stamp(mut self, date: time::Date, time: time::Time) -> Self {
+        self.date_time_stamp = time::PrimitiveDateTime::new(date, time).assume_utc();
+        self
+    }
+
+    /// Set the ORGANIZER, the calendar user who asked for the free/busy time.
+    pub fn set_organizer(mut self, organizer: impl ToString) -> Self {
+        self.organizer = Some(organizer.to_string());
+        self
+    }
+
+    /// Set the ATTENDEE, the calendar user whose free/busy time this is.
+    pub fn set_attendee(mut self, attendee: impl ToString) -> Self {
+        self.attendee = Some(attendee.to_string());
+        self
+    }
+
+    /// Find the busy periods, coalesced and grouped by their type.
+    ///
+    /// The types are ordered BUSY, BUSY-UNAVAILABLE, BUSY-TENTATIVE and then any other types
+    /// in the order they were first seen. Periods are ordered by their start.
+    pub fn busy_periods(&self) -> Vec<(FreeBusyTimeType, Vec<(OffsetDateTime, OffsetDateTime)>)> {
+        let mut groups: Vec<(FreeBusyTimeType, Vec<(OffsetDateTime, OffsetDateTime)>)> = vec![
+            (FreeBusyTimeType::Busy, Vec::new()),
+            (FreeBusyTimeType::BusyUnavailable, Vec::new()),
+            (FreeBusyTimeType::BusyTentative, Vec::new()),
+        ];
+        let range = TimeRange::new(self.start, self.end);
+
+        let mut add = |fb_type: &FreeBusyTimeType, start: OffsetDateTime, end: OffsetDateTime| {
+            if end <= start || !range.overlaps(start, end) {
+                return;
+            }
+            let period = (start.max(self.start), end.min(self.end));
+            match groups.iter_mut().find(|(t, _)| t == fb_type) {
+                Some((_, periods)) => periods.push(period),
+                None => groups.push((fb_type.clone(), vec![period])),
+            }
+        };
+
+        for object in &self.objects {
+            let mut expander = Expander::new(object);
+            expander.set_floating_offset(self.floating_offset);
+
+            for component in &object.components {
+                match component {
+                    CalendarComponent::Event(_) => {
+                        let Some(fb_type) = event_time_type(component) else {
+                            continue;
+                        };
+                        for occurrence in expander.occurrences(component, &range) {
+                            add(&fb_type, occurrence.start, occurrence.end);
+                        }
+                    }
+                    CalendarComponent::FreeBusy(_) => {
+                        for free_busy in component.get_properties::<FreeBusyTimeProperty>() {
+                            let fb_type = free_busy
+                                .params
+                                .iter()
+                                .find_map(|p| match p {
+                                    Param::FreeBusyTimeType(FreeBusyTimeTypeParam { fb_type }) => {
+                                        Some(fb_type.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .unwrap_or(FreeBusyTimeType::Busy);
+                            if fb_type == FreeBusyTimeType::Free {
+                                continue;
+                            }
+
+                            for period in &free_busy.value {
+                                // FREEBUSY periods are always in UTC, RFC 5545 section 3.8.2.6
+                                if let Ok(Some((start, end))) = period.expand() {
+                                    add(
+                                        &fb_type,
+                                        to_primitive(&start).assume_utc(),
+                                        to_primitive(&end).assume_utc(),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        groups.retain(|(_, periods)| !periods.is_empty());
+        for (_, periods) in &mut groups {
+            *periods = IntervalSet::from_intervals(std::mem::take(periods))
+                .intervals()
+                .to_vec();
+        }
+
+        groups
+    }
+
+    /// Build the VFREEBUSY component.
+    pub fn build(&self) -> CalendarComponent {
+        let mut properties = vec![
+            ComponentProperty::DateTimeStamp(DateTimeStampProperty {
+                value: to_utc_date_time(self.date_time_stamp),
+                params: Vec::new(),
+            }),
+            ComponentProperty::UniqueIdentifier(UniqueIdentifierProperty {
+                value: self.uid.clone().unwrap_or_else(|| {
+                    format!(
+                        "{}-{}-{}",
+                        format_utc(self.start),
+                        format_utc(self.end),
+                        format_utc(self.date_time_stamp)
+                    )
+                }),
+                params: Vec::new(),
+            }),
+            ComponentProperty::DateTimeStart(DateTimeStartProperty {
+                value: to_utc_date_time(self.start),
+                params: Vec::new(),
+            }),
+            ComponentProperty::DateTimeEnd(DateTimeEndProperty {
+                value: to_utc_date_time(self.end),
+                params: Vec::new(),
+            }),
+        ];
+        if let Some(organizer) = &self.organizer {
+            properties.push(ComponentProperty::Organizer(OrganizerProperty {
+                value: organizer.clone(),
+                params: Vec::new(),
+            }));
+        }
+        if let Some(attendee) = &self.attendee {
+            properties.push(ComponentProperty::Attendee(AttendeeProperty {
+                value: attendee.clone(),
+                params: Vec::new(),
+            }));
+        }
+
+        for (fb_type, periods) in self.busy_periods() {
+            properties.push(ComponentProperty::FreeBusyTime(FreeBusyTimeProperty {
+                value: IntervalSet::from_intervals(periods).to_periods(),
+                params: vec![Param::FreeBusyTimeType(FreeBusyTimeTypeParam { fb_type })],
+            }));
+        }
+
+        CalendarComponent::FreeBusy(FreeBusyComponent { properties })
+    }
+}
+
+/// How an event takes up time, or `None` if it does not, RFC 4791 section 7.10.
+pub(crate) fn event_time_type(event: &CalendarComponent) -> Option<FreeBusyTimeType> {
+    if event
+        .get_property::<TimeTransparencyProperty>()
+        .is_some_and(|t| t.value == TimeTransparency::Transparent)
+    {
+        return None;
+    }
+
+    match event.get_property::<StatusProperty>().map(|s| &s.value) {
+        Some(Status::Cancelled) => None,
+        Some(Status::Tentative) => Some(FreeBusyTimeType::BusyTentative),
+        _ => Some(FreeBusyTimeType::Busy),
+    }
+}
+
+fn to_utc_date_time(at: OffsetDateTime) -> crate::common::CalendarDateTime {
+    (at.date(), at.time(), true).into()
+}
+
+fn format_utc(at: OffsetDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        at.year(),
+        at.month() as u8,
+        at.day(),
+        at.hour(),
+        at.minute(),
+        at.second()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+    use crate::serialize::WriteModel;
+    use time::Month;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Europe/Berlin\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701025T030000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\n\
+TZOFFSETFROM:+0200\r\n\
+TZOFFSETTO:+0100\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700329T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0200\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:daily\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=Europe/Berlin:20240102T100000\r\n\
+DURATION:PT1H\r\n\
+RRULE:FREQ=DAILY;COUNT=3\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:overlapping\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T093000Z\r\n\
+DTEND:20240102T103000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:tentative\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240103T140000Z\r\n\
+DTEND:20240103T150000Z\r\n\
+STATUS:TENTATIVE\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:cancelled\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240103T160000Z\r\n\
+DTEND:20240103T170000Z\r\n\
+STATUS:CANCELLED\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:transparent\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;VALUE=DATE:20240103\r\n\
+TRANSP:TRANSPARENT\r\n\
+END:VEVENT\r\n\
+BEGIN:VFREEBUSY\r\n\
+UID:blocked\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+FREEBUSY;FBTYPE=BUSY-UNAVAILABLE:20240104T000000Z/PT8H\r\n\
+FREEBUSY;FBTYPE=FREE:20240104T080000Z/PT8H\r\n\
+END:VFREEBUSY\r\n\
+END:VCALENDAR\r\n";
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, Month::January, day).unwrap(),
+            time::Time::from_hms(hour, minute, 0).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    #[test]
+    fn busy_periods_by_type() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+
+        let periods = FreeBusyCalculator::new(utc(1, 0, 0), utc(8, 0, 0))
+            .add_object(&object)
+            .busy_periods();
+
+        assert_eq!(
+            vec![
+                (
+                    FreeBusyTimeType::Busy,
+                    vec![
+                        (utc(2, 9, 0), utc(2, 10, 30)),
+                        (utc(3, 9, 0), utc(3, 10, 0)),
+                        (utc(4, 9, 0), utc(4, 10, 0)),
+                    ]
+                ),
+                (
+                    FreeBusyTimeType::BusyUnavailable,
+                    vec![(utc(4, 0, 0), utc(4, 8, 0))]
+                ),
+                (
+                    FreeBusyTimeType::BusyTentative,
+                    vec![(utc(3, 14, 0), utc(3, 15, 0))]
+                ),
+            ],
+            periods
+        );
+    }
+
+    #[test]
+    fn periods_are_clipped_to_the_range() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+
+        let periods = FreeBusyCalculator::new(utc(2, 10, 0), utc(3, 9, 30))
+            .add_object(&object)
+            .busy_periods();
+
+        assert_eq!(
+            vec![(
+                FreeBusyTimeType::Busy,
+                vec![
+                    (utc(2, 10, 0), utc(2, 10, 30)),
+                    (utc(3, 9, 0), utc(3, 9, 30))
+                ]
+            )],
+            periods
+        );
+    }
+
+    #[test]
+    fn build_free_busy_component() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+
+        let component = FreeBusyCalculator::new(utc(3, 0, 0), utc(4, 0, 0))
+            .add_object(&object)
+            .set_date_time_stamp(
+                time::Date::from_calendar_date(2024, Month::February, 1).unwrap(),
+                time::Time::MIDNIGHT,
+            )
+            .set_organizer("mailto:alice@example.com")
+            .set_attendee("mailto:bob@example.com")
+            .build();
+
+        let mut out = Vec::new();
+        component.write_model(&mut out).unwrap();
+        assert_eq!(
+            "BEGIN:VFREEBUSY\r\n\
+DTSTAMP:20240201T000000Z\r\n\
+UID:20240103T000000Z-20240104T000000Z-20240201T000000Z\r\n\
+DTSTART:20240103T000000Z\r\n\
+DTEND:20240104T000000Z\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE:mailto:bob@example.com\r\n\
+FREEBUSY;FBTYPE=BUSY:20240103T090000Z/20240103T100000Z\r\n\
+FREEBUSY;FBTYPE=BUSY-TENTATIVE:20240103T140000Z/20240103T150000Z\r\n\
+END:VFREEBUSY",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn all_day_events_use_the_floating_offset() {
+        let content = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:holiday\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;VALUE=DATE:20240105\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let object = load_ical(content).unwrap().pop().unwrap();
+
+        let periods = FreeBusyCalculator::new(utc(1, 0, 0), utc(8, 0, 0))
+            .add_object(&object)
+            .set_floating_offset(UtcOffset::from_hms(2, 0, 0).unwrap())
+            .busy_periods();
+
+        assert_eq!(
+            vec![(FreeBusyTimeType::Busy, vec![(utc(4, 22, 0), utc(5, 22, 0))])],
+            periods
+        );
+    }
+}
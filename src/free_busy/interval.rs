@@ -0,0 +1,596 @@
+use crate::common::FreeBusyTimeType;
+use crate::expand::{to_primitive, TimeZoneResolver};
+use crate::model::access::ComponentAccess;
+use crate::model::param::{FreeBusyTimeTypeParam, Param};
+use crate::model::property::{FreeBusyTimeProperty, Period};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// A set of spans of time, kept as ordered intervals that do not overlap or touch.
+///
+/// Each interval includes its start and excludes its end. Intervals are kept in UTC.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<(OffsetDateTime, OffsetDateTime)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a set from intervals in any order, which may overlap. Empty intervals are ignored.
+    pub fn from_intervals(
+        intervals: impl IntoIterator<Item = (OffsetDateTime, OffsetDateTime)>,
+    ) -> Self {
+        let mut intervals = intervals
+            .into_iter()
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| {
+                (
+                    start.to_offset(UtcOffset::UTC),
+                    end.to_offset(UtcOffset::UTC),
+                )
+            })
+            .collect::<Vec<_>>();
+        intervals.sort();
+
+        let mut merged: Vec<(OffsetDateTime, OffsetDateTime)> = Vec::with_capacity(intervals.len());
+        for (start, end) in intervals {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    /// The busy time of a VFREEBUSY component, from all of its FREEBUSY properties that are not
+    /// FBTYPE=FREE.
+    ///
+    /// Periods that are not in UTC are ignored, since RFC 5545 section 3.8.2.6 requires FREEBUSY
+    /// values to be in UTC.
+    pub fn from_free_busy(component: &impl ComponentAccess) -> Self {
+        Self::from_free_busy_matching(component, |fb_type| *fb_type != FreeBusyTimeType::Free)
+    }
+
+    /// The time of a VFREEBUSY component with the given FBTYPE.
+    pub fn from_free_busy_type(
+        component: &impl ComponentAccess,
+        free_busy_time_type: &FreeBusyTimeType,
+    ) -> Self {
+        Self::from_free_busy_matching(component, |fb_type| fb_type == free_busy_time_type)
+    }
+
+    fn from_free_busy_matching(
+        component: &impl ComponentAccess,
+        matches: impl Fn(&FreeBusyTimeType) -> bool,
+    ) -> Self {
+        Self::from_intervals(
+            component
+                .get_properties::<FreeBusyTimeProperty>()
+                .into_iter()
+                .filter(|free_busy| {
+                    // FBTYPE defaults to BUSY, RFC 5545 section 3.2.9
+                    let fb_type = free_busy
+                        .params
+                        .iter()
+                        .find_map(|p| match p {
+                            Param::FreeBusyTimeType(FreeBusyTimeTypeParam { fb_type }) => {
+                                Some(fb_type)
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(&FreeBusyTimeType::Busy);
+                    matches(fb_type)
+                })
+                .flat_map(|free_busy| &free_busy.value)
+                .filter_map(|period| match period.expand() {
+                    Ok(Some((start, end))) => Some((
+                        to_primitive(&start).assume_utc(),
+                        to_primitive(&end).assume_utc(),
+                    )),
+                    _ => None,
+                }),
+        )
+    }
+
+    /// The intervals, in order.
+    pub fn intervals(&self) -> &[(OffsetDateTime, OffsetDateTime)] {
+        &self.intervals
+    }
+
+    /// The intervals as UTC periods, for use in a FREEBUSY property.
+    pub fn to_periods(&self) -> Vec<Period> {
+        self.intervals
+            .iter()
+            .map(|(start, end)| {
+                Period::new_explicit(start.date(), start.time(), end.date(), end.time(), true)
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The total length of the intervals.
+    pub fn total_duration(&self) -> time::Duration {
+        self.intervals
+            .iter()
+            .map(|(start, end)| *end - *start)
+            .sum()
+    }
+
+    /// Check whether an instant falls within one of the intervals.
+    pub fn contains(&self, instant: OffsetDateTime) -> bool {
+        self.intervals
+            .iter()
+            .any(|(start, end)| *start <= instant && instant < *end)
+    }
+
+    /// Add an interval to the set.
+    pub fn insert(&mut self, start: OffsetDateTime, end: OffsetDateTime) {
+        *self = self.union(&IntervalSet::from_intervals([(start, end)]));
+    }
+
+    /// The time that is in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::from_intervals(self.intervals.iter().chain(&other.intervals).cloned())
+    }
+
+    /// The time that is in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a_start, a_end) = self.intervals[i];
+            let (b_start, b_end) = other.intervals[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start < end {
+                intervals.push((start, end));
+            }
+
+            // Move past whichever interval finishes first
+            if a_end <= b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The time that is in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+
+        let mut j = 0;
+        for &(start, end) in &self.intervals {
+            let mut current = start;
+
+            // Skip intervals of the other set that end before this interval
+            while j < other.intervals.len() && other.intervals[j].1 <= current {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].0 < end {
+                let (cut_start, cut_end) = other.intervals[k];
+                if cut_start > current {
+                    intervals.push((current, cut_start));
+                }
+                current = current.max(cut_end);
+                k += 1;
+            }
+
+            if current < end {
+                intervals.push((current, end));
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The gaps between the intervals within a range, which is the free time if the set holds
+    /// the busy time.
+    pub fn gaps(&self, start: OffsetDateTime, end: OffsetDateTime) -> IntervalSet {
+        IntervalSet::from_intervals([(start, end)]).difference(self)
+    }
+}
+
+/// Searches for free slots of a fixed length, such as for a meeting that several attendees can
+/// go to.
+///
+/// The busy time of the attendees is combined with [IntervalSet::union] and then searched for
+/// slots within the working hours.
+#[derive(Debug, Clone)]
+pub struct SlotSearch {
+    duration: time::Duration,
+    step: Option<time::Duration>,
+    working_hours: Option<(Time, Time)>,
+    working_days: Vec<Weekday>,
+    offset: UtcOffset,
+    limit: Option<usize>,
+}
+
+impl SlotSearch {
+    pub fn new(duration: time::Duration) -> Self {
+        SlotSearch {
+            duration,
+            step: None,
+            working_hours: None,
+            working_days: Vec::new(),
+            offset: UtcOffset::UTC,
+            limit: None,
+        }
+    }
+
+    /// Only find slots that fall between `start` and `end` on each day.
+    pub fn set_working_hours(mut self, start: Time, end: Time) -> Self {
+        self.working_hours = Some((start, end));
+        self
+    }
+
+    /// Only find slots on these days of the week. All days are used if none are set.
+    pub fn add_working_day(mut self, weekday: Weekday) -> Self {
+        self.working_days.push(weekday);
+        self
+    }
+
+    /// Set the offset that the working hours and days are in, which defaults to UTC.
+    ///
+    /// The offset is fixed, so working hours set this way do not follow daylight saving time.
+    /// Use [SlotSearch::working_time_in] and [SlotSearch::find_in] to keep working hours in a
+    /// time zone from the calendar instead.
+    pub fn set_offset(mut self, offset: UtcOffset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the time between the starts of slots within the same free time. Defaults to the
+    /// length of the slot, so that slots do not overlap.
+    pub fn set_step(mut self, step: time::Duration) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the most slots to return.
+    pub fn set_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The time within the range that is inside working hours.
+    pub fn working_time(&self, start: OffsetDateTime, end: OffsetDateTime) -> IntervalSet {
+        self.working_time_by(start, end, |date, time| at(date, time, self.offset))
+    }
+
+    /// The time within the range that is inside working hours, with the working hours and days
+    /// in the time zone with the given `TZID`.
+    ///
+    /// The offset is looked up for each day, so working hours follow daylight saving time. If
+    /// the calendar does not define the time zone then the resolver's floating offset is used.
+    pub fn working_time_in(
+        &self,
+        time_zones: &TimeZoneResolver,
+        tz_id: &str,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> IntervalSet {
+        self.working_time_by(start, end, |date, time| {
+            time_zones.resolve_local(PrimitiveDateTime::new(date, time), Some(tz_id), false)
+        })
+    }
+
+    fn working_time_by(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        resolve: impl Fn(Date, Time) -> OffsetDateTime,
+    ) -> IntervalSet {
+        let range = IntervalSet::from_intervals([(start, end)]);
+        if self.working_hours.is_none() && self.working_days.is_empty() {
+            return range;
+        }
+
+        let (day_start, day_end) = self
+            .working_hours
+            .unwrap_or((Time::MIDNIGHT, Time::MIDNIGHT));
+
+        let mut working = Vec::new();
+        // The local dates are at most a day either side of the UTC dates, and any working time
+        // outside the range is removed below.
+        let start_utc = start.to_offset(UtcOffset::UTC).date();
+        let mut date = start_utc.previous_day().unwrap_or(start_utc);
+        let end_utc = end.to_offset(UtcOffset::UTC).date();
+        let last = end_utc.next_day().unwrap_or(end_utc);
+        while date <= last {
+            if self.working_days.is_empty() || self.working_days.contains(&date.weekday()) {
+                let open = resolve(date, day_start);
+                // Working hours that end at or before they start run into the next day
+                let close = match date.next_day() {
+                    Some(next) if day_end <= day_start => resolve(next, day_end),
+                    _ => resolve(date, day_end),
+                };
+                working.push((open, close));
+            }
+
+            date = match date.next_day() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        IntervalSet::from_intervals(working).intersection(&range)
+    }
+
+    /// Find the slots within the range that do not overlap the busy time, in order.
+    pub fn find(
+        &self,
+        busy: &IntervalSet,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        self.find_within(&self.working_time(start, end), busy)
+    }
+
+    /// Find the slots within the range that do not overlap the busy time, in order, with the
+    /// working hours and days in the time zone with the given `TZID`.
+    ///
+    /// See [SlotSearch::working_time_in].
+    pub fn find_in(
+        &self,
+        time_zones: &TimeZoneResolver,
+        tz_id: &str,
+        busy: &IntervalSet,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        self.find_within(&self.working_time_in(time_zones, tz_id, start, end), busy)
+    }
+
+    fn find_within(
+        &self,
+        working: &IntervalSet,
+        busy: &IntervalSet,
+    ) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        let mut slots = Vec::new();
+        if self.duration <= time::Duration::ZERO {
+            return slots;
+        }
+
+        let step = self
+            .step
+            .filter(|step| step.is_positive())
+            .unwrap_or(self.duration);
+        let free = working.difference(busy);
+
+        for &(free_start, free_end) in free.intervals() {
+            let mut slot_start = free_start;
+            while let Some(slot_end) = slot_start.checked_add(self.duration) {
+                if slot_end > free_end {
+                    break;
+                }
+                if self.limit.is_some_and(|limit| slots.len() >= limit) {
+                    return slots;
+                }
+                slots.push((slot_start, slot_end));
+
+                slot_start = match slot_start.checked_add(step) {
+                    Some(next) => next,
+                    None => break,
+                };
+            }
+        }
+
+        slots
+    }
+}
+
+fn at(date: Date, time: Time, offset: UtcOffset) -> OffsetDateTime {
+    PrimitiveDateTime::new(date, time).assume_offset(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::component::CalendarComponent;
+    use crate::ops::load_ical;
+    use time::Month;
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::January, day).unwrap(),
+            Time::from_hms(hour, minute, 0).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    /// Build a set from `(start hour, start minute, end hour, end minute)` on the same day.
+    fn set(intervals: &[(u8, u8, u8, u8)]) -> IntervalSet {
+        IntervalSet::from_intervals(
+            intervals
+                .iter()
+                .map(|(sh, sm, eh, em)| (utc(1, *sh, *sm), utc(1, *eh, *em))),
+        )
+    }
+
+    #[test]
+    fn normalizes_intervals() {
+        let intervals = set(&[(11, 0, 12, 0), (9, 0, 10, 0), (10, 0, 10, 30)]);
+
+        assert_eq!(
+            &[
+                (utc(1, 9, 0), utc(1, 10, 30)),
+                (utc(1, 11, 0), utc(1, 12, 0))
+            ],
+            intervals.intervals()
+        );
+        assert_eq!(time::Duration::minutes(150), intervals.total_duration());
+        assert!(intervals.contains(utc(1, 10, 0)));
+        assert!(!intervals.contains(utc(1, 10, 30)));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(9, 0, 11, 0), (13, 0, 15, 0)]);
+        let b = set(&[(10, 0, 14, 0), (14, 30, 16, 0)]);
+
+        assert_eq!(set(&[(9, 0, 16, 0)]), a.union(&b));
+        assert_eq!(
+            set(&[(10, 0, 11, 0), (13, 0, 14, 0), (14, 30, 15, 0)]),
+            a.intersection(&b)
+        );
+        assert_eq!(set(&[(9, 0, 10, 0), (14, 0, 14, 30)]), a.difference(&b));
+        assert_eq!(
+            set(&[(8, 0, 9, 0), (11, 0, 13, 0), (15, 0, 18, 0)]),
+            a.gaps(utc(1, 8, 0), utc(1, 18, 0))
+        );
+
+        let mut c = a.clone();
+        c.insert(utc(1, 11, 0), utc(1, 13, 0));
+        assert_eq!(set(&[(9, 0, 15, 0)]), c);
+    }
+
+    #[test]
+    fn from_free_busy_components() {
+        let content = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VFREEBUSY\r\n\
+UID:bob\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+FREEBUSY:20240101T090000Z/PT1H,20240101T093000Z/20240101T110000Z\r\n\
+FREEBUSY;FBTYPE=BUSY-TENTATIVE:20240101T140000Z/PT1H\r\n\
+FREEBUSY;FBTYPE=FREE:20240101T120000Z/PT1H\r\n\
+END:VFREEBUSY\r\n\
+BEGIN:VFREEBUSY\r\n\
+UID:carol\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+FREEBUSY:20240101T100000Z/PT3H\r\n\
+END:VFREEBUSY\r\n\
+END:VCALENDAR\r\n";
+        let object = load_ical(content).unwrap().pop().unwrap();
+        let [CalendarComponent::FreeBusy(bob), CalendarComponent::FreeBusy(carol)] =
+            object.components.as_slice()
+        else {
+            panic!("Expected two VFREEBUSY components");
+        };
+
+        let bob_busy = IntervalSet::from_free_busy(bob);
+        assert_eq!(set(&[(9, 0, 11, 0), (14, 0, 15, 0)]), bob_busy);
+        assert_eq!(
+            set(&[(14, 0, 15, 0)]),
+            IntervalSet::from_free_busy_type(bob, &FreeBusyTimeType::BusyTentative)
+        );
+
+        let carol_busy = IntervalSet::from_free_busy(carol);
+        assert_eq!(
+            set(&[(9, 0, 13, 0), (14, 0, 15, 0)]),
+            bob_busy.union(&carol_busy)
+        );
+        assert_eq!(set(&[(10, 0, 11, 0)]), bob_busy.intersection(&carol_busy));
+    }
+
+    #[test]
+    fn find_common_slots() {
+        let busy = IntervalSet::from_intervals([
+            (utc(1, 9, 0), utc(1, 10, 0)),
+            (utc(1, 11, 0), utc(1, 16, 0)),
+            (utc(2, 8, 0), utc(2, 12, 30)),
+        ]);
+
+        // 2024-01-01 is a Monday
+        let search = SlotSearch::new(time::Duration::hours(1))
+            .set_working_hours(
+                Time::from_hms(9, 0, 0).unwrap(),
+                Time::from_hms(17, 0, 0).unwrap(),
+            )
+            .add_working_day(Weekday::Monday)
+            .add_working_day(Weekday::Tuesday)
+            .set_offset(UtcOffset::from_hms(1, 0, 0).unwrap())
+            .set_step(time::Duration::minutes(30));
+
+        assert_eq!(
+            vec![
+                // Working hours are 08:00 to 16:00 in UTC
+                (utc(1, 8, 0), utc(1, 9, 0)),
+                (utc(1, 10, 0), utc(1, 11, 0)),
+                (utc(2, 12, 30), utc(2, 13, 30)),
+                (utc(2, 13, 0), utc(2, 14, 0)),
+                (utc(2, 13, 30), utc(2, 14, 30)),
+                (utc(2, 14, 0), utc(2, 15, 0)),
+                (utc(2, 14, 30), utc(2, 15, 30)),
+                (utc(2, 15, 0), utc(2, 16, 0)),
+            ],
+            search.find(&busy, utc(1, 0, 0), utc(8, 0, 0))
+        );
+
+        assert_eq!(
+            vec![(utc(1, 8, 0), utc(1, 9, 0))],
+            search.set_limit(1).find(&busy, utc(1, 0, 0), utc(8, 0, 0))
+        );
+    }
+
+    #[test]
+    fn working_hours_follow_daylight_saving_time() {
+        let object = load_ical(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:20070311T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+END:DAYLIGHT\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:20071104T020000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+END:VCALENDAR\r\n",
+        )
+        .unwrap()
+        .pop()
+        .unwrap();
+        let time_zones = TimeZoneResolver::new(&object);
+
+        let march = |day: u8, hour: u8| {
+            PrimitiveDateTime::new(
+                Date::from_calendar_date(2024, Month::March, day).unwrap(),
+                Time::from_hms(hour, 0, 0).unwrap(),
+            )
+            .assume_utc()
+        };
+
+        // Daylight saving time starts on Sunday 2024-03-10
+        let search = SlotSearch::new(time::Duration::hours(8))
+            .set_working_hours(
+                Time::from_hms(9, 0, 0).unwrap(),
+                Time::from_hms(17, 0, 0).unwrap(),
+            )
+            .add_working_day(Weekday::Friday)
+            .add_working_day(Weekday::Monday);
+
+        assert_eq!(
+            vec![(march(8, 14), march(8, 22)), (march(11, 13), march(11, 21))],
+            search.find_in(
+                &time_zones,
+                "America/New_York",
+                &IntervalSet::new(),
+                march(8, 0),
+                march(12, 0)
+            )
+        );
+    }
+}
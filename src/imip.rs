@@ -0,0 +1,554 @@
+mod mime;
+
+use crate::common::CalendarAddress;
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::imip::mime::{
+    encode_base64, encode_header_value, format_date, split_multipart, to_utf8, Entity,
+};
+use crate::itip::ItipMethod;
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::property::{
+    AttendeeProperty, CalendarProperty, DescriptionProperty, OrganizerProperty, SummaryProperty,
+};
+use crate::ops::load_ical;
+use crate::serialize::WriteModel;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Multipart bodies can be nested, but there is no reason for an invitation to be nested deeply.
+const MAX_MULTIPART_DEPTH: usize = 8;
+
+/// Build an email message that carries an iTIP message, RFC 6047.
+///
+/// The message is a multipart/alternative body with a text/plain part for mail clients that do
+/// not understand calendars, followed by the text/calendar part. Both parts are UTF-8 and base64
+/// encoded, so that the message can be sent over any transport.
+///
+/// The sender and recipients default to the organizer and attendees of the iTIP message, as
+/// appropriate for its METHOD. Only `mailto:` addresses can be used by default.
+pub struct ImipMessageBuilder<'a> {
+    message: &'a ICalObject,
+    from: Option<String>,
+    to: Vec<String>,
+    subject: Option<String>,
+    text: Option<String>,
+    boundary: Option<String>,
+    date: Option<time::OffsetDateTime>,
+    message_id: Option<String>,
+}
+
+impl<'a> ImipMessageBuilder<'a> {
+    pub fn new(message: &'a ICalObject) -> Self {
+        ImipMessageBuilder {
+            message,
+            from: None,
+            to: Vec::new(),
+            subject: None,
+            text: None,
+            boundary: None,
+            date: None,
+            message_id: None,
+        }
+    }
+
+    /// Set the sender, instead of the organizer or the replying attendee.
+    pub fn set_from(mut self, from: impl ToString) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    /// Add a recipient. If any recipients are added, they replace the default recipients.
+    pub fn add_to(mut self, to: impl ToString) -> Self {
+        self.to.push(to.to_string());
+        self
+    }
+
+    /// Set the subject, which defaults to the SUMMARY of the calendar component.
+    pub fn set_subject(mut self, subject: impl ToString) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    /// Set the content of the text/plain part, which defaults to the SUMMARY and DESCRIPTION of
+    /// the calendar component.
+    pub fn set_text(mut self, text: impl ToString) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Set the multipart boundary, which defaults to a value derived from the calendar content.
+    pub fn set_boundary(mut self, boundary: impl ToString) -> Self {
+        self.boundary = Some(boundary.to_string());
+        self
+    }
+
+    /// Set the date of the message, which defaults to the current time.
+    pub fn set_date(mut self, date: time::OffsetDateTime) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Set the Message-ID, without the angle brackets. It defaults to a value derived from the
+    /// date and the calendar content, at the domain of the sender.
+    pub fn set_message_id(mut self, message_id: impl ToString) -> Self {
+        self.message_id = Some(message_id.to_string());
+        self
+    }
+
+    /// Build the message, with CRLF line endings.
+    ///
+    /// Fails if there is no sender or recipient, or if an address, the boundary or the Message-ID
+    /// contains a line break, since that would start a new header.
+    pub fn build(self) -> AetoliaResult<Vec<u8>> {
+        let method = method_of(self.message)
+            .ok_or_else(|| AetoliaError::other("An iMIP message must have a METHOD"))?;
+        let itip_method = ItipMethod::from_name(method)
+            .ok_or_else(|| AetoliaError::other(format!("Unknown iTIP method {method}")))?;
+
+        let mut calendar = Vec::new();
+        self.message.write_model(&mut calendar)?;
+
+        let component = self
+            .message
+            .components
+            .iter()
+            .find(|c| !matches!(c, CalendarComponent::TimeZone(_)));
+        let summary = component
+            .and_then(|c| c.get_property::<SummaryProperty>())
+            .map(|s| s.value.clone());
+
+        let from = match self.from {
+            Some(from) => from,
+            None => component
+                .and_then(|c| default_sender(c, itip_method))
+                .ok_or_else(|| AetoliaError::other("No sender for the iMIP message"))?,
+        };
+        let to = match self.to.is_empty() {
+            false => self.to,
+            true => component
+                .map(|c| default_recipients(c, itip_method, &from))
+                .unwrap_or_default(),
+        };
+        if to.is_empty() {
+            return Err(AetoliaError::other("No recipients for the iMIP message"));
+        }
+
+        let subject = self
+            .subject
+            .or_else(|| summary.clone())
+            .unwrap_or_else(|| format!("Calendar {}", itip_method.name()));
+        let text = self.text.unwrap_or_else(|| {
+            let description = component
+                .and_then(|c| c.get_property::<DescriptionProperty>())
+                .map(|d| d.value.clone());
+            [summary, description]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\r\n\r\n")
+        });
+        let boundary = self.boundary.unwrap_or_else(|| {
+            // Base64 content never contains '_', so this can't be confused with the content
+            let mut hasher = DefaultHasher::new();
+            calendar.hash(&mut hasher);
+            format!("=_aetolia_{:016x}", hasher.finish())
+        });
+        let date = self.date.unwrap_or_else(time::OffsetDateTime::now_utc);
+        let message_id = self.message_id.unwrap_or_else(|| {
+            let mut hasher = DefaultHasher::new();
+            calendar.hash(&mut hasher);
+            to.hash(&mut hasher);
+            let domain = from
+                .rsplit_once('@')
+                .map(|(_, domain)| domain.trim_end_matches('>'))
+                .unwrap_or("aetolia.invalid");
+            format!(
+                "{:x}.{:016x}@{domain}",
+                date.unix_timestamp_nanos(),
+                hasher.finish()
+            )
+        });
+
+        // Values that are written into headers as they are must not start a new header
+        check_header_value("From", &from)?;
+        for to in &to {
+            check_header_value("To", to)?;
+        }
+        check_header_value("Message-ID", &message_id)?;
+        check_header_value("Content-Type boundary", &boundary)?;
+
+        let mut out = String::new();
+        out.push_str(&format!("From: {from}\r\n"));
+        out.push_str(&format!("To: {}\r\n", to.join(", ")));
+        out.push_str(&format!("Subject: {}\r\n", encode_header_value(&subject)));
+        out.push_str(&format!("Date: {}\r\n", format_date(date)));
+        out.push_str(&format!("Message-ID: <{message_id}>\r\n"));
+        out.push_str("MIME-Version: 1.0\r\n");
+        out.push_str(&format!(
+            "Content-Type: multipart/alternative; boundary=\"{boundary}\"\r\n"
+        ));
+        out.push_str("\r\n");
+
+        out.push_str(&format!("--{boundary}\r\n"));
+        out.push_str("Content-Type: text/plain; charset=UTF-8\r\n");
+        out.push_str("Content-Transfer-Encoding: base64\r\n");
+        out.push_str("\r\n");
+        out.push_str(&encode_base64(text.as_bytes()));
+
+        out.push_str(&format!("--{boundary}\r\n"));
+        out.push_str(&format!(
+            "Content-Type: text/calendar; method={}; charset=UTF-8\r\n",
+            itip_method.name()
+        ));
+        out.push_str("Content-Transfer-Encoding: base64\r\n");
+        out.push_str("\r\n");
+        out.push_str(&encode_base64(&calendar));
+
+        out.push_str(&format!("--{boundary}--\r\n"));
+
+        Ok(out.into_bytes())
+    }
+}
+
+/// A text/calendar part of an email message.
+#[derive(Debug)]
+pub struct ImipCalendar {
+    /// The `method` parameter of the Content-Type, which RFC 6047 requires to match the METHOD
+    /// of the calendar objects.
+    pub method: Option<String>,
+    /// The calendar objects in the part.
+    pub objects: Vec<ICalObject>,
+}
+
+/// Find and parse the text/calendar parts of an RFC 5322 email message, RFC 6047.
+///
+/// Multipart bodies are searched, including nested ones, so that calendars are found in both
+/// multipart/alternative and multipart/mixed messages. Parts that are base64 or quoted-printable
+/// encoded are decoded, and UTF-8, US-ASCII and ISO-8859-1 content is accepted.
+pub fn extract_calendars(raw: &[u8]) -> AetoliaResult<Vec<ImipCalendar>> {
+    let mut calendars = Vec::new();
+    extract_from_entity(&Entity::parse(raw), 0, &mut calendars)?;
+
+    Ok(calendars)
+}
+
+fn extract_from_entity(
+    entity: &Entity,
+    depth: usize,
+    calendars: &mut Vec<ImipCalendar>,
+) -> AetoliaResult<()> {
+    let content_type = entity.content_type();
+
+    if content_type.mime_type.starts_with("multipart/") {
+        if depth >= MAX_MULTIPART_DEPTH {
+            return Err(AetoliaError::other(
+                "Multipart bodies are nested too deeply",
+            ));
+        }
+        let boundary = content_type
+            .param("boundary")
+            .ok_or_else(|| AetoliaError::other("Multipart body does not have a boundary"))?;
+
+        for part in split_multipart(entity.body, boundary) {
+            extract_from_entity(&Entity::parse(part), depth + 1, calendars)?;
+        }
+    } else if content_type.mime_type == "text/calendar" {
+        let mut content = to_utf8(
+            entity.decoded_body()?,
+            content_type.param("charset").unwrap_or("utf-8"),
+        )?;
+        // The line break before a multipart delimiter is part of the delimiter, so the last
+        // content line may be left without one.
+        if !content.ends_with(b"\n") {
+            content.extend_from_slice(b"\r\n");
+        }
+
+        calendars.push(ImipCalendar {
+            method: content_type.param("method").map(|m| m.to_string()),
+            objects: load_ical(content)?,
+        });
+    }
+
+    Ok(())
+}
+
+fn method_of(object: &ICalObject) -> Option<&str> {
+    object.properties.iter().find_map(|p| match p {
+        CalendarProperty::Method(method) => Some(method.value.as_str()),
+        _ => None,
+    })
+}
+
+/// Whether the message is sent by an attendee to the organizer, rather than the other way around.
+fn is_sent_by_attendee(method: ItipMethod) -> bool {
+    matches!(
+        method,
+        ItipMethod::Reply | ItipMethod::Refresh | ItipMethod::Counter
+    )
+}
+
+fn default_sender(component: &CalendarComponent, method: ItipMethod) -> Option<String> {
+    if is_sent_by_attendee(method) {
+        component
+            .get_properties::<AttendeeProperty>()
+            .into_iter()
+            .find_map(|a| email_address(&a.value))
+    } else {
+        component
+            .get_property::<OrganizerProperty>()
+            .and_then(|o| email_address(&o.value))
+    }
+}
+
+fn default_recipients(
+    component: &CalendarComponent,
+    method: ItipMethod,
+    from: &str,
+) -> Vec<String> {
+    if is_sent_by_attendee(method) {
+        component
+            .get_property::<OrganizerProperty>()
+            .and_then(|o| email_address(&o.value))
+            .into_iter()
+            .collect()
+    } else {
+        component
+            .get_properties::<AttendeeProperty>()
+            .into_iter()
+            .filter_map(|a| email_address(&a.value))
+            .filter(|address| CalendarAddress::new(address) != CalendarAddress::new(from))
+            .collect()
+    }
+}
+
+fn check_header_value(name: &str, value: &str) -> AetoliaResult<()> {
+    if value.contains(['\r', '\n']) {
+        return Err(AetoliaError::other(format!(
+            "The {name} header must not contain a line break"
+        )));
+    }
+
+    Ok(())
+}
+
+/// The email address of a `mailto:` calendar user address.
+fn email_address(calendar_address: &str) -> Option<String> {
+    CalendarAddress::new(calendar_address)
+        .email()
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ParticipationStatusUnknown;
+    use crate::itip::ItipMessageBuilder;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:review\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T090000Z\r\n\
+DURATION:PT1H\r\n\
+SUMMARY:Café review\r\n\
+DESCRIPTION:Quarterly review\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:MAILTO:bob@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:urn:uuid:meeting-room\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn load() -> ICalObject {
+        load_ical(CALENDAR).unwrap().pop().unwrap()
+    }
+
+    fn itip(object: &ICalObject) -> ItipMessageBuilder<'_> {
+        ItipMessageBuilder::new(object)
+            .unwrap()
+            .set_date_time_stamp(
+                time::Date::from_calendar_date(2024, time::Month::February, 1).unwrap(),
+                time::Time::from_hms(12, 0, 0).unwrap(),
+            )
+    }
+
+    #[test]
+    fn request_round_trip() {
+        let object = load();
+        let request = itip(&object).request();
+
+        let raw = ImipMessageBuilder::new(&request)
+            .set_boundary("boundary")
+            .set_date(
+                time::PrimitiveDateTime::new(
+                    time::Date::from_calendar_date(2024, time::Month::February, 1).unwrap(),
+                    time::Time::from_hms(12, 0, 0).unwrap(),
+                )
+                .assume_utc(),
+            )
+            .set_message_id("review-1@example.com")
+            .build()
+            .unwrap();
+        let content = String::from_utf8(raw.clone()).unwrap();
+
+        assert!(content.starts_with(
+            "From: alice@example.com\r\n\
+To: bob@example.com\r\n\
+Subject: =?UTF-8?B?Q2Fmw6kgcmV2aWV3?=\r\n\
+Date: Thu, 01 Feb 2024 12:00:00 +0000\r\n\
+Message-ID: <review-1@example.com>\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/alternative; boundary=\"boundary\"\r\n\
+\r\n\
+--boundary\r\n\
+Content-Type: text/plain; charset=UTF-8\r\n"
+        ));
+        assert!(content.contains(
+            "--boundary\r\n\
+Content-Type: text/calendar; method=REQUEST; charset=UTF-8\r\n\
+Content-Transfer-Encoding: base64\r\n"
+        ));
+        assert!(content.ends_with("--boundary--\r\n"));
+
+        let calendars = extract_calendars(&raw).unwrap();
+        assert_eq!(1, calendars.len());
+        assert_eq!(Some("REQUEST"), calendars[0].method.as_deref());
+        assert_eq!(vec![request], calendars[0].objects);
+    }
+
+    #[test]
+    fn reply_is_sent_to_the_organizer() {
+        let object = load();
+        let reply = itip(&object)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+
+        let raw = ImipMessageBuilder::new(&reply).build().unwrap();
+        let content = String::from_utf8(raw).unwrap();
+
+        assert!(content.starts_with(
+            "From: bob@example.com\r\nTo: alice@example.com\r\nSubject: Calendar REPLY\r\n"
+        ));
+        assert!(content.contains("method=REPLY"));
+
+        // The Message-ID is at the domain of the sender
+        let message_id = content
+            .lines()
+            .find_map(|line| line.strip_prefix("Message-ID: "))
+            .unwrap();
+        assert!(message_id.starts_with('<'));
+        assert!(message_id.ends_with("@example.com>"));
+        assert!(content.contains("\r\nDate: "));
+    }
+
+    #[test]
+    fn explicit_addresses() {
+        let object = load();
+        let request = itip(&object).request();
+
+        let raw = ImipMessageBuilder::new(&request)
+            .set_from("calendar@example.com")
+            .add_to("room@example.com")
+            .add_to("carol@example.com")
+            .set_subject("Invitation")
+            .build()
+            .unwrap();
+        let content = String::from_utf8(raw).unwrap();
+
+        assert!(content.starts_with(
+            "From: calendar@example.com\r\nTo: room@example.com, carol@example.com\r\nSubject: Invitation\r\n"
+        ));
+    }
+
+    #[test]
+    fn header_injection() {
+        let object = load();
+        let request = itip(&object).request();
+
+        assert!(ImipMessageBuilder::new(&request)
+            .set_from("alice@example.com\r\nBcc: eve@example.com")
+            .build()
+            .is_err());
+        assert!(ImipMessageBuilder::new(&request)
+            .add_to("bob@example.com\nBcc: eve@example.com")
+            .build()
+            .is_err());
+
+        // A subject is encoded when it is not plain text
+        let raw = ImipMessageBuilder::new(&request)
+            .set_subject("Review\r\nBcc: eve@example.com")
+            .build()
+            .unwrap();
+        let content = String::from_utf8(raw).unwrap();
+        assert!(!content.contains("\r\nBcc:"));
+    }
+
+    #[test]
+    fn message_without_method() {
+        let object = load();
+
+        assert!(ImipMessageBuilder::new(&object).build().is_err());
+    }
+
+    #[test]
+    fn extract_from_mixed_message() {
+        let raw = "From: alice@example.com\r\n\
+To: bob@example.com\r\n\
+Subject: Review\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/mixed; boundary=outer\r\n\
+\r\n\
+This is a multi-part message in MIME format.\r\n\
+--outer\r\n\
+Content-Type: multipart/alternative;\r\n\
+\tboundary=\"inner\"\r\n\
+\r\n\
+--inner\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Review\r\n\
+--inner\r\n\
+Content-Type: text/calendar; charset=\"iso-8859-1\"; method=PUBLISH\r\n\
+Content-Transfer-Encoding: quoted-printable\r\n\
+\r\n\
+BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+METHOD:PUBLISH\r\n\
+BEGIN:VEVENT\r\n\
+UID:review\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T090000Z\r\n\
+SUMMARY:Caf=E9 review with a summary that is long enough to need a soft line b=\r\n\
+reak\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n\
+--inner--\r\n\
+--outer\r\n\
+Content-Type: application/pdf\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+JVBERi0=\r\n\
+--outer--\r\n";
+
+        let calendars = extract_calendars(raw.as_bytes()).unwrap();
+
+        assert_eq!(1, calendars.len());
+        assert_eq!(Some("PUBLISH"), calendars[0].method.as_deref());
+        let summary = calendars[0].objects[0].components[0]
+            .get_property::<SummaryProperty>()
+            .unwrap();
+        assert_eq!(
+            "Café review with a summary that is long enough to need a soft line break",
+            summary.value
+        );
+    }
+}
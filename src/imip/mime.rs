@@ -0,0 +1,487 @@
+use crate::error::{AetoliaError, AetoliaResult};
+use base64::Engine;
+
+/// The longest line of base64 encoded content that is allowed, RFC 2045 section 6.8.
+const BASE64_LINE_LENGTH: usize = 76;
+
+/// The most bytes of text in one RFC 2047 encoded word. These encode to 52 characters, so the
+/// encoded word is 64 characters and a line that starts with a header name stays within the 76
+/// characters allowed by RFC 2047 section 2.
+const ENCODED_WORD_BYTES: usize = 39;
+
+/// A header field of a message or body part, with its value unfolded.
+#[derive(Debug)]
+pub(super) struct Header {
+    pub(super) name: String,
+    pub(super) value: String,
+}
+
+/// A parsed Content-Type header, RFC 2045 section 5.1.
+#[derive(Debug)]
+pub(super) struct ContentType {
+    /// The type and subtype, in lowercase.
+    pub(super) mime_type: String,
+    /// The parameters, with their names in lowercase and their values unquoted.
+    pub(super) params: Vec<(String, String)>,
+}
+
+impl ContentType {
+    /// The type of body parts without a Content-Type header, RFC 2045 section 5.2.
+    fn default_type() -> Self {
+        ContentType {
+            mime_type: "text/plain".to_string(),
+            params: vec![("charset".to_string(), "us-ascii".to_string())],
+        }
+    }
+
+    pub(super) fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A message, or one part of a multipart body, split into its headers and raw body.
+#[derive(Debug)]
+pub(super) struct Entity<'a> {
+    pub(super) headers: Vec<Header>,
+    pub(super) body: &'a [u8],
+}
+
+impl<'a> Entity<'a> {
+    pub(super) fn parse(raw: &'a [u8]) -> Self {
+        let (header_block, body) = match find_blank_line(raw) {
+            Some((end, body_start)) => (&raw[..end], &raw[body_start..]),
+            None => (raw, &raw[raw.len()..]),
+        };
+
+        let mut headers: Vec<Header> = Vec::new();
+        for line in header_block.split(|b| *b == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                continue;
+            }
+
+            // Unfolding removes the line break but keeps the whitespace, RFC 5322 section 2.2.3
+            if matches!(line[0], b' ' | b'\t') {
+                if let Some(last) = headers.last_mut() {
+                    last.value.push_str(&String::from_utf8_lossy(line));
+                }
+                continue;
+            }
+
+            if let Some(colon) = line.iter().position(|b| *b == b':') {
+                headers.push(Header {
+                    name: String::from_utf8_lossy(&line[..colon]).trim().to_string(),
+                    value: String::from_utf8_lossy(&line[colon + 1..])
+                        .trim_start()
+                        .to_string(),
+                });
+            }
+        }
+
+        Entity { headers, body }
+    }
+
+    pub(super) fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.trim())
+    }
+
+    pub(super) fn content_type(&self) -> ContentType {
+        self.header("Content-Type")
+            .and_then(parse_content_type)
+            .unwrap_or_else(ContentType::default_type)
+    }
+
+    /// The body, with the Content-Transfer-Encoding removed.
+    pub(super) fn decoded_body(&self) -> AetoliaResult<Vec<u8>> {
+        let encoding = self
+            .header("Content-Transfer-Encoding")
+            .unwrap_or("7bit")
+            .to_ascii_lowercase();
+
+        match encoding.as_str() {
+            "7bit" | "8bit" | "binary" => Ok(self.body.to_vec()),
+            "base64" => decode_base64(self.body),
+            "quoted-printable" => Ok(decode_quoted_printable(self.body)),
+            other => Err(AetoliaError::other(format!(
+                "Unsupported Content-Transfer-Encoding: {other}"
+            ))),
+        }
+    }
+}
+
+/// Find the blank line that ends the headers, returning where the headers end and where the body
+/// starts.
+fn find_blank_line(raw: &[u8]) -> Option<(usize, usize)> {
+    let mut line_start = 0;
+    while line_start < raw.len() {
+        let line_end = raw[line_start..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|p| line_start + p)?;
+        if matches!(&raw[line_start..line_end], b"" | b"\r") {
+            return Some((line_start, line_end + 1));
+        }
+        line_start = line_end + 1;
+    }
+
+    None
+}
+
+fn parse_content_type(value: &str) -> Option<ContentType> {
+    let mut segments = split_unquoted(value, ';').into_iter();
+
+    let mime_type = segments.next()?.trim().to_ascii_lowercase();
+    if !mime_type.contains('/') {
+        return None;
+    }
+
+    let params = segments
+        .filter_map(|segment| {
+            let (name, value) = segment.split_once('=')?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(quoted) => unquote(quoted),
+                None => value.to_string(),
+            };
+
+            Some((name.trim().to_ascii_lowercase(), value))
+        })
+        .collect();
+
+    Some(ContentType { mime_type, params })
+}
+
+/// Split on a separator that is not inside a quoted string.
+fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            c if c == separator && !in_quotes => {
+                segments.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    segments.push(&value[start..]);
+
+    segments
+}
+
+fn unquote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.extend(chars.next()),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Split a multipart body into the raw content of its parts, RFC 2046 section 5.1.1.
+///
+/// The preamble and epilogue are discarded. A body that is missing its closing delimiter is
+/// accepted, with the last part running to the end of the body.
+pub(super) fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{boundary}");
+
+    let mut parts = Vec::new();
+    let mut part_start = None;
+    let mut line_start = 0;
+    while line_start < body.len() {
+        let line_end = body[line_start..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|p| line_start + p)
+            .unwrap_or(body.len());
+
+        if let Some(rest) = body[line_start..line_end].strip_prefix(delimiter.as_bytes()) {
+            let is_close = rest.starts_with(b"--");
+            if is_close || rest.iter().all(|b| b.is_ascii_whitespace()) {
+                if let Some(start) = part_start {
+                    // The line break before the delimiter belongs to the delimiter
+                    let mut end = line_start.saturating_sub(1).max(start);
+                    if end > start && body[end - 1] == b'\r' {
+                        end -= 1;
+                    }
+                    parts.push(&body[start..end]);
+                }
+                if is_close {
+                    return parts;
+                }
+                part_start = Some((line_end + 1).min(body.len()));
+            }
+        }
+
+        line_start = line_end + 1;
+    }
+
+    if let Some(start) = part_start {
+        parts.push(&body[start..]);
+    }
+
+    parts
+}
+
+fn decode_base64(body: &[u8]) -> AetoliaResult<Vec<u8>> {
+    let content = body
+        .iter()
+        .filter(|b| !b.is_ascii_whitespace())
+        .copied()
+        .collect::<Vec<_>>();
+
+    base64::prelude::BASE64_STANDARD
+        .decode(content)
+        .map_err(|e| AetoliaError::other(format!("Invalid base64 content: {e}")))
+}
+
+/// Decode quoted-printable content, RFC 2045 section 6.7. Invalid escapes are kept as they are.
+fn decode_quoted_printable(body: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(body.len());
+
+    let mut i = 0;
+    while i < body.len() {
+        if body[i] != b'=' {
+            out.push(body[i]);
+            i += 1;
+            continue;
+        }
+
+        match &body[i + 1..] {
+            // Soft line breaks
+            [b'\r', b'\n', ..] => i += 3,
+            [b'\n', ..] => i += 2,
+            [hi, lo, ..] if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                out.extend(hex::decode([*hi, *lo]).unwrap_or_default());
+                i += 3;
+            }
+            _ => {
+                out.push(b'=');
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Convert text in the given charset to UTF-8.
+pub(super) fn to_utf8(content: Vec<u8>, charset: &str) -> AetoliaResult<Vec<u8>> {
+    match charset.to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" | "us-ascii" => Ok(content),
+        "iso-8859-1" | "latin1" => Ok(content
+            .into_iter()
+            .map(char::from)
+            .collect::<String>()
+            .into_bytes()),
+        other => Err(AetoliaError::other(format!("Unsupported charset: {other}"))),
+    }
+}
+
+/// Encode a body as base64, wrapped into lines of the maximum length.
+pub(super) fn encode_base64(content: &[u8]) -> String {
+    let encoded = base64::prelude::BASE64_STANDARD.encode(content);
+
+    let mut out = String::with_capacity(encoded.len() + encoded.len() / BASE64_LINE_LENGTH * 2);
+    for line in encoded.as_bytes().chunks(BASE64_LINE_LENGTH) {
+        // Base64 output is ASCII
+        out.push_str(std::str::from_utf8(line).unwrap());
+        out.push_str("\r\n");
+    }
+
+    out
+}
+
+/// Encode a header value as RFC 2047 encoded words if it is not plain ASCII.
+///
+/// The text is split between characters into words of at most [ENCODED_WORD_BYTES] bytes, which
+/// are folded onto separate lines. The space between encoded words is not part of the value.
+pub(super) fn encode_header_value(value: &str) -> String {
+    if value.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+        return value.to_string();
+    }
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for (index, c) in value.char_indices() {
+        if index + c.len_utf8() - start > ENCODED_WORD_BYTES {
+            words.push(&value[start..end]);
+            start = end;
+        }
+        end = index + c.len_utf8();
+    }
+    words.push(&value[start..end]);
+
+    words
+        .into_iter()
+        .map(|word| {
+            format!(
+                "=?UTF-8?B?{}?=",
+                base64::prelude::BASE64_STANDARD.encode(word)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
+
+/// Format a date for the Date header, RFC 5322 section 3.3.
+pub(super) fn format_date(date: time::OffsetDateTime) -> String {
+    let weekday = match date.weekday() {
+        time::Weekday::Monday => "Mon",
+        time::Weekday::Tuesday => "Tue",
+        time::Weekday::Wednesday => "Wed",
+        time::Weekday::Thursday => "Thu",
+        time::Weekday::Friday => "Fri",
+        time::Weekday::Saturday => "Sat",
+        time::Weekday::Sunday => "Sun",
+    };
+    let month = match date.month() {
+        time::Month::January => "Jan",
+        time::Month::February => "Feb",
+        time::Month::March => "Mar",
+        time::Month::April => "Apr",
+        time::Month::May => "May",
+        time::Month::June => "Jun",
+        time::Month::July => "Jul",
+        time::Month::August => "Aug",
+        time::Month::September => "Sep",
+        time::Month::October => "Oct",
+        time::Month::November => "Nov",
+        time::Month::December => "Dec",
+    };
+    let (offset_hours, offset_minutes, _) = date.offset().as_hms();
+
+    format!(
+        "{weekday}, {:02} {month} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        date.day(),
+        date.year(),
+        date.hour(),
+        date.minute(),
+        date.second(),
+        if date.offset().is_negative() {
+            '-'
+        } else {
+            '+'
+        },
+        offset_hours.unsigned_abs(),
+        offset_minutes.unsigned_abs(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers_are_unfolded() {
+        let entity = Entity::parse(
+            b"Subject: Team\r\n meeting\r\nContent-Type: text/calendar;\r\n\tmethod=REQUEST\r\n\r\nbody",
+        );
+
+        assert_eq!(Some("Team meeting"), entity.header("subject"));
+        let content_type = entity.content_type();
+        assert_eq!("text/calendar", content_type.mime_type);
+        assert_eq!(Some("REQUEST"), content_type.param("method"));
+        assert_eq!(b"body", entity.body);
+    }
+
+    #[test]
+    fn quoted_params() {
+        let content_type =
+            parse_content_type("multipart/mixed; boundary=\"a;b\\\"c\"; charset=UTF-8").unwrap();
+
+        assert_eq!(Some("a;b\"c"), content_type.param("boundary"));
+        assert_eq!(Some("UTF-8"), content_type.param("charset"));
+    }
+
+    #[test]
+    fn multipart_body() {
+        let body =
+            b"preamble\r\n--xyz\r\nfirst\r\n--xyz  \r\n\r\nsecond\r\n\r\n--xyz--\r\nepilogue";
+
+        assert_eq!(
+            vec![&b"first"[..], &b"\r\nsecond\r\n"[..]],
+            split_multipart(body, "xyz")
+        );
+    }
+
+    #[test]
+    fn quoted_printable() {
+        assert_eq!(
+            b"caf\xc3\xa9 =long line".to_vec(),
+            decode_quoted_printable(b"caf=C3=A9 =3Dlong=\r\n line")
+        );
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let content = "x".repeat(100);
+        let encoded = encode_base64(content.as_bytes());
+
+        assert!(encoded.lines().all(|l| l.len() <= BASE64_LINE_LENGTH));
+        assert_eq!(
+            content.as_bytes(),
+            decode_base64(encoded.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn long_header_value() {
+        let subject = "Überprüfung der Änderungen für das nächste Quartal – bitte bestätigen";
+        let encoded = encode_header_value(subject);
+
+        let lines = encoded.split("\r\n").collect::<Vec<_>>();
+        assert!(lines.len() > 1);
+        assert!(lines[1..].iter().all(|l| l.starts_with(' ')));
+
+        let mut decoded = Vec::new();
+        for line in &lines {
+            let word = line.trim_start();
+            assert!(("Subject: ".len() + word.len()) <= 76);
+
+            let text = word
+                .strip_prefix("=?UTF-8?B?")
+                .and_then(|w| w.strip_suffix("?="))
+                .unwrap();
+            // Each word is valid UTF-8 by itself
+            let text = decode_base64(text.as_bytes()).unwrap();
+            assert!(std::str::from_utf8(&text).is_ok());
+            decoded.extend(text);
+        }
+        assert_eq!(subject.as_bytes(), decoded);
+    }
+
+    #[test]
+    fn date_header() {
+        let date = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::February, 1).unwrap(),
+            time::Time::from_hms(9, 5, 0).unwrap(),
+        );
+
+        assert_eq!(
+            "Thu, 01 Feb 2024 09:05:00 +0000",
+            format_date(date.assume_utc())
+        );
+        assert_eq!(
+            "Thu, 01 Feb 2024 09:05:00 -0330",
+            format_date(date.assume_offset(time::UtcOffset::from_hms(-3, -30, 0).unwrap()))
+        );
+    }
+}
@@ -0,0 +1,744 @@
+mod process;
+
+pub use process::{process_itip_message, IgnoredReason, ItipChange, ItipOutcome, ItipProcessor};
+
+use crate::common::{CalendarAddress, CalendarDateTime, ParticipationStatusUnknown, Status};
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::{Param, ParticipationStatusParam, TimeZoneIdParam};
+use crate::model::property::{
+    AttendeeProperty, CalendarProperty, ComponentProperty, DateTimeStampProperty,
+    DateTimeStartProperty, MethodProperty, ProductIdProperty, RecurrenceIdProperty,
+    SequenceProperty, StatusProperty, UniqueIdentifierProperty, VersionProperty,
+};
+
+/// The product identifier used for messages built from a calendar object that does not have one.
+const DEFAULT_PRODUCT_ID: &str = "-//aetolia//iTIP//EN";
+
+/// The iTIP methods, RFC 5546 section 1.4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItipMethod {
+    Publish,
+    Request,
+    Reply,
+    Add,
+    Cancel,
+    Refresh,
+    Counter,
+    DeclineCounter,
+}
+
+impl ItipMethod {
+    /// Find a method by the value of a METHOD property, matched case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "PUBLISH" => Some(ItipMethod::Publish),
+            "REQUEST" => Some(ItipMethod::Request),
+            "REPLY" => Some(ItipMethod::Reply),
+            "ADD" => Some(ItipMethod::Add),
+            "CANCEL" => Some(ItipMethod::Cancel),
+            "REFRESH" => Some(ItipMethod::Refresh),
+            "COUNTER" => Some(ItipMethod::Counter),
+            "DECLINECOUNTER" => Some(ItipMethod::DeclineCounter),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItipMethod::Publish => "PUBLISH",
+            ItipMethod::Request => "REQUEST",
+            ItipMethod::Reply => "REPLY",
+            ItipMethod::Add => "ADD",
+            ItipMethod::Cancel => "CANCEL",
+            ItipMethod::Refresh => "REFRESH",
+            ItipMethod::Counter => "COUNTER",
+            ItipMethod::DeclineCounter => "DECLINECOUNTER",
+        }
+    }
+}
+
+/// Builds iTIP messages, RFC 5546, for a scheduled component in a calendar object.
+///
+/// The scheduled component is a VEVENT, VTODO or VJOURNAL, along with any components that
+/// override its instances with a RECURRENCE-ID. Every message sets METHOD and DTSTAMP and copies
+/// the VTIMEZONE components of the calendar object.
+///
+/// SEQUENCE is incremented by the methods that the organizer uses to revise the component, that
+/// is PUBLISH, REQUEST, ADD and CANCEL. Replies, counter proposals and refresh requests must
+/// carry the SEQUENCE of the revision they refer to, so it is copied unchanged.
+pub struct ItipMessageBuilder<'a> {
+    source: &'a ICalObject,
+    uid: &'a str,
+    date_time_stamp: CalendarDateTime,
+}
+
+impl<'a> ItipMessageBuilder<'a> {
+    /// Build messages for the first scheduled component in the calendar object.
+    pub fn new(source: &'a ICalObject) -> AetoliaResult<Self> {
+        let uid = source
+            .components
+            .iter()
+            .filter(|c| is_scheduled(c))
+            .find_map(|c| c.get_property::<UniqueIdentifierProperty>())
+            .ok_or_else(|| {
+                AetoliaError::other("No VEVENT, VTODO or VJOURNAL with a UID to schedule")
+            })?;
+
+        Ok(Self::with_uid(source, uid.value.as_str()))
+    }
+
+    /// Build messages for the scheduled component with the given UID.
+    pub fn for_uid(source: &'a ICalObject, uid: &'a str) -> AetoliaResult<Self> {
+        let found = source
+            .components
+            .iter()
+            .any(|c| is_scheduled(c) && component_uid(c) == Some(uid));
+        if !found {
+            return Err(AetoliaError::other(format!(
+                "No VEVENT, VTODO or VJOURNAL with UID {uid}"
+            )));
+        }
+
+        Ok(Self::with_uid(source, uid))
+    }
+
+    fn with_uid(source: &'a ICalObject, uid: &'a str) -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        ItipMessageBuilder {
+            source,
+            uid,
+            date_time_stamp: (now.date(), now.time().replace_nanosecond(0).unwrap(), true).into(),
+        }
+    }
+
+    /// Set the DTSTAMP of the messages, which defaults to the current time.
+    pub fn set_date_time_stamp(mut self, date: time::Date, time: time::Time) -> Self {
+        self.date_time_stamp = (date, time, true).into();
+        self
+    }
+
+    /// Publish the component to a calendar without attendees, RFC 5546 section 3.2.1.
+    pub fn publish(&self) -> ICalObject {
+        let components = self
+            .components()
+            .map(|c| {
+                let mut c = c.clone();
+                c.properties_mut()
+                    .retain(|p| !matches!(p, ComponentProperty::Attendee(_)));
+                self.revise(c)
+            })
+            .collect();
+
+        self.message(ItipMethod::Publish, components)
+    }
+
+    /// Invite the attendees to the component, or send them a revision of it, RFC 5546
+    /// section 3.2.2.
+    pub fn request(&self) -> ICalObject {
+        let components = self.components().map(|c| self.revise(c.clone())).collect();
+
+        self.message(ItipMethod::Request, components)
+    }
+
+    /// Reply to the organizer with the participation status of one attendee, RFC 5546
+    /// section 3.2.3.
+    ///
+    /// The reply is for the whole component, or for the single instance with the given
+    /// RECURRENCE-ID.
+    pub fn reply(
+        &self,
+        attendee: &str,
+        status: ParticipationStatusUnknown,
+        recurrence_id: Option<&CalendarDateTime>,
+    ) -> AetoliaResult<ICalObject> {
+        let base = self.instance(recurrence_id)?;
+
+        let mut attendee = find_attendee(base, attendee)?.clone();
+        attendee.params.retain(|p| {
+            !matches!(
+                p,
+                Param::ParticipationStatus(_) | Param::Rsvp(_) | Param::ScheduleStatus(_)
+            )
+        });
+        attendee
+            .params
+            .push(Param::ParticipationStatus(ParticipationStatusParam {
+                status,
+            }));
+
+        let mut component = self.minimal(base, recurrence_id, false);
+        component
+            .properties_mut()
+            .push(ComponentProperty::Attendee(attendee));
+
+        Ok(self.message(ItipMethod::Reply, vec![component]))
+    }
+
+    /// Cancel the whole component, or the single instance with the given RECURRENCE-ID, for all
+    /// attendees, RFC 5546 section 3.2.5.
+    pub fn cancel(&self, recurrence_id: Option<&CalendarDateTime>) -> AetoliaResult<ICalObject> {
+        let base = self.instance(recurrence_id)?;
+
+        let mut component = self.minimal(base, recurrence_id, true);
+        if recurrence_id.is_some() {
+            // An overridden instance may be behind the series, and the cancellation has to be
+            // newer than both, RFC 5546 section 3.2.5.
+            let latest = self
+                .components()
+                .filter(|c| recurrence_id_of(c).is_none())
+                .chain([base])
+                .map(sequence)
+                .max()
+                .unwrap_or_default();
+            let properties = component.properties_mut();
+            properties.retain(|p| !matches!(p, ComponentProperty::Sequence(_)));
+            properties.push(ComponentProperty::Sequence(SequenceProperty {
+                value: latest,
+                params: Vec::new(),
+            }));
+        }
+        component.properties_mut().extend(
+            base.properties()
+                .iter()
+                .filter(|p| matches!(p, ComponentProperty::Attendee(_)))
+                .cloned(),
+        );
+        component
+            .properties_mut()
+            .push(ComponentProperty::Status(StatusProperty {
+                value: Status::Cancelled,
+                params: Vec::new(),
+            }));
+
+        Ok(self.message(ItipMethod::Cancel, vec![self.revise(component)]))
+    }
+
+    /// Propose changes to the organizer, RFC 5546 section 3.2.7.
+    ///
+    /// The calendar object that the builder was created with should hold the proposed version of
+    /// the component, which is sent as it is.
+    pub fn counter(&self) -> ICalObject {
+        let components = self.components().map(|c| self.stamp(c.clone())).collect();
+
+        self.message(ItipMethod::Counter, components)
+    }
+
+    /// Decline a counter proposal from an attendee, RFC 5546 section 3.2.8.
+    pub fn decline_counter(
+        &self,
+        attendee: &str,
+        recurrence_id: Option<&CalendarDateTime>,
+    ) -> AetoliaResult<ICalObject> {
+        self.to_attendee(ItipMethod::DeclineCounter, attendee, recurrence_id, false)
+    }
+
+    /// Ask the organizer for the latest version of the component on behalf of an attendee,
+    /// RFC 5546 section 3.2.6.
+    pub fn refresh(
+        &self,
+        attendee: &str,
+        recurrence_id: Option<&CalendarDateTime>,
+    ) -> AetoliaResult<ICalObject> {
+        // A refresh has no SEQUENCE, it is asking for the latest one.
+        let mut message = self.to_attendee(ItipMethod::Refresh, attendee, recurrence_id, false)?;
+        for component in &mut message.components {
+            component
+                .properties_mut()
+                .retain(|p| !matches!(p, ComponentProperty::Sequence(_)));
+        }
+        Ok(message)
+    }
+
+    /// Add the instances described by the component to an existing recurring component,
+    /// RFC 5546 section 3.2.4.
+    ///
+    /// Only the component without a RECURRENCE-ID is sent, with the new instances described by
+    /// its RDATE properties.
+    pub fn add(&self) -> ICalObject {
+        let components = self
+            .components()
+            .filter(|c| recurrence_id_of(c).is_none())
+            .map(|c| self.revise(c.clone()))
+            .collect();
+
+        self.message(ItipMethod::Add, components)
+    }
+
+    fn components(&self) -> impl Iterator<Item = &'a CalendarComponent> + '_ {
+        self.source
+            .components
+            .iter()
+            .filter(|c| is_scheduled(c) && component_uid(c) == Some(self.uid))
+    }
+
+    /// The component for a single instance, or the component for the whole series when there is
+    /// no RECURRENCE-ID or the instance has not been overridden.
+    fn instance(
+        &self,
+        recurrence_id: Option<&CalendarDateTime>,
+    ) -> AetoliaResult<&'a CalendarComponent> {
+        let mut master = None;
+        for component in self.components() {
+            match recurrence_id_of(component) {
+                Some(id) if Some(&id.value) == recurrence_id => return Ok(component),
+                Some(_) => {}
+                None => master = Some(component),
+            }
+        }
+
+        master.ok_or_else(|| {
+            AetoliaError::other(format!(
+                "No component with UID {} for the instance",
+                self.uid
+            ))
+        })
+    }
+
+    /// A component of the same kind as `base` with only the properties that identify it.
+    fn minimal(
+        &self,
+        base: &CalendarComponent,
+        recurrence_id: Option<&CalendarDateTime>,
+        include_summary: bool,
+    ) -> CalendarComponent {
+        let mut component = base.clone();
+        clear_nested_components(&mut component);
+        component.properties_mut().retain(|p| match p {
+            ComponentProperty::UniqueIdentifier(_)
+            | ComponentProperty::DateTimeStamp(_)
+            | ComponentProperty::Organizer(_)
+            | ComponentProperty::Sequence(_)
+            | ComponentProperty::RecurrenceId(_) => true,
+            ComponentProperty::Summary(_) => include_summary,
+            _ => false,
+        });
+
+        if let Some(recurrence_id) = recurrence_id {
+            if recurrence_id_of(&component).is_none() {
+                // The instance is not overridden, so it takes the time zone of the series.
+                let params = match base.get_property::<DateTimeStartProperty>() {
+                    Some(dt_start) if !recurrence_id.is_utc() && recurrence_id.is_date_time() => {
+                        dt_start
+                            .params
+                            .iter()
+                            .filter(|p| matches!(p, Param::TimeZoneId(TimeZoneIdParam { .. })))
+                            .cloned()
+                            .collect()
+                    }
+                    _ => Vec::new(),
+                };
+                component
+                    .properties_mut()
+                    .push(ComponentProperty::RecurrenceId(RecurrenceIdProperty {
+                        value: recurrence_id.clone(),
+                        params,
+                    }));
+            }
+        }
+
+        self.stamp(component)
+    }
+
+    fn to_attendee(
+        &self,
+        method: ItipMethod,
+        attendee: &str,
+        recurrence_id: Option<&CalendarDateTime>,
+        include_summary: bool,
+    ) -> AetoliaResult<ICalObject> {
+        let base = self.instance(recurrence_id)?;
+        let attendee = find_attendee(base, attendee)?.clone();
+
+        let mut component = self.minimal(base, recurrence_id, include_summary);
+        component
+            .properties_mut()
+            .push(ComponentProperty::Attendee(attendee));
+
+        Ok(self.message(method, vec![component]))
+    }
+
+    /// Set the DTSTAMP of a component to the time of the message.
+    fn stamp(&self, mut component: CalendarComponent) -> CalendarComponent {
+        let properties = component.properties_mut();
+        let date_time_stamp = ComponentProperty::DateTimeStamp(DateTimeStampProperty {
+            value: self.date_time_stamp.clone(),
+            params: Vec::new(),
+        });
+        match properties
+            .iter()
+            .position(|p| matches!(p, ComponentProperty::DateTimeStamp(_)))
+        {
+            Some(index) => properties[index] = date_time_stamp,
+            None => properties.insert(0, date_time_stamp),
+        }
+
+        component
+    }
+
+    /// Stamp a component and increment its SEQUENCE, for a revision by the organizer.
+    fn revise(&self, component: CalendarComponent) -> CalendarComponent {
+        let mut component = self.stamp(component);
+
+        let properties = component.properties_mut();
+        match properties.iter_mut().find_map(|p| match p {
+            ComponentProperty::Sequence(sequence) => Some(sequence),
+            _ => None,
+        }) {
+            Some(sequence) => sequence.value += 1,
+            None => properties.push(ComponentProperty::Sequence(SequenceProperty {
+                value: 1,
+                params: Vec::new(),
+            })),
+        }
+
+        component
+    }
+
+    fn message(&self, method: ItipMethod, components: Vec<CalendarComponent>) -> ICalObject {
+        let mut properties = vec![
+            self.source
+                .properties
+                .iter()
+                .find(|p| matches!(p, CalendarProperty::ProductId(_)))
+                .cloned()
+                .unwrap_or_else(|| {
+                    CalendarProperty::ProductId(ProductIdProperty {
+                        value: DEFAULT_PRODUCT_ID.to_string(),
+                        params: Vec::new(),
+                    })
+                }),
+            CalendarProperty::Version(VersionProperty {
+                min_version: None,
+                max_version: "2.0".to_string(),
+                params: Vec::new(),
+            }),
+        ];
+        properties.extend(
+            self.source
+                .properties
+                .iter()
+                .filter(|p| matches!(p, CalendarProperty::CalendarScale(_)))
+                .cloned(),
+        );
+        properties.push(CalendarProperty::Method(MethodProperty {
+            value: method.name().to_string(),
+            params: Vec::new(),
+        }));
+
+        let mut all_components = self
+            .source
+            .components
+            .iter()
+            .filter(|c| matches!(c, CalendarComponent::TimeZone(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        all_components.extend(components);
+
+        ICalObject {
+            properties,
+            components: all_components,
+        }
+    }
+}
+
+fn is_scheduled(component: &CalendarComponent) -> bool {
+    matches!(
+        component,
+        CalendarComponent::Event(_) | CalendarComponent::ToDo(_) | CalendarComponent::Journal(_)
+    )
+}
+
+fn component_uid(component: &CalendarComponent) -> Option<&str> {
+    component
+        .get_property::<UniqueIdentifierProperty>()
+        .map(|uid| uid.value.as_str())
+}
+
+fn recurrence_id_of(component: &CalendarComponent) -> Option<&RecurrenceIdProperty> {
+    component.get_property::<RecurrenceIdProperty>()
+}
+
+fn sequence(component: &CalendarComponent) -> u32 {
+    component
+        .get_property::<SequenceProperty>()
+        .map(|s| s.value)
+        .unwrap_or_default()
+}
+
+fn find_attendee<'c>(
+    component: &'c CalendarComponent,
+    address: &str,
+) -> AetoliaResult<&'c AttendeeProperty> {
+    let calendar_address = CalendarAddress::new(address);
+    component
+        .get_properties::<AttendeeProperty>()
+        .into_iter()
+        .find(|a| a.calendar_address() == calendar_address)
+        .ok_or_else(|| AetoliaError::other(format!("{address} is not an attendee")))
+}
+
+fn clear_nested_components(component: &mut CalendarComponent) {
+    match component {
+        CalendarComponent::Event(event) => event.components.clear(),
+        CalendarComponent::ToDo(to_do) => to_do.alarms.clear(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::access::PropertyAccess;
+    use crate::ops::load_ical;
+    use crate::serialize::WriteModel;
+    use crate::validate::{validate_itip_message, validate_model};
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Europe/London\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701025T020000\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0000\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=Europe/London:20240102T090000\r\n\
+DURATION:PT15M\r\n\
+RRULE:FREQ=DAILY\r\n\
+SUMMARY:Standup\r\n\
+SEQUENCE:2\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:bob@example.com\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:carol@example.com\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Standup soon\r\n\
+TRIGGER:-PT5M\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RECURRENCE-ID;TZID=Europe/London:20240103T090000\r\n\
+DTSTART;TZID=Europe/London:20240103T100000\r\n\
+DURATION:PT15M\r\n\
+SUMMARY:Standup\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn load() -> ICalObject {
+        load_ical(CALENDAR).unwrap().pop().unwrap()
+    }
+
+    fn builder(object: &ICalObject) -> ItipMessageBuilder<'_> {
+        ItipMessageBuilder::new(object)
+            .unwrap()
+            .set_date_time_stamp(
+                time::Date::from_calendar_date(2024, time::Month::February, 1).unwrap(),
+                time::Time::from_hms(12, 0, 0).unwrap(),
+            )
+    }
+
+    fn to_string(object: &ICalObject) -> String {
+        let mut out = Vec::new();
+        object.write_model(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn method_names() {
+        assert_eq!(
+            Some(ItipMethod::DeclineCounter),
+            ItipMethod::from_name("declinecounter")
+        );
+        assert_eq!("REFRESH", ItipMethod::Refresh.name());
+        assert_eq!(None, ItipMethod::from_name("SEND"));
+    }
+
+    #[test]
+    fn request_revises_every_component() {
+        let object = load();
+        let message = builder(&object).request();
+
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:REQUEST\r\n"));
+        assert!(content.contains("BEGIN:VTIMEZONE\r\n"));
+        assert!(content.contains("BEGIN:VALARM\r\n"));
+        assert_eq!(2, content.matches("DTSTAMP:20240201T120000Z\r\n").count());
+        assert!(content.contains("SEQUENCE:3\r\n"));
+        assert!(content.contains("SEQUENCE:1\r\n"));
+
+        assert!(validate_model(&message).unwrap().is_empty());
+    }
+
+    #[test]
+    fn publish_without_attendees() {
+        let object = load();
+        let message = builder(&object).publish();
+
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:PUBLISH\r\n"));
+        assert!(!content.contains("ATTENDEE"));
+        assert!(content.contains("SEQUENCE:3\r\n"));
+
+        assert!(validate_itip_message(&message).is_empty());
+    }
+
+    #[test]
+    fn reply_for_one_attendee() {
+        let object = load();
+        let message = builder(&object)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            "BEGIN:VCALENDAR\r\n\
+PRODID:-//test//EN\r\n\
+VERSION:2.0\r\n\
+METHOD:REPLY\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Europe/London\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701025T020000\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0000\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+SEQUENCE:2\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n",
+            to_string(&message)
+        );
+
+        assert!(builder(&object)
+            .reply(
+                "mailto:dave@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn cancel_series_and_instance() {
+        let object = load();
+
+        let message = builder(&object).cancel(None).unwrap();
+        let event = &message.components[1];
+        assert_eq!(
+            &Status::Cancelled,
+            event.get_property::<StatusProperty>().unwrap().value()
+        );
+        assert_eq!(
+            3,
+            *event.get_property::<SequenceProperty>().unwrap().value()
+        );
+        assert_eq!(2, event.get_properties::<AttendeeProperty>().len());
+        assert!(recurrence_id_of(event).is_none());
+
+        // An instance that has not been overridden takes the time zone of the series
+        let instance: CalendarDateTime = (
+            time::Date::from_calendar_date(2024, time::Month::January, 4).unwrap(),
+            time::Time::from_hms(9, 0, 0).unwrap(),
+            false,
+        )
+            .into();
+        let message = builder(&object).cancel(Some(&instance)).unwrap();
+        let content = to_string(&message);
+        assert!(content.contains("RECURRENCE-ID;TZID=Europe/London:20240104T090000\r\n"));
+        assert!(content.contains("SEQUENCE:3\r\n"));
+        assert!(content.contains("STATUS:CANCELLED\r\n"));
+    }
+
+    #[test]
+    fn cancel_overridden_instance() {
+        let object = load();
+        let instance: CalendarDateTime = (
+            time::Date::from_calendar_date(2024, time::Month::January, 3).unwrap(),
+            time::Time::from_hms(9, 0, 0).unwrap(),
+            false,
+        )
+            .into();
+
+        let message = builder(&object).cancel(Some(&instance)).unwrap();
+        let content = to_string(&message);
+        assert!(content.contains("RECURRENCE-ID;TZID=Europe/London:20240103T090000\r\n"));
+        // The override is at SEQUENCE 0 and the series at 2
+        assert!(content.contains("SEQUENCE:3\r\n"));
+        assert_eq!(1, content.matches("ATTENDEE").count());
+
+        // An override that is ahead of the series is cancelled after its own SEQUENCE
+        let object = load_ical(CALENDAR.replace(
+            "RECURRENCE-ID;TZID=Europe/London:20240103T090000\r\n",
+            "RECURRENCE-ID;TZID=Europe/London:20240103T090000\r\nSEQUENCE:5\r\n",
+        ))
+        .unwrap()
+        .pop()
+        .unwrap();
+        let message = builder(&object).cancel(Some(&instance)).unwrap();
+        assert!(to_string(&message).contains("SEQUENCE:6\r\n"));
+    }
+
+    #[test]
+    fn counter_and_decline_counter() {
+        let object = load();
+
+        let message = builder(&object).counter();
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:COUNTER\r\n"));
+        assert!(content.contains("SEQUENCE:2\r\n"));
+
+        let message = builder(&object)
+            .decline_counter("mailto:carol@example.com", None)
+            .unwrap();
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:DECLINECOUNTER\r\n"));
+        assert!(content.contains("ATTENDEE;PARTSTAT=ACCEPTED:mailto:carol@example.com\r\n"));
+        assert!(!content.contains("bob@example.com"));
+    }
+
+    #[test]
+    fn refresh_and_add() {
+        let object = load();
+
+        let message = builder(&object)
+            .refresh("mailto:bob@example.com", None)
+            .unwrap();
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:REFRESH\r\n"));
+        assert!(!content.contains("SEQUENCE"));
+
+        let message = builder(&object).add();
+        let content = to_string(&message);
+        assert!(content.contains("METHOD:ADD\r\n"));
+        assert!(!content.contains("RECURRENCE-ID"));
+        assert!(content.contains("SEQUENCE:3\r\n"));
+    }
+
+    #[test]
+    fn select_by_uid() {
+        let object = load();
+
+        assert!(ItipMessageBuilder::for_uid(&object, "standup").is_ok());
+        assert!(ItipMessageBuilder::for_uid(&object, "missing").is_err());
+    }
+}
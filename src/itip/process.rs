@@ -0,0 +1,1083 @@
+use crate::common::{CalendarAddress, CalendarDateTime, ParticipationStatusUnknown, Status};
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::expand::{tz_id_of, TimeZoneResolver};
+use crate::itip::{component_uid, is_scheduled, recurrence_id_of, sequence, ItipMethod};
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::{Param, ParticipationStatusParam, TimeZoneIdParam};
+use crate::model::property::{
+    AttendeeProperty, CalendarProperty, ComponentProperty, DateTimeStampProperty,
+    DateTimeStartProperty, ExceptionDateTimesProperty, RecurrenceIdProperty, StatusProperty,
+    TimeZoneIdProperty,
+};
+
+/// A DTSTAMP, as the date and time that order revisions and replies.
+type DateTimeStamp = (time::Date, Option<time::Time>);
+
+/// The result of applying an iTIP message to the stored copy of a calendar object.
+#[derive(Debug)]
+pub struct ItipOutcome {
+    /// The updated calendar object. This is a copy of the stored object when nothing changed.
+    pub object: ICalObject,
+    /// What the message changed, or why parts of it were not applied.
+    pub changes: Vec<ItipChange>,
+}
+
+/// A change made by an iTIP message, for the component with `uid` and, when the change only
+/// applies to one instance, `recurrence_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItipChange {
+    /// A component that was not stored before was added.
+    Added {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+    },
+    /// A stored component was replaced by a newer revision.
+    Updated {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+    },
+    /// An overridden instance was removed, because a newer revision of the series did not
+    /// include it.
+    Removed {
+        uid: String,
+        recurrence_id: CalendarDateTime,
+    },
+    /// RDATE properties were added to a recurring component by an ADD message.
+    InstancesAdded { uid: String },
+    /// An attendee replied with their participation status. `previous` is `None` if the
+    /// attendee was not invited.
+    ParticipationStatus {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+        attendee: String,
+        previous: Option<ParticipationStatusUnknown>,
+        status: ParticipationStatusUnknown,
+    },
+    /// The component, or one instance of it, was cancelled.
+    Cancelled {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+    },
+    /// An attendee proposed changes, which the organizer has to accept or decline.
+    CounterProposed {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+    },
+    /// The organizer declined changes proposed by this attendee.
+    CounterDeclined {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+    },
+    /// An attendee asked for the latest revision of the component to be sent to them.
+    RefreshRequested { uid: String, attendee: String },
+    /// Part of the message was not applied.
+    Ignored {
+        uid: String,
+        recurrence_id: Option<CalendarDateTime>,
+        reason: IgnoredReason,
+    },
+}
+
+/// Why part of an iTIP message was not applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgnoredReason {
+    /// The stored component has the same or a newer revision, so the message arrived late or
+    /// was delivered twice.
+    Outdated,
+    /// The message refers to a component that is not stored, so it may have arrived before the
+    /// message that created the component.
+    UnknownComponent,
+}
+
+/// Apply an iTIP message to the stored copy of a calendar object, RFC 5546 section 3.
+///
+/// This does not know about replies that were applied before, so a REPLY is only ignored when it
+/// is for an older revision than the one stored. Use an [ItipProcessor] to also order the
+/// replies from each attendee.
+pub fn process_itip_message(
+    stored: &ICalObject,
+    message: &ICalObject,
+) -> AetoliaResult<ItipOutcome> {
+    ItipProcessor::new().process(stored, message)
+}
+
+/// Applies iTIP messages to the stored copies of calendar objects, RFC 5546 section 3.
+///
+/// Revisions are ordered by SEQUENCE, and then by DTSTAMP for the same SEQUENCE, as described in
+/// RFC 5546 section 2.1.5. A message that is older than the stored revision is ignored, so
+/// messages that arrive out of order leave the newest revision in place.
+///
+/// A REPLY is ignored when it is for an older SEQUENCE than the one stored. Replies from the same
+/// attendee are ordered by their DTSTAMP, so a reply is also ignored when it is not newer than
+/// the last reply from that attendee. The processor remembers the DTSTAMP of the last reply from
+/// each attendee rather than adding it to the calendar object, so the same processor should be
+/// used for all the messages to a calendar.
+///
+/// COUNTER, DECLINECOUNTER and REFRESH messages need a response rather than a change to the
+/// stored copy, so they are only described in the outcome.
+#[derive(Debug, Default, Clone)]
+pub struct ItipProcessor {
+    replies: Vec<LastReply>,
+}
+
+/// The DTSTAMP of the last reply that was applied for an attendee of a component.
+#[derive(Debug, Clone)]
+struct LastReply {
+    uid: String,
+    recurrence_id: Option<CalendarDateTime>,
+    attendee: CalendarAddress,
+    date_time_stamp: DateTimeStamp,
+}
+
+impl ItipProcessor {
+    pub fn new() -> Self {
+        ItipProcessor::default()
+    }
+
+    /// Apply an iTIP message to the stored copy of a calendar object.
+    pub fn process(
+        &mut self,
+        stored: &ICalObject,
+        message: &ICalObject,
+    ) -> AetoliaResult<ItipOutcome> {
+        let method = message
+            .properties
+            .iter()
+            .find_map(|p| match p {
+                CalendarProperty::Method(method) => Some(method.value.as_str()),
+                _ => None,
+            })
+            .ok_or_else(|| AetoliaError::other("The message does not have a METHOD"))?;
+        let method = ItipMethod::from_name(method)
+            .ok_or_else(|| AetoliaError::other(format!("Unknown iTIP method {method}")))?;
+
+        let mut processor = Processor {
+            object: stored.clone(),
+            changes: Vec::new(),
+            replies: &mut self.replies,
+        };
+
+        for component in message.components.iter().filter(|c| is_scheduled(c)) {
+            let Some(uid) = component_uid(component) else {
+                continue;
+            };
+
+            match method {
+                ItipMethod::Publish | ItipMethod::Request => {
+                    processor.apply_revision(uid, component, message)
+                }
+                ItipMethod::Add => processor.apply_add(uid, component),
+                ItipMethod::Reply => processor.apply_reply(uid, component),
+                ItipMethod::Cancel => processor.apply_cancel(uid, component),
+                ItipMethod::Counter => processor.changes.push(ItipChange::CounterProposed {
+                    uid: uid.to_string(),
+                    recurrence_id: recurrence_id(component),
+                }),
+                ItipMethod::DeclineCounter => processor.changes.push(ItipChange::CounterDeclined {
+                    uid: uid.to_string(),
+                    recurrence_id: recurrence_id(component),
+                }),
+                ItipMethod::Refresh => {
+                    for attendee in component.get_properties::<AttendeeProperty>() {
+                        processor.changes.push(ItipChange::RefreshRequested {
+                            uid: uid.to_string(),
+                            attendee: attendee.value.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if processor
+            .changes
+            .iter()
+            .any(|c| matches!(c, ItipChange::Added { .. } | ItipChange::Updated { .. }))
+        {
+            processor.copy_time_zones(message);
+        }
+
+        Ok(ItipOutcome {
+            object: processor.object,
+            changes: processor.changes,
+        })
+    }
+}
+
+struct Processor<'a> {
+    object: ICalObject,
+    changes: Vec<ItipChange>,
+    replies: &'a mut Vec<LastReply>,
+}
+
+impl Processor<'_> {
+    /// Add or replace a component from a PUBLISH or REQUEST.
+    fn apply_revision(&mut self, uid: &str, component: &CalendarComponent, message: &ICalObject) {
+        let rid = recurrence_id(component);
+
+        match self.find(uid, rid.as_ref()) {
+            Some(index) => {
+                if !is_newer(component, &self.object.components[index]) {
+                    self.ignore(uid, rid, IgnoredReason::Outdated);
+                    return;
+                }
+
+                self.object.components[index] = component.clone();
+                self.changes.push(ItipChange::Updated {
+                    uid: uid.to_string(),
+                    recurrence_id: rid.clone(),
+                });
+
+                // A new revision of the series replaces the overridden instances, unless they
+                // were revised in the same message.
+                if rid.is_none() {
+                    self.remove_outdated_overrides(uid, message);
+                }
+            }
+            None => {
+                self.object.components.push(component.clone());
+                self.changes.push(ItipChange::Added {
+                    uid: uid.to_string(),
+                    recurrence_id: rid,
+                });
+            }
+        }
+    }
+
+    /// Add the RDATE properties of an ADD message to the stored series.
+    fn apply_add(&mut self, uid: &str, component: &CalendarComponent) {
+        let Some(index) = self.find(uid, None) else {
+            self.ignore(uid, None, IgnoredReason::UnknownComponent);
+            return;
+        };
+        if !is_newer(component, &self.object.components[index]) {
+            self.ignore(uid, None, IgnoredReason::Outdated);
+            return;
+        }
+
+        let stored = &mut self.object.components[index];
+        copy_revision(component, stored);
+        stored.properties_mut().extend(
+            component
+                .properties()
+                .iter()
+                .filter(|p| matches!(p, ComponentProperty::RecurrenceDateTimes(_)))
+                .cloned(),
+        );
+
+        self.changes.push(ItipChange::InstancesAdded {
+            uid: uid.to_string(),
+        });
+    }
+
+    /// Update the participation status of the attendees that replied.
+    fn apply_reply(&mut self, uid: &str, component: &CalendarComponent) {
+        let rid = recurrence_id(component);
+
+        let index = match self.find(uid, rid.as_ref()) {
+            Some(index) => index,
+            None => match (&rid, self.find(uid, None)) {
+                // The reply is for one instance of the series, which needs its own component
+                // to record the reply against.
+                (Some(rid), Some(master)) => {
+                    let time_zones = TimeZoneResolver::new(&self.object);
+                    let instance = instantiate(&self.object.components[master], rid, &time_zones);
+                    self.object.components.push(instance);
+                    self.object.components.len() - 1
+                }
+                _ => {
+                    self.ignore(uid, rid, IgnoredReason::UnknownComponent);
+                    return;
+                }
+            },
+        };
+
+        // A reply is for a SEQUENCE of the component. Its DTSTAMP is when the attendee replied,
+        // which is not ordered against the DTSTAMP of the organizer's revision.
+        if sequence(component) < sequence(&self.object.components[index]) {
+            self.ignore(uid, rid, IgnoredReason::Outdated);
+            return;
+        }
+
+        let reply_stamp = date_time_stamp(component);
+        for reply in component.get_properties::<AttendeeProperty>() {
+            let status = reply
+                .params
+                .iter()
+                .find_map(|p| match p {
+                    Param::ParticipationStatus(ParticipationStatusParam { status }) => {
+                        Some(status.clone())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_default();
+
+            // Replies from one attendee are ordered by their DTSTAMP, so a reply that arrives
+            // after a newer one from the same attendee is ignored.
+            let reply_address = reply.calendar_address();
+            let last_reply = self.replies.iter().position(|r| {
+                r.uid == uid && r.recurrence_id == rid && r.attendee == reply_address
+            });
+            if let (Some(last_reply), Some(reply_stamp)) = (last_reply, reply_stamp) {
+                if reply_stamp <= self.replies[last_reply].date_time_stamp {
+                    self.ignore(uid, rid.clone(), IgnoredReason::Outdated);
+                    continue;
+                }
+            }
+            if let Some(reply_stamp) = reply_stamp {
+                match last_reply {
+                    Some(last_reply) => self.replies[last_reply].date_time_stamp = reply_stamp,
+                    None => self.replies.push(LastReply {
+                        uid: uid.to_string(),
+                        recurrence_id: rid.clone(),
+                        attendee: reply_address.clone(),
+                        date_time_stamp: reply_stamp,
+                    }),
+                }
+            }
+
+            let stored = &mut self.object.components[index];
+            let previous = match stored.properties_mut().iter_mut().find_map(|p| match p {
+                ComponentProperty::Attendee(a) if a.calendar_address() == reply_address => Some(a),
+                _ => None,
+            }) {
+                Some(attendee) => {
+                    let previous = attendee.params.iter().find_map(|p| match p {
+                        Param::ParticipationStatus(ParticipationStatusParam { status }) => {
+                            Some(status.clone())
+                        }
+                        _ => None,
+                    });
+                    attendee
+                        .params
+                        .retain(|p| !matches!(p, Param::ParticipationStatus(_) | Param::Rsvp(_)));
+                    attendee
+                        .params
+                        .push(Param::ParticipationStatus(ParticipationStatusParam {
+                            status: status.clone(),
+                        }));
+                    Some(previous.unwrap_or_default())
+                }
+                None => {
+                    // An uninvited attendee, which the organizer may choose to accept
+                    stored
+                        .properties_mut()
+                        .push(ComponentProperty::Attendee(reply.clone()));
+                    None
+                }
+            };
+
+            self.changes.push(ItipChange::ParticipationStatus {
+                uid: uid.to_string(),
+                recurrence_id: rid.clone(),
+                attendee: reply.value.clone(),
+                previous,
+                status,
+            });
+        }
+    }
+
+    /// Cancel the series, or exclude one instance from it.
+    fn apply_cancel(&mut self, uid: &str, component: &CalendarComponent) {
+        let rid = recurrence_id(component);
+
+        let Some(master) = self
+            .find(uid, None)
+            .or_else(|| self.find(uid, rid.as_ref()))
+        else {
+            self.ignore(uid, rid, IgnoredReason::UnknownComponent);
+            return;
+        };
+        let target = self.find(uid, rid.as_ref()).unwrap_or(master);
+        if !is_newer(component, &self.object.components[target]) {
+            self.ignore(uid, rid, IgnoredReason::Outdated);
+            return;
+        }
+
+        match (&rid, recurrence_id(&self.object.components[master])) {
+            (Some(rid), None) => {
+                // Exclude the instance from the series, and drop any override for it
+                let params = exception_params(&self.object.components[master], rid);
+                if target != master {
+                    self.object.components.remove(target);
+                }
+                let master = self.find(uid, None).expect("Master is still present");
+                self.object.components[master].properties_mut().push(
+                    ComponentProperty::ExceptionDateTimes(ExceptionDateTimesProperty {
+                        value: vec![rid.clone()],
+                        params,
+                    }),
+                );
+            }
+            _ => {
+                // The whole series is cancelled, which includes all of its instances
+                for stored in self.object.components.iter_mut().filter(|c| {
+                    is_scheduled(c)
+                        && component_uid(c) == Some(uid)
+                        && (rid.is_none() || recurrence_id(c) == rid)
+                }) {
+                    copy_revision(component, stored);
+                    let properties = stored.properties_mut();
+                    properties.retain(|p| !matches!(p, ComponentProperty::Status(_)));
+                    properties.push(ComponentProperty::Status(StatusProperty {
+                        value: Status::Cancelled,
+                        params: Vec::new(),
+                    }));
+                }
+            }
+        }
+
+        self.changes.push(ItipChange::Cancelled {
+            uid: uid.to_string(),
+            recurrence_id: rid,
+        });
+    }
+
+    fn find(&self, uid: &str, rid: Option<&CalendarDateTime>) -> Option<usize> {
+        self.object.components.iter().position(|c| {
+            is_scheduled(c) && component_uid(c) == Some(uid) && recurrence_id(c).as_ref() == rid
+        })
+    }
+
+    fn ignore(
+        &mut self,
+        uid: &str,
+        recurrence_id: Option<CalendarDateTime>,
+        reason: IgnoredReason,
+    ) {
+        self.changes.push(ItipChange::Ignored {
+            uid: uid.to_string(),
+            recurrence_id,
+            reason,
+        });
+    }
+
+    fn remove_outdated_overrides(&mut self, uid: &str, message: &ICalObject) {
+        let revised = message
+            .components
+            .iter()
+            .filter(|c| is_scheduled(c) && component_uid(c) == Some(uid))
+            .filter_map(recurrence_id)
+            .collect::<Vec<_>>();
+
+        let mut removed = Vec::new();
+        self.object.components.retain(|c| {
+            if !is_scheduled(c) || component_uid(c) != Some(uid) {
+                return true;
+            }
+            match recurrence_id(c) {
+                Some(rid) if !revised.contains(&rid) => {
+                    removed.push(rid);
+                    false
+                }
+                _ => true,
+            }
+        });
+
+        self.changes.extend(
+            removed
+                .into_iter()
+                .map(|recurrence_id| ItipChange::Removed {
+                    uid: uid.to_string(),
+                    recurrence_id,
+                }),
+        );
+    }
+
+    /// Copy the VTIMEZONE components that the stored object does not have yet.
+    fn copy_time_zones(&mut self, message: &ICalObject) {
+        for time_zone in &message.components {
+            let CalendarComponent::TimeZone(_) = time_zone else {
+                continue;
+            };
+            let tz_id = time_zone.get_property::<TimeZoneIdProperty>();
+            let known = self.object.components.iter().any(|c| {
+                matches!(c, CalendarComponent::TimeZone(_))
+                    && c.get_property::<TimeZoneIdProperty>() == tz_id
+            });
+            if !known {
+                self.object.components.insert(0, time_zone.clone());
+            }
+        }
+    }
+}
+
+fn recurrence_id(component: &CalendarComponent) -> Option<CalendarDateTime> {
+    recurrence_id_of(component).map(|r| r.value.clone())
+}
+
+/// The SEQUENCE and DTSTAMP of a component, which order its revisions.
+fn revision(component: &CalendarComponent) -> (u32, Option<DateTimeStamp>) {
+    (sequence(component), date_time_stamp(component))
+}
+
+fn date_time_stamp(component: &CalendarComponent) -> Option<DateTimeStamp> {
+    component
+        .get_property::<DateTimeStampProperty>()
+        .map(|d| (*d.value.date(), d.value.time_opt().copied()))
+}
+
+fn is_newer(message: &CalendarComponent, stored: &CalendarComponent) -> bool {
+    revision(message) > revision(stored)
+}
+
+/// Copy the SEQUENCE and DTSTAMP of a message to the stored component it was applied to.
+fn copy_revision(message: &CalendarComponent, stored: &mut CalendarComponent) {
+    let properties = stored.properties_mut();
+    properties.retain(|p| {
+        !matches!(
+            p,
+            ComponentProperty::Sequence(_) | ComponentProperty::DateTimeStamp(_)
+        )
+    });
+    properties.extend(
+        message
+            .properties()
+            .iter()
+            .filter(|p| {
+                matches!(
+                    p,
+                    ComponentProperty::Sequence(_) | ComponentProperty::DateTimeStamp(_)
+                )
+            })
+            .cloned(),
+    );
+}
+
+/// The TZID of the series, which also applies to a RECURRENCE-ID or EXDATE value in local time.
+fn exception_params(master: &CalendarComponent, rid: &CalendarDateTime) -> Vec<Param> {
+    match master.get_property::<DateTimeStartProperty>() {
+        Some(dt_start) if !rid.is_utc() && rid.is_date_time() => dt_start
+            .params
+            .iter()
+            .filter(|p| matches!(p, Param::TimeZoneId(TimeZoneIdParam { .. })))
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Create the component for one instance of a recurring series, as if the organizer had
+/// overridden it without changes.
+fn instantiate(
+    master: &CalendarComponent,
+    rid: &CalendarDateTime,
+    time_zones: &TimeZoneResolver,
+) -> CalendarComponent {
+    let mut instance = master.clone();
+
+    // The instance starts at its RECURRENCE-ID, and ends the same time after its start as the
+    // series does. A RECURRENCE-ID in UTC for a series in local time is an exact instant, so the
+    // times of the instance are then moved by an exact amount and written in UTC.
+    let dt_start = master.get_property::<DateTimeStartProperty>();
+    let in_utc = dt_start.is_some_and(|dt_start| {
+        rid.is_utc() && !dt_start.value.is_utc() && dt_start.value.is_date_time()
+    });
+    let shift = match dt_start {
+        Some(dt_start) if in_utc => {
+            time_zones.resolve(rid, None) - time_zones.resolve_property(dt_start)
+        }
+        Some(dt_start) => local_time(rid) - local_time(&dt_start.value),
+        None => time::Duration::ZERO,
+    };
+
+    let params = exception_params(master, rid);
+    let properties = instance.properties_mut();
+    properties.retain(|p| {
+        !matches!(
+            p,
+            ComponentProperty::RecurrenceRule(_)
+                | ComponentProperty::RecurrenceDateTimes(_)
+                | ComponentProperty::ExceptionDateTimes(_)
+        )
+    });
+    for property in properties.iter_mut() {
+        match property {
+            ComponentProperty::DateTimeStart(p) if in_utc => {
+                (p.value, p.params) = shifted_to_utc(time_zones, &p.value, &p.params, shift)
+            }
+            ComponentProperty::DateTimeEnd(p) if in_utc => {
+                (p.value, p.params) = shifted_to_utc(time_zones, &p.value, &p.params, shift)
+            }
+            ComponentProperty::DateTimeDue(p) if in_utc => {
+                (p.value, p.params) = shifted_to_utc(time_zones, &p.value, &p.params, shift)
+            }
+            ComponentProperty::DateTimeStart(p) => p.value = shifted(&p.value, shift),
+            ComponentProperty::DateTimeEnd(p) => p.value = shifted(&p.value, shift),
+            ComponentProperty::DateTimeDue(p) => p.value = shifted(&p.value, shift),
+            _ => {}
+        }
+    }
+    properties.push(ComponentProperty::RecurrenceId(RecurrenceIdProperty {
+        value: rid.clone(),
+        params,
+    }));
+
+    instance
+}
+
+/// Move a value by an exact amount of time and write it in UTC, without its TZID.
+fn shifted_to_utc(
+    time_zones: &TimeZoneResolver,
+    value: &CalendarDateTime,
+    params: &[Param],
+    shift: time::Duration,
+) -> (CalendarDateTime, Vec<Param>) {
+    let moved = time_zones
+        .resolve(value, tz_id_of(params))
+        .saturating_add(shift);
+    let params = params
+        .iter()
+        .filter(|p| !matches!(p, Param::TimeZoneId(_)))
+        .cloned()
+        .collect();
+
+    ((moved.date(), moved.time(), true).into(), params)
+}
+
+fn local_time(value: &CalendarDateTime) -> time::PrimitiveDateTime {
+    time::PrimitiveDateTime::new(
+        *value.date(),
+        value.time_opt().copied().unwrap_or(time::Time::MIDNIGHT),
+    )
+}
+
+fn shifted(value: &CalendarDateTime, shift: time::Duration) -> CalendarDateTime {
+    let moved = local_time(value) + shift;
+    match value.time_opt() {
+        Some(_) => (moved.date(), moved.time(), value.is_utc()).into(),
+        None => (moved.date(), None, false).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::itip::ItipMessageBuilder;
+    use crate::model::property::SequenceProperty;
+    use crate::ops::load_ical;
+    use crate::serialize::WriteModel;
+
+    const STORED: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T090000Z\r\n\
+DTEND:20240102T091500Z\r\n\
+RRULE:FREQ=DAILY\r\n\
+SUMMARY:Standup\r\n\
+SEQUENCE:1\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RECURRENCE-ID:20240103T090000Z\r\n\
+DTSTART:20240103T100000Z\r\n\
+DTEND:20240103T101500Z\r\n\
+SUMMARY:Standup\r\n\
+SEQUENCE:1\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn load(content: &str) -> ICalObject {
+        load_ical(content).unwrap().pop().unwrap()
+    }
+
+    fn builder(object: &ICalObject, day: u8) -> ItipMessageBuilder<'_> {
+        ItipMessageBuilder::new(object)
+            .unwrap()
+            .set_date_time_stamp(
+                time::Date::from_calendar_date(2024, time::Month::February, day).unwrap(),
+                time::Time::from_hms(12, 0, 0).unwrap(),
+            )
+    }
+
+    fn utc(day: u8, hour: u8) -> CalendarDateTime {
+        (
+            time::Date::from_calendar_date(2024, time::Month::January, day).unwrap(),
+            time::Time::from_hms(hour, 0, 0).unwrap(),
+            true,
+        )
+            .into()
+    }
+
+    fn to_string(object: &ICalObject) -> String {
+        let mut out = Vec::new();
+        object.write_model(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn request_creates_and_updates() {
+        let stored = load(STORED);
+        let empty = ICalObject::builder().build();
+
+        let request = builder(&stored, 1).request();
+        let outcome = process_itip_message(&empty, &request).unwrap();
+        assert_eq!(2, outcome.object.components.len());
+        assert_eq!(
+            ItipChange::Added {
+                uid: "standup".to_string(),
+                recurrence_id: None
+            },
+            outcome.changes[0]
+        );
+
+        let outcome = process_itip_message(&stored, &request).unwrap();
+        assert_eq!(
+            vec![
+                ItipChange::Updated {
+                    uid: "standup".to_string(),
+                    recurrence_id: None
+                },
+                ItipChange::Updated {
+                    uid: "standup".to_string(),
+                    recurrence_id: Some(utc(3, 9))
+                },
+            ],
+            outcome.changes
+        );
+        assert!(to_string(&outcome.object).contains("SEQUENCE:2\r\n"));
+    }
+
+    #[test]
+    fn series_update_removes_outdated_overrides() {
+        let stored = load(STORED);
+
+        let mut request = builder(&stored, 1).request();
+        request.components.pop();
+
+        let outcome = process_itip_message(&stored, &request).unwrap();
+        assert_eq!(
+            ItipChange::Removed {
+                uid: "standup".to_string(),
+                recurrence_id: utc(3, 9)
+            },
+            outcome.changes[1]
+        );
+        assert_eq!(1, outcome.object.components.len());
+    }
+
+    #[test]
+    fn out_of_order_requests() {
+        let stored = load(STORED);
+
+        let first = builder(&stored, 1).request();
+        let newer = process_itip_message(&stored, &first).unwrap().object;
+
+        // A second revision, with the same SEQUENCE but a later DTSTAMP, is newer
+        let second = builder(&stored, 2).request();
+        let outcome = process_itip_message(&newer, &second).unwrap();
+        assert!(matches!(outcome.changes[0], ItipChange::Updated { .. }));
+
+        // Delivering the first revision after the second one has no effect
+        let outcome = process_itip_message(&outcome.object, &first).unwrap();
+        assert_eq!(
+            ItipChange::Ignored {
+                uid: "standup".to_string(),
+                recurrence_id: None,
+                reason: IgnoredReason::Outdated,
+            },
+            outcome.changes[0]
+        );
+        assert!(to_string(&outcome.object).contains("DTSTAMP:20240202T120000Z\r\n"));
+    }
+
+    #[test]
+    fn reply_updates_participation_status() {
+        let stored = load(STORED);
+
+        let reply = builder(&stored, 1)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+        let outcome = process_itip_message(&stored, &reply).unwrap();
+
+        assert_eq!(
+            vec![ItipChange::ParticipationStatus {
+                uid: "standup".to_string(),
+                recurrence_id: None,
+                attendee: "mailto:bob@example.com".to_string(),
+                previous: Some(ParticipationStatusUnknown::NeedsAction),
+                status: ParticipationStatusUnknown::Accepted,
+            }],
+            outcome.changes
+        );
+        let content = to_string(&outcome.object);
+        assert!(content.contains("ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n"));
+        // The reply does not change the revision of the stored component
+        assert!(content.contains("DTSTAMP:20240101T000000Z\r\n"));
+    }
+
+    #[test]
+    fn reply_to_an_older_revision_is_ignored() {
+        let stored = load(STORED);
+        let revised = process_itip_message(&stored, &builder(&stored, 1).request())
+            .unwrap()
+            .object;
+
+        let reply = builder(&stored, 2)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Declined,
+                None,
+            )
+            .unwrap();
+        let outcome = process_itip_message(&revised, &reply).unwrap();
+        assert!(matches!(
+            outcome.changes[0],
+            ItipChange::Ignored {
+                reason: IgnoredReason::Outdated,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reply_after_the_revision_is_sent_again() {
+        let stored = load(STORED);
+        let reply = builder(&stored, 1)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+
+        // The organizer sends the same SEQUENCE again, after the attendee replied
+        let mut resent = builder(&stored, 2).request();
+        resent.components.pop();
+        let mut resent = process_itip_message(&stored, &resent).unwrap().object;
+        for component in &mut resent.components {
+            component
+                .properties_mut()
+                .retain(|p| !matches!(p, ComponentProperty::Sequence(_)));
+            component
+                .properties_mut()
+                .push(ComponentProperty::Sequence(SequenceProperty {
+                    value: 1,
+                    params: Vec::new(),
+                }));
+        }
+
+        let outcome = process_itip_message(&resent, &reply).unwrap();
+        assert!(matches!(
+            outcome.changes[0],
+            ItipChange::ParticipationStatus {
+                status: ParticipationStatusUnknown::Accepted,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn older_reply_from_the_same_attendee_is_ignored() {
+        let stored = load(STORED);
+
+        let declined = builder(&stored, 3)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Declined,
+                None,
+            )
+            .unwrap();
+        let accepted = builder(&stored, 2)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+
+        let mut processor = ItipProcessor::new();
+        let outcome = processor.process(&stored, &declined).unwrap();
+        let outcome = processor.process(&outcome.object, &accepted).unwrap();
+        assert_eq!(
+            vec![ItipChange::Ignored {
+                uid: "standup".to_string(),
+                recurrence_id: None,
+                reason: IgnoredReason::Outdated,
+            }],
+            outcome.changes
+        );
+        assert!(to_string(&outcome.object)
+            .contains("ATTENDEE;PARTSTAT=DECLINED:mailto:bob@example.com\r\n"));
+
+        // A newer reply is applied
+        let accepted = builder(&stored, 4)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+        let outcome = processor.process(&outcome.object, &accepted).unwrap();
+        assert!(matches!(
+            outcome.changes[0],
+            ItipChange::ParticipationStatus {
+                previous: Some(ParticipationStatusUnknown::Declined),
+                status: ParticipationStatusUnknown::Accepted,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reply_history_is_not_sent_on() {
+        let stored = load(STORED);
+        let reply = builder(&stored, 2)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+
+        let replied = ItipProcessor::new()
+            .process(&stored, &reply)
+            .unwrap()
+            .object;
+        let request = builder(&replied, 3).request();
+
+        let content = to_string(&request);
+        assert!(content.contains("ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n"));
+        assert!(!content.contains("X-"));
+        assert!(!to_string(&replied).contains("X-"));
+    }
+
+    #[test]
+    fn reply_for_an_instance_that_is_not_overridden() {
+        let stored = load(STORED);
+
+        let reply = builder(&stored, 1)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Tentative,
+                Some(&utc(5, 9)),
+            )
+            .unwrap();
+        let outcome = process_itip_message(&stored, &reply).unwrap();
+
+        assert_eq!(3, outcome.object.components.len());
+        let content = to_string(&outcome.object);
+        assert!(content.contains("RECURRENCE-ID:20240105T090000Z\r\n"));
+        assert!(content.contains("DTSTART:20240105T090000Z\r\n"));
+        assert!(content.contains("DTEND:20240105T091500Z\r\n"));
+        assert!(content.contains("ATTENDEE;PARTSTAT=TENTATIVE:mailto:bob@example.com\r\n"));
+    }
+
+    #[test]
+    fn cancel_instance_and_series() {
+        let stored = load(STORED);
+
+        let cancel = builder(&stored, 1).cancel(Some(&utc(3, 9))).unwrap();
+        let outcome = process_itip_message(&stored, &cancel).unwrap();
+        assert_eq!(1, outcome.object.components.len());
+        assert!(to_string(&outcome.object).contains("EXDATE:20240103T090000Z\r\n"));
+
+        let cancel = builder(&stored, 1).cancel(None).unwrap();
+        let outcome = process_itip_message(&stored, &cancel).unwrap();
+        let content = to_string(&outcome.object);
+        assert_eq!(2, content.matches("STATUS:CANCELLED\r\n").count());
+        assert_eq!(
+            vec![ItipChange::Cancelled {
+                uid: "standup".to_string(),
+                recurrence_id: None
+            }],
+            outcome.changes
+        );
+
+        // Cancelling again has no effect
+        let outcome = process_itip_message(&outcome.object, &cancel).unwrap();
+        assert!(matches!(outcome.changes[0], ItipChange::Ignored { .. }));
+    }
+
+    #[test]
+    fn utc_recurrence_id_for_a_series_in_a_time_zone() {
+        let stored = load(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Europe/Berlin\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701025T030000\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0100\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=Europe/Berlin:20240102T090000\r\n\
+DTEND;TZID=Europe/Berlin:20240102T091500\r\n\
+RRULE:FREQ=DAILY\r\n\
+SEQUENCE:1\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=NEEDS-ACTION:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n",
+        );
+
+        let reply = builder(&stored, 1)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                Some(&utc(5, 8)),
+            )
+            .unwrap();
+        let content = to_string(&process_itip_message(&stored, &reply).unwrap().object);
+        assert!(content.contains("RECURRENCE-ID:20240105T080000Z\r\n"));
+        assert!(content.contains("DTSTART:20240105T080000Z\r\n"));
+        assert!(content.contains("DTEND:20240105T081500Z\r\n"));
+
+        let cancel = builder(&stored, 1).cancel(Some(&utc(7, 8))).unwrap();
+        let content = to_string(&process_itip_message(&stored, &cancel).unwrap().object);
+        assert!(content.contains("EXDATE:20240107T080000Z\r\n"));
+    }
+
+    #[test]
+    fn messages_that_need_a_response() {
+        let stored = load(STORED);
+
+        let refresh = builder(&stored, 1)
+            .refresh("mailto:bob@example.com", None)
+            .unwrap();
+        let outcome = process_itip_message(&stored, &refresh).unwrap();
+        assert_eq!(
+            vec![ItipChange::RefreshRequested {
+                uid: "standup".to_string(),
+                attendee: "mailto:bob@example.com".to_string(),
+            }],
+            outcome.changes
+        );
+        assert_eq!(stored, outcome.object);
+
+        let empty = ICalObject::builder().build();
+        let reply = builder(&stored, 1)
+            .reply(
+                "mailto:bob@example.com",
+                ParticipationStatusUnknown::Accepted,
+                None,
+            )
+            .unwrap();
+        let outcome = process_itip_message(&empty, &reply).unwrap();
+        assert!(matches!(
+            outcome.changes[0],
+            ItipChange::Ignored {
+                reason: IgnoredReason::UnknownComponent,
+                ..
+            }
+        ));
+    }
+}
@@ -10,9 +10,27 @@ use std::num::NonZeroUsize;
 /// Common types.
 pub mod common;
 
+/// Detection of events that take up the same time.
+pub mod conflict;
+
 /// Conversion from the parser model to the core representation.
 pub mod convert;
 
+/// Application defined properties and components.
+pub mod extension;
+
+/// Expansion of components into occurrences, including recurrence and time zones.
+pub mod expand;
+
+/// Free/busy time, computed from the events in calendars.
+pub mod free_busy;
+
+/// Sending and receiving iTIP messages by email, following iMIP (RFC 6047).
+pub mod imip;
+
+/// Scheduling messages between organizers and attendees, following iTIP (RFC 5546).
+pub mod itip;
+
 /// The core representation that is used for everything except the parser.
 pub mod model;
 
@@ -22,6 +40,12 @@ pub mod ops;
 /// The iCalendar parser.
 pub mod parser;
 
+/// Filtering of calendar data, following the CalDAV calendar-query filters.
+pub mod query;
+
+/// Relationships between components, resolved from their RELATED-TO properties.
+pub mod relation;
+
 /// The serializer for the core representation back to the iCalendar text format.
 pub mod serialize;
 
@@ -38,15 +62,22 @@ pub mod prelude {
     pub use crate::common::PropertyKind;
     pub use crate::common::*;
     pub use crate::error::{AetoliaError, AetoliaResult};
+    pub use crate::extension::{
+        ComponentExtension, ExtensionData, ExtensionRegistry, ExtensionValue, PropertyExtension,
+    };
     pub use crate::model::access::*;
+    pub use crate::model::attendee::*;
     pub use crate::model::component::*;
     pub use crate::model::object::*;
     pub use crate::model::param::*;
     pub use crate::model::property::*;
-    pub use crate::ops::{load_ical, read_ical};
+    pub use crate::ops::{load_ical, load_ical_with_extensions, read_ical};
     pub use crate::parser::{content_line_first_pass, ical_object, ical_stream};
     pub use crate::serialize::WriteModel;
-    pub use crate::validate::{validate_model, ICalendarErrorSeverity};
+    pub use crate::validate::{
+        validate_itip_message, validate_model, validate_model_with_extensions,
+        ICalendarErrorSeverity,
+    };
 }
 
 /// Streaming, single character matching the predicate
@@ -1,20 +1,25 @@
 pub(crate) mod access;
+pub(crate) mod attendee;
 pub mod component;
 pub mod object;
 pub mod param;
 pub mod property;
+pub mod visit;
 
 pub use access::*;
+pub use attendee::*;
 
 #[cfg(test)]
 mod tests {
     use crate::common::{LanguageTag, Range, RecurFreq, RelationshipType, TimeTransparency};
+    use crate::model::access::{ComponentAccess, PropertyAccess};
     use crate::model::component::CalendarComponent;
     use crate::model::object::ICalObject;
     use crate::model::param::{OtherParamsBuilder, ParticipationStatusEvent};
     use crate::model::property::{
-        Classification, ComponentProperty, Duration, Period, StatusEvent,
+        Classification, ComponentProperty, Duration, Period, StatusEvent, SummaryProperty,
     };
+    use crate::serialize::WriteModel;
     use time::Date;
 
     #[test]
@@ -472,4 +477,121 @@ mod tests {
             _ => panic!("Expected EventComponent"),
         }
     }
+
+    #[test]
+    fn x_and_iana_params() {
+        let obj = ICalObject::builder()
+            .add_event_component()
+            .add_summary("Params")
+            .add_x_param("X-Tag", "one")
+            .add_x_param_values("x-list", vec!["a".to_string(), "b:c".to_string()])
+            .add_iana_param("tag", "two")
+            .add_iana_param_values("list", vec!["c".to_string(), "d;e".to_string()])
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let summary = match &obj.components[0] {
+            CalendarComponent::Event(e) => e.get_property::<SummaryProperty>().unwrap(),
+            _ => panic!("Expected EventComponent"),
+        };
+
+        assert_eq!(vec!["one"], summary.get_x_params("x-tag"));
+        assert!(summary.get_iana_params("x-tag").is_empty());
+        assert_eq!(vec!["two"], summary.get_iana_params("TAG"));
+        assert!(summary.get_x_params("tag").is_empty());
+
+        assert_eq!(vec![vec!["a", "b:c"]], summary.get_x_param_values("X-LIST"));
+        assert_eq!(
+            vec![vec!["c", "d;e"]],
+            summary.get_iana_param_values("list")
+        );
+
+        let mut target = Vec::new();
+        summary.params.as_slice().write_model(&mut target).unwrap();
+        assert_eq!(
+            ";X-Tag=one;x-list=a,\"b:c\";tag=two;list=c,\"d;e\"",
+            String::from_utf8(target).unwrap()
+        );
+
+        let mut target = Vec::new();
+        obj.write_model(&mut target).unwrap();
+        let parsed = crate::ops::load_ical(target).unwrap();
+        assert_eq!(obj, parsed[0]);
+    }
+
+    #[test]
+    fn alarm_after_participant() {
+        let obj = ICalObject::builder()
+            .add_event_component()
+            .add_participant()
+            .add_unique_identifier("participant-1")
+            .finish_property()
+            .finish_component()
+            .add_display_alarm()
+            .add_action()
+            .finish_property()
+            .add_unique_identifier("alarm-1")
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .build();
+
+        let CalendarComponent::Event(event) = &obj.components[0] else {
+            panic!("Expected EventComponent");
+        };
+        assert_eq!(1, event.alarms().len());
+        assert!(matches!(
+            obj.components[0].nested_components(),
+            [
+                CalendarComponent::Participant(_),
+                CalendarComponent::Alarm(_)
+            ]
+        ));
+
+        let mut target = Vec::new();
+        obj.write_model(&mut target).unwrap();
+        let parsed = crate::ops::load_ical(target).unwrap();
+        assert_eq!(obj, parsed[0]);
+    }
+
+    #[test]
+    fn alarm_extensions() {
+        let obj = ICalObject::builder()
+            .add_event_component()
+            .add_display_alarm()
+            .add_action()
+            .finish_property()
+            .add_unique_identifier("alarm-2")
+            .finish_property()
+            .add_related_to("alarm-1")
+            .add_relationship_type(RelationshipType::Snooze)
+            .finish_property()
+            .finish_component()
+            .add_audio_alarm()
+            .add_action()
+            .finish_property()
+            .add_default_alarm()
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .build();
+
+        let alarms = match &obj.components[0] {
+            CalendarComponent::Event(e) => e.alarms(),
+            _ => panic!("Expected EventComponent"),
+        };
+
+        let (snoozed, default) = match alarms.as_slice() {
+            [CalendarComponent::Alarm(snoozed), CalendarComponent::Alarm(default)] => {
+                (snoozed, default)
+            }
+            _ => panic!("Expected two alarms"),
+        };
+
+        assert_eq!(Some("alarm-1"), snoozed.snoozed_alarm_uid());
+        assert!(!snoozed.is_default_alarm());
+        assert_eq!(None, default.snoozed_alarm_uid());
+        assert!(default.is_default_alarm());
+    }
 }
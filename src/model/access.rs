@@ -1,5 +1,7 @@
 use crate::model::param::{Param, ParamInner};
-use crate::model::property::{ComponentPropertiesInner, ComponentProperty, ComponentPropertyInner};
+use crate::model::property::{
+    ComponentPropertiesInner, ComponentProperty, ComponentPropertyInner, IanaProperty, XProperty,
+};
 
 pub trait ComponentAccess {
     fn properties(&self) -> &[ComponentProperty];
@@ -40,6 +42,28 @@ pub trait ComponentAccess {
             })
             .collect()
     }
+
+    /// Find the X- properties with the given name, matched case-insensitively.
+    fn find_x_properties(&self, name: &str) -> Vec<&XProperty> {
+        self.properties()
+            .iter()
+            .filter_map(|p| match p {
+                ComponentProperty::XProperty(p) if p.name.eq_ignore_ascii_case(name) => Some(p),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Find the IANA properties with the given name, matched case-insensitively.
+    fn find_iana_properties(&self, name: &str) -> Vec<&IanaProperty> {
+        self.properties()
+            .iter()
+            .filter_map(|p| match p {
+                ComponentProperty::IanaProperty(p) if p.name.eq_ignore_ascii_case(name) => Some(p),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 macro_rules! impl_component_access {
@@ -66,27 +90,59 @@ pub trait PropertyAccess<V> {
         self.params().iter().find_map(|p| p.param_inner())
     }
 
+    /// The values of the IANA params with the given name, matched case-insensitively.
+    ///
+    /// The values of all matching params are returned together, use [Self::get_iana_param_values]
+    /// to keep the values of each param separate.
     fn get_iana_params(&self, name: &str) -> Vec<&str> {
+        self.get_iana_param_values(name)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// The values of the X- params with the given name, matched case-insensitively.
+    ///
+    /// The values of all matching params are returned together, use [Self::get_x_param_values]
+    /// to keep the values of each param separate.
+    fn get_x_params(&self, name: &str) -> Vec<&str> {
+        self.get_x_param_values(name)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// The list of values of each IANA param with the given name, matched case-insensitively.
+    fn get_iana_param_values(&self, name: &str) -> Vec<Vec<&str>> {
         self.params()
             .iter()
             .filter_map(|p| match p {
                 Param::Other {
                     name: param_name,
                     value,
-                } if param_name == name => Some(value.as_str()),
+                } if param_name.eq_ignore_ascii_case(name) => Some(vec![value.as_str()]),
+                Param::Others {
+                    name: param_name,
+                    values,
+                } if param_name.eq_ignore_ascii_case(name) => {
+                    Some(values.iter().map(String::as_str).collect())
+                }
                 _ => None,
             })
             .collect()
     }
 
-    fn get_x_params(&self, name: &str) -> Vec<&str> {
+    /// The list of values of each X- param with the given name, matched case-insensitively.
+    fn get_x_param_values(&self, name: &str) -> Vec<Vec<&str>> {
         self.params()
             .iter()
             .filter_map(|p| match p {
-                Param::Other {
+                Param::XName {
                     name: param_name,
-                    value,
-                } if param_name == name => Some(value.as_str()),
+                    values,
+                } if param_name.eq_ignore_ascii_case(name) => {
+                    Some(values.iter().map(String::as_str).collect())
+                }
                 _ => None,
             })
             .collect()
@@ -0,0 +1,405 @@
+use crate::common::{CalendarAddress, ParticipationStatusUnknown, Role};
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::model::access::ComponentAccess;
+use crate::model::component::{EventComponent, JournalComponent, ToDoComponent};
+use crate::model::param::{
+    DelegatedFromParam, DelegatedToParam, Param, ParticipationStatusEvent,
+    ParticipationStatusJournal, ParticipationStatusParam, ParticipationStatusToDo, RoleParam,
+    RsvpParam,
+};
+use crate::model::property::{AttendeeProperty, ComponentProperty};
+
+/// Finding and updating the attendees of a component.
+///
+/// The participation status is typed by the kind of component, since each allows different
+/// PARTSTAT values, RFC 5545 section 3.2.12. Attendees are found by their calendar address, which
+/// is compared as a [CalendarAddress].
+pub trait AttendeeAccess: ComponentAccess {
+    type ParticipationStatus: Into<ParticipationStatusUnknown>;
+
+    fn find_attendee(&self, address: &str) -> Option<&AttendeeProperty> {
+        let address = CalendarAddress::new(address);
+        self.get_properties::<AttendeeProperty>()
+            .into_iter()
+            .find(|a| a.calendar_address() == address)
+    }
+
+    /// Set the PARTSTAT of an attendee, replacing any existing value.
+    fn set_participation_status(
+        &mut self,
+        address: &str,
+        status: Self::ParticipationStatus,
+    ) -> AetoliaResult<()>;
+
+    /// Set whether a reply is expected from an attendee.
+    fn set_rsvp(&mut self, address: &str, rsvp: bool) -> AetoliaResult<()>;
+
+    /// Set the ROLE of an attendee, replacing any existing value.
+    fn set_role(&mut self, address: &str, role: Role) -> AetoliaResult<()>;
+}
+
+/// Delegation of attendance to another calendar user, for components whose attendees can have a
+/// PARTSTAT of DELEGATED.
+pub trait AttendeeDelegation: AttendeeAccess {
+    /// Delegate the attendance of `delegator` to `delegate`, as described in RFC 5546 section
+    /// 4.2.5.
+    ///
+    /// The delegator is marked DELEGATED and gets the delegate added to its DELEGATED-TO. The
+    /// delegate is added as an attendee if it is not one already, with the ROLE of the delegator,
+    /// PARTSTAT=NEEDS-ACTION and RSVP=TRUE, and gets the delegator added to its DELEGATED-FROM.
+    /// A bare email address for the delegate is written as a `mailto:` URI.
+    fn delegate(&mut self, delegator: &str, delegate: &str) -> AetoliaResult<()>;
+}
+
+macro_rules! impl_attendee_access {
+    ($for_type:ty, $status:ty) => {
+        impl AttendeeAccess for $for_type {
+            type ParticipationStatus = $status;
+
+            fn set_participation_status(
+                &mut self,
+                address: &str,
+                status: Self::ParticipationStatus,
+            ) -> AetoliaResult<()> {
+                let attendee = find_attendee_mut(&mut self.properties, address)?;
+                set_param(
+                    &mut attendee.params,
+                    Param::ParticipationStatus(ParticipationStatusParam {
+                        status: status.into(),
+                    }),
+                );
+                Ok(())
+            }
+
+            fn set_rsvp(&mut self, address: &str, rsvp: bool) -> AetoliaResult<()> {
+                let attendee = find_attendee_mut(&mut self.properties, address)?;
+                set_param(&mut attendee.params, Param::Rsvp(RsvpParam { rsvp }));
+                Ok(())
+            }
+
+            fn set_role(&mut self, address: &str, role: Role) -> AetoliaResult<()> {
+                let attendee = find_attendee_mut(&mut self.properties, address)?;
+                set_param(&mut attendee.params, Param::Role(RoleParam { role }));
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_attendee_access!(EventComponent, ParticipationStatusEvent);
+impl_attendee_access!(ToDoComponent, ParticipationStatusToDo);
+impl_attendee_access!(JournalComponent, ParticipationStatusJournal);
+
+impl AttendeeDelegation for EventComponent {
+    fn delegate(&mut self, delegator: &str, delegate: &str) -> AetoliaResult<()> {
+        delegate_attendee(&mut self.properties, delegator, delegate)
+    }
+}
+
+impl AttendeeDelegation for ToDoComponent {
+    fn delegate(&mut self, delegator: &str, delegate: &str) -> AetoliaResult<()> {
+        delegate_attendee(&mut self.properties, delegator, delegate)
+    }
+}
+
+fn find_attendee_mut<'a>(
+    properties: &'a mut [ComponentProperty],
+    address: &str,
+) -> AetoliaResult<&'a mut AttendeeProperty> {
+    let calendar_address = CalendarAddress::new(address);
+    properties
+        .iter_mut()
+        .find_map(|p| match p {
+            ComponentProperty::Attendee(a) if a.calendar_address() == calendar_address => Some(a),
+            _ => None,
+        })
+        .ok_or_else(|| AetoliaError::other(format!("{address} is not an attendee")))
+}
+
+/// Replace the first param of the same kind, or add the param if there is none.
+fn set_param(params: &mut Vec<Param>, param: Param) {
+    match params
+        .iter()
+        .position(|p| std::mem::discriminant(p) == std::mem::discriminant(&param))
+    {
+        Some(index) => params[index] = param,
+        None => params.push(param),
+    }
+}
+
+fn delegate_attendee(
+    properties: &mut Vec<ComponentProperty>,
+    delegator: &str,
+    delegate: &str,
+) -> AetoliaResult<()> {
+    if CalendarAddress::new(delegator) == CalendarAddress::new(delegate) {
+        return Err(AetoliaError::other(format!(
+            "{delegator} cannot delegate to themselves"
+        )));
+    }
+
+    let delegate = &address_uri(delegate);
+    let delegator_attendee = find_attendee_mut(properties, delegator)?;
+    let delegator_address = delegator_attendee.value.clone();
+    let role = delegator_attendee.params.iter().find_map(|p| match p {
+        Param::Role(role) => Some(role.clone()),
+        _ => None,
+    });
+
+    set_param(
+        &mut delegator_attendee.params,
+        Param::ParticipationStatus(ParticipationStatusParam {
+            status: ParticipationStatusUnknown::Delegated,
+        }),
+    );
+    add_address_param(
+        &mut delegator_attendee.params,
+        delegate,
+        |p| match p {
+            Param::DelegatedTo(DelegatedToParam { delegates }) => Some(delegates),
+            _ => None,
+        },
+        |delegates| Param::DelegatedTo(DelegatedToParam { delegates }),
+    );
+
+    let delegate_attendee = match find_attendee_mut(properties, delegate) {
+        Ok(attendee) => attendee,
+        Err(_) => {
+            let mut params = Vec::new();
+            params.extend(role.map(Param::Role));
+            params.push(Param::ParticipationStatus(ParticipationStatusParam {
+                status: ParticipationStatusUnknown::NeedsAction,
+            }));
+            params.push(Param::Rsvp(RsvpParam { rsvp: true }));
+
+            // Keep the attendees together
+            let index = properties
+                .iter()
+                .rposition(|p| matches!(p, ComponentProperty::Attendee(_)))
+                .map(|i| i + 1)
+                .unwrap_or(properties.len());
+            properties.insert(
+                index,
+                ComponentProperty::Attendee(AttendeeProperty {
+                    value: delegate.to_string(),
+                    params,
+                }),
+            );
+
+            find_attendee_mut(properties, delegate)?
+        }
+    };
+    add_address_param(
+        &mut delegate_attendee.params,
+        &delegator_address,
+        |p| match p {
+            Param::DelegatedFrom(DelegatedFromParam { delegators }) => Some(delegators),
+            _ => None,
+        },
+        |delegators| Param::DelegatedFrom(DelegatedFromParam { delegators }),
+    );
+
+    Ok(())
+}
+
+/// The address as a URI, as ATTENDEE values and address params must be. A bare email address is
+/// written as a `mailto:` URI.
+fn address_uri(address: &str) -> String {
+    let address = address.trim();
+    if !address.contains(':') && CalendarAddress::new(address).scheme() == Some("mailto") {
+        format!("mailto:{address}")
+    } else {
+        address.to_string()
+    }
+}
+
+/// Add an address to a param that holds a list of addresses, such as DELEGATED-TO, unless it is
+/// already in the list.
+fn add_address_param(
+    params: &mut Vec<Param>,
+    address: &str,
+    addresses: impl Fn(&mut Param) -> Option<&mut Vec<String>>,
+    new_param: impl Fn(Vec<String>) -> Param,
+) {
+    let calendar_address = CalendarAddress::new(address);
+    match params.iter_mut().find_map(addresses) {
+        Some(existing) => {
+            if !existing
+                .iter()
+                .any(|a| CalendarAddress::new(a) == calendar_address)
+            {
+                existing.push(address.to_string());
+            }
+        }
+        None => params.push(new_param(vec![address.to_string()])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::access::PropertyAccess;
+    use crate::model::component::CalendarComponent;
+    use crate::ops::load_ical;
+    use crate::serialize::WriteModel;
+
+    fn load_event(attendees: &str) -> EventComponent {
+        let content = format!(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T100000Z\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+{attendees}\
+SUMMARY:Meeting\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n"
+        );
+        let mut object = load_ical(content).unwrap().pop().unwrap();
+        match object.components.pop().unwrap() {
+            CalendarComponent::Event(event) => event,
+            _ => panic!("Expected an event"),
+        }
+    }
+
+    fn attendee_lines(event: EventComponent) -> Vec<String> {
+        let mut out = Vec::new();
+        CalendarComponent::Event(event)
+            .write_model(&mut out)
+            .unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .split("\r\n")
+            .filter(|line| line.starts_with("ATTENDEE"))
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn find_by_address() {
+        let event = load_event("ATTENDEE;CN=Bob:MAILTO:Bob@Example.com\r\n");
+
+        let attendee = event.find_attendee("bob@example.com").unwrap();
+        assert_eq!("MAILTO:Bob@Example.com", attendee.value());
+        assert!(event.find_attendee("mailto:BOB@EXAMPLE.COM").is_some());
+        assert!(event.find_attendee("mailto:carol@example.com").is_none());
+    }
+
+    #[test]
+    fn update_attendee_params() {
+        let mut event = load_event(
+            "ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:bob@example.com\r\n\
+ATTENDEE:mailto:carol@example.com\r\n",
+        );
+
+        event
+            .set_participation_status("mailto:bob@example.com", ParticipationStatusEvent::Accepted)
+            .unwrap();
+        event.set_rsvp("mailto:bob@example.com", false).unwrap();
+        event
+            .set_role("mailto:carol@example.com", Role::OptionalParticipant)
+            .unwrap();
+        assert!(event
+            .set_role("mailto:dave@example.com", Role::Chair)
+            .is_err());
+
+        assert_eq!(
+            vec![
+                "ATTENDEE;PARTSTAT=ACCEPTED;RSVP=FALSE:mailto:bob@example.com",
+                "ATTENDEE;ROLE=OPT-PARTICIPANT:mailto:carol@example.com",
+            ],
+            attendee_lines(event)
+        );
+    }
+
+    #[test]
+    fn to_do_status() {
+        let mut to_do = ToDoComponent::new();
+        to_do
+            .properties
+            .push(ComponentProperty::Attendee(AttendeeProperty {
+                value: "mailto:bob@example.com".to_string(),
+                params: Vec::new(),
+            }));
+
+        to_do
+            .set_participation_status("bob@example.com", ParticipationStatusToDo::InProcess)
+            .unwrap();
+
+        let status = to_do
+            .find_attendee("bob@example.com")
+            .unwrap()
+            .get_param::<ParticipationStatusParam>()
+            .unwrap();
+        assert_eq!(ParticipationStatusUnknown::InProcess, status.status);
+    }
+
+    #[test]
+    fn delegate_to_new_attendee() {
+        let mut event = load_event(
+            "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION:mailto:bob@example.com\r\n\
+ATTENDEE:mailto:carol@example.com\r\n",
+        );
+
+        event
+            .delegate("bob@example.com", "mailto:dave@example.com")
+            .unwrap();
+        // Delegating again does not repeat the addresses
+        event
+            .delegate("mailto:bob@example.com", "mailto:dave@example.com")
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=DELEGATED;DELEGATED-TO=\"mailto:dave@example.com\":mailto:bob@example.com",
+                "ATTENDEE:mailto:carol@example.com",
+                "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION;RSVP=TRUE;DELEGATED-FROM=\"mailto:bob@example.com\":mailto:dave@example.com",
+            ],
+            attendee_lines(event)
+        );
+    }
+
+    #[test]
+    fn delegate_to_bare_email_address() {
+        let mut event = load_event("ATTENDEE:mailto:bob@example.com\r\n");
+
+        event
+            .delegate("mailto:bob@example.com", " dave@example.com")
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "ATTENDEE;PARTSTAT=DELEGATED;DELEGATED-TO=\"mailto:dave@example.com\":mailto:bob@example.com",
+                "ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE;DELEGATED-FROM=\"mailto:bob@example.com\":mailto:dave@example.com",
+            ],
+            attendee_lines(event)
+        );
+    }
+
+    #[test]
+    fn delegate_to_existing_attendee() {
+        let mut event = load_event(
+            "ATTENDEE:mailto:bob@example.com\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:carol@example.com\r\n",
+        );
+
+        event
+            .delegate("mailto:bob@example.com", "mailto:carol@example.com")
+            .unwrap();
+        assert!(event
+            .clone()
+            .delegate("mailto:bob@example.com", "MAILTO:BOB@example.com")
+            .is_err());
+
+        assert_eq!(
+            vec![
+                "ATTENDEE;PARTSTAT=DELEGATED;DELEGATED-TO=\"mailto:carol@example.com\":mailto:bob@example.com",
+                "ATTENDEE;PARTSTAT=ACCEPTED;DELEGATED-FROM=\"mailto:bob@example.com\":mailto:carol@example.com",
+            ],
+            attendee_lines(event)
+        );
+    }
+}
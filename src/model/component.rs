@@ -1,9 +1,14 @@
 mod alarm;
+mod availability;
+mod available;
 mod daylight;
 mod event;
 mod free_busy;
 mod iana_component;
 mod journal;
+mod location;
+mod participant;
+mod resource;
 mod standard;
 mod time_zone;
 mod todo;
@@ -13,17 +18,22 @@ pub use crate::model::component::daylight::DaylightComponent;
 pub use crate::model::component::standard::StandardComponent;
 pub use alarm::{
     AddAlarmComponent, AlarmComponent, AudioAlarmComponentBuilder, DisplayAlarmComponentBuilder,
-    EmailAlarmComponentBuilder,
+    EmailAlarmComponentBuilder, DEFAULT_ALARM_PROPERTY,
 };
+pub use availability::{AvailabilityComponent, AvailabilityComponentBuilder};
+pub use available::{AvailableComponent, AvailableComponentBuilder};
 pub use event::{EventComponent, EventComponentBuilder};
 pub use free_busy::{FreeBusyComponent, FreeBusyComponentBuilder};
 pub use iana_component::{IanaComponent, IanaComponentBuilder};
 pub use journal::{JournalComponent, JournalComponentBuilder};
+pub use location::{AddLocationComponent, LocationComponent, LocationComponentBuilder};
+pub use participant::{AddParticipantComponent, ParticipantComponent, ParticipantComponentBuilder};
+pub use resource::{AddResourceComponent, ResourceComponent, ResourceComponentBuilder};
 pub use time_zone::{TimeZoneComponent, TimeZoneComponentBuilder};
 pub use todo::{ToDoComponent, ToDoComponentBuilder};
 pub use x_component::{XComponent, XComponentBuilder};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CalendarComponent {
     Event(EventComponent),
     ToDo(ToDoComponent),
@@ -33,6 +43,11 @@ pub enum CalendarComponent {
     Standard(StandardComponent),
     Daylight(DaylightComponent),
     Alarm(AlarmComponent),
+    Availability(AvailabilityComponent),
+    Available(AvailableComponent),
+    Participant(ParticipantComponent),
+    Location(LocationComponent),
+    Resource(ResourceComponent),
     IanaComponent(IanaComponent),
     XComponent(XComponent),
 }
@@ -48,12 +63,64 @@ impl ComponentAccess for CalendarComponent {
             CalendarComponent::Standard(s) => &s.properties,
             CalendarComponent::Daylight(d) => &d.properties,
             CalendarComponent::Alarm(a) => &a.properties,
+            CalendarComponent::Availability(a) => &a.properties,
+            CalendarComponent::Available(a) => &a.properties,
+            CalendarComponent::Participant(p) => &p.properties,
+            CalendarComponent::Location(l) => &l.properties,
+            CalendarComponent::Resource(r) => &r.properties,
             CalendarComponent::IanaComponent(i) => &i.properties,
             CalendarComponent::XComponent(x) => &x.properties,
         }
     }
 }
 
+impl CalendarComponent {
+    /// The components nested within this component, such as the alarms and participants in an
+    /// event, the standard and daylight observances in a time zone or the available slots in an
+    /// availability.
+    pub fn nested_components(&self) -> &[CalendarComponent] {
+        match self {
+            CalendarComponent::Event(e) => &e.components,
+            CalendarComponent::ToDo(t) => &t.alarms,
+            CalendarComponent::TimeZone(tz) => &tz.components,
+            CalendarComponent::Availability(a) => &a.components,
+            CalendarComponent::Participant(p) => &p.components,
+            _ => &[],
+        }
+    }
+
+    pub(crate) fn properties_mut(&mut self) -> &mut Vec<ComponentProperty> {
+        match self {
+            CalendarComponent::Event(e) => &mut e.properties,
+            CalendarComponent::ToDo(t) => &mut t.properties,
+            CalendarComponent::Journal(j) => &mut j.properties,
+            CalendarComponent::FreeBusy(f) => &mut f.properties,
+            CalendarComponent::TimeZone(tz) => &mut tz.properties,
+            CalendarComponent::Standard(s) => &mut s.properties,
+            CalendarComponent::Daylight(d) => &mut d.properties,
+            CalendarComponent::Alarm(a) => &mut a.properties,
+            CalendarComponent::Availability(a) => &mut a.properties,
+            CalendarComponent::Available(a) => &mut a.properties,
+            CalendarComponent::Participant(p) => &mut p.properties,
+            CalendarComponent::Location(l) => &mut l.properties,
+            CalendarComponent::Resource(r) => &mut r.properties,
+            CalendarComponent::IanaComponent(i) => &mut i.properties,
+            CalendarComponent::XComponent(x) => &mut x.properties,
+        }
+    }
+
+    pub(crate) fn nested_components_mut(&mut self) -> Option<&mut Vec<CalendarComponent>> {
+        match self {
+            CalendarComponent::Event(e) => Some(&mut e.components),
+            CalendarComponent::ToDo(t) => Some(&mut t.alarms),
+            CalendarComponent::TimeZone(tz) => Some(&mut tz.components),
+            CalendarComponent::Availability(a) => Some(&mut a.components),
+            CalendarComponent::Participant(p) => Some(&mut p.components),
+            _ => None,
+        }
+    }
+}
+
 macro_rules! impl_finish_component_build {
     ($ev:expr) => {
         pub fn finish_component(mut self) -> $crate::model::object::ICalObjectBuilder {
@@ -82,6 +149,16 @@ macro_rules! impl_other_component_properties {
         ) -> $iana_builder<$inner> {
             $iana_builder::new(self, name.to_string(), value.to_string())
         }
+
+        /// Add a property that is declared by an extension. The property is added as an X-
+        /// property, with its text value produced by the extension's serializer.
+        pub fn add_extension_property<T: $crate::extension::ExtensionValue>(
+            self,
+            extension: &$crate::extension::PropertyExtension,
+            value: T,
+        ) -> $x_builder<$inner> {
+            $x_builder::new_extension(self, extension, value)
+        }
     };
 }
 
@@ -339,6 +416,52 @@ macro_rules! add_attach {
 
 pub(crate) use add_attach;
 
+macro_rules! add_color {
+    () => {
+        pub fn add_color<V: ToString>(
+            self,
+            value: V,
+        ) -> $crate::model::property::ColorPropertyBuilder<Self> {
+            $crate::model::property::ColorPropertyBuilder::new(self, value.to_string())
+        }
+    };
+}
+
+pub(crate) use add_color;
+
+macro_rules! add_image {
+    () => {
+        pub fn add_image_uri(
+            self,
+            value: &str,
+        ) -> $crate::model::property::ImagePropertyBuilder<Self> {
+            $crate::model::property::ImagePropertyBuilder::new_with_uri(self, value.to_string())
+        }
+
+        pub fn add_image_binary(
+            self,
+            value: &str,
+        ) -> $crate::model::property::ImagePropertyBuilder<Self> {
+            $crate::model::property::ImagePropertyBuilder::new_with_binary(self, value.to_string())
+        }
+    };
+}
+
+pub(crate) use add_image;
+
+macro_rules! add_conference {
+    () => {
+        pub fn add_conference(
+            self,
+            value: &str,
+        ) -> $crate::model::property::ConferencePropertyBuilder<Self> {
+            $crate::model::property::ConferencePropertyBuilder::new(self, value.to_string())
+        }
+    };
+}
+
+pub(crate) use add_conference;
+
 macro_rules! add_categories {
     () => {
         pub fn add_categories(
@@ -422,6 +545,28 @@ macro_rules! add_related {
         ) -> $crate::model::property::RelatedToPropertyBuilder<Self> {
             $crate::model::property::RelatedToPropertyBuilder::new(self, value.to_string())
         }
+
+        pub fn add_related_to_uri(
+            self,
+            value: &str,
+        ) -> $crate::model::property::RelatedToPropertyBuilder<Self> {
+            $crate::model::property::RelatedToPropertyBuilder::new_with_value_type(
+                self,
+                $crate::common::Value::Uri,
+                value.to_string(),
+            )
+        }
+
+        pub fn add_related_to_uid(
+            self,
+            value: &str,
+        ) -> $crate::model::property::RelatedToPropertyBuilder<Self> {
+            $crate::model::property::RelatedToPropertyBuilder::new_with_value_type(
+                self,
+                $crate::common::Value::Uid,
+                value.to_string(),
+            )
+        }
     };
 }
 
@@ -545,6 +690,197 @@ macro_rules! add_alarms {
     };
 }
 
+pub(crate) use add_alarms;
+
+macro_rules! add_participants {
+    () => {
+        pub fn add_participant(
+            self,
+        ) -> $crate::model::component::participant::ParticipantComponentBuilder<Self> {
+            $crate::model::component::participant::ParticipantComponentBuilder::new(self)
+        }
+    };
+}
+
+pub(crate) use add_participants;
+
+macro_rules! add_locations_and_resources {
+    () => {
+        pub fn add_vlocation(
+            self,
+        ) -> $crate::model::component::location::LocationComponentBuilder<Self> {
+            $crate::model::component::location::LocationComponentBuilder::new(self)
+        }
+
+        pub fn add_vresource(
+            self,
+        ) -> $crate::model::component::resource::ResourceComponentBuilder<Self> {
+            $crate::model::component::resource::ResourceComponentBuilder::new(self)
+        }
+    };
+}
+
+pub(crate) use add_locations_and_resources;
+
+macro_rules! add_styled_description {
+    () => {
+        pub fn add_styled_description_text<V: ToString>(
+            self,
+            value: V,
+        ) -> $crate::model::property::StyledDescriptionPropertyBuilder<Self> {
+            $crate::model::property::StyledDescriptionPropertyBuilder::new_with_text(
+                self,
+                value.to_string(),
+            )
+        }
+
+        pub fn add_styled_description_uri(
+            self,
+            value: &str,
+        ) -> $crate::model::property::StyledDescriptionPropertyBuilder<Self> {
+            $crate::model::property::StyledDescriptionPropertyBuilder::new_with_uri(
+                self,
+                value.to_string(),
+            )
+        }
+    };
+}
+
+pub(crate) use add_styled_description;
+
+macro_rules! add_structured_data {
+    () => {
+        pub fn add_structured_data_text<V: ToString>(
+            self,
+            value: V,
+        ) -> $crate::model::property::StructuredDataPropertyBuilder<Self> {
+            $crate::model::property::StructuredDataPropertyBuilder::new_with_text(
+                self,
+                value.to_string(),
+            )
+        }
+
+        pub fn add_structured_data_uri(
+            self,
+            value: &str,
+        ) -> $crate::model::property::StructuredDataPropertyBuilder<Self> {
+            $crate::model::property::StructuredDataPropertyBuilder::new_with_uri(
+                self,
+                value.to_string(),
+            )
+        }
+
+        pub fn add_structured_data_binary(
+            self,
+            value: &str,
+        ) -> $crate::model::property::StructuredDataPropertyBuilder<Self> {
+            $crate::model::property::StructuredDataPropertyBuilder::new_with_binary(
+                self,
+                value.to_string(),
+            )
+        }
+    };
+}
+
+pub(crate) use add_structured_data;
+
+macro_rules! add_acknowledged {
+    () => {
+        pub fn add_acknowledged(
+            self,
+            date: time::Date,
+            time: time::Time,
+        ) -> $crate::model::property::AcknowledgedPropertyBuilder<Self> {
+            $crate::model::property::AcknowledgedPropertyBuilder::new(self, date, time)
+        }
+    };
+}
+
+pub(crate) use add_acknowledged;
+
+macro_rules! add_proximity {
+    () => {
+        pub fn add_proximity(
+            self,
+            value: $crate::model::property::Proximity,
+        ) -> $crate::model::property::ProximityPropertyBuilder<Self> {
+            $crate::model::property::ProximityPropertyBuilder::new(self, value)
+        }
+    };
+}
+
+pub(crate) use add_proximity;
+
+macro_rules! add_concept {
+    () => {
+        pub fn add_concept(
+            self,
+            value: &str,
+        ) -> $crate::model::property::ConceptPropertyBuilder<Self> {
+            $crate::model::property::ConceptPropertyBuilder::new(self, value.to_string())
+        }
+    };
+}
+
+pub(crate) use add_concept;
+
+macro_rules! add_link {
+    () => {
+        pub fn add_link_uri(
+            self,
+            value: &str,
+            relation: &str,
+        ) -> $crate::model::property::LinkPropertyBuilder<Self> {
+            $crate::model::property::LinkPropertyBuilder::new(
+                self,
+                $crate::common::Value::Uri,
+                value.to_string(),
+                relation.to_string(),
+            )
+        }
+
+        pub fn add_link_uid(
+            self,
+            value: &str,
+            relation: &str,
+        ) -> $crate::model::property::LinkPropertyBuilder<Self> {
+            $crate::model::property::LinkPropertyBuilder::new(
+                self,
+                $crate::common::Value::Uid,
+                value.to_string(),
+                relation.to_string(),
+            )
+        }
+
+        pub fn add_link_xml_reference(
+            self,
+            value: &str,
+            relation: &str,
+        ) -> $crate::model::property::LinkPropertyBuilder<Self> {
+            $crate::model::property::LinkPropertyBuilder::new(
+                self,
+                $crate::common::Value::XmlReference,
+                value.to_string(),
+                relation.to_string(),
+            )
+        }
+    };
+}
+
+pub(crate) use add_link;
+
+macro_rules! add_ref_id {
+    () => {
+        pub fn add_ref_id(
+            self,
+            value: &str,
+        ) -> $crate::model::property::RefIdPropertyBuilder<Self> {
+            $crate::model::property::RefIdPropertyBuilder::new(self, value.to_string())
+        }
+    };
+}
+
+pub(crate) use add_ref_id;
+
 use crate::model::property::ComponentProperty;
 use crate::model::ComponentAccess;
-pub(crate) use add_alarms;
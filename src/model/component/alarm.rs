@@ -1,15 +1,34 @@
+use crate::common::RelationshipType;
 use crate::model::component::{
-    add_action, add_attach, add_description, add_duration, add_repeat, add_summary, add_trigger,
+    add_acknowledged, add_action, add_attach, add_description, add_duration, add_proximity,
+    add_related, add_repeat, add_summary, add_trigger, add_unique_identifier,
     impl_other_component_properties,
 };
-use crate::model::impl_component_access;
-use crate::model::param::ParticipationStatusEvent;
+use crate::model::param::{ParticipationStatusEvent, RelationshipTypeParam};
 use crate::model::property::{
     Action, AddComponentProperty, AttendeePropertyBuilder, ComponentProperty,
     IanaComponentPropertyBuilder, XComponentPropertyBuilder,
 };
+use crate::model::{impl_component_access, PropertyAccess};
+
+/// The X- property that marks an alarm as the client's default alarm, rather than one the user
+/// has chosen.
+pub const DEFAULT_ALARM_PROPERTY: &str = "X-APPLE-DEFAULT-ALARM";
+
+macro_rules! add_default_alarm {
+    () => {
+        /// Mark this alarm as a default alarm, using the [DEFAULT_ALARM_PROPERTY] convention.
+        pub fn add_default_alarm(self) -> XComponentPropertyBuilder<Self> {
+            XComponentPropertyBuilder::new(
+                self,
+                DEFAULT_ALARM_PROPERTY.to_string(),
+                "TRUE".to_string(),
+            )
+        }
+    };
+}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AlarmComponent {
     pub(crate) properties: Vec<ComponentProperty>,
 }
@@ -24,6 +43,37 @@ impl AlarmComponent {
     }
 }
 
+impl AlarmComponent {
+    /// Whether this alarm is marked as a default alarm with [DEFAULT_ALARM_PROPERTY].
+    pub fn is_default_alarm(&self) -> bool {
+        self.properties.iter().any(|p| match p {
+            ComponentProperty::XProperty(x_prop) => {
+                x_prop.name.eq_ignore_ascii_case(DEFAULT_ALARM_PROPERTY)
+                    && x_prop.value.eq_ignore_ascii_case("TRUE")
+            }
+            _ => false,
+        })
+    }
+
+    /// The UID of the alarm that this alarm is a snoozed copy of, from a RELATED-TO property with
+    /// RELTYPE=SNOOZE. RFC 9074, section 7.
+    pub fn snoozed_alarm_uid(&self) -> Option<&str> {
+        self.properties.iter().find_map(|p| match p {
+            ComponentProperty::RelatedTo(related_to)
+                if matches!(
+                    related_to.get_param::<RelationshipTypeParam>(),
+                    Some(RelationshipTypeParam {
+                        relationship: RelationshipType::Snooze
+                    })
+                ) =>
+            {
+                Some(related_to.value.as_str())
+            }
+            _ => None,
+        })
+    }
+}
+
 impl Default for AlarmComponent {
     fn default() -> Self {
         Self::new()
@@ -62,6 +112,16 @@ where
 
     add_attach!();
 
+    add_unique_identifier!();
+
+    add_acknowledged!();
+
+    add_related!();
+
+    add_proximity!();
+
+    add_default_alarm!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
@@ -110,6 +170,16 @@ where
 
     add_repeat!();
 
+    add_unique_identifier!();
+
+    add_acknowledged!();
+
+    add_related!();
+
+    add_proximity!();
+
+    add_default_alarm!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
@@ -169,6 +239,16 @@ where
 
     add_attach!();
 
+    add_unique_identifier!();
+
+    add_acknowledged!();
+
+    add_related!();
+
+    add_proximity!();
+
+    add_default_alarm!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
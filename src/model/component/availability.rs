@@ -0,0 +1,119 @@
+use crate::model::component::available::AvailableComponentBuilder;
+use crate::model::component::{
+    add_categories, add_class, add_comment, add_contact, add_created, add_date_time_end,
+    add_date_time_stamp, add_date_time_start, add_description, add_duration, add_last_modified,
+    add_location, add_organizer, add_priority, add_sequence, add_summary, add_unique_identifier,
+    add_url, impl_finish_component_build, impl_other_component_properties, CalendarComponent,
+    ComponentProperty,
+};
+use crate::model::impl_component_access;
+use crate::model::object::ICalObjectBuilder;
+use crate::model::property::{
+    AddComponentProperty, BusyType, BusyTypePropertyBuilder, IanaComponentPropertyBuilder,
+    XComponentPropertyBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailabilityComponent {
+    pub(crate) properties: Vec<ComponentProperty>,
+    pub(crate) components: Vec<CalendarComponent>,
+}
+
+impl_component_access!(AvailabilityComponent);
+
+impl AvailabilityComponent {
+    pub(crate) fn new() -> Self {
+        AvailabilityComponent {
+            properties: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    pub fn nested_components(&self) -> &[CalendarComponent] {
+        &self.components
+    }
+}
+
+impl Default for AvailabilityComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct AvailabilityComponentBuilder {
+    owner: ICalObjectBuilder,
+    pub(crate) inner: AvailabilityComponent,
+}
+
+impl AvailabilityComponentBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder) -> Self {
+        AvailabilityComponentBuilder {
+            owner,
+            inner: AvailabilityComponent {
+                properties: Vec::new(),
+                components: Vec::new(),
+            },
+        }
+    }
+
+    add_date_time_stamp!();
+
+    add_unique_identifier!();
+
+    pub fn add_busy_type(self, value: BusyType) -> BusyTypePropertyBuilder<Self> {
+        BusyTypePropertyBuilder::new(self, value)
+    }
+
+    add_class!();
+
+    add_created!();
+
+    add_description!();
+
+    add_date_time_start!();
+
+    add_last_modified!();
+
+    add_location!();
+
+    add_organizer!();
+
+    add_priority!();
+
+    add_sequence!();
+
+    add_summary!();
+
+    add_url!();
+
+    add_date_time_end!();
+
+    add_duration!();
+
+    add_categories!();
+
+    add_comment!();
+
+    add_contact!();
+
+    pub fn add_available(
+        self,
+        builder: fn(AvailableComponentBuilder) -> AvailableComponentBuilder,
+    ) -> Self {
+        builder(AvailableComponentBuilder::new(self)).build()
+    }
+
+    impl_other_component_properties!(
+        XComponentPropertyBuilder,
+        IanaComponentPropertyBuilder,
+        AvailabilityComponentBuilder
+    );
+
+    impl_finish_component_build!(CalendarComponent::Availability);
+}
+
+impl AddComponentProperty for AvailabilityComponentBuilder {
+    fn add_property(&mut self, property: ComponentProperty) {
+        self.inner.properties.push(property);
+    }
+}
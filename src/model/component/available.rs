@@ -0,0 +1,103 @@
+use crate::model::component::availability::AvailabilityComponentBuilder;
+use crate::model::component::{
+    add_categories, add_comment, add_contact, add_created, add_date_time_end, add_date_time_stamp,
+    add_date_time_start, add_description, add_duration, add_exception_date_times,
+    add_last_modified, add_location, add_recurrence_date, add_recurrence_id, add_recurrence_rule,
+    add_summary, add_unique_identifier, impl_other_component_properties, CalendarComponent,
+    ComponentProperty,
+};
+use crate::model::impl_component_access;
+use crate::model::property::{
+    AddComponentProperty, IanaComponentPropertyBuilder, XComponentPropertyBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailableComponent {
+    pub(crate) properties: Vec<ComponentProperty>,
+}
+
+impl_component_access!(AvailableComponent);
+
+impl AvailableComponent {
+    pub(crate) fn new() -> Self {
+        AvailableComponent {
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl Default for AvailableComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct AvailableComponentBuilder {
+    owner: AvailabilityComponentBuilder,
+    inner: AvailableComponent,
+}
+
+impl AvailableComponentBuilder {
+    pub(crate) fn new(owner: AvailabilityComponentBuilder) -> Self {
+        AvailableComponentBuilder {
+            owner,
+            inner: AvailableComponent {
+                properties: Vec::new(),
+            },
+        }
+    }
+
+    add_date_time_stamp!();
+
+    add_unique_identifier!();
+
+    add_date_time_start!();
+
+    add_date_time_end!();
+
+    add_duration!();
+
+    add_created!();
+
+    add_description!();
+
+    add_last_modified!();
+
+    add_location!();
+
+    add_recurrence_id!();
+
+    add_recurrence_rule!();
+
+    add_summary!();
+
+    add_categories!();
+
+    add_comment!();
+
+    add_contact!();
+
+    add_exception_date_times!();
+
+    add_recurrence_date!();
+
+    impl_other_component_properties!(
+        XComponentPropertyBuilder,
+        IanaComponentPropertyBuilder,
+        AvailableComponentBuilder
+    );
+
+    pub(crate) fn build(mut self) -> AvailabilityComponentBuilder {
+        self.owner
+            .inner
+            .components
+            .push(CalendarComponent::Available(self.inner));
+        self.owner
+    }
+}
+
+impl AddComponentProperty for AvailableComponentBuilder {
+    fn add_property(&mut self, property: ComponentProperty) {
+        self.inner.properties.push(property);
+    }
+}
@@ -10,7 +10,7 @@ use crate::model::property::{
     TimeZoneOffsetToPropertyBuilder, XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DaylightComponent {
     pub(crate) properties: Vec<ComponentProperty>,
 }
@@ -1,15 +1,18 @@
 use crate::common::TimeTransparency;
 use crate::model::component::{
-    add_alarms, add_attach, add_categories, add_class, add_comment, add_contact, add_created,
-    add_date_time_end, add_date_time_stamp, add_date_time_start, add_description, add_duration,
-    add_exception_date_times, add_geographic_position, add_last_modified, add_location,
-    add_organizer, add_priority, add_recurrence_date, add_recurrence_id, add_recurrence_rule,
-    add_related, add_request_status, add_resources, add_sequence, add_summary,
-    add_unique_identifier, add_url,
+    add_alarms, add_attach, add_categories, add_class, add_color, add_comment, add_concept,
+    add_conference, add_contact, add_created, add_date_time_end, add_date_time_stamp,
+    add_date_time_start, add_description, add_duration, add_exception_date_times,
+    add_geographic_position, add_image, add_last_modified, add_link, add_location, add_organizer,
+    add_priority, add_recurrence_date, add_recurrence_id, add_recurrence_rule, add_ref_id,
+    add_related, add_request_status, add_resources, add_sequence, add_structured_data,
+    add_styled_description, add_summary, add_unique_identifier, add_url,
 };
 use crate::model::component::{
-    impl_finish_component_build, impl_other_component_properties, AddAlarmComponent,
-    AlarmComponent, CalendarComponent,
+    add_locations_and_resources, add_participants, impl_finish_component_build,
+    impl_other_component_properties, AddAlarmComponent, AddLocationComponent,
+    AddParticipantComponent, AddResourceComponent, AlarmComponent, CalendarComponent,
+    LocationComponent, ParticipantComponent, ResourceComponent,
 };
 use crate::model::impl_component_access;
 use crate::model::object::ICalObjectBuilder;
@@ -22,10 +25,11 @@ use crate::model::property::{
     TimeTransparencyPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EventComponent {
     pub(crate) properties: Vec<ComponentProperty>,
-    pub(crate) alarms: Vec<CalendarComponent>,
+    /// Nested components, the alarms along with any participants, locations and resources.
+    pub(crate) components: Vec<CalendarComponent>,
 }
 
 impl_component_access!(EventComponent);
@@ -34,12 +38,15 @@ impl EventComponent {
     pub fn new() -> Self {
         EventComponent {
             properties: Vec::new(),
-            alarms: Vec::new(),
+            components: Vec::new(),
         }
     }
 
-    pub fn alarms(&self) -> &[CalendarComponent] {
-        &self.alarms
+    pub fn alarms(&self) -> Vec<&CalendarComponent> {
+        self.components
+            .iter()
+            .filter(|c| matches!(c, CalendarComponent::Alarm(_)))
+            .collect()
     }
 }
 
@@ -134,14 +141,34 @@ impl EventComponentBuilder {
 
     add_recurrence_date!();
 
+    add_color!();
+
+    add_image!();
+
+    add_concept!();
+
+    add_link!();
+
+    add_ref_id!();
+
+    add_conference!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
         EventComponentBuilder
     );
 
+    add_styled_description!();
+
+    add_structured_data!();
+
     add_alarms!();
 
+    add_participants!();
+
+    add_locations_and_resources!();
+
     impl_finish_component_build!(CalendarComponent::Event);
 }
 
@@ -153,7 +180,34 @@ impl AddComponentProperty for EventComponentBuilder {
 
 impl AddAlarmComponent for EventComponentBuilder {
     fn add_alarm(mut self, alarm: AlarmComponent) -> Self {
-        self.inner.alarms.push(CalendarComponent::Alarm(alarm));
+        self.inner.components.push(CalendarComponent::Alarm(alarm));
+        self
+    }
+}
+
+impl AddParticipantComponent for EventComponentBuilder {
+    fn add_participant_component(mut self, participant: ParticipantComponent) -> Self {
+        self.inner
+            .components
+            .push(CalendarComponent::Participant(participant));
+        self
+    }
+}
+
+impl AddLocationComponent for EventComponentBuilder {
+    fn add_location_component(mut self, location: LocationComponent) -> Self {
+        self.inner
+            .components
+            .push(CalendarComponent::Location(location));
+        self
+    }
+}
+
+impl AddResourceComponent for EventComponentBuilder {
+    fn add_resource_component(mut self, resource: ResourceComponent) -> Self {
+        self.inner
+            .components
+            .push(CalendarComponent::Resource(resource));
         self
     }
 }
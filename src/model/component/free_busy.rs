@@ -12,7 +12,7 @@ use crate::model::property::{
     IanaComponentPropertyBuilder, Period, XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FreeBusyComponent {
     pub(crate) properties: Vec<ComponentProperty>,
 }
@@ -8,7 +8,7 @@ use crate::model::property::{
     XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IanaComponent {
     pub(crate) name: String,
     pub(crate) properties: Vec<ComponentProperty>,
@@ -1,8 +1,9 @@
 use crate::model::component::{
-    add_attach, add_categories, add_class, add_comment, add_contact, add_created,
-    add_date_time_stamp, add_date_time_start, add_description, add_exception_date_times,
-    add_last_modified, add_organizer, add_recurrence_date, add_recurrence_id, add_recurrence_rule,
-    add_related, add_request_status, add_sequence, add_summary, add_unique_identifier, add_url,
+    add_attach, add_categories, add_class, add_color, add_comment, add_concept, add_contact,
+    add_created, add_date_time_stamp, add_date_time_start, add_description,
+    add_exception_date_times, add_image, add_last_modified, add_link, add_organizer,
+    add_recurrence_date, add_recurrence_id, add_recurrence_rule, add_ref_id, add_related,
+    add_request_status, add_sequence, add_summary, add_unique_identifier, add_url,
     impl_finish_component_build, impl_other_component_properties, CalendarComponent,
 };
 use crate::model::impl_component_access;
@@ -13,7 +14,7 @@ use crate::model::property::{
     StatusJournal, StatusPropertyBuilder, XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct JournalComponent {
     pub(crate) properties: Vec<ComponentProperty>,
 }
@@ -102,6 +103,16 @@ impl JournalComponentBuilder {
 
     add_request_status!();
 
+    add_color!();
+
+    add_image!();
+
+    add_concept!();
+
+    add_link!();
+
+    add_ref_id!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
@@ -0,0 +1,84 @@
+use crate::model::component::{
+    add_description, add_geographic_position, add_structured_data, add_unique_identifier, add_url,
+    impl_other_component_properties, ComponentProperty,
+};
+use crate::model::impl_component_access;
+use crate::model::property::{
+    AddComponentProperty, IanaComponentPropertyBuilder, LocationTypePropertyBuilder,
+    XComponentPropertyBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationComponent {
+    pub(crate) properties: Vec<ComponentProperty>,
+}
+
+impl_component_access!(LocationComponent);
+
+impl LocationComponent {
+    pub(crate) fn new() -> Self {
+        LocationComponent {
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl Default for LocationComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait AddLocationComponent {
+    fn add_location_component(self, location: LocationComponent) -> Self;
+}
+
+pub struct LocationComponentBuilder<P: AddLocationComponent> {
+    owner: P,
+    inner: LocationComponent,
+}
+
+impl<P> LocationComponentBuilder<P>
+where
+    P: AddLocationComponent,
+{
+    pub(crate) fn new(owner: P) -> Self {
+        LocationComponentBuilder {
+            owner,
+            inner: LocationComponent::new(),
+        }
+    }
+
+    add_unique_identifier!();
+
+    add_description!();
+
+    add_geographic_position!();
+
+    pub fn add_location_type(self, value: Vec<&str>) -> LocationTypePropertyBuilder<Self> {
+        LocationTypePropertyBuilder::new(self, value.into_iter().map(|s| s.to_string()).collect())
+    }
+
+    add_url!();
+
+    add_structured_data!();
+
+    impl_other_component_properties!(
+        XComponentPropertyBuilder,
+        IanaComponentPropertyBuilder,
+        LocationComponentBuilder<P>
+    );
+
+    pub fn finish_component(self) -> P {
+        self.owner.add_location_component(self.inner)
+    }
+}
+
+impl<P> AddComponentProperty for LocationComponentBuilder<P>
+where
+    P: AddLocationComponent,
+{
+    fn add_property(&mut self, property: ComponentProperty) {
+        self.inner.properties.push(property);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::model::component::location::{AddLocationComponent, LocationComponent};
+use crate::model::component::resource::{AddResourceComponent, ResourceComponent};
+use crate::model::component::{
+    add_attach, add_categories, add_comment, add_contact, add_created, add_date_time_stamp,
+    add_description, add_geographic_position, add_last_modified, add_location,
+    add_locations_and_resources, add_priority, add_related, add_request_status, add_resources,
+    add_sequence, add_structured_data, add_styled_description, add_summary, add_unique_identifier,
+    add_url, impl_other_component_properties, CalendarComponent, ComponentProperty,
+};
+use crate::model::impl_component_access;
+use crate::model::property::{
+    AddComponentProperty, IanaComponentPropertyBuilder, ParticipantType,
+    ParticipantTypePropertyBuilder, XComponentPropertyBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticipantComponent {
+    pub(crate) properties: Vec<ComponentProperty>,
+    pub(crate) components: Vec<CalendarComponent>,
+}
+
+impl_component_access!(ParticipantComponent);
+
+impl ParticipantComponent {
+    pub(crate) fn new() -> Self {
+        ParticipantComponent {
+            properties: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    pub fn nested_components(&self) -> &[CalendarComponent] {
+        &self.components
+    }
+}
+
+impl Default for ParticipantComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait AddParticipantComponent {
+    fn add_participant_component(self, participant: ParticipantComponent) -> Self;
+}
+
+pub struct ParticipantComponentBuilder<P: AddParticipantComponent> {
+    owner: P,
+    inner: ParticipantComponent,
+}
+
+impl<P> ParticipantComponentBuilder<P>
+where
+    P: AddParticipantComponent,
+{
+    pub(crate) fn new(owner: P) -> Self {
+        ParticipantComponentBuilder {
+            owner,
+            inner: ParticipantComponent::new(),
+        }
+    }
+
+    add_unique_identifier!();
+
+    pub fn add_participant_type(
+        self,
+        value: ParticipantType,
+    ) -> ParticipantTypePropertyBuilder<Self> {
+        ParticipantTypePropertyBuilder::new(self, value)
+    }
+
+    add_created!();
+
+    add_description!();
+
+    add_date_time_stamp!();
+
+    add_geographic_position!();
+
+    add_last_modified!();
+
+    add_priority!();
+
+    add_sequence!();
+
+    add_summary!();
+
+    add_url!();
+
+    add_attach!();
+
+    add_categories!();
+
+    add_comment!();
+
+    add_contact!();
+
+    add_location!();
+
+    add_request_status!();
+
+    add_related!();
+
+    add_resources!();
+
+    add_styled_description!();
+
+    add_structured_data!();
+
+    impl_other_component_properties!(
+        XComponentPropertyBuilder,
+        IanaComponentPropertyBuilder,
+        ParticipantComponentBuilder<P>
+    );
+
+    add_locations_and_resources!();
+
+    pub fn finish_component(self) -> P {
+        self.owner.add_participant_component(self.inner)
+    }
+}
+
+impl<P> AddComponentProperty for ParticipantComponentBuilder<P>
+where
+    P: AddParticipantComponent,
+{
+    fn add_property(&mut self, property: ComponentProperty) {
+        self.inner.properties.push(property);
+    }
+}
+
+impl<P> AddLocationComponent for ParticipantComponentBuilder<P>
+where
+    P: AddParticipantComponent,
+{
+    fn add_location_component(mut self, location: LocationComponent) -> Self {
+        self.inner
+            .components
+            .push(CalendarComponent::Location(location));
+        self
+    }
+}
+
+impl<P> AddResourceComponent for ParticipantComponentBuilder<P>
+where
+    P: AddParticipantComponent,
+{
+    fn add_resource_component(mut self, resource: ResourceComponent) -> Self {
+        self.inner
+            .components
+            .push(CalendarComponent::Resource(resource));
+        self
+    }
+}
@@ -0,0 +1,82 @@
+use crate::model::component::{
+    add_description, add_geographic_position, add_structured_data, add_unique_identifier,
+    impl_other_component_properties, ComponentProperty,
+};
+use crate::model::impl_component_access;
+use crate::model::property::{
+    AddComponentProperty, IanaComponentPropertyBuilder, ResourceType, ResourceTypePropertyBuilder,
+    XComponentPropertyBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceComponent {
+    pub(crate) properties: Vec<ComponentProperty>,
+}
+
+impl_component_access!(ResourceComponent);
+
+impl ResourceComponent {
+    pub(crate) fn new() -> Self {
+        ResourceComponent {
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl Default for ResourceComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait AddResourceComponent {
+    fn add_resource_component(self, resource: ResourceComponent) -> Self;
+}
+
+pub struct ResourceComponentBuilder<P: AddResourceComponent> {
+    owner: P,
+    inner: ResourceComponent,
+}
+
+impl<P> ResourceComponentBuilder<P>
+where
+    P: AddResourceComponent,
+{
+    pub(crate) fn new(owner: P) -> Self {
+        ResourceComponentBuilder {
+            owner,
+            inner: ResourceComponent::new(),
+        }
+    }
+
+    add_unique_identifier!();
+
+    add_description!();
+
+    add_geographic_position!();
+
+    pub fn add_resource_type(self, value: ResourceType) -> ResourceTypePropertyBuilder<Self> {
+        ResourceTypePropertyBuilder::new(self, value)
+    }
+
+    add_structured_data!();
+
+    impl_other_component_properties!(
+        XComponentPropertyBuilder,
+        IanaComponentPropertyBuilder,
+        ResourceComponentBuilder<P>
+    );
+
+    pub fn finish_component(self) -> P {
+        self.owner.add_resource_component(self.inner)
+    }
+}
+
+impl<P> AddComponentProperty for ResourceComponentBuilder<P>
+where
+    P: AddResourceComponent,
+{
+    fn add_property(&mut self, property: ComponentProperty) {
+        self.inner.properties.push(property);
+    }
+}
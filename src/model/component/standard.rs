@@ -10,7 +10,7 @@ use crate::model::property::{
     XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StandardComponent {
     pub(crate) properties: Vec<ComponentProperty>,
 }
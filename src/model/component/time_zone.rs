@@ -11,7 +11,7 @@ use crate::model::property::{
     TimeZoneUrlPropertyBuilder, XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneComponent {
     pub(crate) properties: Vec<ComponentProperty>,
     pub(crate) components: Vec<CalendarComponent>,
@@ -1,11 +1,12 @@
 use crate::model::component::{
-    add_alarms, add_attach, add_categories, add_class, add_comment, add_contact, add_created,
-    add_date_time_stamp, add_date_time_start, add_description, add_duration,
-    add_exception_date_times, add_geographic_position, add_last_modified, add_location,
-    add_organizer, add_priority, add_recurrence_date, add_recurrence_id, add_recurrence_rule,
-    add_related, add_request_status, add_resources, add_sequence, add_summary,
-    add_unique_identifier, add_url, impl_finish_component_build, impl_other_component_properties,
-    AddAlarmComponent, AlarmComponent, CalendarComponent, ComponentProperty,
+    add_alarms, add_attach, add_categories, add_class, add_color, add_comment, add_concept,
+    add_conference, add_contact, add_created, add_date_time_stamp, add_date_time_start,
+    add_description, add_duration, add_exception_date_times, add_geographic_position, add_image,
+    add_last_modified, add_link, add_location, add_organizer, add_priority, add_recurrence_date,
+    add_recurrence_id, add_recurrence_rule, add_ref_id, add_related, add_request_status,
+    add_resources, add_sequence, add_summary, add_unique_identifier, add_url,
+    impl_finish_component_build, impl_other_component_properties, AddAlarmComponent,
+    AlarmComponent, CalendarComponent, ComponentProperty,
 };
 use crate::model::impl_component_access;
 use crate::model::object::ICalObjectBuilder;
@@ -16,7 +17,7 @@ use crate::model::property::{
     StatusPropertyBuilder, StatusToDo, XComponentPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ToDoComponent {
     pub(crate) properties: Vec<ComponentProperty>,
     pub(crate) alarms: Vec<CalendarComponent>,
@@ -142,6 +143,18 @@ impl ToDoComponentBuilder {
 
     add_recurrence_date!();
 
+    add_color!();
+
+    add_image!();
+
+    add_concept!();
+
+    add_link!();
+
+    add_ref_id!();
+
+    add_conference!();
+
     impl_other_component_properties!(
         XComponentPropertyBuilder,
         IanaComponentPropertyBuilder,
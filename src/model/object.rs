@@ -1,16 +1,20 @@
+use crate::extension::{ExtensionValue, PropertyExtension};
 use crate::model::component::CalendarComponent;
 use crate::model::component::IanaComponentBuilder;
 use crate::model::component::XComponentBuilder;
 use crate::model::component::{
-    EventComponentBuilder, FreeBusyComponentBuilder, JournalComponentBuilder,
-    TimeZoneComponentBuilder, ToDoComponentBuilder,
+    AvailabilityComponentBuilder, EventComponentBuilder, FreeBusyComponentBuilder,
+    JournalComponentBuilder, TimeZoneComponentBuilder, ToDoComponentBuilder,
 };
 use crate::model::property::{
-    CalendarProperty, CalendarScalePropertyBuilder, IanaPropertyBuilder, MethodPropertyBuilder,
-    ProductIdPropertyBuilder, VersionPropertyBuilder, XPropertyBuilder,
+    CalendarColorPropertyBuilder, CalendarDescriptionPropertyBuilder, CalendarImagePropertyBuilder,
+    CalendarLastModifiedPropertyBuilder, CalendarProperty, CalendarScalePropertyBuilder,
+    CalendarUrlPropertyBuilder, Duration, IanaProperty, IanaPropertyBuilder, MethodPropertyBuilder,
+    NamePropertyBuilder, ProductIdPropertyBuilder, RefreshIntervalPropertyBuilder,
+    SourcePropertyBuilder, VersionPropertyBuilder, XProperty, XPropertyBuilder,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ICalObject {
     pub properties: Vec<CalendarProperty>,
     pub components: Vec<CalendarComponent>,
@@ -29,6 +33,28 @@ impl ICalObject {
             components: Vec::new(),
         }
     }
+
+    /// Find the calendar level X- properties with the given name, matched case-insensitively.
+    pub fn find_x_properties(&self, name: &str) -> Vec<&XProperty> {
+        self.properties
+            .iter()
+            .filter_map(|p| match p {
+                CalendarProperty::XProperty(p) if p.name.eq_ignore_ascii_case(name) => Some(p),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Find the calendar level IANA properties with the given name, matched case-insensitively.
+    pub fn find_iana_properties(&self, name: &str) -> Vec<&IanaProperty> {
+        self.properties
+            .iter()
+            .filter_map(|p| match p {
+                CalendarProperty::IanaProperty(p) if p.name.eq_ignore_ascii_case(name) => Some(p),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 pub struct ICalObjectBuilder {
@@ -60,10 +86,60 @@ impl ICalObjectBuilder {
         MethodPropertyBuilder::new(self, value.to_string())
     }
 
+    pub fn add_name<V: ToString>(self, value: V) -> NamePropertyBuilder {
+        NamePropertyBuilder::new(self, value.to_string())
+    }
+
+    pub fn add_description<V: ToString>(self, value: V) -> CalendarDescriptionPropertyBuilder {
+        CalendarDescriptionPropertyBuilder::new(self, value.to_string())
+    }
+
+    pub fn add_last_modified(
+        self,
+        date: time::Date,
+        time: time::Time,
+    ) -> CalendarLastModifiedPropertyBuilder {
+        CalendarLastModifiedPropertyBuilder::new(self, date, time)
+    }
+
+    pub fn add_url(self, value: &str) -> CalendarUrlPropertyBuilder {
+        CalendarUrlPropertyBuilder::new(self, value.to_string())
+    }
+
+    pub fn add_refresh_interval(self, builder: fn() -> Duration) -> RefreshIntervalPropertyBuilder {
+        RefreshIntervalPropertyBuilder::new(self, builder())
+    }
+
+    pub fn add_source(self, value: &str) -> SourcePropertyBuilder {
+        SourcePropertyBuilder::new(self, value.to_string())
+    }
+
+    pub fn add_color<V: ToString>(self, value: V) -> CalendarColorPropertyBuilder {
+        CalendarColorPropertyBuilder::new(self, value.to_string())
+    }
+
+    pub fn add_image_uri(self, value: &str) -> CalendarImagePropertyBuilder {
+        CalendarImagePropertyBuilder::new_with_uri(self, value.to_string())
+    }
+
+    pub fn add_image_binary(self, value: &str) -> CalendarImagePropertyBuilder {
+        CalendarImagePropertyBuilder::new_with_binary(self, value.to_string())
+    }
+
     pub fn add_x_property<N: ToString, V: ToString>(self, name: N, value: V) -> XPropertyBuilder {
         XPropertyBuilder::new(self, name.to_string(), value.to_string())
     }
 
+    /// Add a property that is declared by an extension. The property is added as an X- property,
+    /// with its text value produced by the extension's serializer.
+    pub fn add_extension_property<T: ExtensionValue>(
+        self,
+        extension: &PropertyExtension,
+        value: T,
+    ) -> XPropertyBuilder {
+        XPropertyBuilder::new_extension(self, extension, value)
+    }
+
     pub fn add_iana_property<N: ToString, V: ToString>(
         self,
         name: N,
@@ -92,6 +168,10 @@ impl ICalObjectBuilder {
         TimeZoneComponentBuilder::new(self)
     }
 
+    pub fn add_availability_component(self) -> AvailabilityComponentBuilder {
+        AvailabilityComponentBuilder::new(self)
+    }
+
     pub fn add_iana_component<N: ToString>(
         self,
         name: N,
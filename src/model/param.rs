@@ -20,8 +20,31 @@ pub enum Param {
     RelationshipType(RelationshipTypeParam),
     FreeBusyTimeType(FreeBusyTimeTypeParam),
     TriggerRelationship(TriggerRelationshipParam),
-    Other { name: String, value: String },
-    Others { name: String, values: Vec<String> },
+    Display(DisplayParam),
+    Email(EmailParam),
+    Feature(FeatureParam),
+    Label(LabelParam),
+    ScheduleAgent(ScheduleAgentParam),
+    ScheduleForceSend(ScheduleForceSendParam),
+    ScheduleStatus(ScheduleStatusParam),
+    Gap(GapParam),
+    LinkRelation(LinkRelationParam),
+    /// An IANA param that is not otherwise supported, or a supported param with a value that
+    /// could not be interpreted.
+    Other {
+        name: String,
+        value: String,
+    },
+    /// As [Param::Other], with multiple values.
+    Others {
+        name: String,
+        values: Vec<String>,
+    },
+    /// An experimental param, with a name that starts with `X-`.
+    XName {
+        name: String,
+        values: Vec<String>,
+    },
 }
 
 pub trait ParamInner<T> {
@@ -91,6 +114,12 @@ pub struct SentByParam {
 
 impl_param_inner!(SentByParam, SentBy);
 
+impl SentByParam {
+    pub fn calendar_address(&self) -> CalendarAddress {
+        CalendarAddress::new(&self.address)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RangeParam {
     pub range: Range,
@@ -127,6 +156,12 @@ pub struct MembersParam {
 
 impl_param_inner!(MembersParam, Members);
 
+impl MembersParam {
+    pub fn calendar_addresses(&self) -> Vec<CalendarAddress> {
+        self.members.iter().map(CalendarAddress::from).collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RoleParam {
     pub role: Role,
@@ -155,6 +190,12 @@ pub struct DelegatedToParam {
 
 impl_param_inner!(DelegatedToParam, DelegatedTo);
 
+impl DelegatedToParam {
+    pub fn calendar_addresses(&self) -> Vec<CalendarAddress> {
+        self.delegates.iter().map(CalendarAddress::from).collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DelegatedFromParam {
     pub delegators: Vec<String>,
@@ -162,6 +203,12 @@ pub struct DelegatedFromParam {
 
 impl_param_inner!(DelegatedFromParam, DelegatedFrom);
 
+impl DelegatedFromParam {
+    pub fn calendar_addresses(&self) -> Vec<CalendarAddress> {
+        self.delegators.iter().map(CalendarAddress::from).collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RelationshipTypeParam {
     pub relationship: RelationshipType,
@@ -183,6 +230,71 @@ pub struct TriggerRelationshipParam {
 
 impl_param_inner!(TriggerRelationshipParam, TriggerRelationship);
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayParam {
+    pub display: Vec<DisplayType>,
+}
+
+impl_param_inner!(DisplayParam, Display);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailParam {
+    pub email: String,
+}
+
+impl_param_inner!(EmailParam, Email);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureParam {
+    pub features: Vec<FeatureType>,
+}
+
+impl_param_inner!(FeatureParam, Feature);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelParam {
+    pub label: String,
+}
+
+impl_param_inner!(LabelParam, Label);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleAgentParam {
+    pub agent: ScheduleAgent,
+}
+
+impl_param_inner!(ScheduleAgentParam, ScheduleAgent);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleForceSendParam {
+    pub force_send: ScheduleForceSend,
+}
+
+impl_param_inner!(ScheduleForceSendParam, ScheduleForceSend);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleStatusParam {
+    /// Status codes, with two or three parts such as `[2, 0]` for `2.0`.
+    pub statuses: Vec<Vec<u32>>,
+}
+
+impl_param_inner!(ScheduleStatusParam, ScheduleStatus);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapParam {
+    pub duration: Duration,
+}
+
+impl_param_inner!(GapParam, Gap);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkRelationParam {
+    /// A registered link relation type, or a URI for an extension relation type.
+    pub relation: String,
+}
+
+impl_param_inner!(LinkRelationParam, LinkRelation);
+
 impl Display for TimeTransparency {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -272,6 +384,26 @@ impl From<ParticipationStatusJournal> for ParticipationStatusUnknown {
     }
 }
 
+/// Whether a param name is an X- name rather than an IANA token.
+pub(crate) fn is_x_name(name: &[u8]) -> bool {
+    name.len() > 2 && name[..2].eq_ignore_ascii_case(b"X-")
+}
+
+/// Create a param that was added as an IANA param. Params are told apart by their name, so one
+/// with an X- name is kept as an X- param.
+pub(crate) fn iana_param(name: String, mut values: Vec<String>) -> Param {
+    if is_x_name(name.as_bytes()) {
+        Param::XName { name, values }
+    } else if values.len() == 1 {
+        Param::Other {
+            name,
+            value: values.remove(0),
+        }
+    } else {
+        Param::Others { name, values }
+    }
+}
+
 pub trait OtherParamsBuilder {
     fn add_iana_param<N: ToString, V: ToString>(self, name: N, value: V) -> Self;
 
@@ -280,42 +412,89 @@ pub trait OtherParamsBuilder {
     fn add_x_param<N: ToString, V: ToString>(self, name: N, value: V) -> Self;
 
     fn add_x_param_values<N: ToString>(self, name: N, values: Vec<String>) -> Self;
+
+    /// Add a DISPLAY param, RFC 7986 section 6.1
+    fn add_display(self, display: Vec<DisplayType>) -> Self;
+
+    /// Add an EMAIL param, RFC 7986 section 6.2
+    fn add_email<V: ToString>(self, email: V) -> Self;
+
+    /// Add a FEATURE param, RFC 7986 section 6.3
+    fn add_feature(self, features: Vec<FeatureType>) -> Self;
+
+    /// Add a LABEL param, RFC 7986 section 6.4
+    fn add_label<V: ToString>(self, label: V) -> Self;
 }
 
 macro_rules! impl_other_params_builder {
     ($builder:ty) => {
         impl crate::model::param::OtherParamsBuilder for $builder {
             fn add_iana_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
-                self.inner.params.push(Param::Other {
-                    name: name.to_string(),
-                    value: value.to_string(),
-                });
+                self.inner.params.push(crate::model::param::iana_param(
+                    name.to_string(),
+                    vec![value.to_string()],
+                ));
                 self
             }
 
             fn add_iana_param_values<N: ToString>(mut self, name: N, values: Vec<String>) -> Self {
-                self.inner.params.push(Param::Others {
-                    name: name.to_string(),
-                    values,
-                });
+                self.inner
+                    .params
+                    .push(crate::model::param::iana_param(name.to_string(), values));
                 self
             }
 
             fn add_x_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
-                self.inner.params.push(Param::Other {
+                self.inner.params.push(Param::XName {
                     name: name.to_string(),
-                    value: value.to_string(),
+                    values: vec![value.to_string()],
                 });
                 self
             }
 
             fn add_x_param_values<N: ToString>(mut self, name: N, values: Vec<String>) -> Self {
-                self.inner.params.push(Param::Others {
+                self.inner.params.push(Param::XName {
                     name: name.to_string(),
                     values,
                 });
                 self
             }
+
+            fn add_display(mut self, display: Vec<crate::common::DisplayType>) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Display(crate::model::param::DisplayParam {
+                        display,
+                    }));
+                self
+            }
+
+            fn add_email<V: ToString>(mut self, email: V) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Email(crate::model::param::EmailParam {
+                        email: email.to_string(),
+                    }));
+                self
+            }
+
+            fn add_feature(mut self, features: Vec<crate::common::FeatureType>) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Feature(crate::model::param::FeatureParam {
+                        features,
+                    }));
+                self
+            }
+
+            fn add_label<V: ToString>(mut self, label: V) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Label(crate::model::param::LabelParam {
+                        label: label.to_string(),
+                    }));
+                self
+            }
         }
     };
 }
@@ -331,36 +510,67 @@ macro_rules! impl_other_component_params_builder {
             $p: crate::model::property::AddComponentProperty,
         {
             fn add_iana_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
-                self.inner.params.push(Param::Other {
-                    name: name.to_string(),
-                    value: value.to_string(),
-                });
+                self.inner.params.push(crate::model::param::iana_param(
+                    name.to_string(),
+                    vec![value.to_string()],
+                ));
                 self
             }
 
             fn add_iana_param_values<N: ToString>(mut self, name: N, values: Vec<String>) -> Self {
-                self.inner.params.push(Param::Others {
-                    name: name.to_string(),
-                    values,
-                });
+                self.inner
+                    .params
+                    .push(crate::model::param::iana_param(name.to_string(), values));
                 self
             }
 
             fn add_x_param<N: ToString, V: ToString>(mut self, name: N, value: V) -> Self {
-                self.inner.params.push(Param::Other {
+                self.inner.params.push(Param::XName {
                     name: name.to_string(),
-                    value: value.to_string(),
+                    values: vec![value.to_string()],
                 });
                 self
             }
 
             fn add_x_param_values<N: ToString>(mut self, name: N, values: Vec<String>) -> Self {
-                self.inner.params.push(Param::Others {
+                self.inner.params.push(Param::XName {
                     name: name.to_string(),
                     values,
                 });
                 self
             }
+
+            fn add_display(mut self, display: Vec<crate::common::DisplayType>) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Display(crate::model::param::DisplayParam { display }));
+                self
+            }
+
+            fn add_email<V: ToString>(mut self, email: V) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Email(crate::model::param::EmailParam {
+                        email: email.to_string(),
+                    }));
+                self
+            }
+
+            fn add_feature(mut self, features: Vec<crate::common::FeatureType>) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Feature(crate::model::param::FeatureParam { features }));
+                self
+            }
+
+            fn add_label<V: ToString>(mut self, label: V) -> Self {
+                self.inner
+                    .params
+                    .push(Param::Label(crate::model::param::LabelParam {
+                        label: label.to_string(),
+                    }));
+                self
+            }
         }
     };
 }
@@ -399,6 +609,26 @@ macro_rules! language_param {
 
 pub(crate) use language_param;
 
+macro_rules! fmt_type_param {
+    () => {
+        pub fn add_fmt_type<U: ToString, V: ToString>(
+            mut self,
+            type_name: U,
+            sub_type_name: V,
+        ) -> Self {
+            self.inner
+                .params
+                .push(Param::FormatType($crate::model::param::FormatTypeParam {
+                    type_name: type_name.to_string(),
+                    sub_type_name: sub_type_name.to_string(),
+                }));
+            self
+        }
+    };
+}
+
+pub(crate) use fmt_type_param;
+
 macro_rules! tz_id_param {
     () => {
         pub fn add_tz_id<V: ToString>(mut self, tz_id: V, unique: bool) -> Self {
@@ -431,6 +661,37 @@ macro_rules! sent_by_param {
 
 pub(crate) use sent_by_param;
 
+/// Scheduling params for calendar user properties, RFC 6638 section 7
+macro_rules! schedule_params {
+    () => {
+        pub fn add_schedule_agent(mut self, agent: $crate::common::ScheduleAgent) -> Self {
+            self.inner.params.push(Param::ScheduleAgent(
+                $crate::model::param::ScheduleAgentParam { agent },
+            ));
+            self
+        }
+
+        pub fn add_schedule_force_send(
+            mut self,
+            force_send: $crate::common::ScheduleForceSend,
+        ) -> Self {
+            self.inner.params.push(Param::ScheduleForceSend(
+                $crate::model::param::ScheduleForceSendParam { force_send },
+            ));
+            self
+        }
+
+        pub fn add_schedule_status(mut self, statuses: Vec<Vec<u32>>) -> Self {
+            self.inner.params.push(Param::ScheduleStatus(
+                $crate::model::param::ScheduleStatusParam { statuses },
+            ));
+            self
+        }
+    };
+}
+
+pub(crate) use schedule_params;
+
 macro_rules! common_name_param {
     () => {
         pub fn add_common_name<V: ToString>(mut self, value: V) -> Self {
@@ -461,9 +722,11 @@ macro_rules! directory_entry_reference_param {
 }
 
 use crate::common::{
-    CalendarUserType, Encoding, FreeBusyTimeType, LanguageTag, ParticipationStatusUnknown, Range,
-    RelationshipType, Role, TimeTransparency, TriggerRelationship, Value,
+    CalendarAddress, CalendarUserType, DisplayType, Encoding, FeatureType, FreeBusyTimeType,
+    LanguageTag, ParticipationStatusUnknown, Range, RelationshipType, Role, ScheduleAgent,
+    ScheduleForceSend, TimeTransparency, TriggerRelationship, Value,
 };
+use crate::model::property::Duration;
 pub(crate) use directory_entry_reference_param;
 
 macro_rules! add_is_utc {
@@ -1,14 +1,15 @@
 use crate::common::{
-    CalendarDateTime, CalendarUserType, Encoding, FreeBusyTimeType, ParticipationStatusUnknown,
-    Range, RelationshipType, Role, Status, TimeTransparency, TriggerRelationship, Value,
+    CalendarAddress, CalendarDateTime, CalendarUserType, Encoding, FreeBusyTimeType,
+    ParticipationStatusUnknown, Range, RelationshipType, Role, Status, TimeTransparency,
+    TriggerRelationship, Value,
 };
 use crate::model::object::ICalObjectBuilder;
 use crate::model::param::{
-    add_is_utc, altrep_param, common_name_param, directory_entry_reference_param, language_param,
-    sent_by_param, tz_id_param, CalendarUserTypeParam, DelegatedFromParam, DelegatedToParam,
-    EncodingParam, FormatTypeParam, FreeBusyTimeTypeParam, MembersParam, ParticipationStatusParam,
-    RangeParam, RelationshipTypeParam, RoleParam, RsvpParam, TriggerRelationshipParam,
-    ValueTypeParam,
+    add_is_utc, altrep_param, common_name_param, directory_entry_reference_param, fmt_type_param,
+    language_param, schedule_params, sent_by_param, tz_id_param, CalendarUserTypeParam,
+    DelegatedFromParam, DelegatedToParam, EncodingParam, FreeBusyTimeTypeParam, GapParam,
+    LinkRelationParam, MembersParam, ParticipationStatusParam, RangeParam, RelationshipTypeParam,
+    RoleParam, RsvpParam, TriggerRelationshipParam, ValueTypeParam,
 };
 use crate::model::param::{impl_other_component_params_builder, impl_other_params_builder, Param};
 use std::fmt::Display;
@@ -16,11 +17,15 @@ use std::marker::PhantomData;
 
 mod duration;
 mod recur;
+mod typed;
 
 use crate::error::AetoliaResult;
+use crate::extension::{ExtensionData, ExtensionValue, PropertyExtension};
 use crate::model::impl_property_access;
 pub use duration::*;
 pub use recur::*;
+use typed::impl_typed_value_access;
+pub use typed::*;
 
 pub trait AddComponentProperty {
     fn add_property(&mut self, property: ComponentProperty);
@@ -67,7 +72,7 @@ macro_rules! impl_date_time_query {
     };
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Classification {
     Public,
     Private,
@@ -89,6 +94,112 @@ impl Display for Classification {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BusyType {
+    Busy,
+    BusyUnavailable,
+    BusyTentative,
+    XName(String),
+    IanaToken(String),
+}
+
+impl Display for BusyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            BusyType::Busy => "BUSY".to_string(),
+            BusyType::BusyUnavailable => "BUSY-UNAVAILABLE".to_string(),
+            BusyType::BusyTentative => "BUSY-TENTATIVE".to_string(),
+            BusyType::XName(name) => name.to_string(),
+            BusyType::IanaToken(token) => token.to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParticipantType {
+    Active,
+    Inactive,
+    Sponsor,
+    Contact,
+    BookingContact,
+    EmergencyContact,
+    PublicityContact,
+    PlannerContact,
+    Performer,
+    Speaker,
+    XName(String),
+    IanaToken(String),
+}
+
+impl Display for ParticipantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ParticipantType::Active => "ACTIVE".to_string(),
+            ParticipantType::Inactive => "INACTIVE".to_string(),
+            ParticipantType::Sponsor => "SPONSOR".to_string(),
+            ParticipantType::Contact => "CONTACT".to_string(),
+            ParticipantType::BookingContact => "BOOKING-CONTACT".to_string(),
+            ParticipantType::EmergencyContact => "EMERGENCY-CONTACT".to_string(),
+            ParticipantType::PublicityContact => "PUBLICITY-CONTACT".to_string(),
+            ParticipantType::PlannerContact => "PLANNER-CONTACT".to_string(),
+            ParticipantType::Performer => "PERFORMER".to_string(),
+            ParticipantType::Speaker => "SPEAKER".to_string(),
+            ParticipantType::XName(name) => name.to_string(),
+            ParticipantType::IanaToken(token) => token.to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ResourceType {
+    Projector,
+    Room,
+    RemoteConferenceAudio,
+    RemoteConferenceVideo,
+    XName(String),
+    IanaToken(String),
+}
+
+impl Display for ResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ResourceType::Projector => "PROJECTOR".to_string(),
+            ResourceType::Room => "ROOM".to_string(),
+            ResourceType::RemoteConferenceAudio => "REMOTE-CONFERENCE-AUDIO".to_string(),
+            ResourceType::RemoteConferenceVideo => "REMOTE-CONFERENCE-VIDEO".to_string(),
+            ResourceType::XName(name) => name.to_string(),
+            ResourceType::IanaToken(token) => token.to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Proximity {
+    Arrive,
+    Depart,
+    Connect,
+    Disconnect,
+    XName(String),
+    IanaToken(String),
+}
+
+impl Display for Proximity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Proximity::Arrive => "ARRIVE".to_string(),
+            Proximity::Depart => "DEPART".to_string(),
+            Proximity::Connect => "CONNECT".to_string(),
+            Proximity::Disconnect => "DISCONNECT".to_string(),
+            Proximity::XName(name) => name.to_string(),
+            Proximity::IanaToken(token) => token.to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
 pub enum StatusEvent {
     Tentative,
     Confirmed,
@@ -157,17 +268,81 @@ macro_rules! impl_finish_component_property_build {
     };
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CalendarProperty {
     ProductId(ProductIdProperty),
     Version(VersionProperty),
     CalendarScale(CalendarScaleProperty),
     Method(MethodProperty),
+    /// RFC 7986, 5.1
+    /// Value type: TEXT
+    Name(NameProperty),
+    /// RFC 7986, 5.2
+    /// Value type: TEXT
+    Description(DescriptionProperty),
+    /// RFC 7986, 5.4
+    /// Value type: DATE-TIME
+    LastModified(LastModifiedProperty),
+    /// RFC 7986, 5.5
+    /// Value type: URI
+    Url(UrlProperty),
+    /// RFC 7986, 5.7
+    /// Value type: DURATION
+    RefreshInterval(RefreshIntervalProperty),
+    /// RFC 7986, 5.8
+    /// Value type: URI
+    Source(SourceProperty),
+    /// RFC 7986, 5.9
+    /// Value type: TEXT
+    Color(ColorProperty),
+    /// RFC 7986, 5.10
+    /// Value type: URI or BINARY
+    Image(ImageProperty),
     XProperty(XProperty),
     IanaProperty(IanaProperty),
 }
 
-#[derive(Debug, PartialEq)]
+impl CalendarProperty {
+    pub fn params(&self) -> &[Param] {
+        match self {
+            CalendarProperty::ProductId(p) => &p.params,
+            CalendarProperty::Version(p) => &p.params,
+            CalendarProperty::CalendarScale(p) => &p.params,
+            CalendarProperty::Method(p) => &p.params,
+            CalendarProperty::Name(p) => &p.params,
+            CalendarProperty::Description(p) => &p.params,
+            CalendarProperty::LastModified(p) => &p.params,
+            CalendarProperty::Url(p) => &p.params,
+            CalendarProperty::RefreshInterval(p) => &p.params,
+            CalendarProperty::Source(p) => &p.params,
+            CalendarProperty::Color(p) => &p.params,
+            CalendarProperty::Image(p) => &p.params,
+            CalendarProperty::XProperty(p) => &p.params,
+            CalendarProperty::IanaProperty(p) => &p.params,
+        }
+    }
+
+    pub fn params_mut(&mut self) -> &mut Vec<Param> {
+        match self {
+            CalendarProperty::ProductId(p) => &mut p.params,
+            CalendarProperty::Version(p) => &mut p.params,
+            CalendarProperty::CalendarScale(p) => &mut p.params,
+            CalendarProperty::Method(p) => &mut p.params,
+            CalendarProperty::Name(p) => &mut p.params,
+            CalendarProperty::Description(p) => &mut p.params,
+            CalendarProperty::LastModified(p) => &mut p.params,
+            CalendarProperty::Url(p) => &mut p.params,
+            CalendarProperty::RefreshInterval(p) => &mut p.params,
+            CalendarProperty::Source(p) => &mut p.params,
+            CalendarProperty::Color(p) => &mut p.params,
+            CalendarProperty::Image(p) => &mut p.params,
+            CalendarProperty::XProperty(p) => &mut p.params,
+            CalendarProperty::IanaProperty(p) => &mut p.params,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ProductIdProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -194,7 +369,7 @@ impl ProductIdPropertyBuilder {
 
 impl_other_params_builder!(ProductIdPropertyBuilder);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct VersionProperty {
     pub(crate) min_version: Option<String>,
     pub(crate) max_version: String,
@@ -227,7 +402,7 @@ impl VersionPropertyBuilder {
 
 impl_other_params_builder!(VersionPropertyBuilder);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CalendarScaleProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -254,7 +429,7 @@ impl CalendarScalePropertyBuilder {
 
 impl_other_params_builder!(CalendarScalePropertyBuilder);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MethodProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -281,7 +456,230 @@ impl MethodPropertyBuilder {
 
 impl_other_params_builder!(MethodPropertyBuilder);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(NameProperty, String);
+
+pub struct NamePropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: NameProperty,
+}
+
+impl NamePropertyBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder, value: String) -> NamePropertyBuilder {
+        NamePropertyBuilder {
+            owner,
+            inner: NameProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    altrep_param!();
+    language_param!();
+
+    impl_finish_property_build!(CalendarProperty::Name);
+}
+
+impl_other_params_builder!(NamePropertyBuilder);
+
+pub struct CalendarDescriptionPropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: DescriptionProperty,
+}
+
+impl CalendarDescriptionPropertyBuilder {
+    pub(crate) fn new(
+        owner: ICalObjectBuilder,
+        value: String,
+    ) -> CalendarDescriptionPropertyBuilder {
+        CalendarDescriptionPropertyBuilder {
+            owner,
+            inner: DescriptionProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    altrep_param!();
+    language_param!();
+
+    impl_finish_property_build!(CalendarProperty::Description);
+}
+
+impl_other_params_builder!(CalendarDescriptionPropertyBuilder);
+
+pub struct CalendarLastModifiedPropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: LastModifiedProperty,
+}
+
+impl CalendarLastModifiedPropertyBuilder {
+    pub(crate) fn new(
+        owner: ICalObjectBuilder,
+        date: time::Date,
+        time: time::Time,
+    ) -> CalendarLastModifiedPropertyBuilder {
+        CalendarLastModifiedPropertyBuilder {
+            owner,
+            inner: LastModifiedProperty {
+                value: (date, time, false).into(),
+                params: Vec::new(),
+            },
+        }
+    }
+
+    add_is_utc!();
+
+    impl_finish_property_build!(CalendarProperty::LastModified);
+}
+
+impl_other_params_builder!(CalendarLastModifiedPropertyBuilder);
+
+pub struct CalendarUrlPropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: UrlProperty,
+}
+
+impl CalendarUrlPropertyBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder, value: String) -> CalendarUrlPropertyBuilder {
+        CalendarUrlPropertyBuilder {
+            owner,
+            inner: UrlProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_property_build!(CalendarProperty::Url);
+}
+
+impl_other_params_builder!(CalendarUrlPropertyBuilder);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshIntervalProperty {
+    pub(crate) value: Duration,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(RefreshIntervalProperty, Duration);
+
+pub struct RefreshIntervalPropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: RefreshIntervalProperty,
+}
+
+impl RefreshIntervalPropertyBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder, value: Duration) -> RefreshIntervalPropertyBuilder {
+        RefreshIntervalPropertyBuilder {
+            owner,
+            inner: RefreshIntervalProperty {
+                value,
+                // The value type is required for this property, RFC 7986 section 5.7
+                params: vec![Param::ValueType(ValueTypeParam {
+                    value: Value::Duration,
+                })],
+            },
+        }
+    }
+
+    impl_finish_property_build!(CalendarProperty::RefreshInterval);
+}
+
+impl_other_params_builder!(RefreshIntervalPropertyBuilder);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(SourceProperty, String);
+
+pub struct SourcePropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: SourceProperty,
+}
+
+impl SourcePropertyBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder, value: String) -> SourcePropertyBuilder {
+        SourcePropertyBuilder {
+            owner,
+            inner: SourceProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_property_build!(CalendarProperty::Source);
+}
+
+impl_other_params_builder!(SourcePropertyBuilder);
+
+pub struct CalendarColorPropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: ColorProperty,
+}
+
+impl CalendarColorPropertyBuilder {
+    pub(crate) fn new(owner: ICalObjectBuilder, value: String) -> CalendarColorPropertyBuilder {
+        CalendarColorPropertyBuilder {
+            owner,
+            inner: ColorProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_property_build!(CalendarProperty::Color);
+}
+
+impl_other_params_builder!(CalendarColorPropertyBuilder);
+
+pub struct CalendarImagePropertyBuilder {
+    owner: ICalObjectBuilder,
+    inner: ImageProperty,
+}
+
+impl CalendarImagePropertyBuilder {
+    pub(crate) fn new_with_uri(
+        owner: ICalObjectBuilder,
+        uri: String,
+    ) -> CalendarImagePropertyBuilder {
+        CalendarImagePropertyBuilder {
+            owner,
+            inner: ImageProperty::new_with_uri(uri),
+        }
+    }
+
+    pub(crate) fn new_with_binary(
+        owner: ICalObjectBuilder,
+        binary: String,
+    ) -> CalendarImagePropertyBuilder {
+        CalendarImagePropertyBuilder {
+            owner,
+            inner: ImageProperty::new_with_binary(binary),
+        }
+    }
+
+    altrep_param!();
+    fmt_type_param!();
+
+    impl_finish_property_build!(CalendarProperty::Image);
+}
+
+impl_other_params_builder!(CalendarImagePropertyBuilder);
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ComponentProperty {
     /// RFC 5545, 3.8.1.1
     /// Value type: URI or BINARY
@@ -415,6 +813,48 @@ pub enum ComponentProperty {
     /// RFC 5545, 3.8.8.3
     /// Value type: TEXT
     RequestStatus(RequestStatusProperty),
+    /// RFC 7986, 5.9
+    /// Value type: TEXT
+    Color(ColorProperty),
+    /// RFC 7986, 5.10
+    /// Value type: URI or BINARY
+    Image(ImageProperty),
+    /// RFC 7986, 5.11
+    /// Value type: URI
+    Conference(ConferenceProperty),
+    /// RFC 7953, 3.2
+    /// Value type: TEXT
+    BusyType(BusyTypeProperty),
+    /// RFC 9073, 6.5
+    /// Value type: TEXT or URI
+    StyledDescription(StyledDescriptionProperty),
+    /// RFC 9073, 6.6
+    /// Value type: TEXT, BINARY or URI
+    StructuredData(StructuredDataProperty),
+    /// RFC 9073, 6.1
+    /// Value type: TEXT
+    LocationType(LocationTypeProperty),
+    /// RFC 9073, 6.2
+    /// Value type: TEXT
+    ParticipantType(ParticipantTypeProperty),
+    /// RFC 9073, 6.3
+    /// Value type: TEXT
+    ResourceType(ResourceTypeProperty),
+    /// RFC 9074, 6
+    /// Value type: DATE-TIME
+    Acknowledged(AcknowledgedProperty),
+    /// RFC 9074, 8.1
+    /// Value type: TEXT
+    Proximity(ProximityProperty),
+    /// RFC 9253, 7.1
+    /// Value type: URI
+    Concept(ConceptProperty),
+    /// RFC 9253, 7.2
+    /// Value type: URI, UID or XML-REFERENCE
+    Link(LinkProperty),
+    /// RFC 9253, 7.3
+    /// Value type: TEXT
+    RefId(RefIdProperty),
 }
 
 impl ComponentProperty {
@@ -462,10 +902,87 @@ impl ComponentProperty {
             ComponentProperty::Action(p) => &p.params,
             ComponentProperty::Trigger(p) => &p.params,
             ComponentProperty::Repeat(p) => &p.params,
+            ComponentProperty::Color(p) => &p.params,
+            ComponentProperty::Image(p) => &p.params,
+            ComponentProperty::Conference(p) => &p.params,
+            ComponentProperty::BusyType(p) => &p.params,
+            ComponentProperty::StyledDescription(p) => &p.params,
+            ComponentProperty::StructuredData(p) => &p.params,
+            ComponentProperty::LocationType(p) => &p.params,
+            ComponentProperty::ParticipantType(p) => &p.params,
+            ComponentProperty::ResourceType(p) => &p.params,
+            ComponentProperty::Acknowledged(p) => &p.params,
+            ComponentProperty::Proximity(p) => &p.params,
+            ComponentProperty::Concept(p) => &p.params,
+            ComponentProperty::Link(p) => &p.params,
+            ComponentProperty::RefId(p) => &p.params,
             ComponentProperty::IanaProperty(p) => &p.params,
             ComponentProperty::XProperty(p) => &p.params,
         }
     }
+
+    pub fn params_mut(&mut self) -> &mut Vec<Param> {
+        match self {
+            ComponentProperty::DateTimeStamp(p) => &mut p.params,
+            ComponentProperty::UniqueIdentifier(p) => &mut p.params,
+            ComponentProperty::DateTimeStart(p) => &mut p.params,
+            ComponentProperty::Classification(p) => &mut p.params,
+            ComponentProperty::DateTimeCreated(p) => &mut p.params,
+            ComponentProperty::Description(p) => &mut p.params,
+            ComponentProperty::GeographicPosition(p) => &mut p.params,
+            ComponentProperty::LastModified(p) => &mut p.params,
+            ComponentProperty::Location(p) => &mut p.params,
+            ComponentProperty::Organizer(p) => &mut p.params,
+            ComponentProperty::Priority(p) => &mut p.params,
+            ComponentProperty::Sequence(p) => &mut p.params,
+            ComponentProperty::Summary(p) => &mut p.params,
+            ComponentProperty::TimeTransparency(p) => &mut p.params,
+            ComponentProperty::RequestStatus(p) => &mut p.params,
+            ComponentProperty::Url(p) => &mut p.params,
+            ComponentProperty::RecurrenceId(p) => &mut p.params,
+            ComponentProperty::RecurrenceRule(p) => &mut p.params,
+            ComponentProperty::DateTimeEnd(p) => &mut p.params,
+            ComponentProperty::Duration(p) => &mut p.params,
+            ComponentProperty::Attach(p) => &mut p.params,
+            ComponentProperty::Attendee(p) => &mut p.params,
+            ComponentProperty::Categories(p) => &mut p.params,
+            ComponentProperty::Comment(p) => &mut p.params,
+            ComponentProperty::Contact(p) => &mut p.params,
+            ComponentProperty::ExceptionDateTimes(p) => &mut p.params,
+            ComponentProperty::Status(p) => &mut p.params,
+            ComponentProperty::RelatedTo(p) => &mut p.params,
+            ComponentProperty::Resources(p) => &mut p.params,
+            ComponentProperty::RecurrenceDateTimes(p) => &mut p.params,
+            ComponentProperty::DateTimeCompleted(p) => &mut p.params,
+            ComponentProperty::PercentComplete(p) => &mut p.params,
+            ComponentProperty::DateTimeDue(p) => &mut p.params,
+            ComponentProperty::FreeBusyTime(p) => &mut p.params,
+            ComponentProperty::TimeZoneId(p) => &mut p.params,
+            ComponentProperty::TimeZoneUrl(p) => &mut p.params,
+            ComponentProperty::TimeZoneOffsetTo(p) => &mut p.params,
+            ComponentProperty::TimeZoneOffsetFrom(p) => &mut p.params,
+            ComponentProperty::TimeZoneName(p) => &mut p.params,
+            ComponentProperty::Action(p) => &mut p.params,
+            ComponentProperty::Trigger(p) => &mut p.params,
+            ComponentProperty::Repeat(p) => &mut p.params,
+            ComponentProperty::Color(p) => &mut p.params,
+            ComponentProperty::Image(p) => &mut p.params,
+            ComponentProperty::Conference(p) => &mut p.params,
+            ComponentProperty::BusyType(p) => &mut p.params,
+            ComponentProperty::StyledDescription(p) => &mut p.params,
+            ComponentProperty::StructuredData(p) => &mut p.params,
+            ComponentProperty::LocationType(p) => &mut p.params,
+            ComponentProperty::ParticipantType(p) => &mut p.params,
+            ComponentProperty::ResourceType(p) => &mut p.params,
+            ComponentProperty::Acknowledged(p) => &mut p.params,
+            ComponentProperty::Proximity(p) => &mut p.params,
+            ComponentProperty::Concept(p) => &mut p.params,
+            ComponentProperty::Link(p) => &mut p.params,
+            ComponentProperty::RefId(p) => &mut p.params,
+            ComponentProperty::IanaProperty(p) => &mut p.params,
+            ComponentProperty::XProperty(p) => &mut p.params,
+        }
+    }
 }
 
 pub trait ComponentPropertyInner<T> {
@@ -488,6 +1005,14 @@ macro_rules! impl_component_property_inner {
 }
 
 impl_component_property_inner!(ClassificationProperty, Classification);
+impl_component_property_inner!(BusyTypeProperty, BusyType);
+impl_component_property_inner!(StyledDescriptionProperty, StyledDescription);
+impl_component_property_inner!(StructuredDataProperty, StructuredData);
+impl_component_property_inner!(LocationTypeProperty, LocationType);
+impl_component_property_inner!(ParticipantTypeProperty, ParticipantType);
+impl_component_property_inner!(ResourceTypeProperty, ResourceType);
+impl_component_property_inner!(AcknowledgedProperty, Acknowledged);
+impl_component_property_inner!(ProximityProperty, Proximity);
 impl_component_property_inner!(DescriptionProperty, Description);
 impl_component_property_inner!(GeographicPositionProperty, GeographicPosition);
 impl_component_property_inner!(LocationProperty, Location);
@@ -549,18 +1074,21 @@ impl_component_properties_inner!(ExceptionDateTimesProperty, ExceptionDateTimes)
 impl_component_properties_inner!(RecurrenceDateTimesProperty, RecurrenceDateTimes);
 impl_component_properties_inner!(RequestStatusProperty, RequestStatus);
 impl_component_properties_inner!(RelatedToProperty, RelatedTo);
+impl_component_properties_inner!(ConceptProperty, Concept);
+impl_component_properties_inner!(LinkProperty, Link);
+impl_component_properties_inner!(RefIdProperty, RefId);
 impl_component_properties_inner!(ResourcesProperty, Resources);
 impl_component_properties_inner!(DescriptionProperty, Description);
 impl_component_properties_inner!(FreeBusyTimeProperty, FreeBusyTime);
 impl_component_properties_inner!(TimeZoneNameProperty, TimeZoneName);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TriggerProperty {
     pub(crate) value: TriggerValue,
     pub(crate) params: Vec<Param>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TriggerValue {
     Relative(Duration),
     Absolute(CalendarDateTime),
@@ -568,14 +1096,23 @@ pub enum TriggerValue {
 
 impl_property_access!(TriggerProperty, TriggerValue);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct XProperty {
     pub(crate) name: String,
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
+    /// The decoded value, if this property is a registered extension.
+    pub(crate) extension: Option<ExtensionData>,
 }
 
 impl_property_access!(XProperty, String);
+impl_typed_value_access!(XProperty);
+
+/// The raw text for an extension value. This is empty if the value does not have the type that
+/// the extension expects, which is reported when the property is validated or serialized.
+fn raw_value(data: &ExtensionData) -> String {
+    data.serialize().unwrap_or_default()
+}
 
 pub struct XPropertyBuilder {
     owner: ICalObjectBuilder,
@@ -590,23 +1127,39 @@ impl XPropertyBuilder {
                 name,
                 value,
                 params: Vec::new(),
+                extension: None,
             },
         }
     }
 
+    pub(crate) fn new_extension<T: ExtensionValue>(
+        owner: ICalObjectBuilder,
+        extension: &PropertyExtension,
+        value: T,
+    ) -> XPropertyBuilder {
+        let data = extension.wrap(value);
+        let mut builder =
+            XPropertyBuilder::new(owner, extension.name().to_string(), raw_value(&data));
+        builder.inner.extension = Some(data);
+        builder
+    }
+
     impl_finish_property_build!(CalendarProperty::XProperty);
 }
 
 impl_other_params_builder!(XPropertyBuilder);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IanaProperty {
     pub(crate) name: String,
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
+    /// The decoded value, if this property is a registered extension.
+    pub(crate) extension: Option<ExtensionData>,
 }
 
 impl_property_access!(IanaProperty, String);
+impl_typed_value_access!(IanaProperty);
 
 pub struct IanaPropertyBuilder {
     owner: ICalObjectBuilder,
@@ -625,6 +1178,7 @@ impl IanaPropertyBuilder {
                 name,
                 value,
                 params: Vec::new(),
+                extension: None,
             },
         }
     }
@@ -650,10 +1204,23 @@ where
                 params: Vec::new(),
                 name,
                 value,
+                extension: None,
             },
         }
     }
 
+    pub(crate) fn new_extension<T: ExtensionValue>(
+        owner: P,
+        extension: &PropertyExtension,
+        value: T,
+    ) -> XComponentPropertyBuilder<P> {
+        let data = extension.wrap(value);
+        let mut builder =
+            XComponentPropertyBuilder::new(owner, extension.name().to_string(), raw_value(&data));
+        builder.inner.extension = Some(data);
+        builder
+    }
+
     impl_finish_component_property_build!(ComponentProperty::XProperty);
 }
 
@@ -675,6 +1242,7 @@ where
                 name,
                 value,
                 params: Vec::new(),
+                extension: None,
             },
         }
     }
@@ -684,7 +1252,7 @@ where
 
 impl_other_component_params_builder!(IanaComponentPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DateTimeStampProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -722,7 +1290,7 @@ where
 
 impl_other_component_params_builder!(DateTimeStampPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UniqueIdentifierProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -804,7 +1372,7 @@ impl_other_component_params_builder!(DateTimeStartPropertyBuilder<P>);
 
 impl_date_time_query!(DateTimeStartProperty);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ClassificationProperty {
     pub(crate) value: Classification,
     pub(crate) params: Vec<Param>,
@@ -836,7 +1404,7 @@ where
 
 impl_other_component_params_builder!(ClassificationPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreatedProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -870,7 +1438,7 @@ where
 
 impl_other_component_params_builder!(CreatedPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DescriptionProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -905,13 +1473,13 @@ where
 
 impl_other_component_params_builder!(DescriptionPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GeographicPositionProperty {
     pub(crate) value: GeographicPositionPropertyValue,
     pub(crate) params: Vec<Param>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GeographicPositionPropertyValue {
     pub latitude: f64,
     pub longitude: f64,
@@ -950,7 +1518,7 @@ where
 
 impl_other_component_params_builder!(GeographicPositionPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LastModifiedProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -988,7 +1556,7 @@ where
 
 impl_other_component_params_builder!(LastModifiedPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LocationProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1023,7 +1591,7 @@ where
 
 impl_other_component_params_builder!(LocationPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OrganizerProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1031,8 +1599,15 @@ pub struct OrganizerProperty {
 
 impl_property_access!(OrganizerProperty, String);
 
-pub struct OrganizerPropertyBuilder<P: AddComponentProperty> {
-    owner: P,
+impl OrganizerProperty {
+    /// The calendar user address of the organizer.
+    pub fn calendar_address(&self) -> CalendarAddress {
+        CalendarAddress::new(&self.value)
+    }
+}
+
+pub struct OrganizerPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
     inner: OrganizerProperty,
 }
 
@@ -1058,12 +1633,14 @@ where
 
     language_param!();
 
+    schedule_params!();
+
     impl_finish_component_property_build!(ComponentProperty::Organizer);
 }
 
 impl_other_component_params_builder!(OrganizerPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PriorityProperty {
     pub(crate) value: u8,
     pub(crate) params: Vec<Param>,
@@ -1095,7 +1672,7 @@ where
 
 impl_other_component_params_builder!(PriorityPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SequenceProperty {
     pub(crate) value: u32,
     pub(crate) params: Vec<Param>,
@@ -1127,13 +1704,13 @@ where
 
 impl_other_component_params_builder!(SequencePropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RequestStatusProperty {
     pub(crate) value: RequestStatusPropertyValue,
     pub(crate) params: Vec<Param>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RequestStatusPropertyValue {
     pub(crate) status_code: Vec<u32>,
     pub(crate) description: String,
@@ -1177,7 +1754,7 @@ where
 
 impl_other_component_params_builder!(RequestStatusPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SummaryProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1212,7 +1789,7 @@ where
 
 impl_other_component_params_builder!(SummaryPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeTransparencyProperty {
     pub(crate) value: TimeTransparency,
     pub(crate) params: Vec<Param>,
@@ -1244,7 +1821,7 @@ where
 
 impl_other_component_params_builder!(TimeTransparencyPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UrlProperty {
     // TODO should be a URI
     pub(crate) value: String,
@@ -1277,7 +1854,7 @@ where
 
 impl_other_component_params_builder!(UrlPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecurrenceIdProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -1330,7 +1907,7 @@ where
 
 impl_other_component_params_builder!(RecurrenceIdPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecurrenceRuleProperty {
     pub(crate) value: RecurrenceRule,
     pub(crate) params: Vec<Param>,
@@ -1410,7 +1987,9 @@ where
 
 impl_other_component_params_builder!(DateTimeEndPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+impl_date_time_query!(DateTimeEndProperty);
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct DurationProperty {
     pub(crate) value: Duration,
     pub(crate) params: Vec<Param>,
@@ -1442,7 +2021,7 @@ where
 
 impl_other_component_params_builder!(DurationPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AttachProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1486,24 +2065,14 @@ where
         }
     }
 
-    pub fn add_fmt_type<U: ToString, V: ToString>(
-        mut self,
-        type_name: U,
-        sub_type_name: V,
-    ) -> Self {
-        self.inner.params.push(Param::FormatType(FormatTypeParam {
-            type_name: type_name.to_string(),
-            sub_type_name: sub_type_name.to_string(),
-        }));
-        self
-    }
+    fmt_type_param!();
 
     impl_finish_component_property_build!(ComponentProperty::Attach);
 }
 
 impl_other_component_params_builder!(AttachPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AttendeeProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1511,6 +2080,13 @@ pub struct AttendeeProperty {
 
 impl_property_access!(AttendeeProperty, String);
 
+impl AttendeeProperty {
+    /// The calendar user address of the attendee.
+    pub fn calendar_address(&self) -> CalendarAddress {
+        CalendarAddress::new(&self.value)
+    }
+}
+
 pub struct AttendeePropertyBuilder<P: AddComponentProperty, PS> {
     owner: P,
     inner: AttendeeProperty,
@@ -1589,13 +2165,14 @@ where
     common_name_param!();
     directory_entry_reference_param!();
     language_param!();
+    schedule_params!();
 
     impl_finish_component_property_build!(ComponentProperty::Attendee);
 }
 
 impl_other_component_params_builder!(AttendeePropertyBuilder<P, PS>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CategoriesProperty {
     pub(crate) value: Vec<String>,
     pub(crate) params: Vec<Param>,
@@ -1629,7 +2206,7 @@ where
 
 impl_other_component_params_builder!(CategoriesPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CommentProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1664,7 +2241,7 @@ where
 
 impl_other_component_params_builder!(CommentPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ContactProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1699,7 +2276,7 @@ where
 
 impl_other_component_params_builder!(ContactPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExceptionDateTimesProperty {
     pub(crate) value: Vec<CalendarDateTime>,
     pub(crate) params: Vec<Param>,
@@ -1738,7 +2315,7 @@ where
 
 impl_other_component_params_builder!(ExceptionDateTimesPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StatusProperty {
     pub(crate) value: Status,
     pub(crate) params: Vec<Param>,
@@ -1770,7 +2347,7 @@ where
 
 impl_other_component_params_builder!(StatusPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RelatedToProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -1797,6 +2374,20 @@ where
         }
     }
 
+    pub(crate) fn new_with_value_type(
+        owner: P,
+        value_type: Value,
+        value: String,
+    ) -> RelatedToPropertyBuilder<P> {
+        RelatedToPropertyBuilder {
+            owner,
+            inner: RelatedToProperty {
+                value,
+                params: vec![Param::ValueType(ValueTypeParam { value: value_type })],
+            },
+        }
+    }
+
     pub fn add_relationship_type(mut self, relationship_type: RelationshipType) -> Self {
         self.inner
             .params
@@ -1806,12 +2397,18 @@ where
         self
     }
 
+    /// Add a GAP param, the lead or lag time between two related components, RFC 9253 section 6.2
+    pub fn add_gap(mut self, duration: Duration) -> Self {
+        self.inner.params.push(Param::Gap(GapParam { duration }));
+        self
+    }
+
     impl_finish_component_property_build!(ComponentProperty::RelatedTo);
 }
 
 impl_other_component_params_builder!(RelatedToPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResourcesProperty {
     pub(crate) value: Vec<String>,
     pub(crate) params: Vec<Param>,
@@ -1902,13 +2499,13 @@ pub enum PeriodEnd {
     Duration(Duration),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecurrenceDateTimesProperty {
     pub(crate) value: RecurrenceDateTimesPropertyValue,
     pub(crate) params: Vec<Param>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RecurrenceDateTimesPropertyValue {
     DateTimes(Vec<CalendarDateTime>),
     Periods(Vec<Period>),
@@ -1962,7 +2559,7 @@ where
 
 impl_other_component_params_builder!(RecurrenceDateTimesPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DateTimeCompletedProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -1996,7 +2593,7 @@ where
 
 impl_other_component_params_builder!(CompletedPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PercentCompleteProperty {
     pub(crate) value: u8,
     pub(crate) params: Vec<Param>,
@@ -2076,7 +2673,7 @@ where
 
 impl_other_component_params_builder!(DateTimeDuePropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FreeBusyTimeProperty {
     pub(crate) value: Vec<Period>,
     pub(crate) params: Vec<Param>,
@@ -2114,7 +2711,7 @@ where
 
 impl_other_component_params_builder!(FreeBusyTimePropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneIdProperty {
     pub(crate) value: TimeZoneIdPropertyValue,
     pub(crate) params: Vec<Param>,
@@ -2159,7 +2756,7 @@ where
 
 impl_other_component_params_builder!(TimeZoneIdPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneUrlProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -2191,7 +2788,7 @@ where
 
 impl_other_component_params_builder!(TimeZoneUrlPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TimeZoneOffset {
     pub(crate) sign: i8,
     pub(crate) hours: u8,
@@ -2210,7 +2807,7 @@ impl TimeZoneOffset {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneOffsetToProperty {
     pub(crate) value: TimeZoneOffset,
     pub(crate) params: Vec<Param>,
@@ -2242,7 +2839,7 @@ where
 
 impl_other_component_params_builder!(TimeZoneOffsetToPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneOffsetFromProperty {
     pub(crate) value: TimeZoneOffset,
     pub(crate) params: Vec<Param>,
@@ -2274,7 +2871,7 @@ where
 
 impl_other_component_params_builder!(TimeZoneOffsetFromPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZoneNameProperty {
     pub(crate) value: String,
     pub(crate) params: Vec<Param>,
@@ -2317,7 +2914,7 @@ pub enum Action {
     IanaToken(String),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ActionProperty {
     pub(crate) value: Action,
     pub(crate) params: Vec<Param>,
@@ -2349,7 +2946,7 @@ where
 
 impl_other_component_params_builder!(ActionPropertyBuilder<P>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RelativeTriggerProperty {
     pub(crate) value: Duration,
     pub(crate) params: Vec<Param>,
@@ -2395,7 +2992,7 @@ where
 
 impl_other_component_params_builder!(RelativeTriggerPropertyBuilder<P>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct AbsoluteTriggerProperty {
     pub(crate) value: CalendarDateTime,
     pub(crate) params: Vec<Param>,
@@ -2440,7 +3037,7 @@ where
 
 impl_other_component_params_builder!(AbsoluteTriggerPropertyBuilder<P>);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RepeatProperty {
     pub(crate) value: u32,
     pub(crate) params: Vec<Param>,
@@ -2471,3 +3068,548 @@ where
 }
 
 impl_other_component_params_builder!(RepeatPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ColorProperty, String);
+
+pub struct ColorPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ColorProperty,
+}
+
+impl<P> ColorPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: String) -> ColorPropertyBuilder<P> {
+        ColorPropertyBuilder {
+            owner,
+            inner: ColorProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::Color);
+}
+
+impl_other_component_params_builder!(ColorPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ImageProperty, String);
+
+impl ImageProperty {
+    fn new_with_uri(uri: String) -> ImageProperty {
+        ImageProperty {
+            value: uri,
+            // The value type is required if the value is a URI, RFC 7986 section 5.10
+            params: vec![Param::ValueType(ValueTypeParam { value: Value::Uri })],
+        }
+    }
+
+    fn new_with_binary(binary: String) -> ImageProperty {
+        ImageProperty {
+            value: binary,
+            params: vec![
+                Param::Encoding(EncodingParam {
+                    encoding: Encoding::Base64,
+                }),
+                Param::ValueType(ValueTypeParam {
+                    value: Value::Binary,
+                }),
+            ],
+        }
+    }
+}
+
+pub struct ImagePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ImageProperty,
+}
+
+impl<P> ImagePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new_with_uri(owner: P, uri: String) -> ImagePropertyBuilder<P> {
+        ImagePropertyBuilder {
+            owner,
+            inner: ImageProperty::new_with_uri(uri),
+        }
+    }
+
+    pub(crate) fn new_with_binary(owner: P, binary: String) -> ImagePropertyBuilder<P> {
+        ImagePropertyBuilder {
+            owner,
+            inner: ImageProperty::new_with_binary(binary),
+        }
+    }
+
+    altrep_param!();
+    fmt_type_param!();
+
+    impl_finish_component_property_build!(ComponentProperty::Image);
+}
+
+impl_other_component_params_builder!(ImagePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConferenceProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ConferenceProperty, String);
+
+pub struct ConferencePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ConferenceProperty,
+}
+
+impl<P> ConferencePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: String) -> ConferencePropertyBuilder<P> {
+        ConferencePropertyBuilder {
+            owner,
+            inner: ConferenceProperty {
+                value,
+                // The value type is required for this property, RFC 7986 section 5.11
+                params: vec![Param::ValueType(ValueTypeParam { value: Value::Uri })],
+            },
+        }
+    }
+
+    language_param!();
+
+    impl_finish_component_property_build!(ComponentProperty::Conference);
+}
+
+impl_other_component_params_builder!(ConferencePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusyTypeProperty {
+    pub(crate) value: BusyType,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(BusyTypeProperty, BusyType);
+
+pub struct BusyTypePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: BusyTypeProperty,
+}
+
+impl<P> BusyTypePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: BusyType) -> BusyTypePropertyBuilder<P> {
+        BusyTypePropertyBuilder {
+            owner,
+            inner: BusyTypeProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::BusyType);
+}
+
+impl_other_component_params_builder!(BusyTypePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledDescriptionProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(StyledDescriptionProperty, String);
+
+pub struct StyledDescriptionPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: StyledDescriptionProperty,
+}
+
+impl<P> StyledDescriptionPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new_with_text(owner: P, text: String) -> StyledDescriptionPropertyBuilder<P> {
+        StyledDescriptionPropertyBuilder {
+            owner,
+            inner: StyledDescriptionProperty {
+                value: text,
+                // The value type is required for this property, RFC 9073 section 6.5
+                params: vec![Param::ValueType(ValueTypeParam { value: Value::Text })],
+            },
+        }
+    }
+
+    pub(crate) fn new_with_uri(owner: P, uri: String) -> StyledDescriptionPropertyBuilder<P> {
+        StyledDescriptionPropertyBuilder {
+            owner,
+            inner: StyledDescriptionProperty {
+                value: uri,
+                params: vec![Param::ValueType(ValueTypeParam { value: Value::Uri })],
+            },
+        }
+    }
+
+    altrep_param!();
+    language_param!();
+    fmt_type_param!();
+
+    impl_finish_component_property_build!(ComponentProperty::StyledDescription);
+}
+
+impl_other_component_params_builder!(StyledDescriptionPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredDataProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(StructuredDataProperty, String);
+
+pub struct StructuredDataPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: StructuredDataProperty,
+}
+
+impl<P> StructuredDataPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new_with_text(owner: P, text: String) -> StructuredDataPropertyBuilder<P> {
+        StructuredDataPropertyBuilder {
+            owner,
+            inner: StructuredDataProperty {
+                value: text,
+                // The value type is required for this property, RFC 9073 section 6.6
+                params: vec![Param::ValueType(ValueTypeParam { value: Value::Text })],
+            },
+        }
+    }
+
+    pub(crate) fn new_with_uri(owner: P, uri: String) -> StructuredDataPropertyBuilder<P> {
+        StructuredDataPropertyBuilder {
+            owner,
+            inner: StructuredDataProperty {
+                value: uri,
+                params: vec![Param::ValueType(ValueTypeParam { value: Value::Uri })],
+            },
+        }
+    }
+
+    pub(crate) fn new_with_binary(owner: P, binary: String) -> StructuredDataPropertyBuilder<P> {
+        StructuredDataPropertyBuilder {
+            owner,
+            inner: StructuredDataProperty {
+                value: binary,
+                params: vec![
+                    Param::Encoding(EncodingParam {
+                        encoding: Encoding::Base64,
+                    }),
+                    Param::ValueType(ValueTypeParam {
+                        value: Value::Binary,
+                    }),
+                ],
+            },
+        }
+    }
+
+    fmt_type_param!();
+
+    impl_finish_component_property_build!(ComponentProperty::StructuredData);
+}
+
+impl_other_component_params_builder!(StructuredDataPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationTypeProperty {
+    pub(crate) value: Vec<String>,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(LocationTypeProperty, Vec<String>);
+
+pub struct LocationTypePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: LocationTypeProperty,
+}
+
+impl<P> LocationTypePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: Vec<String>) -> LocationTypePropertyBuilder<P> {
+        LocationTypePropertyBuilder {
+            owner,
+            inner: LocationTypeProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::LocationType);
+}
+
+impl_other_component_params_builder!(LocationTypePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticipantTypeProperty {
+    pub(crate) value: ParticipantType,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ParticipantTypeProperty, ParticipantType);
+
+pub struct ParticipantTypePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ParticipantTypeProperty,
+}
+
+impl<P> ParticipantTypePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: ParticipantType) -> ParticipantTypePropertyBuilder<P> {
+        ParticipantTypePropertyBuilder {
+            owner,
+            inner: ParticipantTypeProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::ParticipantType);
+}
+
+impl_other_component_params_builder!(ParticipantTypePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceTypeProperty {
+    pub(crate) value: ResourceType,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ResourceTypeProperty, ResourceType);
+
+pub struct ResourceTypePropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ResourceTypeProperty,
+}
+
+impl<P> ResourceTypePropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: ResourceType) -> ResourceTypePropertyBuilder<P> {
+        ResourceTypePropertyBuilder {
+            owner,
+            inner: ResourceTypeProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::ResourceType);
+}
+
+impl_other_component_params_builder!(ResourceTypePropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcknowledgedProperty {
+    pub(crate) value: CalendarDateTime,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(AcknowledgedProperty, CalendarDateTime);
+
+pub struct AcknowledgedPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: AcknowledgedProperty,
+}
+
+impl<P> AcknowledgedPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(
+        owner: P,
+        date: time::Date,
+        time: time::Time,
+    ) -> AcknowledgedPropertyBuilder<P> {
+        AcknowledgedPropertyBuilder {
+            owner,
+            inner: AcknowledgedProperty {
+                // The value is required to be UTC, RFC 9074 section 6
+                value: (date, time, true).into(),
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::Acknowledged);
+}
+
+impl_other_component_params_builder!(AcknowledgedPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProximityProperty {
+    pub(crate) value: Proximity,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ProximityProperty, Proximity);
+
+pub struct ProximityPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ProximityProperty,
+}
+
+impl<P> ProximityPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: Proximity) -> ProximityPropertyBuilder<P> {
+        ProximityPropertyBuilder {
+            owner,
+            inner: ProximityProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::Proximity);
+}
+
+impl_other_component_params_builder!(ProximityPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConceptProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(ConceptProperty, String);
+
+pub struct ConceptPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: ConceptProperty,
+}
+
+impl<P> ConceptPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: String) -> ConceptPropertyBuilder<P> {
+        ConceptPropertyBuilder {
+            owner,
+            inner: ConceptProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::Concept);
+}
+
+impl_other_component_params_builder!(ConceptPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(LinkProperty, String);
+
+pub struct LinkPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: LinkProperty,
+}
+
+impl<P> LinkPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(
+        owner: P,
+        value_type: Value,
+        value: String,
+        relation: String,
+    ) -> LinkPropertyBuilder<P> {
+        LinkPropertyBuilder {
+            owner,
+            inner: LinkProperty {
+                value,
+                // The value type and link relation are required for this property, RFC 9253
+                // section 7.2
+                params: vec![
+                    Param::ValueType(ValueTypeParam { value: value_type }),
+                    Param::LinkRelation(LinkRelationParam { relation }),
+                ],
+            },
+        }
+    }
+
+    language_param!();
+    fmt_type_param!();
+
+    impl_finish_component_property_build!(ComponentProperty::Link);
+}
+
+impl_other_component_params_builder!(LinkPropertyBuilder<P>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefIdProperty {
+    pub(crate) value: String,
+    pub(crate) params: Vec<Param>,
+}
+
+impl_property_access!(RefIdProperty, String);
+
+pub struct RefIdPropertyBuilder<P: AddComponentProperty> {
+    owner: P,
+    inner: RefIdProperty,
+}
+
+impl<P> RefIdPropertyBuilder<P>
+where
+    P: AddComponentProperty,
+{
+    pub(crate) fn new(owner: P, value: String) -> RefIdPropertyBuilder<P> {
+        RefIdPropertyBuilder {
+            owner,
+            inner: RefIdProperty {
+                value,
+                params: Vec::new(),
+            },
+        }
+    }
+
+    impl_finish_component_property_build!(ComponentProperty::RefId);
+}
+
+impl_other_component_params_builder!(RefIdPropertyBuilder<P>);
@@ -1,6 +1,6 @@
-use crate::common::{CalendarDateTime, OffsetWeekday, RecurFreq, Weekday};
+use crate::common::{CalendarDateTime, OffsetWeekday, RecurFreq, RecurMonth, Skip, Weekday};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum RecurRulePart {
     Freq(RecurFreq),
     Until(CalendarDateTime),
@@ -13,12 +13,14 @@ pub enum RecurRulePart {
     ByMonthDay(Vec<i8>),
     ByYearDay(Vec<i16>),
     ByWeekNumber(Vec<i8>),
-    ByMonth(Vec<time::Month>),
+    ByMonth(Vec<RecurMonth>),
     BySetPos(Vec<i16>),
     WeekStart(Weekday),
+    RScale(String),
+    Skip(Skip),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RecurrenceRule {
     pub parts: Vec<RecurRulePart>,
 }
@@ -91,8 +93,12 @@ impl RecurrenceRule {
         self
     }
 
-    pub fn set_by_month(mut self, by_month: Vec<time::Month>) -> Self {
-        self.parts.push(RecurRulePart::ByMonth(by_month));
+    /// Set the months, which may be [time::Month] values or [RecurMonth] values for leap months
+    /// in other calendar scales.
+    pub fn set_by_month<M: Into<RecurMonth>>(mut self, by_month: Vec<M>) -> Self {
+        self.parts.push(RecurRulePart::ByMonth(
+            by_month.into_iter().map(Into::into).collect(),
+        ));
         self
     }
 
@@ -105,4 +111,15 @@ impl RecurrenceRule {
         self.parts.push(RecurRulePart::WeekStart(week_start));
         self
     }
+
+    /// Set the calendar scale that the rule is expanded in, such as `HEBREW` or `CHINESE`.
+    pub fn set_rscale<V: ToString>(mut self, rscale: V) -> Self {
+        self.parts.push(RecurRulePart::RScale(rscale.to_string()));
+        self
+    }
+
+    pub fn set_skip(mut self, skip: Skip) -> Self {
+        self.parts.push(RecurRulePart::Skip(skip));
+        self
+    }
 }
@@ -0,0 +1,220 @@
+use crate::common::{CalendarDateTime, Value};
+use crate::model::property::{Duration, Period, RecurrenceRule, TimeZoneOffset};
+use std::collections::HashMap;
+
+/// A property value decoded into one of the value data types from RFC 5545, section 3.3.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedValue {
+    /// Decoded binary content, the property value is expected to be base64 encoded.
+    Binary(Vec<u8>),
+    Boolean(bool),
+    CalendarAddress(String),
+    Date(time::Date),
+    DateTime(CalendarDateTime),
+    Duration(Duration),
+    Float(f64),
+    Integer(i32),
+    Period(Period),
+    Recurrence(RecurrenceRule),
+    /// Unescaped text.
+    Text(String),
+    /// A time of day and whether it is in UTC.
+    Time(time::Time, bool),
+    Uri(String),
+    UtcOffset(TimeZoneOffset),
+    /// A value with an X- or IANA value type, which cannot be decoded. Holds the raw value.
+    Other(String),
+}
+
+/// Value types of properties that are not defined by RFC 5545.
+///
+/// The value of an X- or IANA property is text unless it has a `VALUE` param. Registering a
+/// property name here changes the value type that is used when there is no `VALUE` param.
+#[derive(Debug, Clone, Default)]
+pub struct ValueTypeRegistry {
+    types: HashMap<String, Value>,
+}
+
+impl ValueTypeRegistry {
+    pub fn new() -> Self {
+        ValueTypeRegistry::default()
+    }
+
+    /// Register the value type of a property. Property names are matched case-insensitively.
+    pub fn register<N: ToString>(mut self, name: N, value_type: Value) -> Self {
+        self.types
+            .insert(name.to_string().to_ascii_uppercase(), value_type);
+        self
+    }
+
+    pub fn value_type(&self, name: &str) -> Option<&Value> {
+        self.types.get(&name.to_ascii_uppercase())
+    }
+}
+
+macro_rules! impl_typed_value_access {
+    ($for_type:ty) => {
+        impl $for_type {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+
+            /// The value type of this property. A `VALUE` param takes precedence over a type
+            /// registered for the property name, and the default is `TEXT`.
+            pub fn value_type(
+                &self,
+                registry: Option<&$crate::model::property::ValueTypeRegistry>,
+            ) -> $crate::common::Value {
+                use $crate::model::access::PropertyAccess;
+
+                self.get_param::<$crate::model::param::ValueTypeParam>()
+                    .map(|p| &p.value)
+                    .or_else(|| registry.and_then(|r| r.value_type(&self.name)))
+                    .cloned()
+                    .unwrap_or($crate::common::Value::Text)
+            }
+
+            /// Decode the value of this property using its `VALUE` param, or as text.
+            ///
+            /// Value types that allow multiple values are split on commas, so a list of values
+            /// decodes to more than one [TypedValue].
+            pub fn typed_values(
+                &self,
+            ) -> $crate::error::AetoliaResult<Vec<$crate::model::property::TypedValue>> {
+                $crate::convert::decode_typed_values(&self.value_type(None), &self.value)
+            }
+
+            /// Decode the value of this property, falling back to the type registered for its
+            /// name when there is no `VALUE` param.
+            pub fn typed_values_with(
+                &self,
+                registry: &$crate::model::property::ValueTypeRegistry,
+            ) -> $crate::error::AetoliaResult<Vec<$crate::model::property::TypedValue>> {
+                $crate::convert::decode_typed_values(&self.value_type(Some(registry)), &self.value)
+            }
+
+            /// The decoded value, if this property is a registered extension.
+            pub fn extension(&self) -> Option<&$crate::extension::ExtensionData> {
+                self.extension.as_ref()
+            }
+
+            /// The decoded value as the type declared by its extension.
+            pub fn extension_value<T: std::any::Any>(&self) -> Option<&T> {
+                self.extension.as_ref().and_then(|e| e.downcast_ref::<T>())
+            }
+
+            /// Replace the value of this property with a value for the given extension.
+            pub fn set_extension_value<T: $crate::extension::ExtensionValue>(
+                &mut self,
+                extension: &$crate::extension::PropertyExtension,
+                value: T,
+            ) -> $crate::error::AetoliaResult<()> {
+                let data = extension.wrap(value);
+                self.value = data.serialize()?;
+                self.extension = Some(data);
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_typed_value_access;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::access::ComponentAccess;
+    use crate::ops::load_ical;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+X-WR-CALNAME:Work\r\n\
+BEGIN:VEVENT\r\n\
+UID:1\r\n\
+DTSTAMP:20240101T120000Z\r\n\
+X-REMINDER;VALUE=DATE-TIME:20240102T090000Z\r\n\
+x-attempts;VALUE=INTEGER:3,-4\r\n\
+X-CHECKED:TRUE\r\n\
+X-NOTE;X-LANG=en:one\\, two,three\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn find_and_decode() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let event = &object.components[0];
+
+        let reminder = event.find_x_properties("x-reminder");
+        assert_eq!(1, reminder.len());
+        assert_eq!("X-REMINDER", reminder[0].name());
+        assert_eq!(
+            vec![TypedValue::DateTime(
+                (
+                    time::Date::from_calendar_date(2024, time::Month::January, 2).unwrap(),
+                    Some(time::Time::from_hms(9, 0, 0).unwrap()),
+                    true
+                )
+                    .into()
+            )],
+            reminder[0].typed_values().unwrap()
+        );
+
+        let attempts = event.find_x_properties("X-ATTEMPTS");
+        assert_eq!(
+            vec![TypedValue::Integer(3), TypedValue::Integer(-4)],
+            attempts[0].typed_values().unwrap()
+        );
+
+        let note = event.find_x_properties("X-NOTE");
+        assert_eq!(1, note[0].params.len());
+        assert_eq!(
+            vec![
+                TypedValue::Text("one, two".to_string()),
+                TypedValue::Text("three".to_string())
+            ],
+            note[0].typed_values().unwrap()
+        );
+
+        let calendar_name = object.find_x_properties("X-WR-CALNAME");
+        assert_eq!(
+            vec![TypedValue::Text("Work".to_string())],
+            calendar_name[0].typed_values().unwrap()
+        );
+    }
+
+    #[test]
+    fn registered_value_type() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let event = &object.components[0];
+
+        let registry = ValueTypeRegistry::new()
+            .register("x-checked", Value::Boolean)
+            .register("X-REMINDER", Value::Text);
+
+        let checked = event.find_x_properties("X-CHECKED");
+        assert_eq!(
+            vec![TypedValue::Text("TRUE".to_string())],
+            checked[0].typed_values().unwrap()
+        );
+        assert_eq!(
+            vec![TypedValue::Boolean(true)],
+            checked[0].typed_values_with(&registry).unwrap()
+        );
+
+        // The VALUE param wins over the registered type
+        let reminder = event.find_x_properties("X-REMINDER");
+        assert_eq!(Value::DateTime, reminder[0].value_type(Some(&registry)));
+    }
+
+    #[test]
+    fn invalid_value() {
+        let object = load_ical(CALENDAR).unwrap().pop().unwrap();
+        let event = &object.components[0];
+
+        let registry = ValueTypeRegistry::new().register("X-NOTE", Value::Integer);
+        assert!(event.find_x_properties("X-NOTE")[0]
+            .typed_values_with(&registry)
+            .is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! Walking the model without matching on every component and property type.
+//!
+//! Implement [Visitor] or [VisitorMut] and override the methods for the parts of the model that
+//! are of interest. The default implementations call the matching `walk_*` function, which visits
+//! everything contained in that part of the model. An overridden method can call the `walk_*`
+//! function itself to keep descending, or skip it to prune the walk.
+
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::Param;
+use crate::model::property::{CalendarProperty, ComponentProperty};
+
+/// Read-only traversal of the model.
+pub trait Visitor {
+    fn visit_object(&mut self, object: &ICalObject) {
+        walk_object(self, object);
+    }
+
+    fn visit_calendar_property(&mut self, property: &CalendarProperty) {
+        walk_calendar_property(self, property);
+    }
+
+    /// Visit a component. This is called for the top level components of a calendar and for
+    /// nested components, such as alarms and time zone observances.
+    fn visit_component(&mut self, component: &CalendarComponent) {
+        walk_component(self, component);
+    }
+
+    fn visit_component_property(&mut self, property: &ComponentProperty) {
+        walk_component_property(self, property);
+    }
+
+    fn visit_param(&mut self, _param: &Param) {}
+}
+
+pub fn walk_object<V: Visitor + ?Sized>(visitor: &mut V, object: &ICalObject) {
+    for property in &object.properties {
+        visitor.visit_calendar_property(property);
+    }
+
+    for component in &object.components {
+        visitor.visit_component(component);
+    }
+}
+
+pub fn walk_calendar_property<V: Visitor + ?Sized>(visitor: &mut V, property: &CalendarProperty) {
+    for param in property.params() {
+        visitor.visit_param(param);
+    }
+}
+
+pub fn walk_component<V: Visitor + ?Sized>(visitor: &mut V, component: &CalendarComponent) {
+    for property in component.properties() {
+        visitor.visit_component_property(property);
+    }
+
+    for nested in component.nested_components() {
+        visitor.visit_component(nested);
+    }
+}
+
+pub fn walk_component_property<V: Visitor + ?Sized>(visitor: &mut V, property: &ComponentProperty) {
+    for param in property.params() {
+        visitor.visit_param(param);
+    }
+}
+
+/// Mutable traversal of the model.
+pub trait VisitorMut {
+    fn visit_object_mut(&mut self, object: &mut ICalObject) {
+        walk_object_mut(self, object);
+    }
+
+    fn visit_calendar_property_mut(&mut self, property: &mut CalendarProperty) {
+        walk_calendar_property_mut(self, property);
+    }
+
+    /// Visit a component. This is called for the top level components of a calendar and for
+    /// nested components, such as alarms and time zone observances.
+    fn visit_component_mut(&mut self, component: &mut CalendarComponent) {
+        walk_component_mut(self, component);
+    }
+
+    fn visit_component_property_mut(&mut self, property: &mut ComponentProperty) {
+        walk_component_property_mut(self, property);
+    }
+
+    fn visit_param_mut(&mut self, _param: &mut Param) {}
+}
+
+pub fn walk_object_mut<V: VisitorMut + ?Sized>(visitor: &mut V, object: &mut ICalObject) {
+    for property in &mut object.properties {
+        visitor.visit_calendar_property_mut(property);
+    }
+
+    for component in &mut object.components {
+        visitor.visit_component_mut(component);
+    }
+}
+
+pub fn walk_calendar_property_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    property: &mut CalendarProperty,
+) {
+    for param in property.params_mut() {
+        visitor.visit_param_mut(param);
+    }
+}
+
+pub fn walk_component_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    component: &mut CalendarComponent,
+) {
+    for property in component.properties_mut() {
+        visitor.visit_component_property_mut(property);
+    }
+
+    if let Some(nested) = component.nested_components_mut() {
+        for nested in nested {
+            visitor.visit_component_mut(nested);
+        }
+    }
+}
+
+pub fn walk_component_property_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    property: &mut ComponentProperty,
+) {
+    for param in property.params_mut() {
+        visitor.visit_param_mut(param);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::access::PropertyAccess;
+    use crate::model::param::{CommonNameParam, OtherParamsBuilder};
+    use crate::model::property::{AttendeeProperty, Duration, TimeZoneOffset};
+    use crate::validate::{
+        calendar_property_name, component_name, component_property_name, param_name,
+    };
+
+    fn make_object() -> ICalObject {
+        ICalObject::builder()
+            .add_product_id("-//test//EN")
+            .add_x_param("x-source", "test")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_event_component()
+            .add_summary("Lunch")
+            .finish_property()
+            .add_attendee("mailto:alice@example.com")
+            .add_common_name("Alice")
+            .finish_property()
+            .add_display_alarm()
+            .add_action()
+            .finish_property()
+            .add_relative_trigger(Duration::minutes(-1, 5).build())
+            .finish_property()
+            .add_description("Lunch soon")
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .add_time_zone_component()
+            .add_time_zone_id("Europe/London", false)
+            .finish_property()
+            .add_standard_time(|b| {
+                b.add_time_zone_offset_from(TimeZoneOffset::new(1, 1, 0, None))
+                    .finish_property()
+            })
+            .finish_component()
+            .build()
+    }
+
+    #[derive(Default)]
+    struct Names(Vec<String>);
+
+    impl Visitor for Names {
+        fn visit_calendar_property(&mut self, property: &CalendarProperty) {
+            self.0.push(calendar_property_name(property).to_string());
+            walk_calendar_property(self, property);
+        }
+
+        fn visit_component(&mut self, component: &CalendarComponent) {
+            self.0.push(component_name(component).to_string());
+            walk_component(self, component);
+        }
+
+        fn visit_component_property(&mut self, property: &ComponentProperty) {
+            self.0.push(component_property_name(property).to_string());
+            walk_component_property(self, property);
+        }
+
+        fn visit_param(&mut self, param: &Param) {
+            self.0.push(param_name(param).to_string());
+        }
+    }
+
+    #[test]
+    fn visit_everything() {
+        let object = make_object();
+
+        let mut names = Names::default();
+        names.visit_object(&object);
+
+        assert_eq!(
+            vec![
+                "PRODID",
+                "x-source",
+                "VERSION",
+                "VEVENT",
+                "SUMMARY",
+                "ATTENDEE",
+                "CN",
+                "VALARM",
+                "ACTION",
+                "TRIGGER",
+                "DESCRIPTION",
+                "VTIMEZONE",
+                "TZID",
+                "STANDARD",
+                "TZOFFSETFROM",
+            ],
+            names.0
+        );
+    }
+
+    struct SkipAlarms(usize);
+
+    impl Visitor for SkipAlarms {
+        fn visit_component(&mut self, component: &CalendarComponent) {
+            if !matches!(component, CalendarComponent::Alarm(_)) {
+                walk_component(self, component);
+            }
+        }
+
+        fn visit_component_property(&mut self, _property: &ComponentProperty) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn prune_walk() {
+        let object = make_object();
+
+        let mut counter = SkipAlarms(0);
+        counter.visit_object(&object);
+
+        // SUMMARY, ATTENDEE, TZID and TZOFFSETFROM
+        assert_eq!(4, counter.0);
+    }
+
+    struct Redact;
+
+    impl VisitorMut for Redact {
+        fn visit_param_mut(&mut self, param: &mut Param) {
+            if let Param::CommonName(CommonNameParam { name }) = param {
+                *name = "redacted".to_string();
+            }
+        }
+    }
+
+    #[test]
+    fn mutate_params() {
+        let mut object = make_object();
+
+        Redact.visit_object_mut(&mut object);
+
+        let event = &object.components[0];
+        let attendee = event.get_properties::<AttendeeProperty>()[0];
+        assert_eq!(
+            "redacted",
+            attendee.get_param::<CommonNameParam>().unwrap().name
+        );
+    }
+}
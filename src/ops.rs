@@ -1,5 +1,6 @@
 use crate::convert::ToModel;
 use crate::error::{AetoliaError, AetoliaResult};
+use crate::extension::ExtensionRegistry;
 use crate::model::object::ICalObject;
 use crate::parser::{content_line_first_pass, ical_stream, Error};
 use std::io::Read;
@@ -14,6 +15,9 @@ use std::io::Read;
 /// you want to ensure that the data is reasonably correct. If you plan to ingest the data into
 /// another system, you should validate the result of this function because the parser
 /// and model permit a lot of inputs that could confuse other systems.
+///
+/// X- and IANA properties are loaded with their raw text values, use [load_ical_with_extensions]
+/// to also decode the values of application defined properties.
 pub fn load_ical(input: impl AsRef<[u8]>) -> AetoliaResult<Vec<ICalObject>> {
     let (rem, content) = content_line_first_pass::<Error>(input.as_ref())
         .map_err(|e| AetoliaError::other(format!("First pass failed: {e}")))?;
@@ -32,6 +36,21 @@ pub fn load_ical(input: impl AsRef<[u8]>) -> AetoliaResult<Vec<ICalObject>> {
     Ok(model)
 }
 
+/// Load iCalendar data, decoding the values of properties that are declared in the registry.
+///
+/// This is the same as [load_ical], followed by [ExtensionRegistry::decode] for each object.
+pub fn load_ical_with_extensions(
+    input: impl AsRef<[u8]>,
+    registry: &ExtensionRegistry,
+) -> AetoliaResult<Vec<ICalObject>> {
+    let mut objects = load_ical(input)?;
+    for object in &mut objects {
+        registry.decode(object)?;
+    }
+
+    Ok(objects)
+}
+
 /// Convenience function to load iCalendar data from a readable source.
 ///
 /// The data is read to the end and then passed to [load_ical].
@@ -166,6 +166,38 @@ where
     Ok((input, value))
 }
 
+/// Decode the caret escapes that RFC 6868 allows in param values.
+///
+/// `^n` and `^N` become a newline, `^'` becomes a double quote and `^^` becomes a single caret.
+/// A caret followed by any other character is left as it is.
+pub(crate) fn decode_param_text(value: String) -> String {
+    if !value.contains('^') {
+        return value;
+    }
+
+    let mut decoded = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '^' {
+            decoded.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('n') | Some('N') => decoded.push('\n'),
+            Some('\'') => decoded.push('"'),
+            Some('^') => decoded.push('^'),
+            _ => {
+                decoded.push('^');
+                continue;
+            }
+        }
+        chars.next();
+    }
+
+    decoded
+}
+
 fn safe_char<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], &'a [u8], E>
 where
     E: ParseError<&'a [u8]> + From<Error<'a>>,
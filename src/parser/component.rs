@@ -1,10 +1,15 @@
 mod alarm;
+mod availability;
 mod event;
 mod free_busy;
 mod journal;
+mod location;
+mod participant;
+mod resource;
 mod timezone;
 mod todo;
 
+pub use availability::component_availability;
 pub use event::component_event;
 pub use free_busy::component_free_busy;
 pub use journal::component_journal;
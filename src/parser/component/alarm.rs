@@ -1,6 +1,7 @@
 use crate::parser::property::{
-    prop_action, prop_attach, prop_attendee, prop_description, prop_duration, prop_iana,
-    prop_repeat, prop_summary, prop_trigger, prop_x,
+    prop_acknowledged, prop_action, prop_attach, prop_attendee, prop_description, prop_duration,
+    prop_iana, prop_proximity, prop_related_to, prop_repeat, prop_summary, prop_trigger,
+    prop_unique_identifier, prop_x,
 };
 use crate::parser::types::CalendarComponent;
 use crate::parser::types::ComponentProperty;
@@ -29,6 +30,10 @@ where
                 prop_description.map(ComponentProperty::Description),
                 prop_summary.map(ComponentProperty::Summary),
                 prop_attendee.map(ComponentProperty::Attendee),
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_acknowledged.map(ComponentProperty::Acknowledged),
+                prop_related_to.map(ComponentProperty::RelatedTo),
+                prop_proximity.map(ComponentProperty::Proximity),
             )),
             prop_x.map(ComponentProperty::XProperty),
             prop_iana.map(ComponentProperty::IanaProperty),
@@ -43,10 +48,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::Value;
+    use crate::common::{RelationshipType, Value};
     use crate::parser::types::{
-        Action, ActionProperty, AttachProperty, AttachValue, Date, DateTime, Duration,
-        DurationOrDateTime, DurationProperty, ParamValue, RepeatProperty, Time, TriggerProperty,
+        AcknowledgedProperty, Action, ActionProperty, AttachProperty, AttachValue, Date, DateTime,
+        Duration, DurationOrDateTime, DurationProperty, ParamValue, Proximity, ProximityProperty,
+        RelatedToProperty, RepeatProperty, Time, TriggerProperty,
     };
     use crate::parser::Error;
     use crate::test_utils::check_rem;
@@ -131,4 +137,47 @@ END:VALARM\r\n";
             _ => panic!("Unexpected component type"),
         }
     }
+
+    #[test]
+    fn test_component_alarm_rfc_9074() {
+        let input = b"BEGIN:VALARM\r\n\
+UID:8297C37D-BA2D-4476-91AE-C1EAA364F8E1\r\n\
+RELATED-TO;RELTYPE=SNOOZE:DE7B5C34-83FF-47FE-BE9E-FF41AE6DD097\r\n\
+TRIGGER;VALUE=DATE-TIME:20090604T084500Z\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Breakfast reminder\r\n\
+ACKNOWLEDGED:20090604T084000Z\r\n\
+PROXIMITY:ARRIVE\r\n\
+END:VALARM\r\n";
+
+        let (rem, component) = component_alarm::<Error>(input).unwrap();
+        check_rem(rem, 0);
+        match component {
+            CalendarComponent::Alarm { properties } => {
+                assert_eq!(7, properties.len());
+
+                assert_eq!(
+                    properties[1],
+                    ComponentProperty::RelatedTo(RelatedToProperty {
+                        params: vec![ParamValue::RelationshipType {
+                            relationship: RelationshipType::Snooze,
+                        }],
+                        value: b"DE7B5C34-83FF-47FE-BE9E-FF41AE6DD097".to_vec(),
+                    })
+                );
+                assert!(matches!(
+                    properties[5],
+                    ComponentProperty::Acknowledged(AcknowledgedProperty { .. })
+                ));
+                assert_eq!(
+                    properties[6],
+                    ComponentProperty::Proximity(ProximityProperty {
+                        other_params: vec![],
+                        value: Proximity::Arrive,
+                    })
+                );
+            }
+            _ => panic!("Unexpected component type"),
+        }
+    }
 }
@@ -0,0 +1,161 @@
+use crate::parser::property::{
+    prop_busy_type, prop_categories, prop_classification, prop_comment, prop_contact,
+    prop_date_time_created, prop_date_time_end, prop_date_time_stamp, prop_date_time_start,
+    prop_description, prop_duration, prop_exception_date_times, prop_iana, prop_last_modified,
+    prop_location, prop_organizer, prop_priority, prop_recurrence_date_times, prop_recurrence_id,
+    prop_recurrence_rule, prop_sequence, prop_summary, prop_unique_identifier, prop_url, prop_x,
+};
+use crate::parser::types::CalendarComponent;
+use crate::parser::types::ComponentProperty;
+use crate::parser::Error;
+use nom::branch::alt;
+use nom::bytes::streaming::tag;
+use nom::combinator::cut;
+use nom::error::ParseError;
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+/// Parse a VAVAILABILITY component.
+///
+/// RFC 7953, section 3.1
+pub fn component_availability<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], CalendarComponent<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, properties, components, _)) = (
+        tag("BEGIN:VAVAILABILITY\r\n"),
+        cut(many0(alt((
+            alt((
+                prop_date_time_stamp.map(ComponentProperty::DateTimeStamp),
+                prop_date_time_start.map(ComponentProperty::DateTimeStart),
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_busy_type.map(ComponentProperty::BusyType),
+                prop_classification.map(ComponentProperty::Classification),
+                prop_date_time_created.map(ComponentProperty::DateTimeCreated),
+                prop_description.map(ComponentProperty::Description),
+                prop_last_modified.map(ComponentProperty::LastModified),
+                prop_location.map(ComponentProperty::Location),
+                prop_organizer.map(ComponentProperty::Organizer),
+                prop_priority.map(ComponentProperty::Priority),
+                prop_sequence.map(ComponentProperty::Sequence),
+                prop_summary.map(ComponentProperty::Summary),
+                prop_url.map(ComponentProperty::Url),
+                prop_date_time_end.map(ComponentProperty::DateTimeEnd),
+                prop_duration.map(ComponentProperty::Duration),
+                prop_categories.map(ComponentProperty::Categories),
+                prop_comment.map(ComponentProperty::Comment),
+                prop_contact.map(ComponentProperty::Contact),
+            )),
+            prop_x.map(ComponentProperty::XProperty),
+            prop_iana.map(ComponentProperty::IanaProperty),
+        )))),
+        many0(component_available),
+        tag("END:VAVAILABILITY\r\n"),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        CalendarComponent::Availability {
+            properties,
+            components,
+        },
+    ))
+}
+
+/// Parse an AVAILABLE component.
+///
+/// RFC 7953, section 3.1
+pub fn component_available<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], CalendarComponent<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, properties, _)) = (
+        tag("BEGIN:AVAILABLE\r\n"),
+        cut(many0(alt((
+            alt((
+                prop_date_time_stamp.map(ComponentProperty::DateTimeStamp),
+                prop_date_time_start.map(ComponentProperty::DateTimeStart),
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_date_time_end.map(ComponentProperty::DateTimeEnd),
+                prop_duration.map(ComponentProperty::Duration),
+                prop_date_time_created.map(ComponentProperty::DateTimeCreated),
+                prop_description.map(ComponentProperty::Description),
+                prop_last_modified.map(ComponentProperty::LastModified),
+                prop_location.map(ComponentProperty::Location),
+                prop_recurrence_id.map(ComponentProperty::RecurrenceId),
+                prop_recurrence_rule.map(ComponentProperty::RecurrenceRule),
+                prop_summary.map(ComponentProperty::Summary),
+                prop_categories.map(ComponentProperty::Categories),
+                prop_comment.map(ComponentProperty::Comment),
+                prop_contact.map(ComponentProperty::Contact),
+                prop_exception_date_times.map(ComponentProperty::ExceptionDateTimes),
+                prop_recurrence_date_times.map(ComponentProperty::RecurrenceDateTimes),
+            )),
+            prop_x.map(ComponentProperty::XProperty),
+            prop_iana.map(ComponentProperty::IanaProperty),
+        )))),
+        tag("END:AVAILABLE\r\n"),
+    )
+        .parse(input)?;
+
+    Ok((input, CalendarComponent::Available { properties }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::{BusyType, BusyTypeProperty};
+    use crate::parser::Error;
+    use crate::test_utils::check_rem;
+
+    #[test]
+    fn test_component_availability() {
+        let input = b"BEGIN:VAVAILABILITY\r\n\
+UID:20061206T001330Z-1@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+BUSYTYPE:BUSY-UNAVAILABLE\r\n\
+DTSTART;TZID=America/Montreal:20061206T000000\r\n\
+BEGIN:AVAILABLE\r\n\
+UID:20061206T001330Z-2@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+DTSTART;TZID=America/Montreal:20061206T090000\r\n\
+DTEND;TZID=America/Montreal:20061206T170000\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r\n\
+SUMMARY:Monday to Friday from 9:00 to 17:00\r\n\
+END:AVAILABLE\r\n\
+END:VAVAILABILITY\r\n";
+
+        let (rem, component) = component_availability::<Error>(input).unwrap();
+        check_rem(rem, 0);
+
+        match component {
+            CalendarComponent::Availability {
+                properties,
+                components,
+            } => {
+                assert_eq!(properties.len(), 4);
+                assert_eq!(
+                    properties[2],
+                    ComponentProperty::BusyType(BusyTypeProperty {
+                        other_params: vec![],
+                        value: BusyType::BusyUnavailable,
+                    })
+                );
+
+                assert_eq!(components.len(), 1);
+                match &components[0] {
+                    CalendarComponent::Available { properties } => {
+                        assert_eq!(properties.len(), 6);
+                    }
+                    _ => panic!("Unexpected component type"),
+                }
+            }
+            _ => panic!("Unexpected component type"),
+        }
+    }
+}
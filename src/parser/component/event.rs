@@ -1,12 +1,16 @@
 use crate::parser::component::alarm::component_alarm;
+use crate::parser::component::location::component_location;
+use crate::parser::component::participant::component_participant;
+use crate::parser::component::resource::component_resource;
 use crate::parser::property::{
-    prop_attach, prop_attendee, prop_categories, prop_classification, prop_comment, prop_contact,
-    prop_date_time_created, prop_date_time_end, prop_date_time_stamp, prop_date_time_start,
-    prop_description, prop_duration, prop_exception_date_times, prop_geographic_position,
-    prop_iana, prop_last_modified, prop_location, prop_organizer, prop_priority,
-    prop_recurrence_date_times, prop_recurrence_id, prop_recurrence_rule, prop_related_to,
-    prop_request_status, prop_resources, prop_sequence, prop_status, prop_summary,
-    prop_time_transparency, prop_unique_identifier, prop_url, prop_x,
+    prop_attach, prop_attendee, prop_categories, prop_classification, prop_color, prop_comment,
+    prop_concept, prop_conference, prop_contact, prop_date_time_created, prop_date_time_end,
+    prop_date_time_stamp, prop_date_time_start, prop_description, prop_duration,
+    prop_exception_date_times, prop_geographic_position, prop_iana, prop_image, prop_last_modified,
+    prop_link, prop_location, prop_organizer, prop_priority, prop_recurrence_date_times,
+    prop_recurrence_id, prop_recurrence_rule, prop_ref_id, prop_related_to, prop_request_status,
+    prop_resources, prop_sequence, prop_status, prop_structured_data, prop_styled_description,
+    prop_summary, prop_time_transparency, prop_unique_identifier, prop_url, prop_x,
 };
 use crate::parser::types::CalendarComponent;
 use crate::parser::types::ComponentProperty;
@@ -24,7 +28,7 @@ where
         + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
         + From<Error<'a>>,
 {
-    let (input, (_, properties, alarms, _)) = (
+    let (input, (_, properties, components, _)) = (
         tag("BEGIN:VEVENT\r\n"),
         cut(many0(alt((
             alt((
@@ -60,16 +64,37 @@ where
                 prop_related_to.map(ComponentProperty::RelatedTo),
                 prop_resources.map(ComponentProperty::Resources),
                 prop_recurrence_date_times.map(ComponentProperty::RecurrenceDateTimes),
+                prop_color.map(ComponentProperty::Color),
+                prop_image.map(ComponentProperty::Image),
+                prop_conference.map(ComponentProperty::Conference),
+            )),
+            alt((
+                prop_styled_description.map(ComponentProperty::StyledDescription),
+                prop_structured_data.map(ComponentProperty::StructuredData),
+                prop_concept.map(ComponentProperty::Concept),
+                prop_link.map(ComponentProperty::Link),
+                prop_ref_id.map(ComponentProperty::RefId),
             )),
             prop_x.map(ComponentProperty::XProperty),
             prop_iana.map(ComponentProperty::IanaProperty),
         )))),
-        many0(component_alarm),
+        many0(alt((
+            component_alarm,
+            component_participant,
+            component_location,
+            component_resource,
+        ))),
         tag("END:VEVENT\r\n"),
     )
         .parse(input)?;
 
-    Ok((input, CalendarComponent::Event { properties, alarms }))
+    Ok((
+        input,
+        CalendarComponent::Event {
+            properties,
+            components,
+        },
+    ))
 }
 
 #[cfg(test)]
@@ -1,10 +1,10 @@
 use crate::parser::property::{
-    prop_attach, prop_attendee, prop_categories, prop_classification, prop_comment, prop_contact,
-    prop_date_time_created, prop_date_time_stamp, prop_date_time_start, prop_description,
-    prop_exception_date_times, prop_iana, prop_last_modified, prop_organizer,
-    prop_recurrence_date_times, prop_recurrence_id, prop_recurrence_rule, prop_related_to,
-    prop_request_status, prop_sequence, prop_status, prop_summary, prop_unique_identifier,
-    prop_url, prop_x,
+    prop_attach, prop_attendee, prop_categories, prop_classification, prop_color, prop_comment,
+    prop_concept, prop_contact, prop_date_time_created, prop_date_time_stamp, prop_date_time_start,
+    prop_description, prop_exception_date_times, prop_iana, prop_image, prop_last_modified,
+    prop_link, prop_organizer, prop_recurrence_date_times, prop_recurrence_id,
+    prop_recurrence_rule, prop_ref_id, prop_related_to, prop_request_status, prop_sequence,
+    prop_status, prop_summary, prop_unique_identifier, prop_url, prop_x,
 };
 use crate::parser::types::CalendarComponent;
 use crate::parser::types::ComponentProperty;
@@ -51,6 +51,11 @@ where
                 prop_related_to.map(ComponentProperty::RelatedTo),
                 prop_recurrence_date_times.map(ComponentProperty::RecurrenceDateTimes),
                 prop_request_status.map(ComponentProperty::RequestStatus),
+                prop_color.map(ComponentProperty::Color),
+                prop_image.map(ComponentProperty::Image),
+                prop_concept.map(ComponentProperty::Concept),
+                prop_link.map(ComponentProperty::Link),
+                prop_ref_id.map(ComponentProperty::RefId),
             )),
             prop_x.map(ComponentProperty::XProperty),
             prop_iana.map(ComponentProperty::IanaProperty),
@@ -0,0 +1,79 @@
+use crate::parser::property::{
+    prop_description, prop_geographic_position, prop_iana, prop_location_type,
+    prop_structured_data, prop_unique_identifier, prop_url, prop_x,
+};
+use crate::parser::types::CalendarComponent;
+use crate::parser::types::ComponentProperty;
+use crate::parser::Error;
+use nom::branch::alt;
+use nom::bytes::streaming::tag;
+use nom::combinator::cut;
+use nom::error::ParseError;
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+/// Parse a VLOCATION component.
+///
+/// RFC 9073, section 7.2
+pub fn component_location<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], CalendarComponent<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, properties, _)) = (
+        tag("BEGIN:VLOCATION\r\n"),
+        cut(many0(alt((
+            alt((
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_description.map(ComponentProperty::Description),
+                prop_geographic_position.map(ComponentProperty::GeographicPosition),
+                prop_location_type.map(ComponentProperty::LocationType),
+                prop_url.map(ComponentProperty::Url),
+                prop_structured_data.map(ComponentProperty::StructuredData),
+            )),
+            prop_x.map(ComponentProperty::XProperty),
+            prop_iana.map(ComponentProperty::IanaProperty),
+        )))),
+        tag("END:VLOCATION\r\n"),
+    )
+        .parse(input)?;
+
+    Ok((input, CalendarComponent::Location { properties }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::LocationTypeProperty;
+    use crate::parser::Error;
+    use crate::test_utils::check_rem;
+
+    #[test]
+    fn test_component_location() {
+        let input = b"BEGIN:VLOCATION\r\n\
+UID:123456-abcdef-98765432\r\n\
+NAME:The venue\r\n\
+LOCATION-TYPE:industrial,parking-lot\r\n\
+URL:http://example.com/venue\r\n\
+END:VLOCATION\r\n";
+
+        let (rem, component) = component_location::<Error>(input).unwrap();
+        check_rem(rem, 0);
+
+        match component {
+            CalendarComponent::Location { properties } => {
+                assert_eq!(properties.len(), 4);
+                assert!(matches!(properties[1], ComponentProperty::IanaProperty(_)));
+                assert_eq!(
+                    properties[2],
+                    ComponentProperty::LocationType(LocationTypeProperty {
+                        other_params: vec![],
+                        value: vec![b"industrial".to_vec(), b"parking-lot".to_vec()],
+                    })
+                );
+            }
+            _ => panic!("Unexpected component type"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::parser::component::location::component_location;
+use crate::parser::component::resource::component_resource;
+use crate::parser::property::{
+    prop_attach, prop_categories, prop_comment, prop_contact, prop_date_time_created,
+    prop_date_time_stamp, prop_description, prop_geographic_position, prop_iana,
+    prop_last_modified, prop_location, prop_participant_type, prop_priority, prop_related_to,
+    prop_request_status, prop_resources, prop_sequence, prop_structured_data,
+    prop_styled_description, prop_summary, prop_unique_identifier, prop_url, prop_x,
+};
+use crate::parser::types::CalendarComponent;
+use crate::parser::types::ComponentProperty;
+use crate::parser::Error;
+use nom::branch::alt;
+use nom::bytes::streaming::tag;
+use nom::combinator::cut;
+use nom::error::ParseError;
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+/// Parse a PARTICIPANT component.
+///
+/// RFC 9073, section 7.1
+pub fn component_participant<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], CalendarComponent<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, properties, components, _)) = (
+        tag("BEGIN:PARTICIPANT\r\n"),
+        cut(many0(alt((
+            alt((
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_participant_type.map(ComponentProperty::ParticipantType),
+                prop_date_time_created.map(ComponentProperty::DateTimeCreated),
+                prop_description.map(ComponentProperty::Description),
+                prop_date_time_stamp.map(ComponentProperty::DateTimeStamp),
+                prop_geographic_position.map(ComponentProperty::GeographicPosition),
+                prop_last_modified.map(ComponentProperty::LastModified),
+                prop_priority.map(ComponentProperty::Priority),
+                prop_sequence.map(ComponentProperty::Sequence),
+                prop_summary.map(ComponentProperty::Summary),
+                prop_url.map(ComponentProperty::Url),
+            )),
+            alt((
+                prop_attach.map(ComponentProperty::Attach),
+                prop_categories.map(ComponentProperty::Categories),
+                prop_comment.map(ComponentProperty::Comment),
+                prop_contact.map(ComponentProperty::Contact),
+                prop_location.map(ComponentProperty::Location),
+                prop_request_status.map(ComponentProperty::RequestStatus),
+                prop_related_to.map(ComponentProperty::RelatedTo),
+                prop_resources.map(ComponentProperty::Resources),
+                prop_styled_description.map(ComponentProperty::StyledDescription),
+                prop_structured_data.map(ComponentProperty::StructuredData),
+            )),
+            prop_x.map(ComponentProperty::XProperty),
+            prop_iana.map(ComponentProperty::IanaProperty),
+        )))),
+        many0(alt((component_location, component_resource))),
+        tag("END:PARTICIPANT\r\n"),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        CalendarComponent::Participant {
+            properties,
+            components,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::{ParticipantType, ParticipantTypeProperty};
+    use crate::parser::Error;
+    use crate::test_utils::check_rem;
+
+    #[test]
+    fn test_component_participant() {
+        let input = b"BEGIN:PARTICIPANT\r\n\
+UID:participant-1@example.com\r\n\
+PARTICIPANT-TYPE:SPEAKER\r\n\
+SUMMARY:Keynote speaker\r\n\
+BEGIN:VLOCATION\r\n\
+UID:location-1@example.com\r\n\
+LOCATION-TYPE:stage\r\n\
+END:VLOCATION\r\n\
+END:PARTICIPANT\r\n";
+
+        let (rem, component) = component_participant::<Error>(input).unwrap();
+        check_rem(rem, 0);
+
+        match component {
+            CalendarComponent::Participant {
+                properties,
+                components,
+            } => {
+                assert_eq!(properties.len(), 3);
+                assert_eq!(
+                    properties[1],
+                    ComponentProperty::ParticipantType(ParticipantTypeProperty {
+                        other_params: vec![],
+                        value: ParticipantType::Speaker,
+                    })
+                );
+
+                assert_eq!(components.len(), 1);
+                assert!(matches!(components[0], CalendarComponent::Location { .. }));
+            }
+            _ => panic!("Unexpected component type"),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::parser::property::{
+    prop_description, prop_geographic_position, prop_iana, prop_resource_type,
+    prop_structured_data, prop_unique_identifier, prop_x,
+};
+use crate::parser::types::CalendarComponent;
+use crate::parser::types::ComponentProperty;
+use crate::parser::Error;
+use nom::branch::alt;
+use nom::bytes::streaming::tag;
+use nom::combinator::cut;
+use nom::error::ParseError;
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+/// Parse a VRESOURCE component.
+///
+/// RFC 9073, section 7.3
+pub fn component_resource<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], CalendarComponent<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, properties, _)) = (
+        tag("BEGIN:VRESOURCE\r\n"),
+        cut(many0(alt((
+            alt((
+                prop_unique_identifier.map(ComponentProperty::UniqueIdentifier),
+                prop_description.map(ComponentProperty::Description),
+                prop_geographic_position.map(ComponentProperty::GeographicPosition),
+                prop_resource_type.map(ComponentProperty::ResourceType),
+                prop_structured_data.map(ComponentProperty::StructuredData),
+            )),
+            prop_x.map(ComponentProperty::XProperty),
+            prop_iana.map(ComponentProperty::IanaProperty),
+        )))),
+        tag("END:VRESOURCE\r\n"),
+    )
+        .parse(input)?;
+
+    Ok((input, CalendarComponent::Resource { properties }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::{ResourceType, ResourceTypeProperty};
+    use crate::parser::Error;
+    use crate::test_utils::check_rem;
+
+    #[test]
+    fn test_component_resource() {
+        let input = b"BEGIN:VRESOURCE\r\n\
+UID:456789-abcdef-98765432\r\n\
+RESOURCE-TYPE:PROJECTOR\r\n\
+END:VRESOURCE\r\n";
+
+        let (rem, component) = component_resource::<Error>(input).unwrap();
+        check_rem(rem, 0);
+
+        match component {
+            CalendarComponent::Resource { properties } => {
+                assert_eq!(properties.len(), 2);
+                assert_eq!(
+                    properties[1],
+                    ComponentProperty::ResourceType(ResourceTypeProperty {
+                        other_params: vec![],
+                        value: ResourceType::Projector,
+                    })
+                );
+            }
+            _ => panic!("Unexpected component type"),
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::parser::component::alarm::component_alarm;
 use crate::parser::property::{
-    prop_attach, prop_attendee, prop_categories, prop_classification, prop_comment, prop_contact,
-    prop_date_time_completed, prop_date_time_created, prop_date_time_due, prop_date_time_stamp,
-    prop_date_time_start, prop_description, prop_duration, prop_exception_date_times,
-    prop_geographic_position, prop_iana, prop_last_modified, prop_location, prop_organizer,
-    prop_percent_complete, prop_priority, prop_recurrence_date_times, prop_recurrence_id,
-    prop_recurrence_rule, prop_related_to, prop_request_status, prop_resources, prop_sequence,
-    prop_status, prop_summary, prop_unique_identifier, prop_url, prop_x,
+    prop_attach, prop_attendee, prop_categories, prop_classification, prop_color, prop_comment,
+    prop_concept, prop_conference, prop_contact, prop_date_time_completed, prop_date_time_created,
+    prop_date_time_due, prop_date_time_stamp, prop_date_time_start, prop_description,
+    prop_duration, prop_exception_date_times, prop_geographic_position, prop_iana, prop_image,
+    prop_last_modified, prop_link, prop_location, prop_organizer, prop_percent_complete,
+    prop_priority, prop_recurrence_date_times, prop_recurrence_id, prop_recurrence_rule,
+    prop_ref_id, prop_related_to, prop_request_status, prop_resources, prop_sequence, prop_status,
+    prop_summary, prop_unique_identifier, prop_url, prop_x,
 };
 use crate::parser::types::CalendarComponent;
 use crate::parser::types::ComponentProperty;
@@ -62,6 +63,14 @@ where
                 prop_related_to.map(ComponentProperty::RelatedTo),
                 prop_resources.map(ComponentProperty::Resources),
                 prop_recurrence_date_times.map(ComponentProperty::RecurrenceDateTimes),
+                prop_color.map(ComponentProperty::Color),
+                prop_image.map(ComponentProperty::Image),
+                prop_conference.map(ComponentProperty::Conference),
+            )),
+            alt((
+                prop_concept.map(ComponentProperty::Concept),
+                prop_link.map(ComponentProperty::Link),
+                prop_ref_id.map(ComponentProperty::RefId),
             )),
             prop_x.map(ComponentProperty::XProperty),
             prop_iana.map(ComponentProperty::IanaProperty),
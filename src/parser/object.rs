@@ -1,8 +1,11 @@
 use crate::parser::component::{
-    component_event, component_free_busy, component_journal, component_timezone, component_todo,
+    component_availability, component_event, component_free_busy, component_journal,
+    component_timezone, component_todo,
 };
 use crate::parser::property::{
-    prop_calendar_scale, prop_iana, prop_method, prop_product_id, prop_version, prop_x,
+    prop_calendar_scale, prop_color, prop_description, prop_iana, prop_image, prop_last_modified,
+    prop_method, prop_name, prop_product_id, prop_refresh_interval, prop_source, prop_url,
+    prop_version, prop_x,
 };
 use crate::parser::types::CalendarComponent;
 use crate::parser::types::CalendarProperty;
@@ -90,6 +93,14 @@ where
         prop_version.map(CalendarProperty::Version),
         prop_calendar_scale.map(CalendarProperty::CalendarScale),
         prop_method.map(CalendarProperty::Method),
+        prop_name.map(CalendarProperty::Name),
+        prop_description.map(CalendarProperty::Description),
+        prop_last_modified.map(CalendarProperty::LastModified),
+        prop_url.map(CalendarProperty::Url),
+        prop_refresh_interval.map(CalendarProperty::RefreshInterval),
+        prop_source.map(CalendarProperty::Source),
+        prop_color.map(CalendarProperty::Color),
+        prop_image.map(CalendarProperty::Image),
         prop_x.map(CalendarProperty::XProperty),
         prop_iana.map(CalendarProperty::IanaProperty),
     ))
@@ -108,6 +119,7 @@ where
         component_journal,
         component_free_busy,
         component_timezone,
+        component_availability,
         x_comp,
         iana_comp,
     ))
@@ -329,6 +341,29 @@ mod tests {
         assert_eq!(ical[0].components.len(), 1);
     }
 
+    #[test]
+    fn iana_props_named_like_rfc_7986_props() {
+        let input = b"BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:test\r\nNAMESPACE:a\r\nSOURCES:b\r\nREFRESH-INTERVALS:c\r\nBEGIN:x-com\r\nx-prop:I'm a property\r\nEND:x-com\r\nEND:VCALENDAR\r\n";
+        let (rem, ical) = ical_stream::<Error>(input).unwrap();
+        check_rem(rem, 0);
+        let names = ical[0]
+            .properties
+            .iter()
+            .filter_map(|p| match p {
+                CalendarProperty::IanaProperty(p) => Some(p.name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                b"NAMESPACE".as_slice(),
+                b"SOURCES".as_slice(),
+                b"REFRESH-INTERVALS".as_slice()
+            ]
+        );
+    }
+
     #[test]
     #[ignore = "Requires a real file"]
     fn real_file() {
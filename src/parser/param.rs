@@ -4,7 +4,10 @@ use crate::common::Range;
 use crate::parser::language_tag::language_tag;
 use crate::parser::property::uri::param_value_uri;
 use crate::parser::types::ParamValue;
-use crate::parser::{param_name, param_value, read_string, reg_name, x_name, Error};
+use crate::parser::{
+    decode_param_text, iana_token, param_name, param_value, prop_value_duration, read_string,
+    reg_name, x_name, Error,
+};
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::bytes::streaming::tag;
@@ -69,7 +72,7 @@ where
     Ok((
         input,
         ParamValue::CommonName {
-            name: read_string(value, "common_name")?,
+            name: decode_param_text(read_string(value, "common_name")?),
         },
     ))
 }
@@ -168,6 +171,43 @@ where
     Ok((input, ParamValue::DirectoryEntryReference { uri }))
 }
 
+/// Parse a DISPLAY param
+///
+/// RFC 7986, section 6.1
+fn param_display<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, display)) = (
+        tag_no_case("DISPLAY"),
+        char('='),
+        cut(separated_list1(char(','), param_value_display_type)),
+    )
+        .parse(input)?;
+
+    Ok((input, ParamValue::Display { display }))
+}
+
+/// Parse an EMAIL param
+///
+/// RFC 7986, section 6.2
+fn param_email<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, _, value)) =
+        (tag_no_case("EMAIL"), char('='), cut(param_value)).parse(input)?;
+
+    Ok((
+        input,
+        ParamValue::Email {
+            email: decode_param_text(read_string(value, "EMAIL")?),
+        },
+    ))
+}
+
 /// Parse an ENCODING param
 ///
 /// RFC 5545, section 3.2.7
@@ -216,6 +256,25 @@ where
     ))
 }
 
+/// Parse a FEATURE param
+///
+/// RFC 7986, section 6.3
+fn param_feature<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, features)) = (
+        tag_no_case("FEATURE"),
+        char('='),
+        cut(separated_list1(char(','), param_value_feature_type)),
+    )
+        .parse(input)?;
+
+    Ok((input, ParamValue::Feature { features }))
+}
+
 /// Parse an FBTYPE param
 ///
 /// RFC 5545, section 3.2.9
@@ -235,6 +294,64 @@ where
     Ok((input, ParamValue::FreeBusyTimeType { fb_type }))
 }
 
+/// Parse a LABEL param
+///
+/// RFC 7986, section 6.4
+fn param_label<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, _, value)) =
+        (tag_no_case("LABEL"), char('='), cut(param_value)).parse(input)?;
+
+    Ok((
+        input,
+        ParamValue::Label {
+            label: decode_param_text(read_string(value, "LABEL")?),
+        },
+    ))
+}
+
+/// Parse a GAP param
+///
+/// RFC 9253, section 6.2
+fn param_gap<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, _, duration)) =
+        (tag_no_case("GAP"), char('='), cut(prop_value_duration)).parse(input)?;
+
+    Ok((input, ParamValue::Gap { duration }))
+}
+
+/// Parse a LINKREL param
+///
+/// RFC 9253, section 6.1
+fn param_link_relation<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, relation)) = (
+        tag_no_case("LINKREL"),
+        char('='),
+        cut(alt((
+            delimited(char('"'), recognize(param_value_uri), char('"')),
+            iana_token,
+        ))),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ParamValue::LinkRelation {
+            relation: read_string(relation, "LINKREL")?,
+        },
+    ))
+}
+
 /// Parse a LANGUAGE param
 ///
 /// RFC 5545, section 3.2.10
@@ -379,6 +496,70 @@ where
     Ok((input, ParamValue::Rsvp { rsvp }))
 }
 
+/// Parse a SCHEDULE-AGENT param
+///
+/// RFC 6638, section 7.1
+fn param_schedule_agent<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, agent)) = (
+        tag_no_case("SCHEDULE-AGENT"),
+        char('='),
+        cut(param_value_schedule_agent),
+    )
+        .parse(input)?;
+
+    Ok((input, ParamValue::ScheduleAgent { agent }))
+}
+
+/// Parse a SCHEDULE-FORCE-SEND param
+///
+/// RFC 6638, section 7.2
+fn param_schedule_force_send<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, force_send)) = (
+        tag_no_case("SCHEDULE-FORCE-SEND"),
+        char('='),
+        cut(param_value_schedule_force_send),
+    )
+        .parse(input)?;
+
+    Ok((input, ParamValue::ScheduleForceSend { force_send }))
+}
+
+/// Parse a SCHEDULE-STATUS param
+///
+/// RFC 6638, section 7.3
+fn param_schedule_status<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ParamValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, statuses)) = (
+        tag_no_case("SCHEDULE-STATUS"),
+        char('='),
+        cut(alt((
+            delimited(
+                char('"'),
+                separated_list1(char(','), param_value_status_code),
+                char('"'),
+            ),
+            param_value_status_code.map(|status| vec![status]),
+        ))),
+    )
+        .parse(input)?;
+
+    Ok((input, ParamValue::ScheduleStatus { statuses }))
+}
+
 /// Parse an SENT-BY param
 ///
 /// RFC 5545, section 3.2.18
@@ -439,26 +620,38 @@ where
         + From<Error<'a>>,
 {
     let (input, param_value) = alt((
-        param_alternate_text_representation,
-        param_common_name,
-        param_calendar_user_type,
-        param_delegated_from,
-        param_delegated_to,
-        param_dir,
-        param_encoding,
-        param_format_type,
-        param_free_busy_time_type,
-        param_language,
-        param_member,
-        param_participation_status,
-        param_range,
-        param_related,
-        param_relationship_type,
-        param_role,
-        param_rsvp,
-        param_sent_by,
-        param_time_zone_identifier,
-        param_value_type,
+        alt((
+            param_alternate_text_representation,
+            param_common_name,
+            param_calendar_user_type,
+            param_delegated_from,
+            param_delegated_to,
+            param_dir,
+            param_encoding,
+            param_format_type,
+            param_free_busy_time_type,
+            param_language,
+            param_member,
+            param_participation_status,
+            param_range,
+            param_related,
+            param_relationship_type,
+            param_role,
+            param_rsvp,
+            param_sent_by,
+            param_time_zone_identifier,
+            param_value_type,
+        )),
+        // RFC 7986
+        alt((param_display, param_email, param_feature, param_label)),
+        // RFC 6638
+        alt((
+            param_schedule_agent,
+            param_schedule_force_send,
+            param_schedule_status,
+        )),
+        // RFC 9253
+        alt((param_gap, param_link_relation)),
     ))
     .parse(input)?;
 
@@ -528,9 +721,11 @@ mod tests {
     use super::*;
     use crate::common::LanguageTag;
     use crate::common::{
-        CalendarUserType, Encoding, FreeBusyTimeType, ParticipationStatusUnknown, RelationshipType,
-        Role, TriggerRelationship, Value,
+        CalendarUserType, DisplayType, Encoding, FeatureType, FreeBusyTimeType,
+        ParticipationStatusUnknown, RelationshipType, Role, ScheduleAgent, ScheduleForceSend,
+        TriggerRelationship, Value,
     };
+    use crate::parser::types::Duration;
     use crate::test_utils::check_rem;
 
     #[test]
@@ -570,6 +765,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn param_cn_caret_encoded() {
+        let (rem, param) =
+            known_param::<Error>(b"CN=\"George Herman ^'Babe^' Ruth ^^ ^N^x\";").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::CommonName {
+                name: "George Herman \"Babe\" Ruth ^ \n^x".to_string()
+            },
+            param
+        );
+    }
+
     #[test]
     fn param_cu_type_individual() {
         let (rem, param) = known_param::<Error>(b"CUTYPE=INDIVIDUAL;").unwrap();
@@ -917,6 +1125,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn param_rel_type_finish_to_start() {
+        let (rem, param) = known_param::<Error>(b"RELTYPE=FINISHTOSTART;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::RelationshipType {
+                relationship: RelationshipType::FinishToStart
+            },
+            param
+        );
+    }
+
     #[test]
     fn param_role() {
         let (rem, param) = known_param::<Error>(b"ROLE=CHAIR;").unwrap();
@@ -987,4 +1207,141 @@ mod tests {
             param
         );
     }
+
+    #[test]
+    fn param_display() {
+        let (rem, param) = known_param::<Error>(b"DISPLAY=BADGE,x-wall;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::Display {
+                display: vec![DisplayType::Badge, DisplayType::XName("x-wall".to_string())]
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_email() {
+        let (rem, param) = known_param::<Error>(b"EMAIL=\"janedoe@example.com\";").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::Email {
+                email: "janedoe@example.com".to_string()
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_feature() {
+        let (rem, param) = known_param::<Error>(b"FEATURE=PHONE,MODERATOR;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::Feature {
+                features: vec![FeatureType::Phone, FeatureType::Moderator]
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_label() {
+        let (rem, param) = known_param::<Error>(b"LABEL=\"Moderator dial-in\";").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::Label {
+                label: "Moderator dial-in".to_string()
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_gap() {
+        let (rem, param) = known_param::<Error>(b"GAP=-PT1H;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::Gap {
+                duration: Duration {
+                    sign: -1,
+                    hours: Some(1),
+                    ..Default::default()
+                }
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_link_relation_uri() {
+        let (rem, param) =
+            known_param::<Error>(b"LINKREL=\"https://example.com/linkrel/derivedFrom\";").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::LinkRelation {
+                relation: "https://example.com/linkrel/derivedFrom".to_string()
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_link_relation_token() {
+        let (rem, param) = known_param::<Error>(b"LINKREL=latest-version;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::LinkRelation {
+                relation: "latest-version".to_string()
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_schedule_agent() {
+        let (rem, param) = known_param::<Error>(b"SCHEDULE-AGENT=CLIENT;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::ScheduleAgent {
+                agent: ScheduleAgent::Client
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_schedule_force_send() {
+        let (rem, param) = known_param::<Error>(b"SCHEDULE-FORCE-SEND=REQUEST;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::ScheduleForceSend {
+                force_send: ScheduleForceSend::Request
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_schedule_status() {
+        let (rem, param) = known_param::<Error>(b"SCHEDULE-STATUS=1.2;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::ScheduleStatus {
+                statuses: vec![vec![1, 2]]
+            },
+            param
+        );
+    }
+
+    #[test]
+    fn param_schedule_status_list() {
+        let (rem, param) = known_param::<Error>(b"SCHEDULE-STATUS=\"3.7,5.3.1\";").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            ParamValue::ScheduleStatus {
+                statuses: vec![vec![3, 7], vec![5, 3, 1]]
+            },
+            param
+        );
+    }
 }
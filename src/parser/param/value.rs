@@ -1,14 +1,17 @@
 use crate::common::ParticipationStatusUnknown;
 use crate::common::{
-    CalendarUserType, Encoding, FreeBusyTimeType, RelationshipType, Role, TriggerRelationship,
-    Value,
+    CalendarUserType, DisplayType, Encoding, FeatureType, FreeBusyTimeType, RelationshipType, Role,
+    ScheduleAgent, ScheduleForceSend, TriggerRelationship, Value,
 };
-use crate::parser::{iana_token, param_text, read_string, x_name, Error};
+use crate::parser::{iana_token, param_text, read_int, read_string, x_name, Error};
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
+use nom::bytes::streaming::take_while1;
 use nom::character::streaming::char;
-use nom::combinator::{map_res, opt};
+use nom::combinator::{map_res, opt, verify};
 use nom::error::ParseError;
+use nom::multi::separated_list1;
+use nom::AsChar;
 use nom::IResult;
 use nom::Parser;
 
@@ -44,6 +47,32 @@ where
     Ok((input, cu_type))
 }
 
+pub fn param_value_display_type<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], DisplayType, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, display) = alt((
+        tag_no_case("BADGE").map(|_| DisplayType::Badge),
+        tag_no_case("GRAPHIC").map(|_| DisplayType::Graphic),
+        tag_no_case("FULLSIZE").map(|_| DisplayType::FullSize),
+        tag_no_case("THUMBNAIL").map(|_| DisplayType::Thumbnail),
+        map_res(x_name, |x_name| {
+            Ok(DisplayType::XName(read_string(x_name, "DISPLAY x-name")?))
+        }),
+        map_res(iana_token, |iana_token| {
+            Ok(DisplayType::IanaToken(read_string(
+                iana_token,
+                "DISPLAY iana-token",
+            )?))
+        }),
+    ))
+    .parse(input)?;
+
+    Ok((input, display))
+}
+
 pub fn param_value_encoding<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], Encoding, E>
 where
     E: ParseError<&'a [u8]> + From<Error<'a>>,
@@ -57,6 +86,35 @@ where
     Ok((input, encoding))
 }
 
+pub fn param_value_feature_type<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], FeatureType, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, feature) = alt((
+        tag_no_case("AUDIO").map(|_| FeatureType::Audio),
+        tag_no_case("CHAT").map(|_| FeatureType::Chat),
+        tag_no_case("FEED").map(|_| FeatureType::Feed),
+        tag_no_case("MODERATOR").map(|_| FeatureType::Moderator),
+        tag_no_case("PHONE").map(|_| FeatureType::Phone),
+        tag_no_case("SCREEN").map(|_| FeatureType::Screen),
+        tag_no_case("VIDEO").map(|_| FeatureType::Video),
+        map_res(x_name, |x_name| {
+            Ok(FeatureType::XName(read_string(x_name, "FEATURE x-name")?))
+        }),
+        map_res(iana_token, |iana_token| {
+            Ok(FeatureType::IanaToken(read_string(
+                iana_token,
+                "FEATURE iana-token",
+            )?))
+        }),
+    ))
+    .parse(input)?;
+
+    Ok((input, feature))
+}
+
 pub fn param_value_free_busy_time_type<'a, E>(
     input: &'a [u8],
 ) -> IResult<&'a [u8], FreeBusyTimeType, E>
@@ -149,6 +207,21 @@ where
         tag_no_case("PARENT").map(|_| RelationshipType::Parent),
         tag_no_case("CHILD").map(|_| RelationshipType::Child),
         tag_no_case("SIBLING").map(|_| RelationshipType::Sibling),
+        tag_no_case("SNOOZE").map(|_| RelationshipType::Snooze),
+        // RFC 9253
+        alt((
+            tag_no_case("CONCEPT").map(|_| RelationshipType::Concept),
+            tag_no_case("DEPENDS-ON").map(|_| RelationshipType::DependsOn),
+            tag_no_case("FIRST").map(|_| RelationshipType::First),
+            tag_no_case("FINISHTOFINISH").map(|_| RelationshipType::FinishToFinish),
+            tag_no_case("FINISHTOSTART").map(|_| RelationshipType::FinishToStart),
+            tag_no_case("NEXT").map(|_| RelationshipType::Next),
+            tag_no_case("REFID").map(|_| RelationshipType::RefId),
+            tag_no_case("REPLACES").map(|_| RelationshipType::Replaces),
+            tag_no_case("REQUIRES").map(|_| RelationshipType::Requires),
+            tag_no_case("STARTTOFINISH").map(|_| RelationshipType::StartToFinish),
+            tag_no_case("STARTTOSTART").map(|_| RelationshipType::StartToStart),
+        )),
         map_res(x_name, |x_name| {
             Ok(RelationshipType::XName(read_string(
                 x_name,
@@ -203,6 +276,80 @@ where
     Ok((input, rsvp))
 }
 
+pub fn param_value_schedule_agent<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ScheduleAgent, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, agent) = alt((
+        tag_no_case("SERVER").map(|_| ScheduleAgent::Server),
+        tag_no_case("CLIENT").map(|_| ScheduleAgent::Client),
+        tag_no_case("NONE").map(|_| ScheduleAgent::None),
+        map_res(x_name, |x_name| {
+            Ok(ScheduleAgent::XName(read_string(
+                x_name,
+                "SCHEDULE-AGENT x-name",
+            )?))
+        }),
+        map_res(iana_token, |iana_token| {
+            Ok(ScheduleAgent::IanaToken(read_string(
+                iana_token,
+                "SCHEDULE-AGENT iana-token",
+            )?))
+        }),
+    ))
+    .parse(input)?;
+
+    Ok((input, agent))
+}
+
+pub fn param_value_schedule_force_send<'a, E>(
+    input: &'a [u8],
+) -> IResult<&'a [u8], ScheduleForceSend, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, force_send) = alt((
+        tag_no_case("REQUEST").map(|_| ScheduleForceSend::Request),
+        tag_no_case("REPLY").map(|_| ScheduleForceSend::Reply),
+        map_res(x_name, |x_name| {
+            Ok(ScheduleForceSend::XName(read_string(
+                x_name,
+                "SCHEDULE-FORCE-SEND x-name",
+            )?))
+        }),
+        map_res(iana_token, |iana_token| {
+            Ok(ScheduleForceSend::IanaToken(read_string(
+                iana_token,
+                "SCHEDULE-FORCE-SEND iana-token",
+            )?))
+        }),
+    ))
+    .parse(input)?;
+
+    Ok((input, force_send))
+}
+
+/// A status code, as used by SCHEDULE-STATUS, with two or three numeric parts such as `2.0`.
+pub fn param_value_status_code<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], Vec<u32>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    verify(
+        separated_list1(
+            char('.'),
+            map_res(take_while1(AsChar::is_dec_digit), |v| read_int::<E, u32>(v)),
+        ),
+        |parts: &Vec<u32>| parts.len() == 2 || parts.len() == 3,
+    )
+    .parse(input)
+}
+
 pub fn param_value_time_zone_id<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], (String, bool), E>
 where
     E: ParseError<&'a [u8]> + From<Error<'a>>,
@@ -234,6 +381,8 @@ where
         tag_no_case("TIME").map(|_| Value::Time),
         tag_no_case("URI").map(|_| Value::Uri),
         tag_no_case("UTC-OFFSET").map(|_| Value::UtcOffset),
+        tag_no_case("UID").map(|_| Value::Uid),
+        tag_no_case("XML-REFERENCE").map(|_| Value::XmlReference),
         map_res(x_name, |x_name| {
             Ok(Value::XName(read_string(x_name, "VALUE x-name")?))
         }),
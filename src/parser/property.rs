@@ -4,18 +4,19 @@ pub(crate) mod uri;
 pub(crate) mod value;
 
 use crate::parser::param::{other_params, property_params};
+use crate::parser::property::uri::param_value_uri;
 use crate::parser::types::{
-    CalendarScaleProperty, IanaProperty, MethodProperty, ProductIdProperty, VersionProperty,
-    XProperty,
+    CalendarScaleProperty, IanaProperty, MethodProperty, NameProperty, ProductIdProperty,
+    RefreshIntervalProperty, SourceProperty, VersionProperty, XProperty,
 };
-use crate::parser::{iana_token, prop_value_text, value, x_name, Error};
+use crate::parser::{iana_token, prop_value_duration, prop_value_text, value, x_name, Error};
 use crate::single;
 pub use component::*;
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::bytes::streaming::tag;
-use nom::character::streaming::char;
-use nom::combinator::{cut, recognize, verify};
+use nom::character::streaming::{char, one_of};
+use nom::combinator::{cut, peek, recognize, verify};
 use nom::error::ParseError;
 use nom::{AsChar, IResult, Parser};
 
@@ -131,6 +132,71 @@ where
     ))
 }
 
+/// Parse a NAME property.
+///
+/// RFC 7986, section 5.1
+pub fn prop_name<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], NameProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, (params, _, value, _))) = (
+        tag_no_case("NAME"),
+        peek(one_of(";:")),
+        cut((property_params, char(':'), prop_value_text, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((input, NameProperty { params, value }))
+}
+
+/// Parse a REFRESH-INTERVAL property.
+///
+/// RFC 7986, section 5.7
+pub fn prop_refresh_interval<'a, E>(
+    input: &'a [u8],
+) -> IResult<&'a [u8], RefreshIntervalProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, (params, _, value, _))) = (
+        tag_no_case("REFRESH-INTERVAL"),
+        peek(one_of(";:")),
+        cut((property_params, char(':'), prop_value_duration, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((input, RefreshIntervalProperty { params, value }))
+}
+
+/// Parse a SOURCE property.
+///
+/// RFC 7986, section 5.8
+pub fn prop_source<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], SourceProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, _, (other_params, _, value, _))) = (
+        tag_no_case("SOURCE"),
+        peek(one_of(";:")),
+        cut((other_params, char(':'), param_value_uri, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        SourceProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
 pub fn prop_x<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], XProperty<'a>, E>
 where
     E: ParseError<&'a [u8]>
@@ -354,4 +420,35 @@ mod tests {
         );
         assert_eq!(prop.value, b"TRUE");
     }
+
+    #[test]
+    fn name_property() {
+        let (rem, prop) = prop_name::<Error>(b"NAME:Company Vacation Days\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert!(prop.params.is_empty());
+        assert_eq!(prop.value, b"Company Vacation Days");
+    }
+
+    #[test]
+    fn refresh_interval_property() {
+        let (rem, prop) =
+            prop_refresh_interval::<Error>(b"REFRESH-INTERVAL;VALUE=DURATION:P1W\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop.params,
+            vec![ParamValue::ValueType {
+                value: Value::Duration
+            }]
+        );
+        assert_eq!(prop.value.weeks, Some(1));
+    }
+
+    #[test]
+    fn source_property() {
+        let (rem, prop) =
+            prop_source::<Error>(b"SOURCE:https://example.com/holidays.ics\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert!(prop.other_params.is_empty());
+        assert_eq!(prop.value.to_string(), "https://example.com/holidays.ics");
+    }
 }
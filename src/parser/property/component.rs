@@ -3,18 +3,21 @@ use crate::parser::param::{other_params, property_params};
 use crate::parser::property::recur::prop_value_recur;
 use crate::parser::property::uri::param_value_uri;
 use crate::parser::types::{
-    Action, ActionProperty, AttachProperty, AttachValue, AttendeeProperty, CategoriesProperty,
-    Classification, ClassificationProperty, CommentProperty, ContactProperty, DateOrDateTime,
-    DateOrDateTimeOrPeriod, DateTimeCompletedProperty, DateTimeCreatedProperty,
+    AcknowledgedProperty, Action, ActionProperty, AttachProperty, AttachValue, AttendeeProperty,
+    BusyType, BusyTypeProperty, CategoriesProperty, Classification, ClassificationProperty,
+    ColorProperty, CommentProperty, ConceptProperty, ConferenceProperty, ContactProperty,
+    DateOrDateTime, DateOrDateTimeOrPeriod, DateTimeCompletedProperty, DateTimeCreatedProperty,
     DateTimeDueProperty, DateTimeEndProperty, DateTimeStampProperty, DateTimeStartProperty,
     DescriptionProperty, DurationOrDateTime, DurationProperty, ExceptionDateTimesProperty,
-    FreeBusyTimeProperty, GeographicPositionProperty, LastModifiedProperty, LocationProperty,
-    OrganizerProperty, ParamValue, PercentCompleteProperty, PriorityProperty,
-    RecurrenceDateTimesProperty, RecurrenceIdProperty, RecurrenceRuleProperty, RelatedToProperty,
-    RepeatProperty, RequestStatusProperty, ResourcesProperty, SequenceProperty, StatusProperty,
-    SummaryProperty, TimeTransparencyProperty, TimeZoneIdProperty, TimeZoneNameProperty,
-    TimeZoneOffsetProperty, TimeZoneUrlProperty, TriggerProperty, UniqueIdentifierProperty,
-    UrlProperty,
+    FreeBusyTimeProperty, GeographicPositionProperty, ImageProperty, LastModifiedProperty,
+    LinkProperty, LocationProperty, LocationTypeProperty, OrganizerProperty, ParamValue,
+    ParticipantType, ParticipantTypeProperty, PercentCompleteProperty, PriorityProperty, Proximity,
+    ProximityProperty, RecurrenceDateTimesProperty, RecurrenceIdProperty, RecurrenceRuleProperty,
+    RefIdProperty, RelatedToProperty, RepeatProperty, RequestStatusProperty, ResourceType,
+    ResourceTypeProperty, ResourcesProperty, SequenceProperty, StatusProperty,
+    StructuredDataProperty, StructuredDataValue, StyledDescriptionProperty, SummaryProperty,
+    TimeTransparencyProperty, TimeZoneIdProperty, TimeZoneNameProperty, TimeZoneOffsetProperty,
+    TimeZoneUrlProperty, TriggerProperty, UniqueIdentifierProperty, UrlProperty,
 };
 use crate::parser::{iana_token, read_int, x_name, Error, InnerError};
 use crate::parser::{
@@ -43,6 +46,21 @@ where
     let (input, (_, params, _)) =
         (tag_no_case("ATTACH"), cut(property_params), char(':')).parse(input)?;
 
+    let (input, (value, _)) = ((|i| attach_value(&params, i)), tag("\r\n")).parse(input)?;
+
+    Ok((input, AttachProperty { params, value }))
+}
+
+/// Parse the value of a property which may hold either a URI or inline binary content, like ATTACH.
+fn attach_value<'a, E>(
+    params: &[ParamValue<'a>],
+    input: &'a [u8],
+) -> IResult<&'a [u8], AttachValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
     let is_base_64 = params.iter().any(|p| {
         matches!(
             p,
@@ -64,28 +82,258 @@ where
     // Use OR here rather than AND. It's not valid to set one of these and not the other so assume the
     // value is more likely to be binary if one is set and let the error happen later if so.
     if is_base_64 || is_binary {
-        let (input, (v, _)) = (cut(prop_value_binary), tag("\r\n")).parse(input)?;
-
-        Ok((
-            input,
-            AttachProperty {
-                params,
-                value: AttachValue::Binary(v),
-            },
-        ))
+        cut(prop_value_binary).map(AttachValue::Binary).parse(input)
     } else {
-        let (input, (v, _)) = (cut(recognize(param_value_uri)), tag("\r\n")).parse(input)?;
+        cut(recognize(param_value_uri))
+            .map(AttachValue::Uri)
+            .parse(input)
+    }
+}
 
-        Ok((
-            input,
-            AttachProperty {
-                params,
-                value: AttachValue::Uri(v),
-            },
-        ))
+/// Parse a COLOR property.
+///
+/// RFC 7986, section 5.9
+pub fn prop_color<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ColorProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("COLOR"),
+        cut((other_params, char(':'), prop_value_text, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ColorProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a CONFERENCE property.
+///
+/// RFC 7986, section 5.11
+pub fn prop_conference<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ConferenceProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, (params, _, value, _))) = (
+        tag_no_case("CONFERENCE"),
+        cut((
+            property_params,
+            char(':'),
+            recognize(param_value_uri),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((input, ConferenceProperty { params, value }))
+}
+
+/// Parse an IMAGE property.
+///
+/// RFC 7986, section 5.10
+pub fn prop_image<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ImageProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, params, _)) =
+        (tag_no_case("IMAGE"), cut(property_params), char(':')).parse(input)?;
+
+    let (input, (value, _)) = ((|i| attach_value(&params, i)), tag("\r\n")).parse(input)?;
+
+    Ok((input, ImageProperty { params, value }))
+}
+
+/// Parse a STYLED-DESCRIPTION property.
+///
+/// RFC 9073, section 6.5
+pub fn prop_styled_description<'a, E>(
+    input: &'a [u8],
+) -> IResult<&'a [u8], StyledDescriptionProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, params, _)) = (
+        tag_no_case("STYLED-DESCRIPTION"),
+        cut(property_params),
+        char(':'),
+    )
+        .parse(input)?;
+
+    let (input, (value, _)) =
+        ((|i| structured_data_value(&params, i)), tag("\r\n")).parse(input)?;
+
+    Ok((input, StyledDescriptionProperty { params, value }))
+}
+
+/// Parse a STRUCTURED-DATA property.
+///
+/// RFC 9073, section 6.6
+pub fn prop_structured_data<'a, E>(
+    input: &'a [u8],
+) -> IResult<&'a [u8], StructuredDataProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, params, _)) = (
+        tag_no_case("STRUCTURED-DATA"),
+        cut(property_params),
+        char(':'),
+    )
+        .parse(input)?;
+
+    let (input, (value, _)) =
+        ((|i| structured_data_value(&params, i)), tag("\r\n")).parse(input)?;
+
+    Ok((input, StructuredDataProperty { params, value }))
+}
+
+/// Parse the value of a property which may hold text, a URI or inline binary content, depending on
+/// the declared value type, like STRUCTURED-DATA.
+fn structured_data_value<'a, E>(
+    params: &[ParamValue<'a>],
+    input: &'a [u8],
+) -> IResult<&'a [u8], StructuredDataValue<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let value_type = params.iter().find_map(|p| match p {
+        ParamValue::ValueType { value } => Some(value),
+        _ => None,
+    });
+
+    match value_type {
+        Some(Value::Uri) => cut(recognize(param_value_uri))
+            .map(StructuredDataValue::Uri)
+            .parse(input),
+        Some(Value::Binary) => cut(prop_value_binary)
+            .map(StructuredDataValue::Binary)
+            .parse(input),
+        // TEXT is required to be declared, but anything else is best read as text and left for
+        // validation to report.
+        _ => cut(prop_value_text)
+            .map(StructuredDataValue::Text)
+            .parse(input),
     }
 }
 
+/// Parse a LOCATION-TYPE property.
+///
+/// RFC 9073, section 6.1
+pub fn prop_location_type<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], LocationTypeProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("LOCATION-TYPE"),
+        cut((
+            other_params,
+            char(':'),
+            separated_list1(char(','), prop_value_text),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        LocationTypeProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a PARTICIPANT-TYPE property.
+///
+/// RFC 9073, section 6.2
+pub fn prop_participant_type<'a, E>(
+    input: &'a [u8],
+) -> IResult<&'a [u8], ParticipantTypeProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("PARTICIPANT-TYPE"),
+        cut((
+            other_params,
+            char(':'),
+            alt((
+                tag_no_case("ACTIVE").map(|_| ParticipantType::Active),
+                tag_no_case("INACTIVE").map(|_| ParticipantType::Inactive),
+                tag_no_case("SPONSOR").map(|_| ParticipantType::Sponsor),
+                tag_no_case("CONTACT").map(|_| ParticipantType::Contact),
+                tag_no_case("BOOKING-CONTACT").map(|_| ParticipantType::BookingContact),
+                tag_no_case("EMERGENCY-CONTACT").map(|_| ParticipantType::EmergencyContact),
+                tag_no_case("PUBLICITY-CONTACT").map(|_| ParticipantType::PublicityContact),
+                tag_no_case("PLANNER-CONTACT").map(|_| ParticipantType::PlannerContact),
+                tag_no_case("PERFORMER").map(|_| ParticipantType::Performer),
+                tag_no_case("SPEAKER").map(|_| ParticipantType::Speaker),
+                x_name.map(ParticipantType::XName),
+                iana_token.map(ParticipantType::IanaToken),
+            )),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ParticipantTypeProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a RESOURCE-TYPE property.
+///
+/// RFC 9073, section 6.3
+pub fn prop_resource_type<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ResourceTypeProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("RESOURCE-TYPE"),
+        cut((
+            other_params,
+            char(':'),
+            alt((
+                tag_no_case("PROJECTOR").map(|_| ResourceType::Projector),
+                tag_no_case("ROOM").map(|_| ResourceType::Room),
+                tag_no_case("REMOTE-CONFERENCE-AUDIO").map(|_| ResourceType::RemoteConferenceAudio),
+                tag_no_case("REMOTE-CONFERENCE-VIDEO").map(|_| ResourceType::RemoteConferenceVideo),
+                x_name.map(ResourceType::XName),
+                iana_token.map(ResourceType::IanaToken),
+            )),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ResourceTypeProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
 /// Parse a CATEGORIES property.
 ///
 /// RFC 5545, section 3.8.1.2
@@ -109,6 +357,39 @@ where
     Ok((input, CategoriesProperty { params, value }))
 }
 
+/// Parse a BUSYTYPE property.
+///
+/// RFC 7953, section 3.2
+pub fn prop_busy_type<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], BusyTypeProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("BUSYTYPE"),
+        cut((
+            other_params,
+            char(':'),
+            alt((
+                tag_no_case("BUSY-UNAVAILABLE").map(|_| BusyType::BusyUnavailable),
+                tag_no_case("BUSY-TENTATIVE").map(|_| BusyType::BusyTentative),
+                tag_no_case("BUSY").map(|_| BusyType::Busy),
+                x_name.map(BusyType::XName),
+                iana_token.map(BusyType::IanaToken),
+            )),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        BusyTypeProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
 /// Parse a CLASS property.
 ///
 /// RFC 5545, section 3.8.1.3
@@ -769,11 +1050,10 @@ where
         + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
         + From<Error<'a>>,
 {
-    let (input, (_, (params, _, value, _))) = (
-        tag_no_case("RELATED-TO"),
-        cut((property_params, char(':'), prop_value_text, tag("\r\n"))),
-    )
-        .parse(input)?;
+    let (input, (_, params, _)) =
+        (tag_no_case("RELATED-TO"), cut(property_params), char(':')).parse(input)?;
+
+    let (input, (value, _)) = ((|i| reference_value(&params, i)), tag("\r\n")).parse(input)?;
 
     Ok((input, RelatedToProperty { params, value }))
 }
@@ -1019,6 +1299,160 @@ where
     Ok((input, TriggerProperty { params, value }))
 }
 
+/// Parse an ACKNOWLEDGED property.
+///
+/// RFC 9074, section 6
+pub fn prop_acknowledged<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], AcknowledgedProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("ACKNOWLEDGED"),
+        cut((other_params, char(':'), prop_value_date_time, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        AcknowledgedProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a PROXIMITY property.
+///
+/// RFC 9074, section 8.1
+pub fn prop_proximity<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ProximityProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("PROXIMITY"),
+        cut((
+            other_params,
+            char(':'),
+            alt((
+                tag_no_case("ARRIVE").map(|_| Proximity::Arrive),
+                tag_no_case("DEPART").map(|_| Proximity::Depart),
+                tag_no_case("CONNECT").map(|_| Proximity::Connect),
+                tag_no_case("DISCONNECT").map(|_| Proximity::Disconnect),
+                x_name.map(Proximity::XName),
+                iana_token.map(Proximity::IanaToken),
+            )),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ProximityProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a CONCEPT property.
+///
+/// RFC 9253, section 7.1
+pub fn prop_concept<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], ConceptProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("CONCEPT"),
+        cut((
+            other_params,
+            char(':'),
+            recognize(param_value_uri),
+            tag("\r\n"),
+        )),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        ConceptProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse a LINK property.
+///
+/// RFC 9253, section 7.2
+pub fn prop_link<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], LinkProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let (input, (_, params, _)) =
+        (tag_no_case("LINK"), cut(property_params), char(':')).parse(input)?;
+
+    let (input, (value, _)) = ((|i| reference_value(&params, i)), tag("\r\n")).parse(input)?;
+
+    Ok((input, LinkProperty { params, value }))
+}
+
+/// Parse a REFID property.
+///
+/// RFC 9253, section 7.3
+pub fn prop_ref_id<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], RefIdProperty<'a>, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, (_, (other_params, _, value, _))) = (
+        tag_no_case("REFID"),
+        cut((other_params, char(':'), prop_value_text, tag("\r\n"))),
+    )
+        .parse(input)?;
+
+    Ok((
+        input,
+        RefIdProperty {
+            other_params,
+            value,
+        },
+    ))
+}
+
+/// Parse the value of a property which refers to another resource, like LINK or RELATED-TO.
+///
+/// URI and XML-REFERENCE values are kept as they were written, anything else is read as text.
+fn reference_value<'a, E>(
+    params: &[ParamValue<'a>],
+    input: &'a [u8],
+) -> IResult<&'a [u8], Vec<u8>, E>
+where
+    E: ParseError<&'a [u8]>
+        + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
+        + From<Error<'a>>,
+{
+    let value_type = params.iter().find_map(|p| match p {
+        ParamValue::ValueType { value } => Some(value),
+        _ => None,
+    });
+
+    match value_type {
+        Some(Value::Uri) => cut(recognize(param_value_uri))
+            .map(|v: &[u8]| v.to_vec())
+            .parse(input),
+        // An XPointer fragment may contain characters that are not allowed in a URI, such as
+        // brackets, so read up to the end of the line.
+        Some(Value::XmlReference) => cut(recognize(prop_value_text))
+            .map(|v: &[u8]| v.to_vec())
+            .parse(input),
+        _ => cut(prop_value_text).parse(input),
+    }
+}
+
 /// Parse a CREATED property.
 ///
 /// RFC 5545, section 3.8.7.1
@@ -1250,6 +1684,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn busy_type() {
+        let (rem, prop) = prop_busy_type::<Error>(b"BUSYTYPE:BUSY-TENTATIVE\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            BusyTypeProperty {
+                other_params: vec![],
+                value: BusyType::BusyTentative,
+            }
+        );
+    }
+
+    #[test]
+    fn styled_description() {
+        let (rem, prop) = prop_styled_description::<Error>(
+            b"STYLED-DESCRIPTION;VALUE=TEXT;FMTTYPE=text/html:<p>Hello\\, world</p>\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            StyledDescriptionProperty {
+                params: vec![
+                    ParamValue::ValueType { value: Value::Text },
+                    ParamValue::FormatType {
+                        type_name: "text".to_string(),
+                        sub_type_name: "html".to_string(),
+                    },
+                ],
+                value: StructuredDataValue::Text(b"<p>Hello, world</p>".to_vec()),
+            }
+        );
+    }
+
+    #[test]
+    fn structured_data_binary() {
+        let (rem, prop) = prop_structured_data::<Error>(
+            b"STRUCTURED-DATA;ENCODING=BASE64;VALUE=BINARY:dGVzdA==\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            StructuredDataProperty {
+                params: vec![
+                    ParamValue::Encoding {
+                        encoding: Encoding::Base64,
+                    },
+                    ParamValue::ValueType {
+                        value: Value::Binary
+                    },
+                ],
+                value: StructuredDataValue::Binary(b"dGVzdA=="),
+            }
+        );
+    }
+
+    #[test]
+    fn location_type() {
+        let (rem, prop) =
+            prop_location_type::<Error>(b"LOCATION-TYPE:auditorium,parking\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            LocationTypeProperty {
+                other_params: vec![],
+                value: vec![b"auditorium".to_vec(), b"parking".to_vec()],
+            }
+        );
+    }
+
+    #[test]
+    fn participant_type() {
+        let (rem, prop) =
+            prop_participant_type::<Error>(b"PARTICIPANT-TYPE:EMERGENCY-CONTACT\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            ParticipantTypeProperty {
+                other_params: vec![],
+                value: ParticipantType::EmergencyContact,
+            }
+        );
+    }
+
+    #[test]
+    fn acknowledged() {
+        let (rem, prop) =
+            prop_acknowledged::<Error>(b"ACKNOWLEDGED:20090604T084500Z\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            AcknowledgedProperty {
+                other_params: vec![],
+                value: DateTime {
+                    date: Date {
+                        year: 2009,
+                        month: 6,
+                        day: 4,
+                    },
+                    time: Time {
+                        hour: 8,
+                        minute: 45,
+                        second: 0,
+                        is_utc: true,
+                    },
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn proximity() {
+        let (rem, prop) = prop_proximity::<Error>(b"PROXIMITY:DISCONNECT\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            ProximityProperty {
+                other_params: vec![],
+                value: Proximity::Disconnect,
+            }
+        );
+    }
+
     #[test]
     fn classification_public() {
         let (rem, prop) = prop_classification::<Error>(b"CLASS:PUBLIC\r\n;").unwrap();
@@ -2444,4 +3003,116 @@ RSVP to team leader."#
             }
         );
     }
+
+    #[test]
+    fn color() {
+        let (rem, prop) = prop_color::<Error>(b"COLOR:turquoise\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            ColorProperty {
+                other_params: vec![],
+                value: b"turquoise".to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn image() {
+        let (rem, prop) = prop_image::<Error>(
+            b"IMAGE;VALUE=URI;FMTTYPE=image/png:http://example.com/images/party.png\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop.value,
+            AttachValue::Uri(b"http://example.com/images/party.png")
+        );
+        assert_eq!(prop.params.len(), 2);
+    }
+
+    #[test]
+    fn conference() {
+        let (rem, prop) = prop_conference::<Error>(
+            b"CONFERENCE;VALUE=URI;LANGUAGE=en:tel:+1-412-555-0123,,,654321\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(prop.value, b"tel:+1-412-555-0123,,,654321");
+        assert_eq!(prop.params.len(), 2);
+    }
+
+    #[test]
+    fn related_to_uri() {
+        let (rem, prop) = prop_related_to::<Error>(
+            b"RELATED-TO;VALUE=URI;RELTYPE=STARTTOFINISH;GAP=PT1H:https://example.com/caldav/user/jb/cal/event.ics\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop.value,
+            b"https://example.com/caldav/user/jb/cal/event.ics".to_vec()
+        );
+        assert_eq!(prop.params.len(), 3);
+    }
+
+    #[test]
+    fn concept() {
+        let (rem, prop) =
+            prop_concept::<Error>(b"CONCEPT:https://example.com/event-types/arts/music\r\n;")
+                .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            ConceptProperty {
+                other_params: vec![],
+                value: b"https://example.com/event-types/arts/music",
+            }
+        );
+    }
+
+    #[test]
+    fn link_xml_reference() {
+        let (rem, prop) = prop_link::<Error>(
+            b"LINK;LINKREL=\"https://example.com/linkrel/derivedFrom\";VALUE=XML-REFERENCE:https://example.com/xmlDocs/bidFramework.xml#xpointer(descendant::CostStructure/range-start)\r\n;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop.value,
+            b"https://example.com/xmlDocs/bidFramework.xml#xpointer(descendant::CostStructure/range-start)".to_vec()
+        );
+        assert_eq!(
+            prop.params,
+            vec![
+                ParamValue::LinkRelation {
+                    relation: "https://example.com/linkrel/derivedFrom".to_string(),
+                },
+                ParamValue::ValueType {
+                    value: Value::XmlReference,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn link_uid() {
+        let (rem, prop) =
+            prop_link::<Error>(b"LINK;LINKREL=SOURCE;VALUE=UID:1234-5678\\, abcd\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(prop.value, b"1234-5678, abcd".to_vec());
+    }
+
+    #[test]
+    fn ref_id() {
+        let (rem, prop) = prop_ref_id::<Error>(b"REFID:itinerary-2014-11-17\r\n;").unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            prop,
+            RefIdProperty {
+                other_params: vec![],
+                value: b"itinerary-2014-11-17".to_vec(),
+            }
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::common::{OffsetWeekday, RecurFreq, Weekday};
+use crate::common::{OffsetWeekday, RecurFreq, RecurMonth, Skip, Weekday};
 use crate::parser::types::{DateOrDateTime, DateTime, RecurRulePart};
 use crate::parser::{prop_value_date, prop_value_time};
 use crate::parser::{Error, InnerError};
@@ -59,6 +59,8 @@ where
             .map(RecurRulePart::BySetPos)
             .parse(input),
         "WKST" => weekday.map(RecurRulePart::WeekStart).parse(input),
+        "RSCALE" => recur_rscale.map(RecurRulePart::RScale).parse(input),
+        "SKIP" => recur_skip.map(RecurRulePart::Skip).parse(input),
         n => Err(nom::Err::Error(
             Error::new(input, InnerError::InvalidRecurPart(n.to_string())).into(),
         )),
@@ -83,6 +85,37 @@ where
     Ok((input, freq))
 }
 
+fn recur_rscale<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], String, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    let (input, rscale) = take_while1(|c| AsChar::is_alphanum(c) || c == b'-')(input)?;
+
+    let rscale = std::str::from_utf8(rscale).map_err(|e| {
+        nom::Err::Error(
+            Error::new(
+                input,
+                InnerError::EncodingError("Recur rscale".to_string(), e),
+            )
+            .into(),
+        )
+    })?;
+
+    Ok((input, rscale.to_string()))
+}
+
+fn recur_skip<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], Skip, E>
+where
+    E: ParseError<&'a [u8]> + From<Error<'a>>,
+{
+    alt((
+        tag("OMIT").map(|_| Skip::Omit),
+        tag("BACKWARD").map(|_| Skip::Backward),
+        tag("FORWARD").map(|_| Skip::Forward),
+    ))
+    .parse(input)
+}
+
 fn end_date<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], DateOrDateTime, E>
 where
     E: ParseError<&'a [u8]> + From<Error<'a>>,
@@ -323,7 +356,7 @@ where
     .parse(input)
 }
 
-fn recur_by_month_list<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], Vec<u8>, E>
+fn recur_by_month_list<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], Vec<RecurMonth>, E>
 where
     E: ParseError<&'a [u8]>
         + nom::error::FromExternalError<&'a [u8], nom::Err<E>>
@@ -331,20 +364,29 @@ where
 {
     separated_list1(
         char(','),
-        map_res(take_while_m_n(1, 2, AsChar::is_dec_digit), |num| {
-            std::str::from_utf8(num)
-                .map_err(|e| {
-                    nom::Err::Error(
-                        Error::new(
-                            input,
-                            InnerError::EncodingError("Recur month list".to_string(), e),
+        map_res(
+            (
+                take_while_m_n(1, 2, AsChar::is_dec_digit),
+                opt(char('L')).map(|leap| leap.is_some()),
+            ),
+            |(num, leap)| {
+                std::str::from_utf8(num)
+                    .map_err(|e| {
+                        nom::Err::Error(
+                            Error::new(
+                                input,
+                                InnerError::EncodingError("Recur month list".to_string(), e),
+                            )
+                            .into(),
                         )
-                        .into(),
-                    )
-                })?
-                .parse::<u8>()
-                .map_err(|_| nom::Err::Error(Error::new(input, InnerError::InvalidRecurNum).into()))
-        }),
+                    })?
+                    .parse::<u8>()
+                    .map_err(|_| {
+                        nom::Err::Error(Error::new(input, InnerError::InvalidRecurNum).into())
+                    })
+                    .map(|number| RecurMonth { number, leap })
+            },
+        ),
     )
     .parse(input)
 }
@@ -416,7 +458,7 @@ mod tests {
             vec![
                 RecurRulePart::Freq(RecurFreq::Yearly),
                 RecurRulePart::Interval(2),
-                RecurRulePart::ByMonth(vec![1]),
+                RecurRulePart::ByMonth(vec![RecurMonth::new(1)]),
                 RecurRulePart::ByDay(vec![OffsetWeekday {
                     offset_weeks: None,
                     weekday: Weekday::Sunday
@@ -426,4 +468,23 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn rscale_rule() {
+        let (rem, rule) = prop_value_recur::<Error>(
+            b"RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=5L,6;BYMONTHDAY=8;SKIP=FORWARD;",
+        )
+        .unwrap();
+        check_rem(rem, 1);
+        assert_eq!(
+            rule,
+            vec![
+                RecurRulePart::RScale("HEBREW".to_string()),
+                RecurRulePart::Freq(RecurFreq::Yearly),
+                RecurRulePart::ByMonth(vec![RecurMonth::leap_month(5), RecurMonth::new(6)]),
+                RecurRulePart::ByMonthDay(vec![8]),
+                RecurRulePart::Skip(Skip::Forward),
+            ]
+        );
+    }
 }
@@ -1,4 +1,7 @@
-use crate::parser::types::ParamValue;
+use crate::parser::types::{
+    ColorProperty, DescriptionProperty, Duration, ImageProperty, LastModifiedProperty, ParamValue,
+    Uri, UrlProperty,
+};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum CalendarProperty<'a> {
@@ -6,6 +9,14 @@ pub enum CalendarProperty<'a> {
     Version(VersionProperty<'a>),
     CalendarScale(CalendarScaleProperty<'a>),
     Method(MethodProperty<'a>),
+    Name(NameProperty<'a>),
+    Description(DescriptionProperty<'a>),
+    LastModified(LastModifiedProperty<'a>),
+    Url(UrlProperty<'a>),
+    RefreshInterval(RefreshIntervalProperty<'a>),
+    Source(SourceProperty<'a>),
+    Color(ColorProperty<'a>),
+    Image(ImageProperty<'a>),
     XProperty(XProperty<'a>),
     IanaProperty(IanaProperty<'a>),
 }
@@ -35,6 +46,24 @@ pub struct MethodProperty<'a> {
     pub value: &'a [u8],
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct NameProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct RefreshIntervalProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: Duration,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct SourceProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: Uri<'a>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct XProperty<'a> {
     pub name: &'a [u8],
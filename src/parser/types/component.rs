@@ -5,7 +5,8 @@ use crate::parser::types::ContentLine;
 pub enum CalendarComponent<'a> {
     Event {
         properties: Vec<ComponentProperty<'a>>,
-        alarms: Vec<CalendarComponent<'a>>,
+        /// The alarms, participants, locations and resources, in the order they appear.
+        components: Vec<CalendarComponent<'a>>,
     },
     ToDo {
         properties: Vec<ComponentProperty<'a>>,
@@ -30,6 +31,23 @@ pub enum CalendarComponent<'a> {
     Alarm {
         properties: Vec<ComponentProperty<'a>>,
     },
+    Availability {
+        properties: Vec<ComponentProperty<'a>>,
+        components: Vec<CalendarComponent<'a>>,
+    },
+    Available {
+        properties: Vec<ComponentProperty<'a>>,
+    },
+    Participant {
+        properties: Vec<ComponentProperty<'a>>,
+        components: Vec<CalendarComponent<'a>>,
+    },
+    Location {
+        properties: Vec<ComponentProperty<'a>>,
+    },
+    Resource {
+        properties: Vec<ComponentProperty<'a>>,
+    },
     IanaComp {
         name: &'a [u8],
         lines: Vec<ContentLine<'a>>,
@@ -48,6 +48,20 @@ pub enum ComponentProperty<'a> {
     Action(ActionProperty<'a>),
     Trigger(TriggerProperty<'a>),
     RepeatCount(RepeatProperty<'a>),
+    Color(ColorProperty<'a>),
+    Image(ImageProperty<'a>),
+    Conference(ConferenceProperty<'a>),
+    BusyType(BusyTypeProperty<'a>),
+    StyledDescription(StyledDescriptionProperty<'a>),
+    StructuredData(StructuredDataProperty<'a>),
+    LocationType(LocationTypeProperty<'a>),
+    ParticipantType(ParticipantTypeProperty<'a>),
+    ResourceType(ResourceTypeProperty<'a>),
+    Acknowledged(AcknowledgedProperty<'a>),
+    Proximity(ProximityProperty<'a>),
+    Concept(ConceptProperty<'a>),
+    Link(LinkProperty<'a>),
+    RefId(RefIdProperty<'a>),
     XProperty(XProperty<'a>),
     IanaProperty(IanaProperty<'a>),
 }
@@ -64,6 +78,27 @@ pub struct AttachProperty<'a> {
     pub value: AttachValue<'a>,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct ImageProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: AttachValue<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum BusyType<'a> {
+    Busy,
+    BusyUnavailable,
+    BusyTentative,
+    XName(&'a [u8]),
+    IanaToken(&'a [u8]),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BusyTypeProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: BusyType<'a>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct CategoriesProperty<'a> {
     pub params: Vec<ParamValue<'a>>,
@@ -85,12 +120,24 @@ pub struct ClassificationProperty<'a> {
     pub value: Classification<'a>,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct ColorProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: Vec<u8>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct CommentProperty<'a> {
     pub params: Vec<ParamValue<'a>>,
     pub value: Vec<u8>,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct ConferenceProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: &'a [u8],
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct DescriptionProperty<'a> {
     pub params: Vec<ParamValue<'a>>,
@@ -331,3 +378,108 @@ pub struct RequestStatusProperty<'a> {
     pub status_description: Vec<u8>,
     pub exception_data: Option<Vec<u8>>,
 }
+
+/// The value of a STYLED-DESCRIPTION or STRUCTURED-DATA property, which is chosen by the VALUE
+/// parameter.
+#[derive(Debug, Eq, PartialEq)]
+pub enum StructuredDataValue<'a> {
+    Text(Vec<u8>),
+    Uri(&'a [u8]),
+    Binary(&'a [u8]),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct StyledDescriptionProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: StructuredDataValue<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct StructuredDataProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: StructuredDataValue<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct LocationTypeProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParticipantType<'a> {
+    Active,
+    Inactive,
+    Sponsor,
+    Contact,
+    BookingContact,
+    EmergencyContact,
+    PublicityContact,
+    PlannerContact,
+    Performer,
+    Speaker,
+    XName(&'a [u8]),
+    IanaToken(&'a [u8]),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParticipantTypeProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: ParticipantType<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ResourceType<'a> {
+    Projector,
+    Room,
+    RemoteConferenceAudio,
+    RemoteConferenceVideo,
+    XName(&'a [u8]),
+    IanaToken(&'a [u8]),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ResourceTypeProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: ResourceType<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AcknowledgedProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: DateTime,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Proximity<'a> {
+    Arrive,
+    Depart,
+    Connect,
+    Disconnect,
+    XName(&'a [u8]),
+    IanaToken(&'a [u8]),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ProximityProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: Proximity<'a>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ConceptProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: &'a [u8],
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct LinkProperty<'a> {
+    pub params: Vec<ParamValue<'a>>,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct RefIdProperty<'a> {
+    pub other_params: Vec<ParamValue<'a>>,
+    pub value: Vec<u8>,
+}
@@ -1,7 +1,9 @@
 use crate::common::{
-    CalendarUserType, Encoding, FreeBusyTimeType, LanguageTag, ParticipationStatusUnknown, Range,
-    RelationshipType, Role, TriggerRelationship, Value,
+    CalendarUserType, DisplayType, Encoding, FeatureType, FreeBusyTimeType, LanguageTag,
+    ParticipationStatusUnknown, Range, RelationshipType, Role, ScheduleAgent, ScheduleForceSend,
+    TriggerRelationship, Value,
 };
+use crate::parser::types::Duration;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParamValue<'a> {
@@ -23,6 +25,12 @@ pub enum ParamValue<'a> {
     DirectoryEntryReference {
         uri: &'a [u8],
     },
+    Display {
+        display: Vec<DisplayType>,
+    },
+    Email {
+        email: String,
+    },
     Encoding {
         encoding: Encoding,
     },
@@ -31,12 +39,24 @@ pub enum ParamValue<'a> {
         type_name: String,
         sub_type_name: String,
     },
+    Feature {
+        features: Vec<FeatureType>,
+    },
     FreeBusyTimeType {
         fb_type: FreeBusyTimeType,
     },
+    Gap {
+        duration: Duration,
+    },
+    Label {
+        label: String,
+    },
     Language {
         language: LanguageTag,
     },
+    LinkRelation {
+        relation: String,
+    },
     Members {
         members: Vec<&'a [u8]>,
     },
@@ -59,6 +79,15 @@ pub enum ParamValue<'a> {
     Rsvp {
         rsvp: bool,
     },
+    ScheduleAgent {
+        agent: ScheduleAgent,
+    },
+    ScheduleForceSend {
+        force_send: ScheduleForceSend,
+    },
+    ScheduleStatus {
+        statuses: Vec<Vec<u32>>,
+    },
     SentBy {
         address: &'a [u8],
     },
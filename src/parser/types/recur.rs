@@ -1,4 +1,4 @@
-use crate::common::{OffsetWeekday, RecurFreq, Weekday};
+use crate::common::{OffsetWeekday, RecurFreq, RecurMonth, Skip, Weekday};
 use crate::parser::types::DateOrDateTime;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -14,7 +14,9 @@ pub enum RecurRulePart {
     ByMonthDay(Vec<i8>),
     ByYearDay(Vec<i16>),
     ByWeek(Vec<i8>),
-    ByMonth(Vec<u8>),
+    ByMonth(Vec<RecurMonth>),
     BySetPos(Vec<i16>),
     WeekStart(Weekday),
+    RScale(String),
+    Skip(Skip),
 }
@@ -0,0 +1,1016 @@
+use crate::expand::{tz_id_of, Expander, TimeRange};
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::Param;
+use crate::model::property::{
+    CalendarProperty, ComponentProperty, RecurrenceDateTimesPropertyValue, TriggerValue,
+};
+use crate::serialize::WriteModel;
+use crate::validate::{
+    calendar_property_name, component_name, component_property_name, param_name,
+};
+use std::borrow::Cow;
+
+/// Collations that can be used to compare text, as required by RFC 4791, 7.5.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Collation {
+    /// `i;ascii-casemap`, which compares ASCII letters case-insensitively.
+    #[default]
+    AsciiCaseMap,
+    /// `i;octet`, which compares the exact bytes.
+    Octet,
+}
+
+impl Collation {
+    /// Find a collation by its registered name, as used in the `collation` attribute.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i;ascii-casemap" => Some(Collation::AsciiCaseMap),
+            "i;octet" => Some(Collation::Octet),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Collation::AsciiCaseMap => "i;ascii-casemap",
+            Collation::Octet => "i;octet",
+        }
+    }
+
+    fn contains(&self, value: &str, text: &str) -> bool {
+        match self {
+            Collation::AsciiCaseMap => value
+                .to_ascii_lowercase()
+                .contains(&text.to_ascii_lowercase()),
+            Collation::Octet => value.contains(text),
+        }
+    }
+}
+
+/// A substring match against a property or parameter value, RFC 4791, 9.7.5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    text: String,
+    collation: Collation,
+    negate_condition: bool,
+}
+
+impl TextMatch {
+    pub fn new<T: ToString>(text: T) -> Self {
+        TextMatch {
+            text: text.to_string(),
+            collation: Collation::default(),
+            negate_condition: false,
+        }
+    }
+
+    pub fn set_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
+
+    pub fn set_negate_condition(mut self, negate_condition: bool) -> Self {
+        self.negate_condition = negate_condition;
+        self
+    }
+
+    /// Check the text against a set of values. The match succeeds if any of the values contains
+    /// the text, or if none of them do when the condition is negated.
+    pub fn matches<'v>(&self, mut values: impl Iterator<Item = &'v str>) -> bool {
+        let found = values.any(|v| self.collation.contains(v, &self.text));
+        found != self.negate_condition
+    }
+}
+
+/// Filter on a property parameter, RFC 4791, 9.7.3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamFilter {
+    name: String,
+    is_not_defined: bool,
+    text_match: Option<TextMatch>,
+}
+
+impl ParamFilter {
+    pub fn new<N: ToString>(name: N) -> Self {
+        ParamFilter {
+            name: name.to_string(),
+            is_not_defined: false,
+            text_match: None,
+        }
+    }
+
+    /// Match only if the parameter is not present.
+    pub fn set_is_not_defined(mut self) -> Self {
+        self.is_not_defined = true;
+        self
+    }
+
+    pub fn set_text_match(mut self, text_match: TextMatch) -> Self {
+        self.text_match = Some(text_match);
+        self
+    }
+
+    fn matches(&self, params: &[Param]) -> bool {
+        let mut named = params
+            .iter()
+            .filter(|p| param_name(p).eq_ignore_ascii_case(&self.name))
+            .peekable();
+
+        if self.is_not_defined {
+            return named.peek().is_none();
+        }
+
+        named.any(|param| match &self.text_match {
+            Some(text_match) => {
+                let values = param_values(param);
+                text_match.matches(values.iter().map(|v| v.as_ref()))
+            }
+            None => true,
+        })
+    }
+}
+
+/// Filter on a property, RFC 4791, 9.7.2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropFilter {
+    name: String,
+    is_not_defined: bool,
+    time_range: Option<TimeRange>,
+    text_match: Option<TextMatch>,
+    param_filters: Vec<ParamFilter>,
+}
+
+impl PropFilter {
+    pub fn new<N: ToString>(name: N) -> Self {
+        PropFilter {
+            name: name.to_string(),
+            is_not_defined: false,
+            time_range: None,
+            text_match: None,
+            param_filters: Vec::new(),
+        }
+    }
+
+    /// Match only if the property is not present.
+    pub fn set_is_not_defined(mut self) -> Self {
+        self.is_not_defined = true;
+        self
+    }
+
+    /// Match date and date-time properties whose value is within the range.
+    pub fn set_time_range(mut self, time_range: TimeRange) -> Self {
+        self.time_range = Some(time_range);
+        self
+    }
+
+    pub fn set_text_match(mut self, text_match: TextMatch) -> Self {
+        self.text_match = Some(text_match);
+        self
+    }
+
+    pub fn add_param_filter(mut self, param_filter: ParamFilter) -> Self {
+        self.param_filters.push(param_filter);
+        self
+    }
+
+    fn matches_component(&self, context: &QueryContext, properties: &[ComponentProperty]) -> bool {
+        let mut named = properties
+            .iter()
+            .filter(|p| component_property_name(p).eq_ignore_ascii_case(&self.name))
+            .peekable();
+
+        if self.is_not_defined {
+            return named.peek().is_none();
+        }
+
+        named.any(|property| {
+            if let Some(time_range) = &self.time_range {
+                let in_range = date_time_value(property)
+                    .map(|(value, tz_id)| {
+                        time_range.contains(context.expander.time_zones().resolve(value, tz_id))
+                    })
+                    .unwrap_or(false);
+                if !in_range {
+                    return false;
+                }
+            }
+
+            self.matches_text_and_params(|| component_property_text(property), property.params())
+        })
+    }
+
+    fn matches_calendar(&self, properties: &[CalendarProperty]) -> bool {
+        let mut named = properties
+            .iter()
+            .filter(|p| calendar_property_name(p).eq_ignore_ascii_case(&self.name))
+            .peekable();
+
+        if self.is_not_defined {
+            return named.peek().is_none();
+        }
+
+        // None of the calendar properties have date or date-time values.
+        if self.time_range.is_some() {
+            return false;
+        }
+
+        named.any(|property| {
+            self.matches_text_and_params(|| calendar_property_text(property), property.params())
+        })
+    }
+
+    fn matches_text_and_params<'p>(
+        &self,
+        values: impl FnOnce() -> Vec<Cow<'p, str>>,
+        params: &[Param],
+    ) -> bool {
+        if let Some(text_match) = &self.text_match {
+            if !text_match.matches(values().iter().map(|v| v.as_ref())) {
+                return false;
+            }
+        }
+
+        self.param_filters.iter().all(|f| f.matches(params))
+    }
+}
+
+/// Filter on a component, RFC 4791, 9.7.1.
+///
+/// The outermost filter should be for the `VCALENDAR`, which is matched against an
+/// [ICalObject] with [CompFilter::matches]. Nested filters select the components of the
+/// calendar, and then the components nested within those such as `VALARM` in a `VEVENT`.
+///
+/// Time ranges on components are evaluated following RFC 4791, 9.9, with recurring components
+/// expanded. Floating date-times are taken to be in UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompFilter {
+    name: String,
+    is_not_defined: bool,
+    time_range: Option<TimeRange>,
+    prop_filters: Vec<PropFilter>,
+    comp_filters: Vec<CompFilter>,
+}
+
+struct QueryContext<'a> {
+    expander: Expander<'a>,
+}
+
+impl CompFilter {
+    pub fn new<N: ToString>(name: N) -> Self {
+        CompFilter {
+            name: name.to_string(),
+            is_not_defined: false,
+            time_range: None,
+            prop_filters: Vec::new(),
+            comp_filters: Vec::new(),
+        }
+    }
+
+    /// Match only if no component with this name is present.
+    pub fn set_is_not_defined(mut self) -> Self {
+        self.is_not_defined = true;
+        self
+    }
+
+    /// Match components that have an occurrence overlapping the range.
+    pub fn set_time_range(mut self, time_range: TimeRange) -> Self {
+        self.time_range = Some(time_range);
+        self
+    }
+
+    pub fn add_prop_filter(mut self, prop_filter: PropFilter) -> Self {
+        self.prop_filters.push(prop_filter);
+        self
+    }
+
+    pub fn add_comp_filter(mut self, comp_filter: CompFilter) -> Self {
+        self.comp_filters.push(comp_filter);
+        self
+    }
+
+    /// Check whether a calendar matches this filter, which must be for `VCALENDAR`.
+    pub fn matches(&self, object: &ICalObject) -> bool {
+        if !self.name.eq_ignore_ascii_case("VCALENDAR") || self.is_not_defined {
+            return false;
+        }
+
+        // The calendar object itself has no time range.
+        if self.time_range.is_some() {
+            return false;
+        }
+
+        let context = QueryContext {
+            expander: Expander::new(object),
+        };
+
+        self.prop_filters
+            .iter()
+            .all(|f| f.matches_calendar(&object.properties))
+            && self
+                .comp_filters
+                .iter()
+                .all(|f| f.matches_in(&context, &object.components, None))
+    }
+
+    /// Select the components of a calendar that match this filter, which should be for one of
+    /// the components that may appear directly in a calendar, such as `VEVENT`.
+    pub fn select<'a>(&self, object: &'a ICalObject) -> Vec<&'a CalendarComponent> {
+        if self.is_not_defined {
+            return Vec::new();
+        }
+
+        let context = QueryContext {
+            expander: Expander::new(object),
+        };
+
+        object
+            .components
+            .iter()
+            .filter(|c| self.matches_component(&context, c, None))
+            .collect()
+    }
+
+    fn matches_in(
+        &self,
+        context: &QueryContext,
+        components: &[CalendarComponent],
+        parent: Option<&CalendarComponent>,
+    ) -> bool {
+        if self.is_not_defined {
+            return !components
+                .iter()
+                .any(|c| component_name(c).eq_ignore_ascii_case(&self.name));
+        }
+
+        components
+            .iter()
+            .any(|c| self.matches_component(context, c, parent))
+    }
+
+    fn matches_component(
+        &self,
+        context: &QueryContext,
+        component: &CalendarComponent,
+        parent: Option<&CalendarComponent>,
+    ) -> bool {
+        if !component_name(component).eq_ignore_ascii_case(&self.name) {
+            return false;
+        }
+
+        if let Some(time_range) = &self.time_range {
+            if !component_in_range(context, component, parent, time_range) {
+                return false;
+            }
+        }
+
+        self.prop_filters
+            .iter()
+            .all(|f| f.matches_component(context, component.properties()))
+            && self
+                .comp_filters
+                .iter()
+                .all(|f| f.matches_in(context, component.nested_components(), Some(component)))
+    }
+}
+
+fn component_in_range(
+    context: &QueryContext,
+    component: &CalendarComponent,
+    parent: Option<&CalendarComponent>,
+    range: &TimeRange,
+) -> bool {
+    let expander = &context.expander;
+
+    match component {
+        CalendarComponent::ToDo(_) => todo_in_range(context, component, range),
+        CalendarComponent::FreeBusy(_) => free_busy_in_range(context, component, range),
+        CalendarComponent::Alarm(_) => match parent {
+            Some(parent) => !expander.alarm_triggers(parent, component, range).is_empty(),
+            None => false,
+        },
+        _ => expander.occurrences(component, range).next().is_some(),
+    }
+}
+
+/// The VTODO rules from RFC 4791, 9.9.
+fn todo_in_range(context: &QueryContext, component: &CalendarComponent, range: &TimeRange) -> bool {
+    let resolver = context.expander.time_zones();
+
+    let mut has_start = false;
+    let mut has_due = false;
+    let mut has_duration = false;
+    let mut completed = None;
+    let mut created = None;
+    for property in component.properties() {
+        match property {
+            ComponentProperty::DateTimeStart(_) => has_start = true,
+            ComponentProperty::DateTimeDue(_) => has_due = true,
+            ComponentProperty::Duration(_) => has_duration = true,
+            ComponentProperty::DateTimeCompleted(p) => {
+                completed = Some(resolver.resolve(&p.value, tz_id_of(&p.params)))
+            }
+            ComponentProperty::DateTimeCreated(p) => {
+                created = Some(resolver.resolve(&p.value, tz_id_of(&p.params)))
+            }
+            _ => {}
+        }
+    }
+
+    let after = |t| range.start.is_none_or(|s| s <= t);
+    let after_strict = |t| range.start.is_none_or(|s| s < t);
+    let before = |t| range.end.is_none_or(|e| e > t);
+    let before_or_at = |t| range.end.is_none_or(|e| e >= t);
+
+    if has_start || has_due {
+        // The conditions allow a little outside the range, so widen it when expanding.
+        let widened = TimeRange {
+            start: range
+                .start
+                .and_then(|s| s.checked_sub(time::Duration::seconds(1))),
+            end: range
+                .end
+                .and_then(|e| e.checked_add(time::Duration::seconds(1))),
+        };
+
+        return context.expander.occurrences(component, &widened).any(|o| {
+            match (has_start, has_due, has_duration) {
+                (true, false, true) => after(o.end) && (before(o.start) || before_or_at(o.end)),
+                (true, true, _) => {
+                    (after_strict(o.end) || after(o.start))
+                        && (before(o.start) || before_or_at(o.end))
+                }
+                (true, false, false) => after(o.start) && before(o.start),
+                // Only a due date, which the expansion uses as the start
+                _ => after_strict(o.start) && before_or_at(o.start),
+            }
+        });
+    }
+
+    match (completed, created) {
+        (Some(completed), Some(created)) => {
+            (after(created) || after(completed))
+                && (before_or_at(created) || before_or_at(completed))
+        }
+        (Some(completed), None) => after(completed) && before_or_at(completed),
+        (None, Some(created)) => before(created),
+        (None, None) => true,
+    }
+}
+
+/// The VFREEBUSY rules from RFC 4791, 9.9.
+fn free_busy_in_range(
+    context: &QueryContext,
+    component: &CalendarComponent,
+    range: &TimeRange,
+) -> bool {
+    let resolver = context.expander.time_zones();
+
+    let mut any_periods = false;
+    for property in component.properties() {
+        if let ComponentProperty::FreeBusyTime(p) = property {
+            for period in &p.value {
+                any_periods = true;
+                if let Ok(Some((start, end))) = period.expand() {
+                    let start = resolver.resolve(&start, None);
+                    let end = resolver.resolve(&end, None);
+                    if range.start.is_none_or(|s| s < end) && range.end.is_none_or(|e| e > start) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    if any_periods {
+        return false;
+    }
+
+    let mut start = None;
+    let mut end = None;
+    for property in component.properties() {
+        match property {
+            ComponentProperty::DateTimeStart(p) => {
+                start = Some(resolver.resolve(&p.value, tz_id_of(&p.params)))
+            }
+            ComponentProperty::DateTimeEnd(p) => {
+                end = Some(resolver.resolve(&p.value, tz_id_of(&p.params)))
+            }
+            _ => {}
+        }
+    }
+
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            range.start.is_none_or(|s| s <= end) && range.end.is_none_or(|e| e > start)
+        }
+        _ => false,
+    }
+}
+
+fn date_time_value(
+    property: &ComponentProperty,
+) -> Option<(&crate::common::CalendarDateTime, Option<&str>)> {
+    let value = match property {
+        ComponentProperty::DateTimeStamp(p) => &p.value,
+        ComponentProperty::DateTimeStart(p) => &p.value,
+        ComponentProperty::DateTimeEnd(p) => &p.value,
+        ComponentProperty::DateTimeDue(p) => &p.value,
+        ComponentProperty::DateTimeCompleted(p) => &p.value,
+        ComponentProperty::DateTimeCreated(p) => &p.value,
+        ComponentProperty::LastModified(p) => &p.value,
+        ComponentProperty::RecurrenceId(p) => &p.value,
+        _ => return None,
+    };
+
+    Some((value, tz_id_of(property.params())))
+}
+
+/// Get the text of the values of a component property.
+///
+/// Text, URI and calendar address values are matched as they are stored. Other values are matched
+/// as they would be written to iCalendar, one entry for each value of a list.
+fn component_property_text(property: &ComponentProperty) -> Vec<Cow<'_, str>> {
+    match property {
+        ComponentProperty::Attach(p) => one(&p.value),
+        ComponentProperty::Categories(p) => many(&p.value),
+        ComponentProperty::Classification(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Comment(p) => one(&p.value),
+        ComponentProperty::Description(p) => one(&p.value),
+        ComponentProperty::GeographicPosition(p) => vec![Cow::Owned(format!(
+            "{};{}",
+            p.value.latitude, p.value.longitude
+        ))],
+        ComponentProperty::Location(p) => one(&p.value),
+        ComponentProperty::PercentComplete(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Priority(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Resources(p) => many(&p.value),
+        ComponentProperty::Status(p) => written(&p.value),
+        ComponentProperty::Summary(p) => one(&p.value),
+        ComponentProperty::DateTimeCompleted(p) => written(&p.value),
+        ComponentProperty::DateTimeEnd(p) => written(&p.value),
+        ComponentProperty::DateTimeDue(p) => written(&p.value),
+        ComponentProperty::DateTimeStart(p) => written(&p.value),
+        ComponentProperty::Duration(p) => written(&p.value),
+        ComponentProperty::FreeBusyTime(p) => p.value.iter().flat_map(written).collect(),
+        ComponentProperty::TimeTransparency(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::TimeZoneId(p) => one(&p.value.id),
+        ComponentProperty::TimeZoneName(p) => one(&p.value),
+        ComponentProperty::TimeZoneOffsetFrom(p) => written(&p.value),
+        ComponentProperty::TimeZoneOffsetTo(p) => written(&p.value),
+        ComponentProperty::TimeZoneUrl(p) => one(&p.value),
+        ComponentProperty::Attendee(p) => one(&p.value),
+        ComponentProperty::Contact(p) => one(&p.value),
+        ComponentProperty::Organizer(p) => one(&p.value),
+        ComponentProperty::RecurrenceId(p) => written(&p.value),
+        ComponentProperty::RelatedTo(p) => one(&p.value),
+        ComponentProperty::Url(p) => one(&p.value),
+        ComponentProperty::UniqueIdentifier(p) => one(&p.value),
+        ComponentProperty::ExceptionDateTimes(p) => p.value.iter().flat_map(written).collect(),
+        ComponentProperty::RecurrenceDateTimes(p) => match &p.value {
+            RecurrenceDateTimesPropertyValue::DateTimes(date_times) => {
+                date_times.iter().flat_map(written).collect()
+            }
+            RecurrenceDateTimesPropertyValue::Periods(periods) => {
+                periods.iter().flat_map(written).collect()
+            }
+        },
+        ComponentProperty::RecurrenceRule(p) => written(&p.value),
+        ComponentProperty::Action(p) => written(&p.value),
+        ComponentProperty::Repeat(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Trigger(p) => match &p.value {
+            TriggerValue::Relative(duration) => written(duration),
+            TriggerValue::Absolute(date_time) => written(date_time),
+        },
+        ComponentProperty::DateTimeCreated(p) => written(&p.value),
+        ComponentProperty::DateTimeStamp(p) => written(&p.value),
+        ComponentProperty::LastModified(p) => written(&p.value),
+        ComponentProperty::Sequence(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::IanaProperty(p) => one(&p.value),
+        ComponentProperty::XProperty(p) => one(&p.value),
+        ComponentProperty::RequestStatus(p) => {
+            let code = p
+                .value
+                .status_code
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(".");
+            let mut values = vec![
+                Cow::Owned(code),
+                Cow::Borrowed(p.value.description.as_str()),
+            ];
+            values.extend(p.value.exception_data.as_deref().map(Cow::Borrowed));
+            values
+        }
+        ComponentProperty::Color(p) => one(&p.value),
+        ComponentProperty::Image(p) => one(&p.value),
+        ComponentProperty::Conference(p) => one(&p.value),
+        ComponentProperty::BusyType(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::StyledDescription(p) => one(&p.value),
+        ComponentProperty::StructuredData(p) => one(&p.value),
+        ComponentProperty::LocationType(p) => many(&p.value),
+        ComponentProperty::ParticipantType(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::ResourceType(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Acknowledged(p) => written(&p.value),
+        ComponentProperty::Proximity(p) => vec![Cow::Owned(p.value.to_string())],
+        ComponentProperty::Concept(p) => one(&p.value),
+        ComponentProperty::Link(p) => one(&p.value),
+        ComponentProperty::RefId(p) => one(&p.value),
+    }
+}
+
+/// Get the text of the values of a calendar property, as for [component_property_text].
+fn calendar_property_text(property: &CalendarProperty) -> Vec<Cow<'_, str>> {
+    match property {
+        CalendarProperty::ProductId(p) => one(&p.value),
+        CalendarProperty::Version(p) => match &p.min_version {
+            Some(min_version) => vec![Cow::Owned(format!("{};{}", min_version, p.max_version))],
+            None => one(&p.max_version),
+        },
+        CalendarProperty::CalendarScale(p) => one(&p.value),
+        CalendarProperty::Method(p) => one(&p.value),
+        CalendarProperty::Name(p) => one(&p.value),
+        CalendarProperty::Description(p) => one(&p.value),
+        CalendarProperty::LastModified(p) => written(&p.value),
+        CalendarProperty::Url(p) => one(&p.value),
+        CalendarProperty::RefreshInterval(p) => written(&p.value),
+        CalendarProperty::Source(p) => one(&p.value),
+        CalendarProperty::Color(p) => one(&p.value),
+        CalendarProperty::Image(p) => one(&p.value),
+        CalendarProperty::XProperty(p) => one(&p.value),
+        CalendarProperty::IanaProperty(p) => one(&p.value),
+    }
+}
+
+/// Get the text of the values of a parameter.
+fn param_values(param: &Param) -> Vec<Cow<'_, str>> {
+    match param {
+        Param::AltRep(p) => one(&p.uri),
+        Param::CommonName(p) => one(&p.name),
+        Param::ValueType(p) => written(&p.value),
+        Param::TimeZoneId(p) => one(&p.tz_id),
+        Param::Language(p) => written(&p.language),
+        Param::DirectoryEntryReference(p) => one(&p.uri),
+        Param::SentBy(p) => one(&p.address),
+        Param::Range(p) => written(&p.range),
+        Param::FormatType(p) => vec![Cow::Owned(format!("{}/{}", p.type_name, p.sub_type_name))],
+        Param::Encoding(p) => written(&p.encoding),
+        Param::CalendarUserType(p) => written(&p.cu_type),
+        Param::Members(p) => many(&p.members),
+        Param::Role(p) => written(&p.role),
+        Param::ParticipationStatus(p) => written(&p.status),
+        Param::Rsvp(p) => written(&p.rsvp),
+        Param::DelegatedTo(p) => many(&p.delegates),
+        Param::DelegatedFrom(p) => many(&p.delegators),
+        Param::RelationshipType(p) => written(&p.relationship),
+        Param::FreeBusyTimeType(p) => written(&p.fb_type),
+        Param::TriggerRelationship(p) => written(&p.trigger_relationship),
+        Param::Display(p) => p.display.iter().flat_map(written).collect(),
+        Param::Email(p) => one(&p.email),
+        Param::Feature(p) => p.features.iter().flat_map(written).collect(),
+        Param::Label(p) => one(&p.label),
+        Param::ScheduleAgent(p) => written(&p.agent),
+        Param::ScheduleForceSend(p) => written(&p.force_send),
+        Param::ScheduleStatus(p) => p
+            .statuses
+            .iter()
+            .map(|status| {
+                Cow::Owned(
+                    status
+                        .iter()
+                        .map(|part| part.to_string())
+                        .collect::<Vec<_>>()
+                        .join("."),
+                )
+            })
+            .collect(),
+        Param::Gap(p) => written(&p.duration),
+        Param::LinkRelation(p) => one(&p.relation),
+        Param::Other { value, .. } => one(value),
+        Param::Others { values, .. } | Param::XName { values, .. } => many(values),
+    }
+}
+
+fn one(value: &str) -> Vec<Cow<'_, str>> {
+    vec![Cow::Borrowed(value)]
+}
+
+fn many(values: &[String]) -> Vec<Cow<'_, str>> {
+    values.iter().map(|v| Cow::Borrowed(v.as_str())).collect()
+}
+
+/// Write a value that is not text, such as a date-time or an enumerated value.
+fn written<V: WriteModel>(value: &V) -> Vec<Cow<'static, str>> {
+    let mut buf = Vec::new();
+    if value.write_model(&mut buf).is_err() {
+        return Vec::new();
+    }
+
+    vec![Cow::Owned(String::from_utf8_lossy(&buf).into_owned())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    fn utc(year: i32, month: Month, day: u8, hour: u8) -> time::OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms(hour, 0, 0).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240102T090000Z\r\n\
+DURATION:PT15M\r\n\
+RRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR\r\n\
+SUMMARY:Team Standup\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED;CN=Alice:mailto:alice@example.com\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Standup soon\r\n\
+TRIGGER:-PT5M\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:review\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART:20240105T140000Z\r\n\
+DTEND:20240105T150000Z\r\n\
+SUMMARY:Design review\\, part 2\r\n\
+CATEGORIES:WORK,DESIGN\r\n\
+END:VEVENT\r\n\
+BEGIN:VTODO\r\n\
+UID:todo\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DUE:20240110T170000Z\r\n\
+SUMMARY:Write report\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+    fn load() -> ICalObject {
+        load_ical(CALENDAR).unwrap().pop().unwrap()
+    }
+
+    fn summaries(components: Vec<&CalendarComponent>) -> Vec<String> {
+        components
+            .iter()
+            .map(|c| {
+                c.get_property::<crate::model::property::SummaryProperty>()
+                    .map(|s| s.value.clone())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn match_calendar_by_component() {
+        let object = load();
+
+        let filter = CompFilter::new("VCALENDAR").add_comp_filter(CompFilter::new("VTODO"));
+        assert!(filter.matches(&object));
+
+        let filter = CompFilter::new("VCALENDAR").add_comp_filter(CompFilter::new("VJOURNAL"));
+        assert!(!filter.matches(&object));
+
+        let filter = CompFilter::new("VCALENDAR")
+            .add_comp_filter(CompFilter::new("VJOURNAL").set_is_not_defined());
+        assert!(filter.matches(&object));
+    }
+
+    #[test]
+    fn match_calendar_property() {
+        let object = load();
+
+        let filter = CompFilter::new("VCALENDAR")
+            .add_prop_filter(PropFilter::new("PRODID").set_text_match(TextMatch::new("test")));
+        assert!(filter.matches(&object));
+
+        let filter = CompFilter::new("VCALENDAR")
+            .add_prop_filter(PropFilter::new("METHOD").set_is_not_defined());
+        assert!(filter.matches(&object));
+    }
+
+    #[test]
+    fn select_by_time_range_with_recurrence() {
+        let object = load();
+
+        // A Saturday, only the standup is weekdays-only so nothing matches
+        let filter = CompFilter::new("VEVENT").set_time_range(TimeRange::new(
+            utc(2024, Month::January, 6, 0),
+            utc(2024, Month::January, 7, 0),
+        ));
+        assert!(filter.select(&object).is_empty());
+
+        // A Friday, both events
+        let filter = CompFilter::new("VEVENT").set_time_range(TimeRange::new(
+            utc(2024, Month::January, 5, 0),
+            utc(2024, Month::January, 6, 0),
+        ));
+        assert_eq!(
+            vec!["Team Standup", "Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+
+        // Far in the future, only the recurring event
+        let filter = CompFilter::new("VEVENT").set_time_range(TimeRange::starting_at(utc(
+            2030,
+            Month::March,
+            4,
+            0,
+        )));
+        assert_eq!(vec!["Team Standup"], summaries(filter.select(&object)));
+    }
+
+    #[test]
+    fn select_at_the_end_of_the_supported_range() {
+        let object = load_ical(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Fixed\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19700101T000000\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:last\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+DTSTART;TZID=Fixed:99991231T230000\r\n\
+SUMMARY:Last\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n",
+        )
+        .unwrap()
+        .pop()
+        .unwrap();
+
+        let filter = CompFilter::new("VEVENT").set_time_range(TimeRange::starting_at(utc(
+            9999,
+            Month::December,
+            31,
+            0,
+        )));
+        assert_eq!(vec!["Last"], summaries(filter.select(&object)));
+    }
+
+    #[test]
+    fn select_by_text_match() {
+        let object = load();
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("SUMMARY").set_text_match(TextMatch::new("REVIEW, PART")),
+        );
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("SUMMARY")
+                .set_text_match(TextMatch::new("REVIEW").set_collation(Collation::Octet)),
+        );
+        assert!(filter.select(&object).is_empty());
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("SUMMARY")
+                .set_text_match(TextMatch::new("standup").set_negate_condition(true)),
+        );
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("CATEGORIES").set_text_match(TextMatch::new("design")),
+        );
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+    }
+
+    #[test]
+    fn select_by_param() {
+        let object = load();
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("ATTENDEE").add_param_filter(
+                ParamFilter::new("PARTSTAT").set_text_match(TextMatch::new("accepted")),
+            ),
+        );
+        assert_eq!(vec!["Team Standup"], summaries(filter.select(&object)));
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("ATTENDEE")
+                .add_param_filter(ParamFilter::new("ROLE").set_is_not_defined()),
+        );
+        assert_eq!(vec!["Team Standup"], summaries(filter.select(&object)));
+
+        let filter = CompFilter::new("VEVENT")
+            .add_prop_filter(PropFilter::new("ATTENDEE").set_is_not_defined());
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+    }
+
+    #[test]
+    fn text_match_on_each_value() {
+        let object = load();
+
+        // The categories are matched one at a time, not as the list they are written as
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("CATEGORIES").set_text_match(TextMatch::new("work,design")),
+        );
+        assert!(filter.select(&object).is_empty());
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("ATTENDEE")
+                .set_text_match(TextMatch::new("mailto:alice"))
+                .add_param_filter(
+                    ParamFilter::new("CN")
+                        .set_text_match(TextMatch::new("Alice").set_collation(Collation::Octet)),
+                ),
+        );
+        assert_eq!(vec!["Team Standup"], summaries(filter.select(&object)));
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("DTSTART").set_text_match(TextMatch::new("20240105T14")),
+        );
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+    }
+
+    #[test]
+    fn select_by_property_time_range() {
+        let object = load();
+
+        let filter = CompFilter::new("VEVENT").add_prop_filter(
+            PropFilter::new("DTSTART").set_time_range(TimeRange::new(
+                utc(2024, Month::January, 5, 0),
+                utc(2024, Month::January, 6, 0),
+            )),
+        );
+        assert_eq!(
+            vec!["Design review, part 2"],
+            summaries(filter.select(&object))
+        );
+    }
+
+    #[test]
+    fn select_by_alarm_time_range() {
+        let object = load();
+
+        let filter = CompFilter::new("VEVENT").add_comp_filter(
+            CompFilter::new("VALARM").set_time_range(TimeRange::new(
+                utc(2024, Month::January, 3, 8),
+                utc(2024, Month::January, 3, 9),
+            )),
+        );
+        assert_eq!(vec!["Team Standup"], summaries(filter.select(&object)));
+
+        let filter = CompFilter::new("VEVENT").add_comp_filter(
+            CompFilter::new("VALARM").set_time_range(TimeRange::new(
+                utc(2024, Month::January, 3, 9),
+                utc(2024, Month::January, 3, 10),
+            )),
+        );
+        assert!(filter.select(&object).is_empty());
+    }
+
+    #[test]
+    fn select_todo_by_time_range() {
+        let object = load();
+
+        // A to-do with only a due date matches a range that ends at the due date.
+        let filter = CompFilter::new("VTODO").set_time_range(TimeRange::new(
+            utc(2024, Month::January, 10, 0),
+            utc(2024, Month::January, 10, 17),
+        ));
+        assert_eq!(vec!["Write report"], summaries(filter.select(&object)));
+
+        let filter = CompFilter::new("VTODO").set_time_range(TimeRange::starting_at(utc(
+            2024,
+            Month::January,
+            10,
+            17,
+        )));
+        assert!(filter.select(&object).is_empty());
+    }
+}
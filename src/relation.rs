@@ -0,0 +1,708 @@
+use crate::common::{RelationshipType, Value};
+use crate::error::{AetoliaError, AetoliaResult};
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::{GapParam, Param, RelationshipTypeParam, ValueTypeParam};
+use crate::model::property::{ComponentProperty, Duration, UniqueIdentifierProperty};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A component in the relation graph, found by its position in the calendar objects that the
+/// graph was built from.
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentRef<'a> {
+    pub object_index: usize,
+    pub component_index: usize,
+    pub component: &'a CalendarComponent,
+}
+
+/// The components that share a UID.
+///
+/// A recurring component and its overridden instances all have the same UID, so a node can hold
+/// more than one component.
+#[derive(Debug)]
+pub struct RelationNode<'a> {
+    uid: &'a str,
+    components: Vec<ComponentRef<'a>>,
+}
+
+impl<'a> RelationNode<'a> {
+    pub fn uid(&self) -> &'a str {
+        self.uid
+    }
+
+    pub fn components(&self) -> &[ComponentRef<'a>] {
+        &self.components
+    }
+}
+
+/// A single RELATED-TO property, from the component with the `source` UID to the `target` UID.
+#[derive(Debug, Clone)]
+pub struct Relation<'a> {
+    pub source: &'a str,
+    pub target: &'a str,
+    pub relationship: &'a RelationshipType,
+    /// The lead or lag time of a temporal relationship, RFC 9253 section 6.2
+    pub gap: Option<&'a Duration>,
+    /// The component that has the RELATED-TO property.
+    pub component: ComponentRef<'a>,
+    /// The index of the RELATED-TO property within its component.
+    pub property_index: usize,
+}
+
+impl<'a> Relation<'a> {
+    /// The UID of the component that depends on the other, and the UID of the component that it
+    /// depends on, if this is a DEPENDS-ON or temporal relationship.
+    ///
+    /// A DEPENDS-ON relation is on the component that depends on the target, but a temporal
+    /// relation is on the component that the target depends on.
+    pub fn dependency(&self) -> Option<(&'a str, &'a str)> {
+        match dependency_direction(self.relationship)? {
+            DependencyDirection::OnTarget => Some((self.source, self.target)),
+            DependencyDirection::OfTarget => Some((self.target, self.source)),
+        }
+    }
+}
+
+/// Which relations a [Cycle] was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleKind {
+    /// PARENT and CHILD relations, where a component is its own ancestor.
+    Hierarchy,
+    /// Temporal and DEPENDS-ON relations, where a component depends on itself.
+    Dependency,
+}
+
+/// A loop in the relations between components.
+#[derive(Debug, Clone)]
+pub struct Cycle<'a> {
+    pub kind: CycleKind,
+    /// The UIDs around the cycle, starting and ending with the same UID.
+    pub uids: Vec<&'a str>,
+    /// The relations that make up the cycle, in the same order as the UIDs.
+    pub relations: Vec<&'a Relation<'a>>,
+}
+
+/// A graph of the relationships between components, built by resolving RELATED-TO properties
+/// by UID.
+///
+/// Only top level components with a UID become nodes. RELATED-TO properties that refer to a URI,
+/// RFC 9253 section 9.1, or that use a REFID or CONCEPT relationship type do not refer to a UID
+/// and are ignored.
+#[derive(Debug)]
+pub struct RelationGraph<'a> {
+    nodes: Vec<RelationNode<'a>>,
+    node_index: HashMap<&'a str, usize>,
+    relations: Vec<Relation<'a>>,
+}
+
+impl<'a> RelationGraph<'a> {
+    /// Build the graph for the components of a single calendar object.
+    pub fn new(object: &'a ICalObject) -> Self {
+        Self::from_objects(std::slice::from_ref(object))
+    }
+
+    /// Build the graph across several calendar objects, such as the resources of a calendar
+    /// collection.
+    pub fn from_objects(objects: &'a [ICalObject]) -> Self {
+        let mut graph = RelationGraph {
+            nodes: Vec::new(),
+            node_index: HashMap::new(),
+            relations: Vec::new(),
+        };
+
+        for (object_index, object) in objects.iter().enumerate() {
+            for (component_index, component) in object.components.iter().enumerate() {
+                let Some(uid) = component.get_property::<UniqueIdentifierProperty>() else {
+                    continue;
+                };
+                let uid = uid.value.as_str();
+
+                let component_ref = ComponentRef {
+                    object_index,
+                    component_index,
+                    component,
+                };
+
+                let index = *graph.node_index.entry(uid).or_insert_with(|| {
+                    graph.nodes.push(RelationNode {
+                        uid,
+                        components: Vec::new(),
+                    });
+                    graph.nodes.len() - 1
+                });
+                graph.nodes[index].components.push(component_ref);
+
+                graph
+                    .relations
+                    .extend(component_relations(uid, component_ref));
+            }
+        }
+
+        graph
+    }
+
+    pub fn nodes(&self) -> &[RelationNode<'a>] {
+        &self.nodes
+    }
+
+    pub fn get(&self, uid: &str) -> Option<&RelationNode<'a>> {
+        self.node_index.get(uid).map(|i| &self.nodes[*i])
+    }
+
+    pub fn relations(&self) -> &[Relation<'a>] {
+        &self.relations
+    }
+
+    /// The UIDs of the parents of a component. A parent is either named by a PARENT relation on
+    /// the component, or names the component with a CHILD relation.
+    pub fn parents(&self, uid: &str) -> Vec<&'a str> {
+        self.related(uid, RelationshipType::Parent, RelationshipType::Child)
+    }
+
+    /// The UIDs of the children of a component, the inverse of [Self::parents].
+    pub fn children(&self, uid: &str) -> Vec<&'a str> {
+        self.related(uid, RelationshipType::Child, RelationshipType::Parent)
+    }
+
+    /// The UIDs of the siblings of a component, in either direction.
+    pub fn siblings(&self, uid: &str) -> Vec<&'a str> {
+        self.related(uid, RelationshipType::Sibling, RelationshipType::Sibling)
+    }
+
+    /// The components that are not the child of any other component in the graph.
+    pub fn roots(&self) -> Vec<&'a str> {
+        self.nodes
+            .iter()
+            .map(|n| n.uid)
+            .filter(|uid| {
+                self.parents(uid)
+                    .iter()
+                    .all(|p| !self.node_index.contains_key(p))
+            })
+            .collect()
+    }
+
+    /// The relations that make a component depend on another, such as FINISHTOSTART or
+    /// DEPENDS-ON. See [Relation::dependency] for which side of each relation this component is
+    /// on.
+    pub fn dependencies(&self, uid: &str) -> Vec<&Relation<'a>> {
+        self.relations
+            .iter()
+            .filter(|r| {
+                r.dependency()
+                    .is_some_and(|(dependent, _)| dependent == uid)
+            })
+            .collect()
+    }
+
+    /// The relations that make other components depend on this one, the inverse of
+    /// [Self::dependencies].
+    pub fn dependents(&self, uid: &str) -> Vec<&Relation<'a>> {
+        self.relations
+            .iter()
+            .filter(|r| {
+                r.dependency()
+                    .is_some_and(|(_, prerequisite)| prerequisite == uid)
+            })
+            .collect()
+    }
+
+    /// The relations whose target UID is not in the graph, such as a task whose parent has been
+    /// deleted.
+    pub fn orphans(&self) -> Vec<&Relation<'a>> {
+        self.relations
+            .iter()
+            .filter(|r| !self.node_index.contains_key(r.target))
+            .collect()
+    }
+
+    /// Order the components so that each one comes after the components it depends on.
+    ///
+    /// Components with no dependencies between them keep the order they were found in. Fails if
+    /// the dependencies contain a cycle.
+    pub fn topological_order(&self) -> AetoliaResult<Vec<&'a str>> {
+        let edges = self.edges(CycleKind::Dependency);
+
+        // Count the prerequisites of each component, and index the reverse edges.
+        let mut remaining = vec![0; self.nodes.len()];
+        let mut dependents = vec![Vec::new(); self.nodes.len()];
+        for (from, to, _) in &edges {
+            remaining[*from] += 1;
+            dependents[*to].push(*from);
+        }
+
+        let mut ready = (0..self.nodes.len())
+            .filter(|i| remaining[*i] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(self.nodes[index].uid);
+            for dependent in &dependents[index] {
+                remaining[*dependent] -= 1;
+                if remaining[*dependent] == 0 {
+                    ready.push_back(*dependent);
+                }
+            }
+        }
+
+        if order.len() != self.nodes.len() {
+            return Err(AetoliaError::other(
+                "Cannot order components because their dependencies contain a cycle",
+            ));
+        }
+
+        Ok(order)
+    }
+
+    /// Find the cycles in the parent hierarchy and in the dependencies between components.
+    ///
+    /// Each cycle is reported once. Where cycles overlap, only the cycles found by walking the
+    /// graph in order are reported, which is enough to find every component that is in a cycle.
+    pub fn cycles(&self) -> Vec<Cycle<'_>> {
+        let mut cycles = self.find_cycles(CycleKind::Hierarchy);
+        cycles.extend(self.find_cycles(CycleKind::Dependency));
+        cycles
+    }
+
+    fn related(
+        &self,
+        uid: &str,
+        outgoing: RelationshipType,
+        incoming: RelationshipType,
+    ) -> Vec<&'a str> {
+        let mut seen = HashSet::new();
+        self.relations
+            .iter()
+            .filter_map(|r| {
+                if r.source == uid && *r.relationship == outgoing {
+                    Some(r.target)
+                } else if r.target == uid && *r.relationship == incoming {
+                    Some(r.source)
+                } else {
+                    None
+                }
+            })
+            .filter(|related| seen.insert(*related))
+            .collect()
+    }
+
+    /// The edges between nodes for one kind of relation, as `(from, to, relation index)`.
+    ///
+    /// Hierarchy edges go from a child to its parent, and dependency edges go from a component
+    /// to the component that it depends on. Relations to UIDs outside the graph are skipped.
+    fn edges(&self, kind: CycleKind) -> Vec<(usize, usize, usize)> {
+        self.relations
+            .iter()
+            .enumerate()
+            .filter_map(|(relation_index, r)| {
+                let source = *self.node_index.get(r.source)?;
+                let target = *self.node_index.get(r.target)?;
+                match (kind, dependency_direction(r.relationship)) {
+                    (CycleKind::Hierarchy, _) => match r.relationship {
+                        RelationshipType::Parent => Some((source, target, relation_index)),
+                        RelationshipType::Child => Some((target, source, relation_index)),
+                        _ => None,
+                    },
+                    (CycleKind::Dependency, Some(DependencyDirection::OnTarget)) => {
+                        Some((source, target, relation_index))
+                    }
+                    (CycleKind::Dependency, Some(DependencyDirection::OfTarget)) => {
+                        Some((target, source, relation_index))
+                    }
+                    (CycleKind::Dependency, None) => None,
+                }
+            })
+            .collect()
+    }
+
+    fn find_cycles(&self, kind: CycleKind) -> Vec<Cycle<'_>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for (from, to, relation_index) in self.edges(kind) {
+            adjacency[from].push((to, relation_index));
+        }
+
+        let mut state = vec![VisitState::New; self.nodes.len()];
+        let mut path = Vec::new();
+        let mut cycles = Vec::new();
+        for start in 0..self.nodes.len() {
+            if state[start] == VisitState::New {
+                self.visit(kind, start, &adjacency, &mut state, &mut path, &mut cycles);
+            }
+        }
+
+        cycles
+    }
+
+    /// Depth first search, where an edge back to a node on the current path closes a cycle.
+    fn visit<'g>(
+        &'g self,
+        kind: CycleKind,
+        node: usize,
+        adjacency: &[Vec<(usize, usize)>],
+        state: &mut [VisitState],
+        path: &mut Vec<(usize, usize)>,
+        cycles: &mut Vec<Cycle<'g>>,
+    ) {
+        state[node] = VisitState::OnPath;
+        for (next, relation_index) in &adjacency[node] {
+            match state[*next] {
+                VisitState::New => {
+                    path.push((node, *relation_index));
+                    self.visit(kind, *next, adjacency, state, path, cycles);
+                    path.pop();
+                }
+                VisitState::OnPath => {
+                    let start = path
+                        .iter()
+                        .position(|(n, _)| n == next)
+                        .unwrap_or(path.len());
+                    let mut steps = path[start..].to_vec();
+                    steps.push((node, *relation_index));
+
+                    let mut uids = steps
+                        .iter()
+                        .map(|(n, _)| self.nodes[*n].uid)
+                        .collect::<Vec<_>>();
+                    uids.push(self.nodes[*next].uid);
+
+                    cycles.push(Cycle {
+                        kind,
+                        uids,
+                        relations: steps.iter().map(|(_, r)| &self.relations[*r]).collect(),
+                    });
+                }
+                VisitState::Done => {}
+            }
+        }
+        state[node] = VisitState::Done;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    New,
+    OnPath,
+    Done,
+}
+
+/// Which of the components in a relationship depends on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyDirection {
+    /// The component with the RELATED-TO property depends on the component that it refers to.
+    OnTarget,
+    /// The component that the RELATED-TO property refers to depends on the component with the
+    /// property.
+    OfTarget,
+}
+
+/// The direction of the relationships that make one component depend on another.
+///
+/// RFC 9253 section 9.1 defines DEPENDS-ON with the component that has the RELATED-TO property
+/// as the dependent:
+///
+/// > The DEPENDS-ON relationship indicates that the current calendar component depends on the
+/// > referenced calendar component in some manner.
+///
+/// The temporal relationships are "as specified in the RELTYPE parameter definition", RFC 9253
+/// section 5, which puts the property on the other side: "This property will be present in the
+/// predecessor entity and will refer to the successor entity." There, Task-A has the property
+/// and Task-B is the component it refers to.
+///
+/// - FINISHTOSTART: "Task-B cannot start until Task-A finishes."
+/// - FINISHTOFINISH: "Task-B can only finish after Task-A finishes."
+/// - STARTTOFINISH: "The start of Task-A (which occurs after Task-B) controls the finish of
+///   Task-B."
+/// - STARTTOSTART: "The start of Task-A triggers the start of Task-B, that is, Task-B can start
+///   anytime after Task-A starts."
+fn dependency_direction(relationship: &RelationshipType) -> Option<DependencyDirection> {
+    match relationship {
+        RelationshipType::DependsOn => Some(DependencyDirection::OnTarget),
+        RelationshipType::FinishToStart
+        | RelationshipType::FinishToFinish
+        | RelationshipType::StartToFinish
+        | RelationshipType::StartToStart => Some(DependencyDirection::OfTarget),
+        _ => None,
+    }
+}
+
+fn component_relations<'a>(
+    uid: &'a str,
+    component: ComponentRef<'a>,
+) -> impl Iterator<Item = Relation<'a>> {
+    component
+        .component
+        .properties()
+        .iter()
+        .enumerate()
+        .filter_map(move |(property_index, property)| {
+            let ComponentProperty::RelatedTo(related_to) = property else {
+                return None;
+            };
+
+            let mut relationship = &RelationshipType::Parent;
+            let mut gap = None;
+            for param in &related_to.params {
+                match param {
+                    Param::RelationshipType(RelationshipTypeParam { relationship: r }) => {
+                        relationship = r;
+                    }
+                    Param::Gap(GapParam { duration }) => gap = Some(duration),
+                    Param::ValueType(ValueTypeParam { value: Value::Uri }) => return None,
+                    _ => {}
+                }
+            }
+
+            if matches!(
+                relationship,
+                RelationshipType::RefId | RelationshipType::Concept
+            ) {
+                return None;
+            }
+
+            Some(Relation {
+                source: uid,
+                target: related_to.value.as_str(),
+                relationship,
+                gap,
+                component,
+                property_index,
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::load_ical;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:project\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+SUMMARY:Project\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:design\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO:project\r\n\
+RELATED-TO;RELTYPE=FINISHTOSTART;GAP=P1D:build\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:build\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO;RELTYPE=PARENT:project\r\n\
+RELATED-TO;RELTYPE=SIBLING:design\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:release\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO;RELTYPE=DEPENDS-ON:build\r\n\
+RELATED-TO;RELTYPE=PARENT:deleted-project\r\n\
+RELATED-TO;VALUE=URI;RELTYPE=PARENT:https://example.com/project.ics\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:project\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RECURRENCE-ID:20240101T000000Z\r\n\
+RELATED-TO;RELTYPE=CHILD:release\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+    fn load(content: &str) -> ICalObject {
+        load_ical(content).unwrap().pop().unwrap()
+    }
+
+    #[test]
+    fn resolve_hierarchy() {
+        let object = load(CALENDAR);
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(4, graph.nodes().len());
+        assert_eq!(2, graph.get("project").unwrap().components().len());
+
+        assert_eq!(
+            vec!["design", "build", "release"],
+            graph.children("project")
+        );
+        assert_eq!(vec!["project"], graph.parents("build"));
+        assert_eq!(vec!["deleted-project", "project"], graph.parents("release"));
+        assert_eq!(vec!["design"], graph.siblings("build"));
+        assert_eq!(vec!["build"], graph.siblings("design"));
+        assert_eq!(vec!["project"], graph.roots());
+    }
+
+    #[test]
+    fn resolve_dependencies() {
+        let object = load(CALENDAR);
+        let graph = RelationGraph::new(&object);
+
+        let dependencies = graph.dependencies("build");
+        assert_eq!(1, dependencies.len());
+        assert_eq!(Some(("build", "design")), dependencies[0].dependency());
+        assert_eq!(
+            &RelationshipType::FinishToStart,
+            dependencies[0].relationship
+        );
+        assert!(dependencies[0].gap.is_some());
+        assert_eq!(1, dependencies[0].component.component_index);
+        assert_eq!(3, dependencies[0].property_index);
+
+        let dependents = graph.dependents("build");
+        assert_eq!(1, dependents.len());
+        assert_eq!(Some(("release", "build")), dependents[0].dependency());
+    }
+
+    #[test]
+    fn find_orphans() {
+        let object = load(CALENDAR);
+        let graph = RelationGraph::new(&object);
+
+        let orphans = graph.orphans();
+        assert_eq!(1, orphans.len());
+        assert_eq!("release", orphans[0].source);
+        assert_eq!("deleted-project", orphans[0].target);
+    }
+
+    #[test]
+    fn order_by_dependencies() {
+        let object = load(CALENDAR);
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(
+            vec!["project", "design", "build", "release"],
+            graph.topological_order().unwrap()
+        );
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn detect_cycles() {
+        let object = load(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:a\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO;RELTYPE=DEPENDS-ON:b\r\n\
+RELATED-TO;RELTYPE=CHILD:c\r\n\
+RELATED-TO;RELTYPE=STARTTOSTART:b\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:b\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:c\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO:c\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n",
+        );
+        let graph = RelationGraph::new(&object);
+
+        let cycles = graph.cycles();
+        assert_eq!(2, cycles.len());
+
+        assert_eq!(CycleKind::Hierarchy, cycles[0].kind);
+        assert_eq!(vec!["c", "c"], cycles[0].uids);
+
+        assert_eq!(CycleKind::Dependency, cycles[1].kind);
+        assert_eq!(vec!["a", "b", "a"], cycles[1].uids);
+        assert_eq!(2, cycles[1].relations.len());
+
+        assert!(graph.topological_order().is_err());
+    }
+
+    /// A calendar where `a` has a RELATED-TO property with the relationship type that refers to
+    /// `b`, with `b` listed first.
+    fn temporal_graph(reltype: &str) -> ICalObject {
+        load(&format!(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:b\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+UID:a\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO;RELTYPE={reltype}:b\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n"
+        ))
+    }
+
+    #[test]
+    fn order_by_finish_to_start() {
+        let object = temporal_graph("FINISHTOSTART");
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(vec!["a", "b"], graph.topological_order().unwrap());
+    }
+
+    #[test]
+    fn order_by_finish_to_finish() {
+        let object = temporal_graph("FINISHTOFINISH");
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(vec!["a", "b"], graph.topological_order().unwrap());
+    }
+
+    #[test]
+    fn order_by_start_to_finish() {
+        let object = temporal_graph("STARTTOFINISH");
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(vec!["a", "b"], graph.topological_order().unwrap());
+    }
+
+    #[test]
+    fn order_by_start_to_start() {
+        let object = temporal_graph("STARTTOSTART");
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(vec!["a", "b"], graph.topological_order().unwrap());
+    }
+
+    #[test]
+    fn order_by_depends_on() {
+        let object = temporal_graph("DEPENDS-ON");
+        let graph = RelationGraph::new(&object);
+
+        assert_eq!(vec!["b", "a"], graph.topological_order().unwrap());
+    }
+
+    #[test]
+    fn resolve_across_objects() {
+        let objects = load_ical(
+            "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:parent\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n\
+BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:child\r\n\
+DTSTAMP:20240101T000000Z\r\n\
+RELATED-TO:parent\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n",
+        )
+        .unwrap();
+        let graph = RelationGraph::from_objects(&objects);
+
+        assert_eq!(vec!["child"], graph.children("parent"));
+        assert_eq!(1, graph.get("child").unwrap().components()[0].object_index);
+        assert!(graph.orphans().is_empty());
+    }
+}
@@ -24,6 +24,12 @@ mod tests {
         round_trip_ical_object(content);
     }
 
+    #[test]
+    fn rtt_calendar_x_property() {
+        let content = "BEGIN:VCALENDAR\r\nX-WR-CALNAME;X-TEST=a:Work\r\nBEGIN:VEVENT\r\nDTSTAMP:20211010T000000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        round_trip_ical_object(content);
+    }
+
     // Check with data taken from RFC 5545, section 3.6.1
     #[test]
     fn rtt_event() {
@@ -384,6 +390,186 @@ END:VCALENDAR\r\n";
         round_trip_ical_object(example_3);
     }
 
+    // Check with data taken from RFC 7986
+    #[test]
+    fn rtt_rfc_7986_properties() {
+        let content = "BEGIN:VCALENDAR\r\n\
+NAME:Company Vacation Days\r\n\
+DESCRIPTION:Vacation days for the company\r\n\
+LAST-MODIFIED:20230101T000000Z\r\n\
+URL:http://example.com/calendar\r\n\
+REFRESH-INTERVAL;VALUE=DURATION:P1W\r\n\
+SOURCE:http://example.com/holidays.ics\r\n\
+COLOR:turquoise\r\n\
+IMAGE;VALUE=URI:http://example.com/images/calendar.png\r\n\
+BEGIN:VEVENT\r\n\
+COLOR:red\r\n\
+IMAGE;VALUE=URI;FMTTYPE=image/png:http://example.com/images/party.png\r\n\
+CONFERENCE;VALUE=URI;LANGUAGE=en:tel:+1-412-555-0123,,,654321\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_7986_params() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+ATTENDEE;EMAIL=janedoe@example.com:mailto:janedoe@example.net\r\n\
+IMAGE;VALUE=URI;DISPLAY=BADGE,THUMBNAIL:http://example.com/images/party.png\r\n\
+CONFERENCE;VALUE=URI;FEATURE=PHONE,MODERATOR;LABEL=\"Moderator dial-in: 654321\":tel:+1-412-555-0123\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_6638_params() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+ORGANIZER;SCHEDULE-AGENT=SERVER;SCHEDULE-FORCE-SEND=REPLY:mailto:cyrus@example.com\r\n\
+ATTENDEE;SCHEDULE-AGENT=CLIENT;SCHEDULE-STATUS=\"3.7,5.3\":mailto:wilfredo@example.com\r\n\
+ATTENDEE;SCHEDULE-FORCE-SEND=REQUEST;SCHEDULE-STATUS=2.0:mailto:bernard@example.net\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_6868_params() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+ATTENDEE;CN=George Herman ^'Babe^' Ruth:mailto:babe@example.com\r\n\
+GEO;X-ADDRESS=\"Pittsburgh Pirates^n115 Federal St^nPittsburgh, PA 15212\":40.446816;-80.00566\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_9253() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VTODO\r\n\
+CONCEPT:https://example.com/event-types/arts/music\r\n\
+LINK;LINKREL=\"https://example.com/linkrel/derivedFrom\";VALUE=XML-REFERENCE:https://example.com/xmlDocs/bidFramework.xml#xpointer(descendant::CostStructure/range-start)\r\n\
+LINK;LINKREL=SOURCE;LABEL=Venue;VALUE=URI:https://example.com/events\r\n\
+LINK;LINKREL=NEXT;VALUE=UID:1234\\, 5678\r\n\
+REFID:itinerary-2014-11-17\r\n\
+RELATED-TO;VALUE=URI;RELTYPE=STARTTOFINISH;GAP=PT1H:https://example.com/caldav/user/jb/cal/event.ics\r\n\
+RELATED-TO;RELTYPE=DEPENDS-ON:19960401-080045-4000F192713-0052\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_non_ascii_text() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Café in Ļaudona\\, 東京\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_availability() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VAVAILABILITY\r\n\
+UID:20061206T001330Z-1@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+BUSYTYPE:BUSY-UNAVAILABLE\r\n\
+DTSTART;TZID=America/Montreal:20061206T000000\r\n\
+BEGIN:AVAILABLE\r\n\
+UID:20061206T001330Z-2@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+DTSTART;TZID=America/Montreal:20061206T090000\r\n\
+DTEND;TZID=America/Montreal:20061206T170000\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r\n\
+END:AVAILABLE\r\n\
+END:VAVAILABILITY\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_9073() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20240101T120000Z\r\n\
+STYLED-DESCRIPTION;VALUE=TEXT;FMTTYPE=text/html:<p>A\\, B</p>\r\n\
+STRUCTURED-DATA;VALUE=URI:http://example.com/event.json\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+TRIGGER:-PT15M\r\n\
+DESCRIPTION:Reminder\r\n\
+END:VALARM\r\n\
+BEGIN:PARTICIPANT\r\n\
+UID:participant-1\r\n\
+PARTICIPANT-TYPE:BOOKING-CONTACT\r\n\
+BEGIN:VRESOURCE\r\n\
+UID:resource-2\r\n\
+RESOURCE-TYPE:REMOTE-CONFERENCE-VIDEO\r\n\
+END:VRESOURCE\r\n\
+END:PARTICIPANT\r\n\
+BEGIN:VLOCATION\r\n\
+UID:location-1\r\n\
+LOCATION-TYPE:auditorium,hall\r\n\
+END:VLOCATION\r\n\
+BEGIN:VRESOURCE\r\n\
+UID:resource-1\r\n\
+STRUCTURED-DATA;ENCODING=BASE64;VALUE=BINARY:dGVzdA==\r\n\
+END:VRESOURCE\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_9074() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20090604T080000Z\r\n\
+BEGIN:VALARM\r\n\
+UID:alarm-2\r\n\
+RELATED-TO;RELTYPE=SNOOZE:alarm-1\r\n\
+TRIGGER;VALUE=DATE-TIME:20090604T085500Z\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Breakfast meeting\r\n\
+ACKNOWLEDGED:20090604T084500Z\r\n\
+PROXIMITY:CONNECT\r\n\
+X-APPLE-DEFAULT-ALARM:TRUE\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
+    #[test]
+    fn rtt_rfc_7529() {
+        let content = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20140101T080000Z\r\n\
+DTSTART;VALUE=DATE:20140208\r\n\
+RRULE:RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=5L,6;BYMONTHDAY=8;SKIP=FORWARD;COUNT=5\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        round_trip_ical_object(content);
+    }
+
     fn round_trip_ical_object(content: &str) {
         let (rem, object) = crate::parser::ical_object::<Error>(content.as_bytes()).unwrap();
         check_rem(rem, 0);
@@ -13,9 +13,9 @@ impl WriteModel for crate::model::component::CalendarComponent {
                     writer.write_all(b"\r\n")?;
                     property.write_model(writer)?;
                 }
-                for alarm in &component.alarms {
+                for component in &component.components {
                     writer.write_all(b"\r\n")?;
-                    alarm.write_model(writer)?;
+                    component.write_model(writer)?;
                 }
                 writer.write_all(b"\r\nEND:VEVENT")?;
             }
@@ -75,6 +75,54 @@ impl WriteModel for crate::model::component::CalendarComponent {
                 }
                 writer.write_all(b"\r\nEND:DAYLIGHT")?;
             }
+            CalendarComponent::Availability(component) => {
+                writer.write_all(b"BEGIN:VAVAILABILITY")?;
+                for property in &component.properties {
+                    writer.write_all(b"\r\n")?;
+                    property.write_model(writer)?;
+                }
+                for component in &component.components {
+                    writer.write_all(b"\r\n")?;
+                    component.write_model(writer)?;
+                }
+                writer.write_all(b"\r\nEND:VAVAILABILITY")?;
+            }
+            CalendarComponent::Available(component) => {
+                writer.write_all(b"BEGIN:AVAILABLE")?;
+                for property in &component.properties {
+                    writer.write_all(b"\r\n")?;
+                    property.write_model(writer)?;
+                }
+                writer.write_all(b"\r\nEND:AVAILABLE")?;
+            }
+            CalendarComponent::Participant(component) => {
+                writer.write_all(b"BEGIN:PARTICIPANT")?;
+                for property in &component.properties {
+                    writer.write_all(b"\r\n")?;
+                    property.write_model(writer)?;
+                }
+                for component in &component.components {
+                    writer.write_all(b"\r\n")?;
+                    component.write_model(writer)?;
+                }
+                writer.write_all(b"\r\nEND:PARTICIPANT")?;
+            }
+            CalendarComponent::Location(component) => {
+                writer.write_all(b"BEGIN:VLOCATION")?;
+                for property in &component.properties {
+                    writer.write_all(b"\r\n")?;
+                    property.write_model(writer)?;
+                }
+                writer.write_all(b"\r\nEND:VLOCATION")?;
+            }
+            CalendarComponent::Resource(component) => {
+                writer.write_all(b"BEGIN:VRESOURCE")?;
+                for property in &component.properties {
+                    writer.write_all(b"\r\n")?;
+                    property.write_model(writer)?;
+                }
+                writer.write_all(b"\r\nEND:VRESOURCE")?;
+            }
             CalendarComponent::Alarm(component) => {
                 writer.write_all(b"BEGIN:VALARM")?;
                 for property in &component.properties {
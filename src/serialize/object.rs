@@ -1,4 +1,5 @@
 use crate::error::AetoliaResult;
+use crate::serialize::property::write_other_value;
 use crate::serialize::WriteModel;
 use std::io::Write;
 
@@ -53,18 +54,65 @@ impl WriteModel for crate::model::property::CalendarProperty {
                 writer.write_all(b":")?;
                 writer.write_all(property.value.as_bytes())?;
             }
+            CalendarProperty::Name(property) => {
+                writer.write_all(b"NAME")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            CalendarProperty::Description(property) => {
+                writer.write_all(b"DESCRIPTION")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            CalendarProperty::LastModified(property) => {
+                writer.write_all(b"LAST-MODIFIED")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            CalendarProperty::Url(property) => {
+                writer.write_all(b"URL")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
+            CalendarProperty::RefreshInterval(property) => {
+                writer.write_all(b"REFRESH-INTERVAL")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            CalendarProperty::Source(property) => {
+                writer.write_all(b"SOURCE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
+            CalendarProperty::Color(property) => {
+                writer.write_all(b"COLOR")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            CalendarProperty::Image(property) => {
+                writer.write_all(b"IMAGE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
             CalendarProperty::XProperty(property) => {
-                writer.write_all(b"X-")?;
                 writer.write_all(property.name.as_bytes())?;
                 property.params.as_slice().write_model(writer)?;
                 writer.write_all(b":")?;
-                writer.write_all(property.value.as_bytes())?;
+                write_other_value(writer, &property.value, property.extension.as_ref())?;
             }
             CalendarProperty::IanaProperty(property) => {
                 writer.write_all(property.name.as_bytes())?;
                 property.params.as_slice().write_model(writer)?;
                 writer.write_all(b":")?;
-                writer.write_all(property.value.as_bytes())?;
+                write_other_value(writer, &property.value, property.extension.as_ref())?;
             }
         }
 
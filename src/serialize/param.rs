@@ -1,12 +1,14 @@
 use crate::error::AetoliaResult;
 use crate::model::param::{
     AlternateRepresentationParam, CalendarUserTypeParam, CommonNameParam, DelegatedFromParam,
-    DelegatedToParam, DirectoryEntryReferenceParam, EncodingParam, FormatTypeParam,
-    FreeBusyTimeTypeParam, LanguageParam, MembersParam, ParticipationStatusParam, RangeParam,
-    RelationshipTypeParam, RoleParam, RsvpParam, SentByParam, TimeZoneIdParam,
-    TriggerRelationshipParam, ValueTypeParam,
+    DelegatedToParam, DirectoryEntryReferenceParam, DisplayParam, EmailParam, EncodingParam,
+    FeatureParam, FormatTypeParam, FreeBusyTimeTypeParam, GapParam, LabelParam, LanguageParam,
+    LinkRelationParam, MembersParam, ParticipationStatusParam, RangeParam, RelationshipTypeParam,
+    RoleParam, RsvpParam, ScheduleAgentParam, ScheduleForceSendParam, ScheduleStatusParam,
+    SentByParam, TimeZoneIdParam, TriggerRelationshipParam, ValueTypeParam,
 };
 use crate::serialize::WriteModel;
+use std::borrow::Cow;
 use std::io::Write;
 
 impl WriteModel for crate::model::param::Param {
@@ -18,7 +20,8 @@ impl WriteModel for crate::model::param::Param {
                 write!(writer, "ALTREP=\"{}\"", uri)?;
             }
             Param::CommonName(CommonNameParam { name }) => {
-                write!(writer, "CN={}", name)?;
+                writer.write_all(b"CN=")?;
+                write_param_text(writer, name)?;
             }
             Param::ValueType(ValueTypeParam { value }) => {
                 write!(writer, "VALUE=")?;
@@ -112,16 +115,74 @@ impl WriteModel for crate::model::param::Param {
                 writer.write_all(b"RELATED=")?;
                 related.write_model(writer)?;
             }
+            Param::Display(DisplayParam { display }) => {
+                writer.write_all(b"DISPLAY=")?;
+                for (index, display) in display.iter().enumerate() {
+                    if index > 0 {
+                        writer.write_all(b",")?;
+                    }
+                    display.write_model(writer)?;
+                }
+            }
+            Param::Email(EmailParam { email }) => {
+                writer.write_all(b"EMAIL=")?;
+                write_param_text(writer, email)?;
+            }
+            Param::Feature(FeatureParam { features }) => {
+                writer.write_all(b"FEATURE=")?;
+                for (index, feature) in features.iter().enumerate() {
+                    if index > 0 {
+                        writer.write_all(b",")?;
+                    }
+                    feature.write_model(writer)?;
+                }
+            }
+            Param::Label(LabelParam { label }) => {
+                writer.write_all(b"LABEL=")?;
+                write_param_text(writer, label)?;
+            }
+            Param::ScheduleAgent(ScheduleAgentParam { agent }) => {
+                writer.write_all(b"SCHEDULE-AGENT=")?;
+                agent.write_model(writer)?;
+            }
+            Param::ScheduleForceSend(ScheduleForceSendParam { force_send }) => {
+                writer.write_all(b"SCHEDULE-FORCE-SEND=")?;
+                force_send.write_model(writer)?;
+            }
+            Param::ScheduleStatus(ScheduleStatusParam { statuses }) => {
+                let statuses = statuses
+                    .iter()
+                    .map(|status| {
+                        status
+                            .iter()
+                            .map(|part| part.to_string())
+                            .collect::<Vec<_>>()
+                            .join(".")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+                writer.write_all(b"SCHEDULE-STATUS=")?;
+                write_param_text(writer, &statuses)?;
+            }
+            Param::Gap(GapParam { duration }) => {
+                writer.write_all(b"GAP=")?;
+                duration.write_model(writer)?;
+            }
+            Param::LinkRelation(LinkRelationParam { relation }) => {
+                writer.write_all(b"LINKREL=")?;
+                write_param_text(writer, relation)?;
+            }
             Param::Other { name, value } => {
-                write!(writer, "{}={}", name, value)?;
+                write!(writer, "{}=", name)?;
+                write_param_text(writer, value)?;
             }
-            Param::Others { name, values } => {
+            Param::Others { name, values } | Param::XName { name, values } => {
                 write!(writer, "{}=", name)?;
-                if let Some(value) = values.first() {
-                    write!(writer, "\"{}\"", value)?;
-                }
-                for value in values.iter().skip(1) {
-                    write!(writer, ",\"{}\"", value)?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        writer.write_all(b",")?;
+                    }
+                    write_param_text(writer, value)?;
                 }
             }
         }
@@ -129,3 +190,36 @@ impl WriteModel for crate::model::param::Param {
         Ok(())
     }
 }
+
+/// Write a param value, quoting it if it contains characters that are not allowed in `paramtext`.
+///
+/// Characters that can't appear in a param value at all are caret encoded, as described in
+/// RFC 6868.
+fn write_param_text<W: Write>(writer: &mut W, value: &str) -> AetoliaResult<()> {
+    let value = encode_param_text(value);
+    if value.contains([',', ';', ':']) {
+        write!(writer, "\"{}\"", value)?;
+    } else {
+        writer.write_all(value.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn encode_param_text(value: &str) -> Cow<'_, str> {
+    if !value.contains(['^', '"', '\n']) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut encoded = String::with_capacity(value.len() + 4);
+    for c in value.chars() {
+        match c {
+            '^' => encoded.push_str("^^"),
+            '"' => encoded.push_str("^'"),
+            '\n' => encoded.push_str("^n"),
+            _ => encoded.push(c),
+        }
+    }
+
+    Cow::Owned(encoded)
+}
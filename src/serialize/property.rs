@@ -1,4 +1,5 @@
 use crate::error::AetoliaResult;
+use crate::extension::ExtensionData;
 use crate::model::property::RecurrenceDateTimesPropertyValue;
 use crate::serialize::WriteModel;
 use std::io::Write;
@@ -198,7 +199,7 @@ impl WriteModel for crate::model::property::ComponentProperty {
                 writer.write_all(b"RELATED-TO")?;
                 property.params.as_slice().write_model(writer)?;
                 writer.write_all(b":")?;
-                property.value.write_model(writer)?;
+                write_reference_value(writer, &property.params, &property.value)?;
             }
             ComponentProperty::Resources(property) => {
                 writer.write_all(b"RESOURCES")?;
@@ -327,17 +328,107 @@ impl WriteModel for crate::model::property::ComponentProperty {
                 writer.write_all(b":")?;
                 write!(writer, "{}", property.value)?;
             }
+            ComponentProperty::Color(property) => {
+                writer.write_all(b"COLOR")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::Image(property) => {
+                writer.write_all(b"IMAGE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
+            ComponentProperty::Conference(property) => {
+                writer.write_all(b"CONFERENCE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
+            ComponentProperty::BusyType(property) => {
+                writer.write_all(b"BUSYTYPE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::StyledDescription(property) => {
+                writer.write_all(b"STYLED-DESCRIPTION")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                write_structured_value(writer, &property.params, &property.value)?;
+            }
+            ComponentProperty::StructuredData(property) => {
+                writer.write_all(b"STRUCTURED-DATA")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                write_structured_value(writer, &property.params, &property.value)?;
+            }
+            ComponentProperty::LocationType(property) => {
+                writer.write_all(b"LOCATION-TYPE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                if let Some(location_type) = property.value.first() {
+                    location_type.write_model(writer)?;
+                }
+                for location_type in property.value.iter().skip(1) {
+                    writer.write_all(b",")?;
+                    location_type.write_model(writer)?;
+                }
+            }
+            ComponentProperty::ParticipantType(property) => {
+                writer.write_all(b"PARTICIPANT-TYPE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::ResourceType(property) => {
+                writer.write_all(b"RESOURCE-TYPE")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::Acknowledged(property) => {
+                writer.write_all(b"ACKNOWLEDGED")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::Proximity(property) => {
+                writer.write_all(b"PROXIMITY")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
+            ComponentProperty::Concept(property) => {
+                writer.write_all(b"CONCEPT")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                writer.write_all(property.value.as_bytes())?;
+            }
+            ComponentProperty::Link(property) => {
+                writer.write_all(b"LINK")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                write_reference_value(writer, &property.params, &property.value)?;
+            }
+            ComponentProperty::RefId(property) => {
+                writer.write_all(b"REFID")?;
+                property.params.as_slice().write_model(writer)?;
+                writer.write_all(b":")?;
+                property.value.write_model(writer)?;
+            }
             ComponentProperty::IanaProperty(property) => {
                 writer.write_all(property.name.as_bytes())?;
                 property.params.as_slice().write_model(writer)?;
                 writer.write_all(b":")?;
-                writer.write_all(property.value.as_bytes())?;
+                write_other_value(writer, &property.value, property.extension.as_ref())?;
             }
             ComponentProperty::XProperty(property) => {
                 writer.write_all(property.name.as_bytes())?;
                 property.params.as_slice().write_model(writer)?;
                 writer.write_all(b":")?;
-                writer.write_all(property.value.as_bytes())?;
+                write_other_value(writer, &property.value, property.extension.as_ref())?;
             }
         }
 
@@ -355,3 +446,68 @@ impl WriteModel for &[crate::model::param::Param] {
         Ok(())
     }
 }
+
+/// Write the value of an X- or IANA property. A decoded extension value is written using the
+/// serializer of its extension, otherwise the raw value is written as it is.
+pub(super) fn write_other_value<W: Write>(
+    writer: &mut W,
+    value: &str,
+    extension: Option<&ExtensionData>,
+) -> AetoliaResult<()> {
+    match extension {
+        Some(extension) => writer.write_all(extension.serialize()?.as_bytes())?,
+        None => writer.write_all(value.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+/// Values of the RFC 9073 structured properties are only escaped when they are text, URI and
+/// binary values are written as they are.
+fn write_structured_value<W: Write>(
+    writer: &mut W,
+    params: &[crate::model::param::Param],
+    value: &str,
+) -> AetoliaResult<()> {
+    let is_text = !params.iter().any(|p| {
+        matches!(
+            p,
+            crate::model::param::Param::ValueType(crate::model::param::ValueTypeParam {
+                value: crate::common::Value::Uri | crate::common::Value::Binary,
+            })
+        )
+    });
+
+    if is_text {
+        value.to_string().write_model(writer)?;
+    } else {
+        writer.write_all(value.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Write the value of a property that may refer to another resource by URI, by UID or by an XML
+/// reference, RFC 9253. Only UID and text values are escaped.
+fn write_reference_value<W: Write>(
+    writer: &mut W,
+    params: &[crate::model::param::Param],
+    value: &str,
+) -> AetoliaResult<()> {
+    let is_text = !params.iter().any(|p| {
+        matches!(
+            p,
+            crate::model::param::Param::ValueType(crate::model::param::ValueTypeParam {
+                value: crate::common::Value::Uri | crate::common::Value::XmlReference,
+            })
+        )
+    });
+
+    if is_text {
+        value.to_string().write_model(writer)?;
+    } else {
+        writer.write_all(value.as_bytes())?;
+    }
+
+    Ok(())
+}
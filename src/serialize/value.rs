@@ -129,6 +129,12 @@ impl WriteModel for crate::common::Value {
             Value::UtcOffset => {
                 writer.write_all(b"UTC-OFFSET")?;
             }
+            Value::Uid => {
+                writer.write_all(b"UID")?;
+            }
+            Value::XmlReference => {
+                writer.write_all(b"XML-REFERENCE")?;
+            }
             Value::XName(name) => {
                 write!(writer, "{}", name)?;
             }
@@ -264,6 +270,122 @@ impl WriteModel for crate::common::Role {
     }
 }
 
+impl WriteModel for crate::common::DisplayType {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::common::DisplayType;
+
+        match self {
+            DisplayType::Badge => {
+                writer.write_all(b"BADGE")?;
+            }
+            DisplayType::Graphic => {
+                writer.write_all(b"GRAPHIC")?;
+            }
+            DisplayType::FullSize => {
+                writer.write_all(b"FULLSIZE")?;
+            }
+            DisplayType::Thumbnail => {
+                writer.write_all(b"THUMBNAIL")?;
+            }
+            DisplayType::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            DisplayType::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::common::FeatureType {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::common::FeatureType;
+
+        match self {
+            FeatureType::Audio => {
+                writer.write_all(b"AUDIO")?;
+            }
+            FeatureType::Chat => {
+                writer.write_all(b"CHAT")?;
+            }
+            FeatureType::Feed => {
+                writer.write_all(b"FEED")?;
+            }
+            FeatureType::Moderator => {
+                writer.write_all(b"MODERATOR")?;
+            }
+            FeatureType::Phone => {
+                writer.write_all(b"PHONE")?;
+            }
+            FeatureType::Screen => {
+                writer.write_all(b"SCREEN")?;
+            }
+            FeatureType::Video => {
+                writer.write_all(b"VIDEO")?;
+            }
+            FeatureType::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            FeatureType::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::common::ScheduleAgent {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::common::ScheduleAgent;
+
+        match self {
+            ScheduleAgent::Server => {
+                writer.write_all(b"SERVER")?;
+            }
+            ScheduleAgent::Client => {
+                writer.write_all(b"CLIENT")?;
+            }
+            ScheduleAgent::None => {
+                writer.write_all(b"NONE")?;
+            }
+            ScheduleAgent::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            ScheduleAgent::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::common::ScheduleForceSend {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::common::ScheduleForceSend;
+
+        match self {
+            ScheduleForceSend::Request => {
+                writer.write_all(b"REQUEST")?;
+            }
+            ScheduleForceSend::Reply => {
+                writer.write_all(b"REPLY")?;
+            }
+            ScheduleForceSend::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            ScheduleForceSend::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl WriteModel for crate::common::ParticipationStatusUnknown {
     fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
         use crate::common::ParticipationStatusUnknown;
@@ -328,6 +450,42 @@ impl WriteModel for crate::common::RelationshipType {
             RelationshipType::Sibling => {
                 writer.write_all(b"SIBLING")?;
             }
+            RelationshipType::Snooze => {
+                writer.write_all(b"SNOOZE")?;
+            }
+            RelationshipType::Concept => {
+                writer.write_all(b"CONCEPT")?;
+            }
+            RelationshipType::DependsOn => {
+                writer.write_all(b"DEPENDS-ON")?;
+            }
+            RelationshipType::First => {
+                writer.write_all(b"FIRST")?;
+            }
+            RelationshipType::FinishToFinish => {
+                writer.write_all(b"FINISHTOFINISH")?;
+            }
+            RelationshipType::FinishToStart => {
+                writer.write_all(b"FINISHTOSTART")?;
+            }
+            RelationshipType::Next => {
+                writer.write_all(b"NEXT")?;
+            }
+            RelationshipType::RefId => {
+                writer.write_all(b"REFID")?;
+            }
+            RelationshipType::Replaces => {
+                writer.write_all(b"REPLACES")?;
+            }
+            RelationshipType::Requires => {
+                writer.write_all(b"REQUIRES")?;
+            }
+            RelationshipType::StartToFinish => {
+                writer.write_all(b"STARTTOFINISH")?;
+            }
+            RelationshipType::StartToStart => {
+                writer.write_all(b"STARTTOSTART")?;
+            }
             RelationshipType::XName(name) => {
                 writer.write_all(name.as_bytes())?;
             }
@@ -386,6 +544,137 @@ impl WriteModel for crate::common::TriggerRelationship {
     }
 }
 
+impl WriteModel for crate::model::property::BusyType {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::model::property::BusyType;
+
+        match self {
+            BusyType::Busy => {
+                writer.write_all(b"BUSY")?;
+            }
+            BusyType::BusyUnavailable => {
+                writer.write_all(b"BUSY-UNAVAILABLE")?;
+            }
+            BusyType::BusyTentative => {
+                writer.write_all(b"BUSY-TENTATIVE")?;
+            }
+            BusyType::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            BusyType::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::model::property::ParticipantType {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::model::property::ParticipantType;
+
+        match self {
+            ParticipantType::Active => {
+                writer.write_all(b"ACTIVE")?;
+            }
+            ParticipantType::Inactive => {
+                writer.write_all(b"INACTIVE")?;
+            }
+            ParticipantType::Sponsor => {
+                writer.write_all(b"SPONSOR")?;
+            }
+            ParticipantType::Contact => {
+                writer.write_all(b"CONTACT")?;
+            }
+            ParticipantType::BookingContact => {
+                writer.write_all(b"BOOKING-CONTACT")?;
+            }
+            ParticipantType::EmergencyContact => {
+                writer.write_all(b"EMERGENCY-CONTACT")?;
+            }
+            ParticipantType::PublicityContact => {
+                writer.write_all(b"PUBLICITY-CONTACT")?;
+            }
+            ParticipantType::PlannerContact => {
+                writer.write_all(b"PLANNER-CONTACT")?;
+            }
+            ParticipantType::Performer => {
+                writer.write_all(b"PERFORMER")?;
+            }
+            ParticipantType::Speaker => {
+                writer.write_all(b"SPEAKER")?;
+            }
+            ParticipantType::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            ParticipantType::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::model::property::ResourceType {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::model::property::ResourceType;
+
+        match self {
+            ResourceType::Projector => {
+                writer.write_all(b"PROJECTOR")?;
+            }
+            ResourceType::Room => {
+                writer.write_all(b"ROOM")?;
+            }
+            ResourceType::RemoteConferenceAudio => {
+                writer.write_all(b"REMOTE-CONFERENCE-AUDIO")?;
+            }
+            ResourceType::RemoteConferenceVideo => {
+                writer.write_all(b"REMOTE-CONFERENCE-VIDEO")?;
+            }
+            ResourceType::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            ResourceType::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::model::property::Proximity {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        use crate::model::property::Proximity;
+
+        match self {
+            Proximity::Arrive => {
+                writer.write_all(b"ARRIVE")?;
+            }
+            Proximity::Depart => {
+                writer.write_all(b"DEPART")?;
+            }
+            Proximity::Connect => {
+                writer.write_all(b"CONNECT")?;
+            }
+            Proximity::Disconnect => {
+                writer.write_all(b"DISCONNECT")?;
+            }
+            Proximity::XName(name) => {
+                writer.write_all(name.as_bytes())?;
+            }
+            Proximity::IanaToken(token) => {
+                writer.write_all(token.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl WriteModel for crate::model::property::Classification {
     fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
         use crate::model::property::Classification;
@@ -433,36 +722,40 @@ impl WriteModel for crate::model::property::RecurrenceRule {
     fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
         use crate::model::property::RecurRulePart;
 
-        for part in &self.parts {
+        for (index, part) in self.parts.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b";")?;
+            }
+
             match part {
                 RecurRulePart::Freq(freq) => {
                     writer.write_all(b"FREQ=")?;
                     freq.write_model(writer)?;
                 }
                 RecurRulePart::Until(until) => {
-                    writer.write_all(b";UNTIL=")?;
+                    writer.write_all(b"UNTIL=")?;
                     until.write_model(writer)?;
                 }
                 RecurRulePart::Count(count) => {
-                    write!(writer, ";COUNT={}", count)?;
+                    write!(writer, "COUNT={}", count)?;
                 }
                 RecurRulePart::Interval(interval) => {
-                    write!(writer, ";INTERVAL={}", interval)?;
+                    write!(writer, "INTERVAL={}", interval)?;
                 }
                 RecurRulePart::BySecList(by_second) => {
-                    write!(writer, ";BYSECOND=")?;
+                    write!(writer, "BYSECOND=")?;
                     by_second.write_model(writer)?;
                 }
                 RecurRulePart::ByMinute(by_minute) => {
-                    write!(writer, ";BYMINUTE=")?;
+                    write!(writer, "BYMINUTE=")?;
                     by_minute.write_model(writer)?;
                 }
                 RecurRulePart::ByHour(by_hour) => {
-                    write!(writer, ";BYHOUR=")?;
+                    write!(writer, "BYHOUR=")?;
                     by_hour.write_model(writer)?;
                 }
                 RecurRulePart::ByDay(by_day) => {
-                    write!(writer, ";BYDAY=")?;
+                    write!(writer, "BYDAY=")?;
                     if let Some(day) = by_day.first() {
                         day.write_model(writer)?;
                     }
@@ -472,19 +765,19 @@ impl WriteModel for crate::model::property::RecurrenceRule {
                     }
                 }
                 RecurRulePart::ByMonthDay(by_month_day) => {
-                    write!(writer, ";BYMONTHDAY=")?;
+                    write!(writer, "BYMONTHDAY=")?;
                     by_month_day.write_model(writer)?;
                 }
                 RecurRulePart::ByYearDay(by_year_day) => {
-                    write!(writer, ";BYYEARDAY=")?;
+                    write!(writer, "BYYEARDAY=")?;
                     by_year_day.write_model(writer)?;
                 }
                 RecurRulePart::ByWeekNumber(by_week_number) => {
-                    write!(writer, ";BYWEEKNO=")?;
+                    write!(writer, "BYWEEKNO=")?;
                     by_week_number.write_model(writer)?;
                 }
                 RecurRulePart::ByMonth(by_month) => {
-                    write!(writer, ";BYMONTH=")?;
+                    write!(writer, "BYMONTH=")?;
                     if let Some(month) = by_month.first() {
                         month.write_model(writer)?;
                     }
@@ -494,13 +787,20 @@ impl WriteModel for crate::model::property::RecurrenceRule {
                     }
                 }
                 RecurRulePart::BySetPos(by_set_pos) => {
-                    write!(writer, ";BYSETPOS=")?;
+                    write!(writer, "BYSETPOS=")?;
                     by_set_pos.write_model(writer)?;
                 }
                 RecurRulePart::WeekStart(week_start) => {
-                    write!(writer, ";WKST=")?;
+                    write!(writer, "WKST=")?;
                     week_start.write_model(writer)?;
                 }
+                RecurRulePart::RScale(rscale) => {
+                    write!(writer, "RSCALE={}", rscale)?;
+                }
+                RecurRulePart::Skip(skip) => {
+                    write!(writer, "SKIP=")?;
+                    skip.write_model(writer)?;
+                }
             }
         }
 
@@ -597,9 +897,32 @@ impl WriteModel for crate::common::Weekday {
     }
 }
 
-impl WriteModel for time::Month {
+impl WriteModel for crate::common::RecurMonth {
+    fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
+        write!(writer, "{}", self.number)?;
+        if self.leap {
+            writer.write_all(b"L")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteModel for crate::common::Skip {
     fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
-        write!(writer, "{}", (*self) as u8)?;
+        use crate::common::Skip;
+
+        match self {
+            Skip::Omit => {
+                writer.write_all(b"OMIT")?;
+            }
+            Skip::Backward => {
+                writer.write_all(b"BACKWARD")?;
+            }
+            Skip::Forward => {
+                writer.write_all(b"FORWARD")?;
+            }
+        }
 
         Ok(())
     }
@@ -760,12 +1083,12 @@ impl WriteModel for String {
     fn write_model<W: Write>(&self, writer: &mut W) -> AetoliaResult<()> {
         let mut out = Vec::with_capacity(self.len());
         for c in self.chars() {
-            if matches!(c as u8, b';' | b'\\' | b',') {
+            if matches!(c, ';' | '\\' | ',') {
                 out.extend_from_slice(&[b'\\', c as u8]);
             } else if c == '\n' {
                 out.extend_from_slice(b"\\n");
             } else {
-                out.push(c as u8);
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
             }
         }
 
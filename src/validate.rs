@@ -1,18 +1,25 @@
 mod calendar_properties;
 mod component_properties;
 mod error;
+mod extension;
+mod itip;
 mod params;
 mod recur;
 mod value;
 
 use crate::common::{PropertyKind, Value};
+use crate::extension::ExtensionRegistry;
+use crate::itip::ItipMethod;
 use crate::model::component::CalendarComponent;
 use crate::model::object::ICalObject;
 use crate::model::param::{Param, ValueTypeParam};
 use crate::model::property::{CalendarProperty, ComponentProperty};
 use crate::model::ComponentAccess;
+use crate::relation::{CycleKind, RelationGraph};
 use crate::validate::calendar_properties::validate_calendar_properties;
 use crate::validate::component_properties::validate_component_properties;
+use crate::validate::extension::validate_extensions;
+use crate::validate::itip::validate_itip_method;
 use crate::validate::params::validate_params;
 use std::collections::{HashMap, HashSet};
 
@@ -20,6 +27,40 @@ use crate::error::AetoliaResult;
 use crate::prelude::AetoliaError;
 pub use error::*;
 
+/// Validate a calendar object, including the rules that are declared by the registered
+/// extensions.
+///
+/// Registered properties are checked for where they appear, how often they appear and whether
+/// their values parse and pass the extension's validation. Registered components are checked for
+/// their required properties and the extension's validation.
+pub fn validate_model_with_extensions(
+    ical_object: &ICalObject,
+    registry: &ExtensionRegistry,
+) -> AetoliaResult<Vec<ICalendarError>> {
+    let mut errors = validate_model(ical_object)?;
+    errors.extend(validate_extensions(ical_object, registry));
+
+    Ok(errors)
+}
+
+/// Validate the components of an iTIP message against the restrictions of RFC 5546 section 3 for
+/// its METHOD.
+///
+/// `validate_model` checks these for every METHOD except PUBLISH. A PUBLISH object is also how
+/// calendar feeds are served, and those commonly carry attendees, so the PUBLISH restrictions
+/// are only checked here, for an object that is sent as an iTIP message.
+pub fn validate_itip_message(ical_object: &ICalObject) -> Vec<ICalendarError> {
+    ical_object
+        .properties
+        .iter()
+        .find_map(|p| match p {
+            CalendarProperty::Method(method) => ItipMethod::from_name(&method.value),
+            _ => None,
+        })
+        .map(|method| validate_itip_method(ical_object, method))
+        .unwrap_or_default()
+}
+
 pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarError>> {
     let mut errors = Vec::new();
 
@@ -57,27 +98,26 @@ pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarEr
         });
     }
 
-    let validate_alarms = |errors: &mut Vec<ICalendarError>,
-                           alarms: &[CalendarComponent],
-                           index: usize,
-                           name: &str|
+    let validate_alarm = |errors: &mut Vec<ICalendarError>,
+                          alarm: &CalendarComponent,
+                          index: usize,
+                          name: &str,
+                          alarm_index: usize|
      -> AetoliaResult<()> {
-        for (alarm_index, alarm) in alarms.iter().enumerate() {
-            errors.extend_from_slice(
-                ICalendarError::many_from_nested_component_property_errors(
-                    validate_component_properties(
-                        &calendar_info,
-                        PropertyLocation::Alarm,
-                        alarm.properties(),
-                    )?,
-                    index,
-                    name.to_string(),
-                    alarm_index,
-                    component_name(alarm).to_string(),
-                )
-                .as_slice(),
-            );
-        }
+        errors.extend_from_slice(
+            ICalendarError::many_from_nested_component_property_errors(
+                validate_component_properties(
+                    &calendar_info,
+                    PropertyLocation::Alarm,
+                    alarm.properties(),
+                )?,
+                index,
+                name.to_string(),
+                alarm_index,
+                component_name(alarm).to_string(),
+            )
+            .as_slice(),
+        );
 
         Ok(())
     };
@@ -98,7 +138,25 @@ pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarEr
                     .as_slice(),
                 );
 
-                validate_alarms(&mut errors, &event.alarms, index, component_name(component))?;
+                for (nested_index, nested) in event.components.iter().enumerate() {
+                    if let CalendarComponent::Alarm(_) = nested {
+                        validate_alarm(
+                            &mut errors,
+                            nested,
+                            index,
+                            component_name(component),
+                            nested_index,
+                        )?;
+                    } else {
+                        errors.extend(validate_rfc_9073_component(
+                            &calendar_info,
+                            nested,
+                            index,
+                            component_name(component),
+                            nested_index,
+                        )?);
+                    }
+                }
             }
             CalendarComponent::ToDo(to_do) => {
                 errors.extend_from_slice(
@@ -114,7 +172,15 @@ pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarEr
                     .as_slice(),
                 );
 
-                validate_alarms(&mut errors, &to_do.alarms, index, component_name(component))?;
+                for (alarm_index, alarm) in to_do.alarms.iter().enumerate() {
+                    validate_alarm(
+                        &mut errors,
+                        alarm,
+                        index,
+                        component_name(component),
+                        alarm_index,
+                    )?;
+                }
             }
             CalendarComponent::Journal(journal) => {
                 errors.extend_from_slice(
@@ -213,6 +279,37 @@ pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarEr
                     }
                 }
             }
+            CalendarComponent::Availability(availability) => {
+                errors.extend_from_slice(
+                    ICalendarError::many_from_component_property_errors(
+                        validate_component_properties(
+                            &calendar_info,
+                            PropertyLocation::Availability,
+                            &availability.properties,
+                        )?,
+                        index,
+                        component_name(component).to_string(),
+                    )
+                    .as_slice(),
+                );
+
+                for (available_index, available) in availability.components.iter().enumerate() {
+                    errors.extend_from_slice(
+                        ICalendarError::many_from_nested_component_property_errors(
+                            validate_component_properties(
+                                &calendar_info,
+                                PropertyLocation::Available,
+                                available.properties(),
+                            )?,
+                            index,
+                            component_name(component).to_string(),
+                            available_index,
+                            component_name(available).to_string(),
+                        )
+                        .as_slice(),
+                    );
+                }
+            }
             CalendarComponent::IanaComponent(iana_component) => {
                 errors.extend_from_slice(
                     ICalendarError::many_from_component_property_errors(
@@ -248,6 +345,99 @@ pub fn validate_model(ical_object: &ICalObject) -> AetoliaResult<Vec<ICalendarEr
         }
     }
 
+    if let Some(method) = calendar_info
+        .method
+        .as_deref()
+        .and_then(ItipMethod::from_name)
+        .filter(|method| *method != ItipMethod::Publish)
+    {
+        errors.extend(validate_itip_method(ical_object, method));
+    }
+
+    errors.extend(validate_relations(ical_object));
+
+    Ok(errors)
+}
+
+/// Warn about components that are their own ancestor, or that depend on themselves, through their
+/// RELATED-TO properties.
+fn validate_relations(ical_object: &ICalObject) -> Vec<ICalendarError> {
+    RelationGraph::new(ical_object)
+        .cycles()
+        .into_iter()
+        .map(|cycle| {
+            let kind = match cycle.kind {
+                CycleKind::Hierarchy => "Parent",
+                CycleKind::Dependency => "Dependency",
+            };
+            // Report the cycle against the first relation, which all of the others lead back to
+            let relation = cycle.relations[0];
+
+            ICalendarError {
+                message: format!(
+                    "{kind} relationships form a cycle: {}",
+                    cycle.uids.join(" -> ")
+                ),
+                severity: ICalendarErrorSeverity::Warning,
+                location: Some(ICalendarLocation::Component(ComponentLocation {
+                    index: relation.component.component_index,
+                    name: component_name(relation.component.component).to_string(),
+                    location: Some(Box::new(WithinComponentLocation::Property(
+                        ComponentPropertyLocation {
+                            index: relation.property_index,
+                            name: "RELATED-TO".to_string(),
+                            property_location: None,
+                        },
+                    ))),
+                })),
+            }
+        })
+        .collect()
+}
+
+/// Validate a participant, location or resource component nested at `nested_index` within the
+/// component at `index`, descending into any components that a participant contains.
+fn validate_rfc_9073_component(
+    calendar_info: &CalendarInfo,
+    nested: &CalendarComponent,
+    index: usize,
+    name: &str,
+    nested_index: usize,
+) -> AetoliaResult<Vec<ICalendarError>> {
+    let property_location = match nested {
+        CalendarComponent::Participant(_) => PropertyLocation::Participant,
+        CalendarComponent::Location(_) => PropertyLocation::Location,
+        CalendarComponent::Resource(_) => PropertyLocation::Resource,
+        _ => {
+            // Neither the parser nor the builder will let other subcomponents be added here.
+            unreachable!()
+        }
+    };
+
+    let mut errors = ICalendarError::many_from_nested_component_property_errors(
+        validate_component_properties(calendar_info, property_location, nested.properties())?,
+        index,
+        name.to_string(),
+        nested_index,
+        component_name(nested).to_string(),
+    );
+
+    if let CalendarComponent::Participant(participant) = nested {
+        for (inner_index, inner) in participant.components.iter().enumerate() {
+            errors.extend(
+                validate_rfc_9073_component(
+                    calendar_info,
+                    inner,
+                    nested_index,
+                    component_name(nested),
+                    inner_index,
+                )?
+                .into_iter()
+                .map(|e| e.nest_within_component(index, name.to_string())),
+            );
+        }
+    }
+
     Ok(errors)
 }
 
@@ -330,6 +520,11 @@ enum PropertyLocation {
     TimeZoneComponent,
     Other,
     Alarm,
+    Availability,
+    Available,
+    Participant,
+    Location,
+    Resource,
 }
 
 impl<'a> PropertyInfo<'a> {
@@ -421,18 +616,26 @@ fn get_declared_value_type(property: &ComponentProperty) -> Option<(Value, usize
         })
 }
 
-fn calendar_property_name(property: &CalendarProperty) -> &str {
+pub(crate) fn calendar_property_name(property: &CalendarProperty) -> &str {
     match property {
         CalendarProperty::Version { .. } => "VERSION",
         CalendarProperty::ProductId(_) => "PRODID",
         CalendarProperty::CalendarScale(_) => "CALSCALE",
         CalendarProperty::Method(_) => "METHOD",
+        CalendarProperty::Name(_) => "NAME",
+        CalendarProperty::Description(_) => "DESCRIPTION",
+        CalendarProperty::LastModified(_) => "LAST-MODIFIED",
+        CalendarProperty::Url(_) => "URL",
+        CalendarProperty::RefreshInterval(_) => "REFRESH-INTERVAL",
+        CalendarProperty::Source(_) => "SOURCE",
+        CalendarProperty::Color(_) => "COLOR",
+        CalendarProperty::Image(_) => "IMAGE",
         CalendarProperty::XProperty(x_prop) => &x_prop.name,
         CalendarProperty::IanaProperty(iana_prop) => &iana_prop.name,
     }
 }
 
-fn component_property_name(property: &ComponentProperty) -> &str {
+pub(crate) fn component_property_name(property: &ComponentProperty) -> &str {
     match property {
         ComponentProperty::Attach(_) => "ATTACH",
         ComponentProperty::Categories(_) => "CATEGORIES",
@@ -478,10 +681,24 @@ fn component_property_name(property: &ComponentProperty) -> &str {
         ComponentProperty::IanaProperty(iana_prop) => &iana_prop.name,
         ComponentProperty::XProperty(x_prop) => &x_prop.name,
         ComponentProperty::RequestStatus(_) => "REQUEST-STATUS",
+        ComponentProperty::Color(_) => "COLOR",
+        ComponentProperty::Image(_) => "IMAGE",
+        ComponentProperty::Conference(_) => "CONFERENCE",
+        ComponentProperty::BusyType(_) => "BUSYTYPE",
+        ComponentProperty::StyledDescription(_) => "STYLED-DESCRIPTION",
+        ComponentProperty::StructuredData(_) => "STRUCTURED-DATA",
+        ComponentProperty::LocationType(_) => "LOCATION-TYPE",
+        ComponentProperty::ParticipantType(_) => "PARTICIPANT-TYPE",
+        ComponentProperty::ResourceType(_) => "RESOURCE-TYPE",
+        ComponentProperty::Acknowledged(_) => "ACKNOWLEDGED",
+        ComponentProperty::Proximity(_) => "PROXIMITY",
+        ComponentProperty::Concept(_) => "CONCEPT",
+        ComponentProperty::Link(_) => "LINK",
+        ComponentProperty::RefId(_) => "REFID",
     }
 }
 
-fn component_name(component: &CalendarComponent) -> &str {
+pub(crate) fn component_name(component: &CalendarComponent) -> &str {
     match component {
         CalendarComponent::Event(_) => "VEVENT",
         CalendarComponent::ToDo(_) => "VTODO",
@@ -491,12 +708,17 @@ fn component_name(component: &CalendarComponent) -> &str {
         CalendarComponent::Alarm(_) => "VALARM",
         CalendarComponent::Standard(_) => "STANDARD",
         CalendarComponent::Daylight(_) => "DAYLIGHT",
+        CalendarComponent::Availability(_) => "VAVAILABILITY",
+        CalendarComponent::Available(_) => "AVAILABLE",
+        CalendarComponent::Participant(_) => "PARTICIPANT",
+        CalendarComponent::Location(_) => "VLOCATION",
+        CalendarComponent::Resource(_) => "VRESOURCE",
         CalendarComponent::IanaComponent(component) => &component.name,
         CalendarComponent::XComponent(component) => &component.name,
     }
 }
 
-fn param_name(param: &Param) -> &str {
+pub(crate) fn param_name(param: &Param) -> &str {
     match param {
         Param::AltRep { .. } => "ALTREP",
         Param::CommonName { .. } => "CN",
@@ -518,8 +740,18 @@ fn param_name(param: &Param) -> &str {
         Param::SentBy { .. } => "SENT-BY",
         Param::TimeZoneId { .. } => "TZID",
         Param::ValueType { .. } => "VALUE",
+        Param::Display { .. } => "DISPLAY",
+        Param::Email { .. } => "EMAIL",
+        Param::Feature { .. } => "FEATURE",
+        Param::Label { .. } => "LABEL",
+        Param::ScheduleAgent { .. } => "SCHEDULE-AGENT",
+        Param::ScheduleForceSend { .. } => "SCHEDULE-FORCE-SEND",
+        Param::Gap { .. } => "GAP",
+        Param::LinkRelation { .. } => "LINKREL",
+        Param::ScheduleStatus { .. } => "SCHEDULE-STATUS",
         Param::Other { name, .. } => name,
         Param::Others { name, .. } => name,
+        Param::XName { name, .. } => name,
     }
 }
 
@@ -2579,6 +2811,95 @@ END:VCALENDAR\r\n";
         );
     }
 
+    #[test]
+    fn recur_rscale_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:1\r\n\
+DTSTART;VALUE=DATE:20140208\r\n\
+RRULE:RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=8;SKIP=FORWARD\r\n\
+RRULE:RSCALE=CHINESE;FREQ=MONTHLY;BYMONTH=13\r\n\
+RRULE:FREQ=YEARLY;RSCALE=x-custom\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn recur_rscale_from_builder() {
+        use crate::common::{RecurFreq, RecurMonth, Skip};
+
+        let object = ICalObject::builder()
+            .add_product_id("-//ABC Corporation//NONSGML My Product//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_event_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(2014, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(8, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_date_time_start(
+                time::Date::from_calendar_date(2014, time::Month::February, 8).unwrap(),
+                None,
+            )
+            .finish_property()
+            .add_recurrence_rule(RecurFreq::Yearly, |rule| {
+                rule.set_rscale("HEBREW")
+                    .set_by_month(vec![RecurMonth::leap_month(5)])
+                    .set_by_month_day(vec![8])
+                    .set_skip(Skip::Forward)
+            })
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn recur_rscale_violations() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:1\r\n\
+DTSTART:19900101T001000\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=5L\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=1,13\r\n\
+RRULE:RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=1,14\r\n\
+RRULE:FREQ=MONTHLY;SKIP=BACKWARD\r\n\
+RRULE:RSCALE=MAYAN;FREQ=MONTHLY\r\n\
+RRULE:RSCALE=HEBREW;FREQ=MONTHLY;RSCALE=CHINESE\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 3: BYMONTH part at index 1 has a leap month, which is only valid with an RSCALE part",
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 4: Invalid BYMONTH part at index 1, months must be between 1 and 12",
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 5: Invalid BYMONTH part at index 2, months must be between 1 and 13",
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 6: SKIP part at index 1 is only valid with an RSCALE part",
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 7: RSCALE part at index 0 is not a known calendar scale",
+            "In component \"VEVENT\" at index 0, in component property \"RRULE\" at index 8: Repeated RSCALE part at index 2",
+        );
+    }
+
     #[test]
     fn x_prop_declares_boolean_but_is_not_boolean() {
         let content = "BEGIN:VCALENDAR\r\n\
@@ -2714,6 +3035,880 @@ END:VCALENDAR\r\n";
         assert_errors!(&errors, "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0, in nested component property \"ATTACH\" at index 3: ATTACH must only appear once");
     }
 
+    #[test]
+    fn rfc_7986_properties_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+NAME:Company Vacation Days\r\n\
+DESCRIPTION:Vacation days for the company\r\n\
+LAST-MODIFIED:20230101T000000Z\r\n\
+URL:http://example.com/calendar\r\n\
+REFRESH-INTERVAL;VALUE=DURATION:P1W\r\n\
+SOURCE:http://example.com/holidays.ics\r\n\
+COLOR:turquoise\r\n\
+IMAGE;VALUE=URI:http://example.com/images/calendar.png\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+COLOR:red\r\n\
+IMAGE;VALUE=URI;FMTTYPE=image/png:http://example.com/images/party.png\r\n\
+CONFERENCE;VALUE=URI:tel:+1-412-555-0123,,,654321\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_7986_properties_from_builder() {
+        use crate::common::{DisplayType, FeatureType};
+        use crate::model::param::OtherParamsBuilder;
+
+        let object = ICalObject::builder()
+            .add_product_id("-//hacksw/handcal//NONSGML v1.0//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_name("Company Vacation Days")
+            .add_language(crate::common::LanguageTag::new("en"))
+            .finish_property()
+            .add_refresh_interval(|| crate::model::property::Duration::weeks(1, 1))
+            .finish_property()
+            .add_source("http://example.com/holidays.ics")
+            .finish_property()
+            .add_color("turquoise")
+            .finish_property()
+            .add_event_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(1990, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(0, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_date_time_start(
+                time::Date::from_calendar_date(1990, time::Month::January, 1).unwrap(),
+                Some(time::Time::from_hms(0, 0, 0).unwrap()),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_image_uri("http://example.com/images/party.png")
+            .add_fmt_type("image", "png")
+            .add_display(vec![DisplayType::Badge])
+            .finish_property()
+            .add_conference("tel:+1-412-555-0123,,,654321")
+            .add_feature(vec![FeatureType::Phone, FeatureType::Moderator])
+            .add_label("Moderator dial-in")
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_7986_properties_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+COLOR:turquoise\r\n\
+COLOR:red\r\n\
+REFRESH-INTERVAL:P1W\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+COLOR:red\r\n\
+COLOR:blue\r\n\
+CONFERENCE:tel:+1-412-555-0123\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In calendar property \"COLOR\" at index 3: COLOR must only appear once",
+            "In calendar property \"REFRESH-INTERVAL\" at index 4: Property value type must be declared as DURATION",
+            "In component \"VEVENT\" at index 0, in component property \"COLOR\" at index 4: COLOR must only appear once",
+            "In component \"VEVENT\" at index 0, in component property \"CONFERENCE\" at index 5: Property value type must be declared as URI",
+        );
+    }
+
+    #[test]
+    fn rfc_7986_params_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+ORGANIZER;EMAIL=jsmith@example.com:mailto:jsmith@example.net\r\n\
+ATTENDEE;EMAIL=\"janedoe@example.com\":mailto:janedoe@example.net\r\n\
+IMAGE;VALUE=URI;DISPLAY=BADGE,THUMBNAIL:http://example.com/images/party.png\r\n\
+CONFERENCE;VALUE=URI;FEATURE=PHONE,MODERATOR;LABEL=Moderator dial-in:tel:+1-412-555-0123,,,654321\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_7986_params_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+SUMMARY;EMAIL=jsmith@example.com:some summary\r\n\
+IMAGE;VALUE=URI;FEATURE=VIDEO:http://example.com/images/party.png\r\n\
+CONFERENCE;VALUE=URI;DISPLAY=BADGE;LABEL=first;LABEL=second:tel:+1-412-555-0123\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"SUMMARY\" at index 3: Email (EMAIL) is not allowed for this property type",
+            "In component \"VEVENT\" at index 0, in component property \"IMAGE\" at index 4: FEATURE is not allowed",
+            "In component \"VEVENT\" at index 0, in component property \"CONFERENCE\" at index 5: DISPLAY is not allowed",
+            "In component \"VEVENT\" at index 0, in component property \"CONFERENCE\" at index 5: LABEL must only appear once",
+        );
+    }
+
+    #[test]
+    fn rfc_6638_params_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+ORGANIZER;SCHEDULE-AGENT=SERVER;SCHEDULE-FORCE-SEND=REPLY:mailto:cyrus@example.com\r\n\
+ATTENDEE;SCHEDULE-AGENT=CLIENT;SCHEDULE-STATUS=\"3.7,5.3\":mailto:wilfredo@example.com\r\n\
+ATTENDEE;SCHEDULE-FORCE-SEND=REQUEST;SCHEDULE-STATUS=2.0:mailto:bernard@example.net\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_6638_params_from_builder() {
+        use crate::common::{ScheduleAgent, ScheduleForceSend};
+
+        let object = ICalObject::builder()
+            .add_product_id("-//ABC Corporation//NONSGML My Product//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_event_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(1990, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(0, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_date_time_start(
+                time::Date::from_calendar_date(1990, time::Month::January, 1).unwrap(),
+                None,
+            )
+            .finish_property()
+            .add_organizer("mailto:cyrus@example.com")
+            .add_schedule_agent(ScheduleAgent::Server)
+            .add_schedule_force_send(ScheduleForceSend::Reply)
+            .finish_property()
+            .add_attendee("mailto:wilfredo@example.com")
+            .add_schedule_agent(ScheduleAgent::Client)
+            .add_schedule_status(vec![vec![3, 7], vec![5, 3]])
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_6638_params_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+DTSTART:19900101T000000Z\r\n\
+SUMMARY;SCHEDULE-AGENT=CLIENT:some summary\r\n\
+ORGANIZER;SCHEDULE-FORCE-SEND=REQUEST:mailto:cyrus@example.com\r\n\
+ATTENDEE;SCHEDULE-FORCE-SEND=REPLY:mailto:wilfredo@example.com\r\n\
+ATTENDEE;SCHEDULE-STATUS=2.0;SCHEDULE-STATUS=3.7:mailto:bernard@example.net\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"SUMMARY\" at index 3: SCHEDULE-AGENT is not allowed",
+            "In component \"VEVENT\" at index 0, in component property \"ORGANIZER\" at index 4: SCHEDULE-FORCE-SEND=REQUEST is only valid on an ATTENDEE property",
+            "In component \"VEVENT\" at index 0, in component property \"ATTENDEE\" at index 5: SCHEDULE-FORCE-SEND=REPLY is only valid on an ORGANIZER property",
+            "In component \"VEVENT\" at index 0, in component property \"ATTENDEE\" at index 6: SCHEDULE-STATUS must only appear once",
+        );
+    }
+
+    #[test]
+    fn rfc_9253_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTODO\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+CONCEPT:https://example.com/event-types/arts/music\r\n\
+LINK;LINKREL=\"https://example.com/linkrel/derivedFrom\";VALUE=XML-REFERENCE:https://example.com/xmlDocs/bidFramework.xml#xpointer(descendant::CostStructure/range-start)\r\n\
+LINK;LINKREL=SOURCE;LABEL=Venue;VALUE=URI:https://example.com/events\r\n\
+LINK;LINKREL=NEXT;VALUE=UID:1234-5678\r\n\
+REFID:itinerary-2014-11-17\r\n\
+RELATED-TO;VALUE=URI;RELTYPE=STARTTOFINISH;GAP=PT1H:https://example.com/caldav/user/jb/cal/event.ics\r\n\
+RELATED-TO;RELTYPE=DEPENDS-ON:19960401-080045-4000F192713-0052\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9253_from_builder() {
+        use crate::common::RelationshipType;
+        use crate::model::param::OtherParamsBuilder;
+        use crate::model::property::Duration;
+
+        let object = ICalObject::builder()
+            .add_product_id("-//ABC Corporation//NONSGML My Product//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_to_do_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(1990, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(0, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_concept("https://example.com/event-types/arts/music")
+            .finish_property()
+            .add_link_uri("https://example.com/events", "SOURCE")
+            .add_label("Venue")
+            .finish_property()
+            .add_link_uid("1234-5678", "NEXT")
+            .finish_property()
+            .add_ref_id("itinerary-2014-11-17")
+            .finish_property()
+            .add_related_to_uri("https://example.com/caldav/user/jb/cal/event.ics")
+            .add_relationship_type(RelationshipType::StartToFinish)
+            .add_gap(Duration::hours(1, 1).build())
+            .finish_property()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9253_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTODO\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:123\r\n\
+SUMMARY;LINKREL=SOURCE;GAP=PT1H:some summary\r\n\
+LINK;LINKREL=SOURCE:https://example.com/events\r\n\
+LINK;VALUE=URI:https://example.com/events\r\n\
+LINK;LINKREL=SOURCE;VALUE=TEXT:some text\r\n\
+RELATED-TO;GAP=PT1H;GAP=PT2H:19960401-080045-4000F192713-0052\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VTODO\" at index 0, in component property \"SUMMARY\" at index 2: LINKREL is not allowed",
+            "In component \"VTODO\" at index 0, in component property \"SUMMARY\" at index 2: GAP is not allowed",
+            "In component \"VTODO\" at index 0, in component property \"LINK\" at index 3: Property value type must be declared as URI, UID or XML-REFERENCE",
+            "In component \"VTODO\" at index 0, in component property \"LINK\" at index 4: LINKREL is required",
+            "In component \"VTODO\" at index 0, in component property \"LINK\" at index 5: Property is declared to have a text value but that is not valid for this property",
+            "In component \"VTODO\" at index 0, in component property \"LINK\" at index 5: Property value type must be declared as URI, UID or XML-REFERENCE",
+            "In component \"VTODO\" at index 0, in component property \"RELATED-TO\" at index 6: GAP must only appear once",
+        );
+    }
+
+    #[test]
+    fn related_to_cycles() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTODO\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:a\r\n\
+RELATED-TO;RELTYPE=FINISHTOSTART:b\r\n\
+RELATED-TO;RELTYPE=PARENT:b\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+DTSTAMP:19900101T000000Z\r\n\
+UID:b\r\n\
+RELATED-TO;RELTYPE=FINISHTOSTART:a\r\n\
+RELATED-TO;RELTYPE=PARENT:a\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VTODO\" at index 0, in component property \"RELATED-TO\" at index 3: Parent relationships form a cycle: a -> b -> a",
+            "In component \"VTODO\" at index 1, in component property \"RELATED-TO\" at index 2: Dependency relationships form a cycle: a -> b -> a",
+        );
+    }
+
+    #[test]
+    fn itip_reply_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+METHOD:REPLY\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+SEQUENCE:1\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn itip_request_with_x_component_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+METHOD:REQUEST\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+DTSTART:20240205T090000Z\r\n\
+SUMMARY:Planning\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+BEGIN:X-VENDOR-SETTINGS\r\n\
+X-COLOR:red\r\n\
+END:X-VENDOR-SETTINGS\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn itip_publish_restrictions_are_opt_in() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+METHOD:PUBLISH\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+DTSTART:20240205T090000Z\r\n\
+SUMMARY:Planning\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+ATTENDEE:mailto:bob@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+        assert_no_errors!(&errors);
+
+        let (_, object) = crate::parser::ical_object::<Error>(content.as_bytes()).unwrap();
+        let errors = validate_itip_message(&object.to_model().unwrap());
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"ATTENDEE\" at index 5: ATTENDEE is not allowed for METHOD:PUBLISH",
+        );
+    }
+
+    #[test]
+    fn itip_method_restrictions() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+METHOD:REPLY\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:bob@example.com\r\n\
+ATTENDEE;PARTSTAT=DECLINED:mailto:carol@example.com\r\n\
+BEGIN:VALARM\r\n\
+ACTION:AUDIO\r\n\
+TRIGGER:-PT15M\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VJOURNAL\r\n\
+UID:notes\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+END:VJOURNAL\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"ATTENDEE\" at index 3: ATTENDEE must only appear once for METHOD:REPLY",
+            "In component \"VEVENT\" at index 0: ORGANIZER is required for METHOD:REPLY",
+            "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0: VALARM is not allowed for METHOD:REPLY",
+            "In component \"VJOURNAL\" at index 1: An iTIP message must only contain one type of component, found VEVENT and VJOURNAL",
+            "In component \"VJOURNAL\" at index 1: VJOURNAL is not allowed for METHOD:REPLY",
+        );
+    }
+
+    #[test]
+    fn itip_cancel_requires_sequence() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+METHOD:CANCEL\r\n\
+BEGIN:VEVENT\r\n\
+UID:meeting\r\n\
+DTSTAMP:20240201T120000Z\r\n\
+ORGANIZER:mailto:alice@example.com\r\n\
+REQUEST-STATUS:2.0;Success\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0: SEQUENCE is required for METHOD:CANCEL",
+            "In component \"VEVENT\" at index 0, in component property \"REQUEST-STATUS\" at index 3: REQUEST-STATUS is not allowed for METHOD:CANCEL",
+        );
+    }
+
+    #[test]
+    fn availability_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VAVAILABILITY\r\n\
+UID:20061206T001330Z-1@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+BUSYTYPE:BUSY-UNAVAILABLE\r\n\
+DTSTART:20061206T000000Z\r\n\
+BEGIN:AVAILABLE\r\n\
+UID:20061206T001330Z-2@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+DTSTART:20061206T090000Z\r\n\
+DTEND:20061206T170000Z\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r\n\
+SUMMARY:Monday to Friday from 9:00 to 17:00\r\n\
+END:AVAILABLE\r\n\
+END:VAVAILABILITY\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn availability_from_builder() {
+        use crate::model::property::BusyType;
+
+        let object = ICalObject::builder()
+            .add_product_id("-//hacksw/handcal//NONSGML v1.0//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_availability_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(2006, time::Month::December, 6).unwrap(),
+                time::Time::from_hms(0, 13, 30).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("20061206T001330Z-1@example.com")
+            .finish_property()
+            .add_busy_type(BusyType::BusyTentative)
+            .finish_property()
+            .add_available(|available| {
+                available
+                    .add_date_time_stamp(
+                        time::Date::from_calendar_date(2006, time::Month::December, 6).unwrap(),
+                        time::Time::from_hms(0, 13, 30).unwrap(),
+                    )
+                    .set_is_utc()
+                    .finish_property()
+                    .add_unique_identifier("20061206T001330Z-2@example.com")
+                    .finish_property()
+                    .add_date_time_start(
+                        time::Date::from_calendar_date(2006, time::Month::December, 6).unwrap(),
+                        Some(time::Time::from_hms(9, 0, 0).unwrap()),
+                    )
+                    .set_is_utc()
+                    .finish_property()
+                    .add_duration(|| crate::model::property::Duration::hours(1, 8).build())
+                    .finish_property()
+            })
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn availability_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VAVAILABILITY\r\n\
+UID:20061206T001330Z-1@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+BUSYTYPE:BUSY\r\n\
+BUSYTYPE:BUSY-TENTATIVE\r\n\
+DTSTART;VALUE=DATE:20061206\r\n\
+BEGIN:AVAILABLE\r\n\
+UID:20061206T001330Z-2@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+DTSTART:20061206T090000\r\n\
+END:AVAILABLE\r\n\
+BEGIN:AVAILABLE\r\n\
+UID:20061206T001330Z-3@example.com\r\n\
+DTSTAMP:20061206T001330Z\r\n\
+DTSTART:20061206T170000Z\r\n\
+DTEND:20061206T090000Z\r\n\
+END:AVAILABLE\r\n\
+END:VAVAILABILITY\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VAVAILABILITY\" at index 0, in component property \"BUSYTYPE\" at index 3: BUSYTYPE must only appear once",
+            "In component \"VAVAILABILITY\" at index 0, in component property \"DTSTART\" at index 4: DTSTART must be a UTC date-time or a date-time with a time zone",
+            "In component \"VAVAILABILITY\" at index 0, in nested component \"AVAILABLE\" at index 0, in nested component property \"DTSTART\" at index 2: DTSTART must be a UTC date-time or a date-time with a time zone",
+            "In component \"VAVAILABILITY\" at index 0, in nested component \"AVAILABLE\" at index 0: Exactly one of DTEND or DURATION is required",
+            "In component \"VAVAILABILITY\" at index 0, in nested component \"AVAILABLE\" at index 1, in nested component property \"DTEND\" at index 3: DTEND is before DTSTART",
+        );
+    }
+
+    #[test]
+    fn rfc_9073_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20240101T120000Z\r\n\
+DTSTART:20240101T120000Z\r\n\
+SUMMARY:Conference keynote\r\n\
+STYLED-DESCRIPTION;VALUE=TEXT;FMTTYPE=text/html:<p>The keynote</p>\r\n\
+STRUCTURED-DATA;VALUE=URI;FMTTYPE=application/ld+json:http://example.com/keynote.json\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+TRIGGER:-PT15M\r\n\
+DESCRIPTION:Keynote soon\r\n\
+END:VALARM\r\n\
+BEGIN:PARTICIPANT\r\n\
+UID:participant-1\r\n\
+PARTICIPANT-TYPE:SPEAKER\r\n\
+SUMMARY:Jane Doe\r\n\
+BEGIN:VLOCATION\r\n\
+UID:location-2\r\n\
+DESCRIPTION:Speaker room\r\n\
+END:VLOCATION\r\n\
+END:PARTICIPANT\r\n\
+BEGIN:VLOCATION\r\n\
+UID:location-1\r\n\
+LOCATION-TYPE:auditorium,hall\r\n\
+DESCRIPTION:Main hall\r\n\
+END:VLOCATION\r\n\
+BEGIN:VRESOURCE\r\n\
+UID:resource-1\r\n\
+RESOURCE-TYPE:PROJECTOR\r\n\
+STRUCTURED-DATA;VALUE=BINARY;ENCODING=BASE64:dGVzdA==\r\n\
+END:VRESOURCE\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9073_from_builder() {
+        use crate::model::property::{ParticipantType, ResourceType};
+
+        let object = ICalObject::builder()
+            .add_product_id("-//hacksw/handcal//NONSGML v1.0//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_event_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                time::Time::from_hms(12, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_date_time_start(
+                time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+                Some(time::Time::from_hms(12, 0, 0).unwrap()),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_styled_description_text("<p>The keynote</p>")
+            .add_fmt_type("text", "html")
+            .finish_property()
+            .add_participant()
+            .add_unique_identifier("participant-1")
+            .finish_property()
+            .add_participant_type(ParticipantType::Speaker)
+            .finish_property()
+            .add_vlocation()
+            .add_unique_identifier("location-2")
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .add_vlocation()
+            .add_unique_identifier("location-1")
+            .finish_property()
+            .add_location_type(vec!["auditorium"])
+            .finish_property()
+            .finish_component()
+            .add_vresource()
+            .add_unique_identifier("resource-1")
+            .finish_property()
+            .add_resource_type(ResourceType::Room)
+            .finish_property()
+            .add_structured_data_binary("dGVzdA==")
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9073_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20240101T120000Z\r\n\
+DTSTART:20240101T120000Z\r\n\
+STYLED-DESCRIPTION:<p>The keynote</p>\r\n\
+BEGIN:PARTICIPANT\r\n\
+UID:participant-1\r\n\
+SUMMARY:Jane Doe\r\n\
+BEGIN:VRESOURCE\r\n\
+UID:resource-1\r\n\
+RESOURCE-TYPE:ROOM\r\n\
+RESOURCE-TYPE:PROJECTOR\r\n\
+END:VRESOURCE\r\n\
+END:PARTICIPANT\r\n\
+BEGIN:VLOCATION\r\n\
+DESCRIPTION:Main hall\r\n\
+STRUCTURED-DATA:some data\r\n\
+END:VLOCATION\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"STYLED-DESCRIPTION\" at index 3: Property value type must be declared as TEXT or URI",
+            "In component \"VEVENT\" at index 0, in nested component \"PARTICIPANT\" at index 0: PARTICIPANT-TYPE is required",
+            "In component \"VEVENT\" at index 0, in nested component \"PARTICIPANT\" at index 0, in nested component \"VRESOURCE\" at index 0, in nested component property \"RESOURCE-TYPE\" at index 2: RESOURCE-TYPE must only appear once",
+            "In component \"VEVENT\" at index 0, in nested component \"VLOCATION\" at index 1, in nested component property \"STRUCTURED-DATA\" at index 1: Property value type must be declared as TEXT, URI or BINARY",
+            "In component \"VEVENT\" at index 0, in nested component \"VLOCATION\" at index 1: UID is required",
+        );
+    }
+
+    #[test]
+    fn rfc_9074_pass_validation() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20090604T080000Z\r\n\
+DTSTART:20090604T090000Z\r\n\
+BEGIN:VALARM\r\n\
+UID:alarm-1\r\n\
+TRIGGER:-PT15M\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Breakfast meeting\r\n\
+ACKNOWLEDGED:20090604T084500Z\r\n\
+END:VALARM\r\n\
+BEGIN:VALARM\r\n\
+UID:alarm-2\r\n\
+RELATED-TO;RELTYPE=SNOOZE:alarm-1\r\n\
+TRIGGER;VALUE=DATE-TIME:20090604T085500Z\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Breakfast meeting\r\n\
+END:VALARM\r\n\
+BEGIN:VALARM\r\n\
+TRIGGER:-PT1H\r\n\
+ACTION:AUDIO\r\n\
+PROXIMITY:DEPART\r\n\
+X-APPLE-DEFAULT-ALARM:TRUE\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9074_from_builder() {
+        use crate::common::RelationshipType;
+        use crate::model::property::{Duration, Proximity};
+
+        let object = ICalObject::builder()
+            .add_product_id("-//hacksw/handcal//NONSGML v1.0//EN")
+            .finish_property()
+            .add_max_version("2.0")
+            .finish_property()
+            .add_event_component()
+            .add_date_time_stamp(
+                time::Date::from_calendar_date(2009, time::Month::June, 4).unwrap(),
+                time::Time::from_hms(8, 0, 0).unwrap(),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_unique_identifier("123")
+            .finish_property()
+            .add_date_time_start(
+                time::Date::from_calendar_date(2009, time::Month::June, 4).unwrap(),
+                Some(time::Time::from_hms(9, 0, 0).unwrap()),
+            )
+            .set_is_utc()
+            .finish_property()
+            .add_display_alarm()
+            .add_action()
+            .finish_property()
+            .add_unique_identifier("alarm-2")
+            .finish_property()
+            .add_related_to("alarm-1")
+            .add_relationship_type(RelationshipType::Snooze)
+            .finish_property()
+            .add_relative_trigger(Duration::minutes(-1, 5).build())
+            .finish_property()
+            .add_description("Breakfast meeting")
+            .finish_property()
+            .add_acknowledged(
+                time::Date::from_calendar_date(2009, time::Month::June, 4).unwrap(),
+                time::Time::from_hms(8, 50, 0).unwrap(),
+            )
+            .finish_property()
+            .finish_component()
+            .add_audio_alarm()
+            .add_action()
+            .finish_property()
+            .add_relative_trigger(Duration::hours(-1, 1).build())
+            .finish_property()
+            .add_proximity(Proximity::Arrive)
+            .finish_property()
+            .add_default_alarm()
+            .finish_property()
+            .finish_component()
+            .finish_component()
+            .build();
+
+        let errors = validate_model(&object).unwrap();
+
+        assert_no_errors!(&errors);
+    }
+
+    #[test]
+    fn rfc_9074_invalid() {
+        let content = "BEGIN:VCALENDAR\r\n\
+PRODID:test\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:123\r\n\
+DTSTAMP:20090604T080000Z\r\n\
+DTSTART:20090604T090000Z\r\n\
+RELATED-TO;RELTYPE=SNOOZE:456\r\n\
+BEGIN:VALARM\r\n\
+UID:alarm-1\r\n\
+UID:alarm-2\r\n\
+TRIGGER:-PT15M\r\n\
+ACTION:AUDIO\r\n\
+ACKNOWLEDGED:20090604T084500\r\n\
+PROXIMITY:ARRIVE\r\n\
+PROXIMITY:DEPART\r\n\
+X-APPLE-DEFAULT-ALARM:YES\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let errors = validate_content(content);
+
+        assert_errors!(
+            errors,
+            "In component \"VEVENT\" at index 0, in component property \"RELATED-TO\" at index 3: Relationship type SNOOZE is only allowed in an alarm",
+            "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0, in nested component property \"UID\" at index 1: UID must only appear once",
+            "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0, in nested component property \"ACKNOWLEDGED\" at index 4: ACKNOWLEDGED must be a UTC date-time",
+            "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0, in nested component property \"PROXIMITY\" at index 6: PROXIMITY must only appear once",
+            "In component \"VEVENT\" at index 0, in nested component \"VALARM\" at index 0, in nested component property \"X-APPLE-DEFAULT-ALARM\" at index 7: X-APPLE-DEFAULT-ALARM must be TRUE or FALSE",
+        );
+    }
+
     fn validate_content(content: &str) -> Vec<ICalendarError> {
         let (rem, object) = crate::parser::ical_object::<Error>(content.as_bytes()).unwrap();
         check_rem(rem, 0);
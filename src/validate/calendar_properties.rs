@@ -1,14 +1,34 @@
-use crate::common::PropertyKind;
+use crate::common::{PropertyKind, Value};
 use crate::model::object::ICalObject;
+use crate::model::param::{Param, ValueTypeParam};
 use crate::model::property::CalendarProperty;
 use crate::validate::error::CalendarPropertyError;
 use crate::validate::params::validate_params;
 use crate::validate::{
     calendar_property_name, check_occurrence, CalendarInfo, CalendarPropertyLocation,
     ICalendarErrorSeverity, OccurrenceExpectation, PropertyInfo, PropertyLocation, ValueType,
+    WithinPropertyLocation,
 };
 use std::collections::HashMap;
 
+macro_rules! check_calendar_property_occurrence {
+    ($errors:ident, $seen:ident, $property:ident, $index:ident, $occur:expr) => {
+        let name = calendar_property_name($property);
+        $crate::validate::add_to_seen(&mut $seen, name);
+        if let Some(message) = check_occurrence(&$seen, name, $occur) {
+            $errors.push(CalendarPropertyError {
+                message,
+                severity: ICalendarErrorSeverity::Error,
+                location: Some(CalendarPropertyLocation {
+                    index: $index,
+                    name: name.to_string(),
+                    property_location: None,
+                }),
+            });
+        }
+    };
+}
+
 pub(super) fn validate_calendar_properties(
     ical_object: &ICalObject,
     calendar_info: &mut CalendarInfo,
@@ -111,7 +131,172 @@ pub(super) fn validate_calendar_properties(
                     })
                 }
             }
-            _ => {
+            CalendarProperty::Name(name) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalMany
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    PropertyLocation::Calendar,
+                    PropertyKind::Name,
+                    ValueType::Text,
+                );
+                errors.extend(CalendarPropertyError::many_from_param_errors(
+                    validate_params(&name.params, property_info),
+                    index,
+                    calendar_property_name(property).to_string(),
+                ));
+            }
+            CalendarProperty::Description(description) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalMany
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    PropertyLocation::Calendar,
+                    PropertyKind::Description,
+                    ValueType::Text,
+                );
+                errors.extend(CalendarPropertyError::many_from_param_errors(
+                    validate_params(&description.params, property_info),
+                    index,
+                    calendar_property_name(property).to_string(),
+                ));
+            }
+            CalendarProperty::LastModified(last_modified) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalOnce
+                );
+
+                if !last_modified.value.is_utc() {
+                    errors.push(CalendarPropertyError {
+                        message: "LAST-MODIFIED must be a UTC date-time".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(CalendarPropertyLocation {
+                            index,
+                            name: calendar_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+            }
+            CalendarProperty::Url(_) | CalendarProperty::Source(_) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalOnce
+                );
+            }
+            CalendarProperty::RefreshInterval(refresh_interval) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalOnce
+                );
+
+                let declared_duration = refresh_interval.params.iter().any(|p| {
+                    matches!(
+                        p,
+                        Param::ValueType(ValueTypeParam {
+                            value: Value::Duration
+                        })
+                    )
+                });
+                if !declared_duration {
+                    errors.push(CalendarPropertyError {
+                        message: "Property value type must be declared as DURATION".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(CalendarPropertyLocation {
+                            index,
+                            name: calendar_property_name(property).to_string(),
+                            property_location: None,
+                        }),
+                    });
+                }
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    PropertyLocation::Calendar,
+                    PropertyKind::RefreshInterval,
+                    ValueType::Duration,
+                );
+                errors.extend(CalendarPropertyError::many_from_param_errors(
+                    validate_params(&refresh_interval.params, property_info),
+                    index,
+                    calendar_property_name(property).to_string(),
+                ));
+            }
+            CalendarProperty::Color(_) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalOnce
+                );
+            }
+            CalendarProperty::Image(image) => {
+                check_calendar_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    OccurrenceExpectation::OptionalMany
+                );
+
+                let value_type = image.params.iter().find_map(|p| match p {
+                    Param::ValueType(ValueTypeParam { value }) => Some(value),
+                    _ => None,
+                });
+                let value_type = match value_type {
+                    Some(Value::Binary) => ValueType::Binary,
+                    Some(Value::Uri) => ValueType::Uri,
+                    _ => {
+                        errors.push(CalendarPropertyError {
+                            message: "Property value type must be declared as URI or BINARY"
+                                .to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(CalendarPropertyLocation {
+                                index,
+                                name: calendar_property_name(property).to_string(),
+                                property_location: None,
+                            }),
+                        });
+                        ValueType::Uri
+                    }
+                };
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    PropertyLocation::Calendar,
+                    PropertyKind::Image,
+                    value_type,
+                );
+                errors.extend(CalendarPropertyError::many_from_param_errors(
+                    validate_params(&image.params, property_info),
+                    index,
+                    calendar_property_name(property).to_string(),
+                ));
+            }
+            CalendarProperty::XProperty(_) | CalendarProperty::IanaProperty(_) => {
                 // Nothing further to validate
             }
         }
@@ -1,10 +1,12 @@
 use crate::common::{PropertyKind, Status, Value};
 use crate::error::AetoliaResult;
+use crate::model::component::DEFAULT_ALARM_PROPERTY;
 use crate::model::param::Param;
 use crate::model::property::{
-    Action, ComponentProperty, DateTimeCompletedProperty, DateTimeDueProperty, DateTimeEndProperty,
-    DateTimeStampProperty, DateTimeStartProperty, DurationProperty, FreeBusyTimeProperty,
-    LastModifiedProperty, PeriodEnd, StatusProperty,
+    AcknowledgedProperty, Action, ComponentProperty, DateTimeCompletedProperty,
+    DateTimeDueProperty, DateTimeEndProperty, DateTimeQuery, DateTimeStampProperty,
+    DateTimeStartProperty, DurationProperty, FreeBusyTimeProperty, LastModifiedProperty, PeriodEnd,
+    StatusProperty,
 };
 use crate::validate::recur::validate_recurrence_rule;
 use crate::validate::value::check_declared_value;
@@ -60,10 +62,13 @@ pub(super) fn validate_component_properties(
         PropertyLocation::Event
         | PropertyLocation::ToDo
         | PropertyLocation::Journal
-        | PropertyLocation::FreeBusy => OccurrenceExpectation::Once,
+        | PropertyLocation::FreeBusy
+        | PropertyLocation::Availability
+        | PropertyLocation::Available => OccurrenceExpectation::Once,
         PropertyLocation::TimeZone
         | PropertyLocation::TimeZoneComponent
         | PropertyLocation::Alarm => OccurrenceExpectation::Never,
+        PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
         PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
@@ -72,10 +77,17 @@ pub(super) fn validate_component_properties(
         PropertyLocation::Event
         | PropertyLocation::ToDo
         | PropertyLocation::Journal
-        | PropertyLocation::FreeBusy => OccurrenceExpectation::Once,
-        PropertyLocation::TimeZone
-        | PropertyLocation::TimeZoneComponent
-        | PropertyLocation::Alarm => OccurrenceExpectation::Never,
+        | PropertyLocation::FreeBusy
+        | PropertyLocation::Availability
+        | PropertyLocation::Available => OccurrenceExpectation::Once,
+        // RFC 9074, section 5
+        PropertyLocation::Alarm => OccurrenceExpectation::OptionalOnce,
+        PropertyLocation::TimeZone | PropertyLocation::TimeZoneComponent => {
+            OccurrenceExpectation::Never
+        }
+        PropertyLocation::Participant | PropertyLocation::Location | PropertyLocation::Resource => {
+            OccurrenceExpectation::Once
+        }
         PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
@@ -92,7 +104,9 @@ pub(super) fn validate_component_properties(
                 OccurrenceExpectation::OptionalOnce
             }
         }
-        PropertyLocation::TimeZoneComponent => OccurrenceExpectation::Once,
+        PropertyLocation::TimeZoneComponent | PropertyLocation::Available => {
+            OccurrenceExpectation::Once
+        }
         PropertyLocation::ToDo | PropertyLocation::Journal => {
             if is_recurring {
                 OccurrenceExpectation::Once
@@ -100,7 +114,9 @@ pub(super) fn validate_component_properties(
                 OccurrenceExpectation::OptionalOnce
             }
         }
-        PropertyLocation::FreeBusy => OccurrenceExpectation::OptionalOnce,
+        PropertyLocation::FreeBusy | PropertyLocation::Availability => {
+            OccurrenceExpectation::OptionalOnce
+        }
         PropertyLocation::TimeZone | PropertyLocation::Alarm => OccurrenceExpectation::Never,
         PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
@@ -187,7 +203,13 @@ pub(super) fn validate_component_properties(
     };
 
     let description_occurrence_expectation = match property_location {
-        PropertyLocation::Event | PropertyLocation::ToDo => OccurrenceExpectation::OptionalOnce,
+        PropertyLocation::Event
+        | PropertyLocation::ToDo
+        | PropertyLocation::Availability
+        | PropertyLocation::Available
+        | PropertyLocation::Participant
+        | PropertyLocation::Location
+        | PropertyLocation::Resource => OccurrenceExpectation::OptionalOnce,
         PropertyLocation::FreeBusy
         | PropertyLocation::TimeZone
         | PropertyLocation::TimeZoneComponent => OccurrenceExpectation::Never,
@@ -202,9 +224,12 @@ pub(super) fn validate_component_properties(
     };
 
     let summary_occurrence_expectation = match property_location {
-        PropertyLocation::Event | PropertyLocation::ToDo | PropertyLocation::Journal => {
-            OccurrenceExpectation::OptionalOnce
-        }
+        PropertyLocation::Event
+        | PropertyLocation::ToDo
+        | PropertyLocation::Journal
+        | PropertyLocation::Availability
+        | PropertyLocation::Available
+        | PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
         PropertyLocation::FreeBusy
         | PropertyLocation::TimeZone
         | PropertyLocation::TimeZoneComponent => OccurrenceExpectation::Never,
@@ -236,6 +261,12 @@ pub(super) fn validate_component_properties(
         _ => OccurrenceExpectation::Never,
     };
 
+    let participant_type_occurrence_expectation = match property_location {
+        PropertyLocation::Participant => OccurrenceExpectation::Once,
+        PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+
     let mut has_dt_start = false;
     let mut has_dt_end = false;
     let mut has_duration = false;
@@ -328,7 +359,8 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -344,7 +376,10 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -386,6 +421,9 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event | PropertyLocation::ToDo => {
                         OccurrenceExpectation::OptionalOnce
                     }
+                    PropertyLocation::Participant
+                    | PropertyLocation::Location
+                    | PropertyLocation::Resource => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -410,7 +448,10 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
-                    | PropertyLocation::TimeZone => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::TimeZone
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -434,9 +475,11 @@ pub(super) fn validate_component_properties(
             }
             ComponentProperty::Location(location) => {
                 let occurrence_expectation = match property_location {
-                    PropertyLocation::Event | PropertyLocation::ToDo => {
-                        OccurrenceExpectation::OptionalOnce
-                    }
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -461,7 +504,8 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
-                    | PropertyLocation::FreeBusy => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::FreeBusy
+                    | PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -490,9 +534,10 @@ pub(super) fn validate_component_properties(
             }
             ComponentProperty::Priority(priority) => {
                 let occurrence_expectation = match property_location {
-                    PropertyLocation::Event | PropertyLocation::ToDo => {
-                        OccurrenceExpectation::OptionalOnce
-                    }
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -516,7 +561,9 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -606,7 +653,11 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
-                    | PropertyLocation::FreeBusy => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::FreeBusy
+                    | PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Participant | PropertyLocation::Location => {
+                        OccurrenceExpectation::OptionalOnce
+                    }
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -622,7 +673,8 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalOnce,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -659,7 +711,8 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
-                    | PropertyLocation::TimeZoneComponent => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::TimeZoneComponent
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -692,9 +745,10 @@ pub(super) fn validate_component_properties(
                 has_dt_end = true;
 
                 let occurrence_expectation = match property_location {
-                    PropertyLocation::Event | PropertyLocation::FreeBusy => {
-                        OccurrenceExpectation::OptionalOnce
-                    }
+                    PropertyLocation::Event
+                    | PropertyLocation::FreeBusy
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -730,9 +784,11 @@ pub(super) fn validate_component_properties(
                 has_duration = true;
 
                 let occurrence_expectation = match property_location {
-                    PropertyLocation::Event | PropertyLocation::ToDo | PropertyLocation::Alarm => {
-                        OccurrenceExpectation::OptionalOnce
-                    }
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Alarm
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -773,6 +829,7 @@ pub(super) fn validate_component_properties(
                             _ => OccurrenceExpectation::OptionalMany,
                         }
                     }
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -816,7 +873,10 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -842,7 +902,10 @@ pub(super) fn validate_component_properties(
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
                     | PropertyLocation::FreeBusy
-                    | PropertyLocation::TimeZoneComponent => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::TimeZoneComponent
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -867,7 +930,10 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::FreeBusy => OccurrenceExpectation::OptionalOnce,
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Availability
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -891,7 +957,8 @@ pub(super) fn validate_component_properties(
                 let occurrence_expectation = match property_location {
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
-                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -917,6 +984,7 @@ pub(super) fn validate_component_properties(
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
                     | PropertyLocation::FreeBusy => OccurrenceExpectation::OptionalMany,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -941,6 +1009,9 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal => OccurrenceExpectation::OptionalMany,
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
+                    // RFC 9074, section 7
+                    PropertyLocation::Alarm => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -952,11 +1023,17 @@ pub(super) fn validate_component_properties(
                     occurrence_expectation
                 );
 
+                // RELATED-TO may refer to a URI, RFC 9253 section 9.1
+                let value_type = match get_declared_value_type(property) {
+                    Some((Value::Uri, _)) => ValueType::Uri,
+                    _ => ValueType::Text,
+                };
+
                 let property_info = PropertyInfo::new(
                     calendar_info,
                     property_location.clone(),
                     PropertyKind::Related,
-                    ValueType::Text,
+                    value_type,
                 );
                 do_validate_params(&mut errors, property_info, &related_to.params);
             }
@@ -965,6 +1042,7 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event | PropertyLocation::ToDo => {
                         OccurrenceExpectation::OptionalMany
                     }
+                    PropertyLocation::Participant => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -989,7 +1067,8 @@ pub(super) fn validate_component_properties(
                     PropertyLocation::Event
                     | PropertyLocation::ToDo
                     | PropertyLocation::Journal
-                    | PropertyLocation::TimeZoneComponent => OccurrenceExpectation::OptionalMany,
+                    | PropertyLocation::TimeZoneComponent
+                    | PropertyLocation::Available => OccurrenceExpectation::OptionalMany,
                     PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
                     _ => OccurrenceExpectation::Never,
                 };
@@ -1285,11 +1364,447 @@ pub(super) fn validate_component_properties(
                 );
                 do_validate_params(&mut errors, property_info, &repeat.params);
             }
+            ComponentProperty::Color(color) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Journal => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Color,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &color.params);
+            }
+            ComponentProperty::Image(image) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let value_type = match get_declared_value_type(property) {
+                    Some((Value::Binary, _)) => ValueType::Binary,
+                    Some((Value::Uri, _)) => ValueType::Uri,
+                    _ => {
+                        errors.push(ComponentPropertyError {
+                            message: "Property value type must be declared as URI or BINARY"
+                                .to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(ComponentPropertyLocation {
+                                index,
+                                name: component_property_name(property).to_string(),
+                                property_location: None,
+                            }),
+                        });
+                        ValueType::Uri
+                    }
+                };
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Image,
+                    value_type,
+                );
+                do_validate_params(&mut errors, property_info, &image.params);
+            }
+            ComponentProperty::Conference(conference) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event | PropertyLocation::ToDo | PropertyLocation::Other => {
+                        OccurrenceExpectation::OptionalMany
+                    }
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                if !matches!(get_declared_value_type(property), Some((Value::Uri, _))) {
+                    errors.push(ComponentPropertyError {
+                        message: "Property value type must be declared as URI".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index,
+                            name: component_property_name(property).to_string(),
+                            property_location: None,
+                        }),
+                    });
+                }
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Conference,
+                    ValueType::Uri,
+                );
+                do_validate_params(&mut errors, property_info, &conference.params);
+            }
+            ComponentProperty::BusyType(busy_type) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Availability => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::BusyType,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &busy_type.params);
+            }
+            ComponentProperty::StyledDescription(styled_description) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::Participant
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let value_type = match get_declared_value_type(property) {
+                    Some((Value::Text, _)) => ValueType::Text,
+                    Some((Value::Uri, _)) => ValueType::Uri,
+                    _ => {
+                        errors.push(ComponentPropertyError {
+                            message: "Property value type must be declared as TEXT or URI"
+                                .to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(ComponentPropertyLocation {
+                                index,
+                                name: component_property_name(property).to_string(),
+                                property_location: None,
+                            }),
+                        });
+                        ValueType::Text
+                    }
+                };
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::StyledDescription,
+                    value_type,
+                );
+                do_validate_params(&mut errors, property_info, &styled_description.params);
+            }
+            ComponentProperty::StructuredData(structured_data) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::Participant
+                    | PropertyLocation::Location
+                    | PropertyLocation::Resource
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let value_type = match get_declared_value_type(property) {
+                    Some((Value::Text, _)) => ValueType::Text,
+                    Some((Value::Uri, _)) => ValueType::Uri,
+                    Some((Value::Binary, _)) => ValueType::Binary,
+                    _ => {
+                        errors.push(ComponentPropertyError {
+                            message: "Property value type must be declared as TEXT, URI or BINARY"
+                                .to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(ComponentPropertyLocation {
+                                index,
+                                name: component_property_name(property).to_string(),
+                                property_location: None,
+                            }),
+                        });
+                        ValueType::Text
+                    }
+                };
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::StructuredData,
+                    value_type,
+                );
+                do_validate_params(&mut errors, property_info, &structured_data.params);
+            }
+            ComponentProperty::LocationType(location_type) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Location => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::LocationType,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &location_type.params);
+            }
+            ComponentProperty::ParticipantType(participant_type) => {
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    participant_type_occurrence_expectation.clone()
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::ParticipantType,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &participant_type.params);
+            }
+            ComponentProperty::ResourceType(resource_type) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Resource => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::ResourceType,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &resource_type.params);
+            }
+            ComponentProperty::Acknowledged(acknowledged) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Alarm => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                validate_acknowledged(&mut errors, acknowledged, index);
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Acknowledged,
+                    ValueType::DateTime,
+                );
+                do_validate_params(&mut errors, property_info, &acknowledged.params);
+            }
+            ComponentProperty::Proximity(proximity) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Alarm => OccurrenceExpectation::OptionalOnce,
+                    PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Proximity,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &proximity.params);
+            }
+            ComponentProperty::Concept(concept) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Concept,
+                    ValueType::Uri,
+                );
+                do_validate_params(&mut errors, property_info, &concept.params);
+            }
+            ComponentProperty::Link(link) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let value_type = match get_declared_value_type(property) {
+                    Some((Value::Uri | Value::XmlReference, _)) => ValueType::Uri,
+                    Some((Value::Uid, _)) => ValueType::Text,
+                    _ => {
+                        errors.push(ComponentPropertyError {
+                            message:
+                                "Property value type must be declared as URI, UID or XML-REFERENCE"
+                                    .to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(ComponentPropertyLocation {
+                                index,
+                                name: component_property_name(property).to_string(),
+                                property_location: None,
+                            }),
+                        });
+                        ValueType::Uri
+                    }
+                };
+
+                let has_link_relation = link.params.iter().any(|p| {
+                    matches!(p, Param::LinkRelation { .. })
+                        || matches!(p, Param::Other { name, .. } if name == "LINKREL")
+                });
+                if !has_link_relation {
+                    errors.push(ComponentPropertyError {
+                        message: "LINKREL is required".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index,
+                            name: component_property_name(property).to_string(),
+                            property_location: None,
+                        }),
+                    });
+                }
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::Link,
+                    value_type,
+                );
+                do_validate_params(&mut errors, property_info, &link.params);
+            }
+            ComponentProperty::RefId(ref_id) => {
+                let occurrence_expectation = match property_location {
+                    PropertyLocation::Event
+                    | PropertyLocation::ToDo
+                    | PropertyLocation::Journal
+                    | PropertyLocation::Other => OccurrenceExpectation::OptionalMany,
+                    _ => OccurrenceExpectation::Never,
+                };
+                check_component_property_occurrence!(
+                    errors,
+                    seen,
+                    property,
+                    index,
+                    occurrence_expectation
+                );
+
+                let property_info = PropertyInfo::new(
+                    calendar_info,
+                    property_location.clone(),
+                    PropertyKind::RefId,
+                    ValueType::Text,
+                );
+                do_validate_params(&mut errors, property_info, &ref_id.params);
+            }
             ComponentProperty::IanaProperty(_) => {
                 // Nothing to validate
             }
-            ComponentProperty::XProperty(_) => {
-                // Nothing to validate
+            ComponentProperty::XProperty(x_prop) => {
+                if property_location == PropertyLocation::Alarm
+                    && x_prop.name.eq_ignore_ascii_case(DEFAULT_ALARM_PROPERTY)
+                    && !x_prop.value.eq_ignore_ascii_case("TRUE")
+                    && !x_prop.value.eq_ignore_ascii_case("FALSE")
+                {
+                    errors.push(ComponentPropertyError {
+                        message: format!("{} must be TRUE or FALSE", DEFAULT_ALARM_PROPERTY),
+                        severity: ICalendarErrorSeverity::Warning,
+                        location: Some(ComponentPropertyLocation {
+                            index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
             }
         }
     }
@@ -1390,6 +1905,19 @@ pub(super) fn validate_component_properties(
             });
         }
     }
+    if participant_type_occurrence_expectation == OccurrenceExpectation::Once {
+        if let Some(message) = check_occurrence(
+            &seen,
+            "PARTICIPANT-TYPE",
+            participant_type_occurrence_expectation,
+        ) {
+            errors.push(ComponentPropertyError {
+                message,
+                severity: ICalendarErrorSeverity::Error,
+                location: None,
+            });
+        }
+    }
     if attendee_occurrence_expectation == OccurrenceExpectation::Once {
         if let Some(message) = check_occurrence(&seen, "ATTENDEE", attendee_occurrence_expectation)
         {
@@ -1431,6 +1959,34 @@ pub(super) fn validate_component_properties(
                 });
             }
         }
+        PropertyLocation::Availability => {
+            if has_dt_end && has_duration {
+                errors.push(ComponentPropertyError {
+                    message: "Both DTEND and DURATION properties are present, only one is allowed"
+                        .to_string(),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: None,
+                });
+            }
+
+            if has_duration && !has_dt_start {
+                errors.push(ComponentPropertyError {
+                    message: "DURATION property is present but no DTSTART property is present"
+                        .to_string(),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: None,
+                });
+            }
+        }
+        PropertyLocation::Available => {
+            if has_dt_end == has_duration {
+                errors.push(ComponentPropertyError {
+                    message: "Exactly one of DTEND or DURATION is required".to_string(),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: None,
+                });
+            }
+        }
         PropertyLocation::Alarm => {
             if (has_duration && !has_repeat) || (!has_duration && has_repeat) {
                 errors.push(ComponentPropertyError {
@@ -1504,6 +2060,23 @@ fn validate_date_time_end(
     index: usize,
     property_location: PropertyLocation,
 ) {
+    // RFC 7953, 3.1 requires DTEND to be a UTC date-time or a date-time with a time zone
+    if matches!(
+        property_location,
+        PropertyLocation::Availability | PropertyLocation::Available
+    ) && (date_time_end_property.is_date() || date_time_end_property.is_local_time())
+    {
+        errors.push(ComponentPropertyError {
+            message: "DTEND must be a UTC date-time or a date-time with a time zone".to_string(),
+            severity: ICalendarErrorSeverity::Error,
+            location: Some(ComponentPropertyLocation {
+                index,
+                name: "DTEND".to_string(),
+                property_location: Some(WithinPropertyLocation::Value),
+            }),
+        });
+    }
+
     // For a VEVENT, the date/date-time types must match at the start and end
     if property_location == PropertyLocation::Event {
         if let Some(dt_start) = maybe_dt_start {
@@ -1768,6 +2341,21 @@ fn validate_date_time_start(
                 });
             }
         }
+        PropertyLocation::Availability | PropertyLocation::Available => {
+            // RFC 7953, 3.1
+            if date_time_start_property.is_date() || date_time_start_property.is_local_time() {
+                errors.push(ComponentPropertyError {
+                    message: "DTSTART must be a UTC date-time or a date-time with a time zone"
+                        .to_string(),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: Some(ComponentPropertyLocation {
+                        index,
+                        name: "DTSTART".to_string(),
+                        property_location: Some(WithinPropertyLocation::Value),
+                    }),
+                });
+            }
+        }
         PropertyLocation::TimeZoneComponent => {
             if date_time_start_property.value.is_date() || date_time_start_property.value.is_utc() {
                 errors.push(ComponentPropertyError {
@@ -1932,6 +2520,25 @@ fn validate_date_time_stamp(
     }
 }
 
+// RFC 9074, 6
+fn validate_acknowledged(
+    errors: &mut Vec<ComponentPropertyError>,
+    acknowledged_property: &AcknowledgedProperty,
+    index: usize,
+) {
+    if !acknowledged_property.value.is_utc() {
+        errors.push(ComponentPropertyError {
+            message: "ACKNOWLEDGED must be a UTC date-time".to_string(),
+            severity: ICalendarErrorSeverity::Error,
+            location: Some(ComponentPropertyLocation {
+                index,
+                name: "ACKNOWLEDGED".to_string(),
+                property_location: Some(WithinPropertyLocation::Value),
+            }),
+        });
+    }
+}
+
 // RFC 5545, 3.8.7.3
 fn validate_last_modified(
     errors: &mut Vec<ComponentPropertyError>,
@@ -44,20 +44,28 @@ impl Display for ICalendarError {
                                 )?;
                             }
                             WithinComponentLocation::Component(nested_component_location) => {
-                                write!(
-                                    f,
-                                    ", in nested component \"{}\" at index {}",
-                                    nested_component_location.name, nested_component_location.index
-                                )?;
+                                let mut nested_component_location = nested_component_location;
+                                loop {
+                                    write!(
+                                        f,
+                                        ", in nested component \"{}\" at index {}",
+                                        nested_component_location.name,
+                                        nested_component_location.index
+                                    )?;
 
-                                if let Some(nested_within) = &nested_component_location.location {
-                                    if let WithinComponentLocation::Property(cp) = &**nested_within
-                                    {
-                                        write!(
-                                            f,
-                                            ", in nested component property \"{}\" at index {}",
-                                            cp.name, cp.index
-                                        )?;
+                                    match nested_component_location.location.as_deref() {
+                                        Some(WithinComponentLocation::Property(cp)) => {
+                                            write!(
+                                                f,
+                                                ", in nested component property \"{}\" at index {}",
+                                                cp.name, cp.index
+                                            )?;
+                                            break;
+                                        }
+                                        Some(WithinComponentLocation::Component(inner)) => {
+                                            nested_component_location = inner;
+                                        }
+                                        None => break,
                                     }
                                 }
                             }
@@ -139,6 +147,26 @@ impl ICalendarError {
     }
 }
 
+impl ICalendarError {
+    /// Move an error reported against a component so that it is reported against that
+    /// component nested inside the given outer component.
+    pub(super) fn nest_within_component(self, index: usize, name: String) -> Self {
+        match self.location {
+            Some(ICalendarLocation::Component(component_location)) => ICalendarError {
+                location: Some(ICalendarLocation::Component(ComponentLocation {
+                    index,
+                    name,
+                    location: Some(Box::new(WithinComponentLocation::Component(
+                        component_location,
+                    ))),
+                })),
+                ..self
+            },
+            _ => self,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum ICalendarLocation {
     CalendarProperty(CalendarPropertyLocation),
@@ -0,0 +1,217 @@
+use crate::extension::{ExtensionData, ExtensionRegistry};
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::model::param::Param;
+use crate::model::property::{CalendarProperty, ComponentProperty};
+use crate::validate::{
+    component_name, component_property_name, CalendarPropertyLocation, ComponentPropertyError,
+    ComponentPropertyLocation, ICalendarError, ICalendarErrorSeverity, ICalendarLocation,
+    WithinPropertyLocation,
+};
+use std::collections::HashMap;
+
+pub(super) fn validate_extensions(
+    ical_object: &ICalObject,
+    registry: &ExtensionRegistry,
+) -> Vec<ICalendarError> {
+    let mut errors = Vec::new();
+
+    let properties = ical_object
+        .properties
+        .iter()
+        .enumerate()
+        .filter_map(|(index, property)| match property {
+            CalendarProperty::XProperty(p) => Some(OtherProperty {
+                index,
+                name: &p.name,
+                value: &p.value,
+                params: &p.params,
+                extension: p.extension.as_ref(),
+            }),
+            CalendarProperty::IanaProperty(p) => Some(OtherProperty {
+                index,
+                name: &p.name,
+                value: &p.value,
+                params: &p.params,
+                extension: p.extension.as_ref(),
+            }),
+            _ => None,
+        });
+    for (index, name, message, within_value) in check_properties(registry, "VCALENDAR", properties)
+    {
+        errors.push(ICalendarError {
+            message,
+            severity: ICalendarErrorSeverity::Error,
+            location: Some(ICalendarLocation::CalendarProperty(
+                CalendarPropertyLocation {
+                    index,
+                    name,
+                    property_location: within_value.then_some(WithinPropertyLocation::Value),
+                },
+            )),
+        });
+    }
+
+    for (index, component) in ical_object.components.iter().enumerate() {
+        errors.extend(ICalendarError::many_from_component_property_errors(
+            validate_component(registry, component),
+            index,
+            component_name(component).to_string(),
+        ));
+
+        for (nested_index, nested) in component.nested_components().iter().enumerate() {
+            errors.extend(ICalendarError::many_from_nested_component_property_errors(
+                validate_component(registry, nested),
+                index,
+                component_name(component).to_string(),
+                nested_index,
+                component_name(nested).to_string(),
+            ));
+        }
+    }
+
+    errors
+}
+
+fn validate_component(
+    registry: &ExtensionRegistry,
+    component: &CalendarComponent,
+) -> Vec<ComponentPropertyError> {
+    let name = component_name(component);
+    let mut errors = Vec::new();
+
+    let properties = component
+        .properties()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, property)| match property {
+            ComponentProperty::XProperty(p) => Some(OtherProperty {
+                index,
+                name: &p.name,
+                value: &p.value,
+                params: &p.params,
+                extension: p.extension.as_ref(),
+            }),
+            ComponentProperty::IanaProperty(p) => Some(OtherProperty {
+                index,
+                name: &p.name,
+                value: &p.value,
+                params: &p.params,
+                extension: p.extension.as_ref(),
+            }),
+            _ => None,
+        });
+    for (index, property_name, message, within_value) in
+        check_properties(registry, name, properties)
+    {
+        errors.push(ComponentPropertyError {
+            message,
+            severity: ICalendarErrorSeverity::Error,
+            location: Some(ComponentPropertyLocation {
+                index,
+                name: property_name,
+                property_location: within_value.then_some(WithinPropertyLocation::Value),
+            }),
+        });
+    }
+
+    if let Some(extension) = registry.component(name) {
+        for required in extension.required_properties() {
+            let present = component
+                .properties()
+                .iter()
+                .any(|p| component_property_name(p).eq_ignore_ascii_case(required));
+            if !present {
+                errors.push(ComponentPropertyError {
+                    message: format!("Required property \"{required}\" is missing"),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: None,
+                });
+            }
+        }
+
+        for message in extension.validate_component(component) {
+            errors.push(ComponentPropertyError {
+                message,
+                severity: ICalendarErrorSeverity::Error,
+                location: None,
+            });
+        }
+    }
+
+    errors
+}
+
+/// An X- or IANA property and its position within its component.
+struct OtherProperty<'a> {
+    index: usize,
+    name: &'a str,
+    value: &'a str,
+    params: &'a [Param],
+    extension: Option<&'a ExtensionData>,
+}
+
+/// Check the X- and IANA properties of a component against the registered extensions.
+///
+/// Returns the index and name of the offending property, the message, and whether the problem
+/// is with the property value.
+fn check_properties<'a>(
+    registry: &ExtensionRegistry,
+    component_name: &str,
+    properties: impl Iterator<Item = OtherProperty<'a>>,
+) -> Vec<(usize, String, String, bool)> {
+    let mut errors = Vec::new();
+    let mut seen = HashMap::<String, usize>::new();
+
+    for OtherProperty {
+        index,
+        name,
+        value,
+        params,
+        extension: data,
+    } in properties
+    {
+        let extension = match registry.property(name) {
+            Some(extension) => extension,
+            None => continue,
+        };
+
+        if !extension.is_allowed_in(component_name) {
+            errors.push((
+                index,
+                name.to_string(),
+                format!("Property is not allowed in {component_name}"),
+                false,
+            ));
+        }
+
+        let count = seen.entry(name.to_ascii_uppercase()).or_default();
+        *count += 1;
+        if extension.is_once() && *count == 2 {
+            errors.push((
+                index,
+                name.to_string(),
+                "Property is allowed at most once".to_string(),
+                false,
+            ));
+        }
+
+        let data = match data {
+            Some(data) => data.clone(),
+            None => match extension.parse(value, params) {
+                Ok(data) => data,
+                Err(e) => {
+                    errors.push((index, name.to_string(), e.to_string(), true));
+                    continue;
+                }
+            },
+        };
+
+        for message in extension.validate_value(&data, params) {
+            errors.push((index, name.to_string(), message, true));
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,358 @@
+use crate::itip::ItipMethod;
+use crate::model::access::ComponentAccess;
+use crate::model::component::CalendarComponent;
+use crate::model::object::ICalObject;
+use crate::validate::{
+    component_name, component_property_name, ComponentLocation, ComponentPropertyLocation,
+    ICalendarError, ICalendarErrorSeverity, ICalendarLocation, WithinComponentLocation,
+};
+
+/// How often a property may appear in a component of an iTIP message.
+///
+/// These only add to the rules of RFC 5545, which are checked for every calendar object, so a
+/// property that RFC 5545 already limits to one occurrence is `Required` rather than `Once`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ItipExpectation {
+    /// Must appear at least once.
+    Required,
+    /// Must appear exactly once, where RFC 5545 allows it to repeat.
+    Once,
+    /// Must not appear.
+    Never,
+}
+
+use ItipExpectation::*;
+
+/// The descriptive properties that REFRESH and DECLINECOUNTER messages leave out, since they only
+/// identify the component that they refer to.
+const NO_CONTENT: &[(&str, ItipExpectation)] = &[
+    ("ATTACH", Never),
+    ("CATEGORIES", Never),
+    ("CLASS", Never),
+    ("CONTACT", Never),
+    ("CREATED", Never),
+    ("DESCRIPTION", Never),
+    ("DTEND", Never),
+    ("DTSTART", Never),
+    ("DUE", Never),
+    ("DURATION", Never),
+    ("EXDATE", Never),
+    ("GEO", Never),
+    ("LAST-MODIFIED", Never),
+    ("LOCATION", Never),
+    ("PRIORITY", Never),
+    ("RDATE", Never),
+    ("RELATED-TO", Never),
+    ("RESOURCES", Never),
+    ("RRULE", Never),
+    ("STATUS", Never),
+    ("SUMMARY", Never),
+    ("TRANSP", Never),
+    ("URL", Never),
+];
+
+/// The property restrictions for a component in a message with the given method, from the tables
+/// in RFC 5546 section 3. Returns `None` if the component cannot be sent with that method.
+fn restrictions(
+    component: &CalendarComponent,
+    method: ItipMethod,
+) -> Option<Vec<(&'static str, ItipExpectation)>> {
+    let table: Vec<(&str, ItipExpectation)> = match (component, method) {
+        // RFC 5546, section 3.2
+        (CalendarComponent::Event(_), ItipMethod::Publish) => vec![
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("SUMMARY", Required),
+            ("ATTENDEE", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Event(_), ItipMethod::Request) => vec![
+            ("ATTENDEE", Required),
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("SUMMARY", Required),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Event(_), ItipMethod::Reply) => {
+            vec![("ATTENDEE", Once), ("ORGANIZER", Required)]
+        }
+        (CalendarComponent::Event(_), ItipMethod::Add) => vec![
+            ("ATTENDEE", Required),
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("SEQUENCE", Required),
+            ("SUMMARY", Required),
+            ("RECURRENCE-ID", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Event(_), ItipMethod::Cancel) => vec![
+            ("ORGANIZER", Required),
+            ("SEQUENCE", Required),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Event(_), ItipMethod::Refresh) => {
+            let mut table = vec![
+                ("ATTENDEE", Once),
+                ("ORGANIZER", Required),
+                ("SEQUENCE", Never),
+                ("REQUEST-STATUS", Never),
+            ];
+            table.extend_from_slice(NO_CONTENT);
+            table
+        }
+        (CalendarComponent::Event(_), ItipMethod::Counter) => vec![
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("SUMMARY", Required),
+        ],
+        (CalendarComponent::Event(_), ItipMethod::DeclineCounter) => {
+            let mut table = vec![("ORGANIZER", Required)];
+            table.extend_from_slice(NO_CONTENT);
+            table
+        }
+        // RFC 5546, section 3.4
+        (CalendarComponent::ToDo(_), ItipMethod::Publish) => vec![
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("PRIORITY", Required),
+            ("SUMMARY", Required),
+            ("ATTENDEE", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::ToDo(_), ItipMethod::Request) => vec![
+            ("ATTENDEE", Required),
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("PRIORITY", Required),
+            ("SUMMARY", Required),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::ToDo(_), ItipMethod::Reply) => {
+            vec![("ATTENDEE", Required), ("ORGANIZER", Required)]
+        }
+        (CalendarComponent::ToDo(_), ItipMethod::Add) => vec![
+            ("ATTENDEE", Required),
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("PRIORITY", Required),
+            ("SEQUENCE", Required),
+            ("SUMMARY", Required),
+            ("RECURRENCE-ID", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::ToDo(_), ItipMethod::Cancel) => vec![
+            ("ORGANIZER", Required),
+            ("SEQUENCE", Required),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::ToDo(_), ItipMethod::Refresh) => {
+            let mut table = vec![
+                ("ATTENDEE", Once),
+                ("SEQUENCE", Never),
+                ("REQUEST-STATUS", Never),
+            ];
+            table.extend_from_slice(NO_CONTENT);
+            table
+        }
+        (CalendarComponent::ToDo(_), ItipMethod::Counter) => vec![
+            ("ATTENDEE", Required),
+            ("ORGANIZER", Required),
+            ("PRIORITY", Required),
+            ("SUMMARY", Required),
+        ],
+        (CalendarComponent::ToDo(_), ItipMethod::DeclineCounter) => {
+            let mut table = vec![("ATTENDEE", Required), ("ORGANIZER", Required)];
+            table.extend_from_slice(NO_CONTENT);
+            table
+        }
+        // RFC 5546, section 3.5
+        (CalendarComponent::Journal(_), ItipMethod::Publish) => vec![
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("ATTENDEE", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Journal(_), ItipMethod::Add) => vec![
+            ("DTSTART", Required),
+            ("ORGANIZER", Required),
+            ("SEQUENCE", Required),
+            ("ATTENDEE", Never),
+            ("RECURRENCE-ID", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Journal(_), ItipMethod::Cancel) => vec![
+            ("ORGANIZER", Required),
+            ("SEQUENCE", Required),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::Journal(_), _) => return None,
+        // RFC 5546, section 3.3
+        (CalendarComponent::FreeBusy(_), ItipMethod::Publish) => vec![
+            ("DTSTART", Required),
+            ("DTEND", Required),
+            ("ORGANIZER", Required),
+            ("ATTENDEE", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::FreeBusy(_), ItipMethod::Request) => vec![
+            ("ATTENDEE", Required),
+            ("DTSTART", Required),
+            ("DTEND", Required),
+            ("ORGANIZER", Required),
+            ("FREEBUSY", Never),
+            ("REQUEST-STATUS", Never),
+        ],
+        (CalendarComponent::FreeBusy(_), ItipMethod::Reply) => vec![
+            ("ATTENDEE", Once),
+            ("DTSTART", Required),
+            ("DTEND", Required),
+            ("ORGANIZER", Required),
+        ],
+        (CalendarComponent::FreeBusy(_), _) => return None,
+        _ => Vec::new(),
+    };
+
+    Some(table)
+}
+
+/// Whether VALARM components may be included with the given method.
+fn allows_alarms(method: ItipMethod) -> bool {
+    matches!(
+        method,
+        ItipMethod::Publish | ItipMethod::Request | ItipMethod::Add | ItipMethod::Counter
+    )
+}
+
+/// Check the components of an iTIP message against the property restrictions for its METHOD, as
+/// given by RFC 5546 section 3.
+pub(super) fn validate_itip_method(
+    ical_object: &ICalObject,
+    method: ItipMethod,
+) -> Vec<ICalendarError> {
+    let mut errors = Vec::new();
+
+    let mut component_type = None;
+    for (index, component) in ical_object.components.iter().enumerate() {
+        // Time zones are sent along with the components that use them, and X- and IANA
+        // components are not scheduling components, so none of them count towards the
+        // restrictions of the METHOD.
+        if matches!(
+            component,
+            CalendarComponent::TimeZone(_)
+                | CalendarComponent::XComponent(_)
+                | CalendarComponent::IanaComponent(_)
+        ) {
+            continue;
+        }
+
+        let location = |location: Option<WithinComponentLocation>| {
+            Some(ICalendarLocation::Component(ComponentLocation {
+                index,
+                name: component_name(component).to_string(),
+                location: location.map(Box::new),
+            }))
+        };
+
+        // RFC 5546, section 1.4
+        let name = component_name(component);
+        match component_type {
+            None => component_type = Some(name),
+            Some(first) if first != name => {
+                errors.push(ICalendarError {
+                    message: format!(
+                        "An iTIP message must only contain one type of component, found {first} and {name}"
+                    ),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: location(None),
+                });
+            }
+            _ => {}
+        }
+
+        let Some(table) = restrictions(component, method) else {
+            errors.push(ICalendarError {
+                message: format!("{name} is not allowed for METHOD:{}", method.name()),
+                severity: ICalendarErrorSeverity::Error,
+                location: location(None),
+            });
+            continue;
+        };
+
+        for (property_name, expectation) in table {
+            let mut occurrences = component
+                .properties()
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| component_property_name(p) == property_name);
+
+            let property_location = |property_index: usize| {
+                location(Some(WithinComponentLocation::Property(
+                    ComponentPropertyLocation {
+                        index: property_index,
+                        name: property_name.to_string(),
+                        property_location: None,
+                    },
+                )))
+            };
+
+            let error = match expectation {
+                Required | Once => match occurrences.next() {
+                    None => Some((
+                        format!("{property_name} is required for METHOD:{}", method.name()),
+                        location(None),
+                    )),
+                    Some(_) if expectation == Once => occurrences.next().map(|(i, _)| {
+                        (
+                            format!(
+                                "{property_name} must only appear once for METHOD:{}",
+                                method.name()
+                            ),
+                            property_location(i),
+                        )
+                    }),
+                    Some(_) => None,
+                },
+                Never => occurrences.next().map(|(i, _)| {
+                    (
+                        format!(
+                            "{property_name} is not allowed for METHOD:{}",
+                            method.name()
+                        ),
+                        property_location(i),
+                    )
+                }),
+            };
+
+            if let Some((message, location)) = error {
+                errors.push(ICalendarError {
+                    message,
+                    severity: ICalendarErrorSeverity::Error,
+                    location,
+                });
+            }
+        }
+
+        if !allows_alarms(method) {
+            if let Some((alarm_index, alarm)) = component
+                .nested_components()
+                .iter()
+                .enumerate()
+                .find(|(_, c)| matches!(c, CalendarComponent::Alarm(_)))
+            {
+                errors.push(ICalendarError {
+                    message: format!("VALARM is not allowed for METHOD:{}", method.name()),
+                    severity: ICalendarErrorSeverity::Error,
+                    location: location(Some(WithinComponentLocation::Component(
+                        ComponentLocation {
+                            index: alarm_index,
+                            name: component_name(alarm).to_string(),
+                            location: None,
+                        },
+                    ))),
+                });
+            }
+        }
+    }
+
+    errors
+}
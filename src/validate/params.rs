@@ -1,5 +1,10 @@
-use crate::common::{ParticipationStatusUnknown, PropertyKind};
-use crate::model::param::{Param, ParticipationStatusParam, SentByParam, TimeZoneIdParam};
+use crate::common::{
+    ParticipationStatusUnknown, PropertyKind, RelationshipType, ScheduleForceSend,
+};
+use crate::model::param::{
+    Param, ParticipationStatusParam, RelationshipTypeParam, ScheduleForceSendParam, SentByParam,
+    TimeZoneIdParam,
+};
 use crate::parser::param_value_participation_status;
 use crate::parser::Error;
 use crate::validate::error::ParamError;
@@ -252,7 +257,66 @@ pub(super) fn validate_params(params: &[Param], property_info: PropertyInfo) ->
             Param::Other { name, .. } | Param::Others { name, .. } if name == "ALTREP" => {
                 validate_alt_rep_param(&mut errors, &mut seen, param, index, &property_info);
             }
-            Param::Other { .. } | Param::Others { .. } => {
+            Param::Display { .. } => {
+                validate_display_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } | Param::Others { name, .. } if name == "DISPLAY" => {
+                validate_display_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Email { .. } => {
+                validate_email_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } | Param::Others { name, .. } if name == "EMAIL" => {
+                validate_email_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Feature { .. } => {
+                validate_feature_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } | Param::Others { name, .. } if name == "FEATURE" => {
+                validate_feature_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Label { .. } => {
+                validate_label_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } | Param::Others { name, .. } if name == "LABEL" => {
+                validate_label_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::ScheduleAgent { .. } => {
+                validate_schedule_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::ScheduleForceSend(ScheduleForceSendParam { force_send }) => {
+                validate_schedule_force_send_param(
+                    &mut errors,
+                    &mut seen,
+                    param,
+                    force_send,
+                    index,
+                    &property_info,
+                );
+            }
+            Param::ScheduleStatus { .. } => {
+                validate_schedule_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } | Param::Others { name, .. }
+                if name == "SCHEDULE-AGENT"
+                    || name == "SCHEDULE-FORCE-SEND"
+                    || name == "SCHEDULE-STATUS" =>
+            {
+                validate_schedule_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::LinkRelation { .. } => {
+                validate_link_relation_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } if name == "LINKREL" => {
+                validate_link_relation_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Gap { .. } => {
+                validate_gap_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { name, .. } if name == "GAP" => {
+                validate_gap_param(&mut errors, &mut seen, param, index, &property_info);
+            }
+            Param::Other { .. } | Param::Others { .. } | Param::XName { .. } => {
                 // Permit unknown parameters
             }
         }
@@ -269,7 +333,11 @@ fn validate_alt_rep_param(
     index: usize,
     property_info: &PropertyInfo,
 ) {
-    if !property_info.is_other && property_info.value_type != ValueType::Text {
+    // IMAGE is the exception to ALTREP applying to text values, RFC 7986 section 5.10
+    if !property_info.is_other
+        && property_info.value_type != ValueType::Text
+        && property_info.property_kind != PropertyKind::Image
+    {
         errors.push(ParamError {
             index,
             name: param_name(param).to_string(),
@@ -286,7 +354,10 @@ fn validate_alt_rep_param(
         | PropertyKind::Location
         | PropertyKind::Resources
         | PropertyKind::Summary
-        | PropertyKind::Contact => OccurrenceExpectation::OptionalOnce,
+        | PropertyKind::Contact
+        | PropertyKind::Name
+        | PropertyKind::Image
+        | PropertyKind::StyledDescription => OccurrenceExpectation::OptionalOnce,
         PropertyKind::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
@@ -438,7 +509,11 @@ fn validate_fmt_type_param(
     property_info: &PropertyInfo,
 ) {
     let occurrence_expectation = match property_info.property_kind {
-        PropertyKind::Attach => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Attach
+        | PropertyKind::Image
+        | PropertyKind::StyledDescription
+        | PropertyKind::StructuredData
+        | PropertyKind::Link => OccurrenceExpectation::OptionalOnce,
         PropertyKind::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
@@ -480,7 +555,11 @@ fn validate_language_param(
         | PropertyKind::Attendee
         | PropertyKind::Contact
         | PropertyKind::Organizer
-        | PropertyKind::RequestStatus => OccurrenceExpectation::OptionalOnce,
+        | PropertyKind::RequestStatus
+        | PropertyKind::Name
+        | PropertyKind::Conference
+        | PropertyKind::StyledDescription
+        | PropertyKind::Link => OccurrenceExpectation::OptionalOnce,
         PropertyKind::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
@@ -650,7 +729,11 @@ fn validate_relationship_type_param(
     index: usize,
     property_info: &PropertyInfo,
 ) {
-    if !property_info.is_other && property_info.value_type != ValueType::Text {
+    // RELATED-TO may refer to a URI, RFC 9253 section 9.1
+    if !property_info.is_other
+        && property_info.value_type != ValueType::Text
+        && property_info.property_kind != PropertyKind::Related
+    {
         errors.push(ParamError {
             index,
             name: param_name(param).to_string(),
@@ -661,6 +744,23 @@ fn validate_relationship_type_param(
         return;
     }
 
+    // Snoozed alarms are related to the alarm they snooze, RFC 9074 section 7.1
+    if let Param::RelationshipType(RelationshipTypeParam {
+        relationship: RelationshipType::Snooze,
+    }) = param
+    {
+        if property_info.property_location != PropertyLocation::Alarm
+            && property_info.property_location != PropertyLocation::Other
+        {
+            errors.push(ParamError {
+                index,
+                name: param_name(param).to_string(),
+                severity: ICalendarErrorSeverity::Error,
+                message: "Relationship type SNOOZE is only allowed in an alarm".to_string(),
+            });
+        }
+    }
+
     let occurrence_expectation = match property_info.property_kind {
         PropertyKind::Related => OccurrenceExpectation::OptionalOnce,
         PropertyKind::Other => OccurrenceExpectation::OptionalMany,
@@ -830,7 +930,168 @@ fn validate_value_type_param(
         | PropertyKind::RecurrenceId
         | PropertyKind::ExceptionDateTimes
         | PropertyKind::RecurrenceDateTimes
-        | PropertyKind::Trigger => OccurrenceExpectation::OptionalOnce,
+        | PropertyKind::Trigger
+        | PropertyKind::RefreshInterval
+        | PropertyKind::Image
+        | PropertyKind::Conference
+        | PropertyKind::StyledDescription
+        | PropertyKind::StructuredData
+        | PropertyKind::Related
+        | PropertyKind::Link => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 7986, Section 6.1
+fn validate_display_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Image => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 7986, Section 6.2
+fn validate_email_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    if !property_info.is_other && property_info.value_type != ValueType::CalendarAddress {
+        errors.push(ParamError {
+            index,
+            name: param_name(param).to_string(),
+            severity: ICalendarErrorSeverity::Error,
+            message: "Email (EMAIL) is not allowed for this property type".to_string(),
+        });
+        return;
+    }
+
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Attendee => attendee_common_expectation(property_info),
+        PropertyKind::Organizer => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 7986, Section 6.3
+fn validate_feature_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Conference => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 7986, Section 6.4
+fn validate_label_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Conference | PropertyKind::Link => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 6638, Section 7.1 and 7.3
+fn validate_schedule_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Attendee | PropertyKind::Organizer => OccurrenceExpectation::OptionalOnce,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 6638, Section 7.2
+fn validate_schedule_force_send_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    force_send: &ScheduleForceSend,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    // A REQUEST is sent to an attendee, and a REPLY is sent to the organizer
+    let message = match (force_send, &property_info.property_kind) {
+        (ScheduleForceSend::Request, PropertyKind::Organizer) => {
+            Some("SCHEDULE-FORCE-SEND=REQUEST is only valid on an ATTENDEE property")
+        }
+        (ScheduleForceSend::Reply, PropertyKind::Attendee) => {
+            Some("SCHEDULE-FORCE-SEND=REPLY is only valid on an ORGANIZER property")
+        }
+        _ => None,
+    };
+    if let Some(message) = message {
+        errors.push(ParamError {
+            index,
+            name: param_name(param).to_string(),
+            severity: ICalendarErrorSeverity::Error,
+            message: message.to_string(),
+        });
+    }
+
+    validate_schedule_param(errors, seen, param, index, property_info);
+}
+
+// RFC 9253, Section 6.1
+fn validate_link_relation_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Link => OccurrenceExpectation::Once,
+        PropertyKind::Other => OccurrenceExpectation::OptionalMany,
+        _ => OccurrenceExpectation::Never,
+    };
+    check_property_param_occurrence!(errors, seen, param, index, occurrence_expectation);
+}
+
+// RFC 9253, Section 6.2
+fn validate_gap_param(
+    errors: &mut Vec<ParamError>,
+    seen: &mut HashMap<String, u32>,
+    param: &Param,
+    index: usize,
+    property_info: &PropertyInfo,
+) {
+    let occurrence_expectation = match property_info.property_kind {
+        PropertyKind::Related => OccurrenceExpectation::OptionalOnce,
         PropertyKind::Other => OccurrenceExpectation::OptionalMany,
         _ => OccurrenceExpectation::Never,
     };
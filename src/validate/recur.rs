@@ -9,6 +9,28 @@ use crate::validate::{
 };
 use std::collections::HashMap;
 
+/// The calendar names defined by CLDR, which are the values registered for RSCALE in RFC 7529.
+const CLDR_CALENDARS: [&str; 18] = [
+    "BUDDHIST",
+    "CHINESE",
+    "COPTIC",
+    "DANGI",
+    "ETHIOAA",
+    "ETHIOPIC",
+    "GREGORIAN",
+    "HEBREW",
+    "INDIAN",
+    "ISLAMIC",
+    "ISLAMIC-CIVIL",
+    "ISLAMIC-RGSA",
+    "ISLAMIC-TBLA",
+    "ISLAMIC-UMALQURA",
+    "ISO8601",
+    "JAPANESE",
+    "PERSIAN",
+    "ROC",
+];
+
 pub(super) fn validate_recurrence_rule(
     errors: &mut Vec<ComponentPropertyError>,
     property: &ComponentProperty,
@@ -49,15 +71,20 @@ pub(super) fn validate_recurrence_rule(
 
             match maybe_freq {
                 Some((index, freq)) => {
-                    errors.push(ComponentPropertyError {
-                        message: "Recurrence rule must start with a frequency".to_string(),
-                        severity: ICalendarErrorSeverity::Warning,
-                        location: Some(ComponentPropertyLocation {
-                            index: property_index,
-                            name: component_property_name(property).to_string(),
-                            property_location: Some(WithinPropertyLocation::Value),
-                        }),
-                    });
+                    // RFC 7529 puts the calendar scale before the frequency
+                    let follows_rscale =
+                        index == 1 && matches!(rule.parts[0], RecurRulePart::RScale(_));
+                    if !follows_rscale {
+                        errors.push(ComponentPropertyError {
+                            message: "Recurrence rule must start with a frequency".to_string(),
+                            severity: ICalendarErrorSeverity::Warning,
+                            location: Some(ComponentPropertyLocation {
+                                index: property_index,
+                                name: component_property_name(property).to_string(),
+                                property_location: Some(WithinPropertyLocation::Value),
+                            }),
+                        });
+                    }
 
                     freq_index = index;
                     freq
@@ -85,7 +112,16 @@ pub(super) fn validate_recurrence_rule(
             .and_modify(|count| *count += 1)
             .or_insert(1)
     };
-    for (part_index, part) in rule.parts.iter().enumerate().skip(1) {
+    let rscale = rule.parts.iter().find_map(|part| match part {
+        RecurRulePart::RScale(rscale) => Some(rscale),
+        _ => None,
+    });
+    for (part_index, part) in rule
+        .parts
+        .iter()
+        .enumerate()
+        .filter(|(part_index, _)| *part_index != freq_index)
+    {
         match part {
             RecurRulePart::Freq(_) => {
                 if freq_index != part_index {
@@ -504,7 +540,7 @@ pub(super) fn validate_recurrence_rule(
                     });
                 }
             }
-            RecurRulePart::ByMonth(_) => {
+            RecurRulePart::ByMonth(month_list) => {
                 let count = add_count(&mut seen_count, "BYMONTH");
                 if count > 1 {
                     errors.push(ComponentPropertyError {
@@ -517,6 +553,95 @@ pub(super) fn validate_recurrence_rule(
                         }),
                     });
                 }
+
+                // Other calendar scales may have a 13th month
+                let max_month = if rscale.is_some() { 13 } else { 12 };
+                if !month_list
+                    .iter()
+                    .all(|month| 1 <= month.number && month.number <= max_month)
+                {
+                    errors.push(ComponentPropertyError {
+                        message: format!("Invalid BYMONTH part at index {part_index}, months must be between 1 and {max_month}"),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+
+                if rscale.is_none() && month_list.iter().any(|month| month.leap) {
+                    errors.push(ComponentPropertyError {
+                        message: format!("BYMONTH part at index {part_index} has a leap month, which is only valid with an RSCALE part"),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+            }
+            RecurRulePart::RScale(rscale) => {
+                let count = add_count(&mut seen_count, "RSCALE");
+                if count > 1 {
+                    errors.push(ComponentPropertyError {
+                        message: format!("Repeated RSCALE part at index {part_index}"),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+
+                let is_known = CLDR_CALENDARS
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(rscale))
+                    || rscale.to_ascii_uppercase().starts_with("X-");
+                if !is_known {
+                    errors.push(ComponentPropertyError {
+                        message: format!(
+                            "RSCALE part at index {part_index} is not a known calendar scale"
+                        ),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+            }
+            RecurRulePart::Skip(_) => {
+                let count = add_count(&mut seen_count, "SKIP");
+                if count > 1 {
+                    errors.push(ComponentPropertyError {
+                        message: format!("Repeated SKIP part at index {part_index}"),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
+
+                if rscale.is_none() {
+                    errors.push(ComponentPropertyError {
+                        message: format!(
+                            "SKIP part at index {part_index} is only valid with an RSCALE part"
+                        ),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: Some(WithinPropertyLocation::Value),
+                        }),
+                    });
+                }
             }
             RecurRulePart::WeekStart(_) => {
                 let count = add_count(&mut seen_count, "WKST");
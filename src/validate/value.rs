@@ -127,6 +127,12 @@ pub(super) fn check_declared_value(
                     ComponentProperty::Attach(attach) => {
                         require_base64(&attach.value, ICalendarErrorSeverity::Error);
                     }
+                    ComponentProperty::Image(image) => {
+                        require_base64(&image.value, ICalendarErrorSeverity::Error);
+                    }
+                    ComponentProperty::StructuredData(structured_data) => {
+                        require_base64(&structured_data.value, ICalendarErrorSeverity::Error);
+                    }
                     ComponentProperty::XProperty(x_prop) => {
                         require_base64(&x_prop.value, ICalendarErrorSeverity::Warning);
                     }
@@ -337,6 +343,7 @@ pub(super) fn check_declared_value(
                     ComponentProperty::DateTimeCompleted(_)
                     | ComponentProperty::DateTimeCreated(_)
                     | ComponentProperty::DateTimeStamp(_)
+                    | ComponentProperty::Acknowledged(_)
                     | ComponentProperty::DateTimeDue(_)
                     | ComponentProperty::RecurrenceId(_)
                     | ComponentProperty::ExceptionDateTimes(_)
@@ -763,9 +770,20 @@ pub(super) fn check_declared_value(
                     | ComponentProperty::Contact(_)
                     | ComponentProperty::UniqueIdentifier(_)
                     | ComponentProperty::Action(_)
-                    | ComponentProperty::RequestStatus(_) => {
+                    | ComponentProperty::RequestStatus(_)
+                    | ComponentProperty::Color(_)
+                    | ComponentProperty::BusyType(_)
+                    | ComponentProperty::LocationType(_)
+                    | ComponentProperty::ParticipantType(_)
+                    | ComponentProperty::ResourceType(_)
+                    | ComponentProperty::Proximity(_)
+                    | ComponentProperty::RelatedTo(_)
+                    | ComponentProperty::RefId(_) => {
                         push_redundant_error_msg(errors, property_index, property);
                     }
+                    // The value type is required for these properties, so it is not redundant
+                    ComponentProperty::StyledDescription(_)
+                    | ComponentProperty::StructuredData(_) => {}
                     ComponentProperty::XProperty(x_prop) => {
                         invalid = !is_text_valued(&x_prop.value);
                     }
@@ -907,6 +925,37 @@ pub(super) fn check_declared_value(
                         push_redundant_error_msg(errors, property_index, property);
                         require_uri(errors, &attach.value, ICalendarErrorSeverity::Error);
                     }
+                    ComponentProperty::Concept(concept) => {
+                        push_redundant_error_msg(errors, property_index, property);
+                        require_uri(errors, &concept.value, ICalendarErrorSeverity::Error);
+                    }
+                    // The value type is required for these properties, so it is not redundant
+                    ComponentProperty::RelatedTo(related_to) => {
+                        require_uri(errors, &related_to.value, ICalendarErrorSeverity::Error);
+                    }
+                    ComponentProperty::Link(link) => {
+                        require_uri(errors, &link.value, ICalendarErrorSeverity::Error);
+                    }
+                    ComponentProperty::Image(image) => {
+                        require_uri(errors, &image.value, ICalendarErrorSeverity::Error);
+                    }
+                    ComponentProperty::Conference(conference) => {
+                        require_uri(errors, &conference.value, ICalendarErrorSeverity::Error);
+                    }
+                    ComponentProperty::StyledDescription(styled_description) => {
+                        require_uri(
+                            errors,
+                            &styled_description.value,
+                            ICalendarErrorSeverity::Error,
+                        );
+                    }
+                    ComponentProperty::StructuredData(structured_data) => {
+                        require_uri(
+                            errors,
+                            &structured_data.value,
+                            ICalendarErrorSeverity::Error,
+                        );
+                    }
                     ComponentProperty::XProperty(x_prop) => {
                         require_uri(errors, &x_prop.value, ICalendarErrorSeverity::Warning);
                     }
@@ -1001,6 +1050,53 @@ pub(super) fn check_declared_value(
                     });
                 }
             }
+            Value::Uid => match property {
+                ComponentProperty::RelatedTo(_)
+                | ComponentProperty::Link(_)
+                | ComponentProperty::XProperty(_)
+                | ComponentProperty::IanaProperty(_) => {
+                    // A UID is free text, RFC 9253 section 9.1
+                }
+                _ => {
+                    errors.push(ComponentPropertyError {
+                        message: "Property is declared to have a UID value but that is not valid for this property".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: None,
+                        }),
+                    });
+                }
+            },
+            Value::XmlReference => match property {
+                ComponentProperty::Link(link) => {
+                    // An XML reference is a URI with an XPointer fragment, RFC 9253 section 9.2
+                    if !is_uri_valued(&link.value) {
+                        errors.push(ComponentPropertyError {
+                            message: "Property is declared to have an XML-REFERENCE value but the value is not a URI".to_string(),
+                            severity: ICalendarErrorSeverity::Error,
+                            location: Some(ComponentPropertyLocation {
+                                index: property_index,
+                                name: component_property_name(property).to_string(),
+                                property_location: Some(WithinPropertyLocation::Value),
+                            }),
+                        });
+                    }
+                }
+                ComponentProperty::XProperty(_) | ComponentProperty::IanaProperty(_) => {}
+                _ => {
+                    errors.push(ComponentPropertyError {
+                        message: "Property is declared to have an XML-REFERENCE value but that is not valid for this property".to_string(),
+                        severity: ICalendarErrorSeverity::Error,
+                        location: Some(ComponentPropertyLocation {
+                            index: property_index,
+                            name: component_property_name(property).to_string(),
+                            property_location: None,
+                        }),
+                    });
+                }
+            },
             Value::XName(_) | Value::IanaToken(_) => {
                 // Nothing to validate, we don't know anything about the values these should take
             }